@@ -0,0 +1,571 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    ccm_main();
+    eax_main();
+    siv_main();
+    gcm_siv_main();
+}
+
+fn ccm_main()
+{
+    ccm_rfc3610_packet_vector_1();
+    ccm_aes_256();
+    ccm_empty_message();
+    ccm_long_aad();
+    ccm_tampered();
+    ccm_star_encryption_only();
+}
+
+fn eax_main()
+{
+    eax_empty_message();
+    eax_encrypt_decrypt();
+    eax_short_tag();
+    eax_des();
+}
+
+fn siv_main()
+{
+    siv_rfc5297_deterministic();
+    siv_rfc5297_nonce_based();
+    siv_aes_256();
+    siv_empty_message();
+    siv_nonce_reuse();
+}
+
+fn gcm_siv_main()
+{
+    gcm_siv_rfc8452_aes_128();
+    gcm_siv_rfc8452_aes_256();
+    gcm_siv_encrypt_decrypt();
+    gcm_siv_tampered();
+}
+
+fn ccm_rfc3610_packet_vector_1()
+{
+    println!("ccm_rfc3610_packet_vector_1");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = 0xC0 + i as u8; }
+    let nonce = [0x00_u8, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0xA0, 0xA1, 0xA2, 0xA3, 0xA4, 0xA5];
+    let mut packet = [0_u8; 31];
+    for (i, p) in packet.iter_mut().enumerate()
+        { *p = i as u8; }
+    let mut ccm = AES_128_CCM::<8, 13>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm.encrypt_into_vec(nonce, &packet[..8], &packet[8..], &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "588C979A61C663D2F066D0C2C0F989806D5F6B61DAC38417E8D12CFDF926E0");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(ccm.decrypt_into_vec(nonce, &packet[..8], &cipher, tag, &mut recovered));
+    assert_eq!(recovered, &packet[8..]);
+    println!("-------------------------------");
+}
+
+fn ccm_aes_256()
+{
+    println!("ccm_aes_256");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_256, AES_256_CCM };
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let nonce = [0_u8; 12];
+    let message = "The quick brown fox jumps over the lazy dog.";
+    println!("M =\t{}", message);
+    let mut ccm = AES_256_CCM::<16, 12>::new(AES_256::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm.encrypt_into_vec(nonce, "header".as_bytes(), message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "22ED1F3D4C6D164ED2324089B35A62048001934090D005CDDE9D0162E9F4D776396948ACB781F9332E197F140749084BE9ABD27E65C9B4CDA1201FD2");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(ccm.decrypt_into_vec(nonce, "header".as_bytes(), &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn ccm_empty_message()
+{
+    println!("ccm_empty_message");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut ccm = AES_128_CCM::<16, 13>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm.encrypt_into_vec([0_u8; 13], "only header".as_bytes(), &[], &mut cipher);
+    assert!(cipher.is_empty());
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "E416B48C85E5E5FA246B5B04A6DA24BE");
+    println!("-------------------------------");
+}
+
+fn ccm_long_aad()
+{
+    println!("ccm_long_aad");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let nonce = [0_u8, 1, 2, 3, 4, 5, 6, 7];
+    let mut aad = vec![0_u8; 70000];
+    for (i, a) in aad.iter_mut().enumerate()
+        { *a = i as u8; }
+    let mut ccm = AES_128_CCM::<8, 8>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm.encrypt_into_vec(nonce, &aad, "abc".as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "6C951922B652F71B99E488");
+    println!("-------------------------------");
+}
+
+fn ccm_tampered()
+{
+    println!("ccm_tampered");
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+
+    let mut ccm = AES_128_CCM::<12, 11>::new(AES_128::new_with_key(&[0x2B_u8; 16]));
+    let nonce = [0x01_u8; 11];
+    let message = "Transfer 100 dollars to Alice.";
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm.encrypt_into_vec(nonce, &[], message.as_bytes(), &mut cipher);
+
+    cipher[9] ^= 0x01;
+    let mut recovered = Vec::<u8>::new();
+    assert!(!ccm.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+
+    cipher[9] ^= 0x01;
+    let mut wrong_tag = tag;
+    wrong_tag[11] ^= 0x80;
+    assert!(!ccm.decrypt_into_vec(nonce, &[], &cipher, wrong_tag, &mut recovered));
+    assert!(ccm.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn ccm_star_encryption_only()
+{
+    println!("ccm_star_encryption_only");
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM, AES_128_CCM_Star };
+
+    let key = [0xC0_u8; 16];
+    let nonce = [0xAC_u8; 13];
+    let message = "802.15.4 frame payload";
+
+    // CCM* with the tag of zero length only encrypts the message, and the
+    // ciphertext is the same as that of CCM with the same nonce.
+    let mut ccm_star = AES_128_CCM_Star::<0>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = ccm_star.encrypt_into_vec(nonce, &[], message.as_bytes(), &mut cipher);
+    assert_eq!(tag.len(), 0);
+    let mut ccm = AES_128_CCM::<8, 13>::new(AES_128::new_with_key(&key));
+    let mut ccm_cipher = Vec::<u8>::new();
+    ccm.encrypt_into_vec(nonce, &[], message.as_bytes(), &mut ccm_cipher);
+    assert_eq!(cipher, ccm_cipher);
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(ccm_star.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+
+    // CCM* with the tag of non-zero length is CCM.
+    let mut ccm_star = AES_128_CCM_Star::<8>::new(AES_128::new_with_key(&key));
+    let mut star_cipher = Vec::<u8>::new();
+    let star_tag = ccm_star.encrypt_into_vec(nonce, "header".as_bytes(), message.as_bytes(), &mut star_cipher);
+    let tag = ccm.encrypt_into_vec(nonce, "header".as_bytes(), message.as_bytes(), &mut ccm_cipher);
+    assert_eq!(star_tag, tag);
+
+    #[cfg(test)] // It will panic.
+    ccm_should_panic_zero_tag();
+    #[cfg(test)] // It will panic.
+    ccm_star_should_panic_six_byte_tag();
+    println!("-------------------------------");
+}
+
+#[test]
+#[should_panic]
+fn ccm_should_panic_zero_tag()
+{
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+
+    // Only CCM* allows the tag of zero length.
+    let _ccm = AES_128_CCM::<0, 13>::new(AES_128::new_with_key(&[0xC0_u8; 16]));
+}
+
+#[test]
+#[should_panic]
+fn ccm_star_should_panic_six_byte_tag()
+{
+    use cryptocol::symmetric::{ AES_128, AES_128_CCM_Star };
+
+    // CCM* allows only the tags of 0, 4, 8 and 16 bytes.
+    let _ccm_star = AES_128_CCM_Star::<6>::new(AES_128::new_with_key(&[0xC0_u8; 16]));
+}
+
+fn eax_empty_message()
+{
+    println!("eax_empty_message");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_EAX };
+
+    let key = [ 0x23_u8, 0x39, 0x52, 0xDE, 0xE4, 0xD5, 0xED, 0x5F,
+                0x9B, 0x9C, 0x6D, 0x6F, 0xF8, 0x0F, 0xF4, 0x78 ];
+    let nonce = [ 0x62_u8, 0xEC, 0x67, 0xF9, 0xC3, 0xA4, 0xA4, 0x07,
+                  0xFC, 0xB2, 0xA8, 0xC4, 0x90, 0x31, 0xA8, 0xB3 ];
+    let header = [ 0x6B_u8, 0xFB, 0x91, 0x4F, 0xD0, 0x7E, 0xAE, 0x6B ];
+    let mut eax = AES_128_EAX::<16>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = eax.encrypt_into_vec(&nonce, &header, &[], &mut cipher);
+    assert!(cipher.is_empty());
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "E037830E8389F27B025A2D6527E79D01");
+    println!("-------------------------------");
+}
+
+fn eax_encrypt_decrypt()
+{
+    println!("eax_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_EAX };
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let message = "The quick brown fox jumps over the lazy dog.";
+    println!("M =\t{}", message);
+    let mut eax = AES_128_EAX::<16>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let tag = eax.encrypt_into_vec("nonce".as_bytes(), "header".as_bytes(), message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "3482A1DD1AC3B7D4E7CF8614D1A9E8CBB075B865970B31C4E59F58F3E7188F9C43DA56AD1DB45192EF16C459D32D8B62A614D2FE235628EB72E1E692");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(eax.decrypt_into_vec("nonce".as_bytes(), "header".as_bytes(), &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(!eax.decrypt_into_vec("nonce".as_bytes(), "Header".as_bytes(), &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+    println!("-------------------------------");
+}
+
+fn eax_short_tag()
+{
+    println!("eax_short_tag");
+    use cryptocol::symmetric::{ AES_128, AES_128_EAX };
+
+    let key = [0x55_u8; 16];
+    let mut eax_full = AES_128_EAX::<16>::new(AES_128::new_with_key(&key));
+    let mut eax_short = AES_128_EAX::<8>::new(AES_128::new_with_key(&key));
+    let mut cipher = Vec::<u8>::new();
+    let full_tag = eax_full.encrypt_into_vec(&[1, 2, 3], &[], "sensor 17: 21.5C".as_bytes(), &mut cipher);
+    let short_tag = eax_short.encrypt_into_vec(&[1, 2, 3], &[], "sensor 17: 21.5C".as_bytes(), &mut cipher);
+    assert_eq!(full_tag[..8], short_tag);
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(eax_short.decrypt_into_vec(&[1, 2, 3], &[], &cipher, short_tag, &mut recovered));
+    assert_eq!(recovered, "sensor 17: 21.5C".as_bytes());
+    println!("-------------------------------");
+}
+
+fn eax_des()
+{
+    println!("eax_des");
+    use cryptocol::symmetric::{ DES, EAX_Generic };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let mut eax = EAX_Generic::<DES, 8>::new(DES::new_with_key(key));
+    let message = "EAX works with 64-bit block ciphers, too.";
+    let mut cipher = Vec::<u8>::new();
+    let tag = eax.encrypt_into_vec("nonce".as_bytes(), &[], message.as_bytes(), &mut cipher);
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(eax.decrypt_into_vec("nonce".as_bytes(), &[], &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn siv_rfc5297_deterministic()
+{
+    println!("siv_rfc5297_deterministic");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_SIV };
+
+    let mut key1 = [0_u8; 16];
+    let mut key2 = [0_u8; 16];
+    for i in 0..16
+    {
+        key1[i] = 0xFF - i as u8;
+        key2[i] = 0xF0 + i as u8;
+    }
+    let mut aad = [0_u8; 24];
+    for (i, a) in aad.iter_mut().enumerate()
+        { *a = 0x10 + i as u8; }
+    let key_data = [0x11_u8, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE];
+    let mut siv = AES_128_SIV::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let mut wrapped = Vec::<u8>::new();
+    siv.wrap_key_into_vec(&[&aad], &key_data, &mut wrapped);
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "85632D07C6E8F37F950ACD320A2ECC9340C02B9690C4DC04DAEF7F6AFE5C");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(siv.unwrap_key_into_vec(&[&aad], &wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+
+    wrapped[20] ^= 0x04;
+    assert!(!siv.unwrap_key_into_vec(&[&aad], &wrapped, &mut unwrapped));
+    assert!(unwrapped.is_empty());
+    println!("-------------------------------");
+}
+
+fn siv_rfc5297_nonce_based()
+{
+    println!("siv_rfc5297_nonce_based");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_SIV };
+
+    let mut key1 = [0_u8; 16];
+    let mut key2 = [0_u8; 16];
+    for i in 0..16
+    {
+        key1[i] = 0x7F - i as u8;
+        key2[i] = 0x40 + i as u8;
+    }
+    let ad1 = [ 0x00_u8, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+                0xDE, 0xAD, 0xDA, 0xDA, 0xDE, 0xAD, 0xDA, 0xDA, 0xFF, 0xEE, 0xDD, 0xCC, 0xBB, 0xAA, 0x99, 0x88,
+                0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00 ];
+    let ad2 = [ 0x10_u8, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80, 0x90, 0xA0 ];
+    let nonce = [ 0x09_u8, 0xF9, 0x11, 0x02, 0x9D, 0x74, 0xE3, 0x5B, 0xD8, 0x41, 0x56, 0xC5, 0x63, 0x56, 0x88, 0xC0 ];
+    let message = "this is some plaintext to encrypt using SIV-AES";
+    println!("M =\t{}", message);
+    let mut siv = AES_128_SIV::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let mut cipher = Vec::<u8>::new();
+    let v = siv.encrypt_into_vec(&[&ad1, &ad2, &nonce], message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in v.iter().chain(cipher.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "7BDB6E3B432667EB06F4D14BFF2FBD0FCB900F2FDDBE404326601965C889BF17DBA77CEB094FA663B7A3F748BA8AF829EA64AD544A272E9C485B62A3FD5C0D");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(siv.decrypt_into_vec(&[&ad1, &ad2, &nonce], &cipher, v, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+
+    // The order of the associated data matters.
+    let mut recovered = Vec::<u8>::new();
+    assert!(!siv.decrypt_into_vec(&[&ad2, &ad1, &nonce], &cipher, v, &mut recovered));
+    println!("-------------------------------");
+}
+
+fn siv_aes_256()
+{
+    println!("siv_aes_256");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_256, AES_256_SIV };
+
+    let mut key1 = [0_u8; 32];
+    let mut key2 = [0_u8; 32];
+    for i in 0..32
+    {
+        key1[i] = i as u8;
+        key2[i] = 32 + i as u8;
+    }
+    let message = "The quick brown fox jumps over the lazy dog.";
+    let mut siv = AES_256_SIV::new(AES_256::new_with_key(&key1), AES_256::new_with_key(&key2));
+    let mut cipher = Vec::<u8>::new();
+    let v = siv.encrypt_into_vec(&["header".as_bytes()], message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in v.iter().chain(cipher.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "EFFA3DBBA6F3673C48DE62C96E6BF8701D455B05B66770AE2A72326215A5743EFAF70D28CCBD67DAB43133D8D62828B09FEC8F8DD6A6E58398DB90A3");
+    println!("-------------------------------");
+}
+
+fn siv_empty_message()
+{
+    println!("siv_empty_message");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_SIV };
+
+    let mut key1 = [0_u8; 16];
+    let mut key2 = [0_u8; 16];
+    for i in 0..16
+    {
+        key1[i] = 0x7F - i as u8;
+        key2[i] = 0x40 + i as u8;
+    }
+    let mut siv = AES_128_SIV::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let mut cipher = Vec::<u8>::new();
+    let v = siv.encrypt_into_vec(&["x".as_bytes()], &[], &mut cipher);
+    assert!(cipher.is_empty());
+    let mut txt = String::new();
+    for c in v
+        { write!(txt, "{:02X}", c); }
+    println!("V =\t{}", txt);
+    assert_eq!(txt, "26F3BFDE8DC23EB173A0ED621B9E0C13");
+    println!("-------------------------------");
+}
+
+fn siv_nonce_reuse()
+{
+    println!("siv_nonce_reuse");
+    use cryptocol::symmetric::{ AES_128, AES_128_SIV };
+
+    let mut siv = AES_128_SIV::new(AES_128::new_with_key(&[1_u8; 16]), AES_128::new_with_key(&[2_u8; 16]));
+    let nonce = [0_u8; 12];
+    let mut cipher1 = Vec::<u8>::new();
+    let mut cipher2 = Vec::<u8>::new();
+    let mut cipher3 = Vec::<u8>::new();
+    let v1 = siv.encrypt_into_vec(&[&nonce], "attack at dawn".as_bytes(), &mut cipher1);
+    let v2 = siv.encrypt_into_vec(&[&nonce], "attack at dusk".as_bytes(), &mut cipher2);
+    let v3 = siv.encrypt_into_vec(&[&nonce], "attack at dawn".as_bytes(), &mut cipher3);
+    // The repeated nonce reveals only that the same message was encrypted.
+    assert_ne!(v1, v2);
+    assert_ne!(cipher1[..9], cipher2[..9]);
+    assert_eq!(v1, v3);
+    assert_eq!(cipher1, cipher3);
+    println!("-------------------------------");
+}
+
+fn gcm_siv_rfc8452_aes_128()
+{
+    println!("gcm_siv_rfc8452_aes_128");
+    use std::fmt::Write;
+    use cryptocol::symmetric::AES_128_GCM_SIV;
+
+    let mut key = [0_u8; 16];
+    key[0] = 1;
+    let mut nonce = [0_u8; 12];
+    nonce[0] = 3;
+    let mut aead = AES_128_GCM_SIV::new_with_key(&key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &[], &[], &mut cipher);
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "DC20E2D83F25705BB49E439ECA56DE25");
+    println!("-------------------------------");
+}
+
+fn gcm_siv_rfc8452_aes_256()
+{
+    println!("gcm_siv_rfc8452_aes_256");
+    use std::fmt::Write;
+    use cryptocol::symmetric::AES_256_GCM_SIV;
+
+    let mut key = [0_u8; 32];
+    key[0] = 1;
+    let mut nonce = [0_u8; 12];
+    nonce[0] = 3;
+    let aad = [0x01_u8];
+    let mut message = [0_u8; 32];
+    message[0] = 2;
+    message[16] = 3;
+    let mut aead = AES_256_GCM_SIV::new_with_key(&key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &aad, &message, &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "07DAD364BFC2B9DA89116D7BEF6DAAAF6F255510AA654F920AC81B94E8BAD365AEA1BAD12702E1965604374AAB96DBBC");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn gcm_siv_encrypt_decrypt()
+{
+    println!("gcm_siv_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::AES_128_GCM_SIV;
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut nonce = [0_u8; 12];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = i as u8; }
+    let message = "The quick brown fox jumps over the lazy dog.";
+    println!("M =\t{}", message);
+    let mut aead = AES_128_GCM_SIV::new_with_key(&key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, "header".as_bytes(), message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "A3B4D4CF403F108DF35F01E1735624B02B14BBC4F8B4EB2FA96F7930BEF17638579005AF995A8F77ADD087EFC3D9B270C4897528C7B61CE247421BB6");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, "header".as_bytes(), &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn gcm_siv_tampered()
+{
+    println!("gcm_siv_tampered");
+    use cryptocol::symmetric::AES_256_GCM_SIV;
+
+    let mut aead = AES_256_GCM_SIV::new_with_key(&[0x42_u8; 32]);
+    let nonce = [0x24_u8; 12];
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &[], "nonce reuse is survivable".as_bytes(), &mut cipher);
+    let mut recovered = Vec::<u8>::new();
+    let mut wrong_nonce = nonce;
+    wrong_nonce[11] ^= 1;
+    assert!(!aead.decrypt_into_vec(wrong_nonce, &[], &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+    cipher[0] ^= 0x80;
+    assert!(!aead.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+    println!("-------------------------------");
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    aes_main();
+}
+
+fn aes_main()
+{
+    aes_128_encrypt_u128();
+    aes_192_encrypt_u128();
+    aes_256_encrypt_u128();
+    aes_encrypt_array_u128();
+    aes_block_cipher_trait();
+}
+
+fn aes_128_encrypt_u128()
+{
+    println!("aes_128_encrypt_u128");
+    use cryptocol::symmetric::AES_128;
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut a_aes = AES_128::new_with_key(&key);
+    let message = 0x00112233445566778899AABBCCDDEEFF_u128.swap_bytes();
+    let cipher = a_aes.encrypt_u128(message);
+    println!("C =\t{:032X}", cipher.swap_bytes());
+    assert_eq!(format!("{:032X}", cipher.swap_bytes()), "69C4E0D86A7B0430D8CDB78070B4C55A");
+    let recovered = a_aes.decrypt_u128(cipher);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn aes_192_encrypt_u128()
+{
+    println!("aes_192_encrypt_u128");
+    use cryptocol::symmetric::AES_192;
+
+    let mut key = [0_u8; 24];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut a_aes = AES_192::new_with_key(&key);
+    let message = 0x00112233445566778899AABBCCDDEEFF_u128.swap_bytes();
+    let cipher = a_aes.encrypt_u128(message);
+    println!("C =\t{:032X}", cipher.swap_bytes());
+    assert_eq!(format!("{:032X}", cipher.swap_bytes()), "DDA97CA4864CDFE06EAF70A0EC0D7191");
+    let recovered = a_aes.decrypt_u128(cipher);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn aes_256_encrypt_u128()
+{
+    println!("aes_256_encrypt_u128");
+    use cryptocol::symmetric::AES_256;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut a_aes = AES_256::new_with_key(&key);
+    let message = 0x00112233445566778899AABBCCDDEEFF_u128.swap_bytes();
+    let cipher = a_aes.encrypt_u128(message);
+    println!("C =\t{:032X}", cipher.swap_bytes());
+    assert_eq!(format!("{:032X}", cipher.swap_bytes()), "8EA2B7CA516745BFEAFC49904B496089");
+    let recovered = a_aes.decrypt_u128(cipher);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn aes_encrypt_array_u128()
+{
+    println!("aes_encrypt_array_u128");
+    use cryptocol::symmetric::AES_128;
+
+    let mut key = [0_u8; 16];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut a_aes = AES_128::new_with_key(&key);
+    let message = [0x00112233445566778899AABBCCDDEEFF_u128.swap_bytes(); 3];
+    let mut cipher = [0_u128; 3];
+    a_aes.encrypt_array_u128(&message, &mut cipher);
+    for c in cipher
+        { assert_eq!(format!("{:032X}", c.swap_bytes()), "69C4E0D86A7B0430D8CDB78070B4C55A"); }
+    let mut recovered = [0_u128; 3];
+    a_aes.decrypt_array_u128(&cipher, &mut recovered);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn aes_block_cipher_trait()
+{
+    println!("aes_block_cipher_trait");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ BlockCipher, AES_256 };
+
+    let key = [0_u8; 32];
+    let mut a_aes = AES_256::new_with_key(&key);
+    let mut block = [0_u8; 16];
+    a_aes.encrypt_one_block(&mut block);
+    let mut txt = String::new();
+    for b in block
+        { write!(txt, "{:02X}", b); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "DC95C078A2408989AD48A21492842087");
+    a_aes.decrypt_one_block(&mut block);
+    assert_eq!(block, [0_u8; 16]);
+    println!("-------------------------------");
+}
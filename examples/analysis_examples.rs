@@ -0,0 +1,294 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    sbox_main();
+    des_trail_main();
+    avalanche_main();
+}
+
+fn sbox_main()
+{
+    sbox_des();
+    sbox_aes();
+    sbox_small();
+}
+
+fn des_trail_main()
+{
+    des_trail_differential();
+    des_trail_linear();
+    des_trail_modified_sbox();
+}
+
+fn avalanche_main()
+{
+    avalanche_hash();
+    avalanche_block_cipher();
+    avalanche_key_schedule();
+    avalanche_custom_function();
+}
+
+// The S-box of AES made of the inverse in GF(2^8) and the affine transform
+fn aes_sbox() -> Vec<u8>
+{
+    fn mul(mut a: u8, mut b: u8) -> u8
+    {
+        let mut p = 0;
+        while b != 0
+        {
+            if b & 1 == 1
+                { p ^= a; }
+            a = (a << 1) ^ if a & 0x80 != 0 { 0x1B } else { 0 };
+            b >>= 1;
+        }
+        p
+    }
+
+    (0..=255_u8).map(|x| {
+            let inverse = (1..=255_u8).find(|y| mul(x, *y) == 1).unwrap_or(0);
+            inverse ^ inverse.rotate_left(1) ^ inverse.rotate_left(2) ^ inverse.rotate_left(3) ^ inverse.rotate_left(4) ^ 0x63
+        }).collect()
+}
+
+fn sbox_des()
+{
+    println!("sbox_des");
+    use cryptocol::analysis::SBox;
+    use cryptocol::symmetric::DES;
+
+    let nonlinearity = [14, 16, 16, 16, 12, 18, 14, 16];
+    for (i, s) in DES::get_sbox_table().iter().enumerate()
+    {
+        let sbox = SBox::from_des(s);
+        let properties = sbox.get_properties();
+        println!("S{}\n{}", i + 1, properties);
+        assert_eq!(properties.differential_uniformity, 16);
+        assert_eq!(properties.nonlinearity, nonlinearity[i]);
+        assert_eq!(properties.algebraic_degree, 5);
+        assert_eq!(properties.differential_branch_number, 2);
+        assert!(!sbox.is_permutation());
+
+        // Every row of DDT sums up to 64, and no nonzero input mask is
+        // correlated with the output mask zero.
+        assert!(sbox.get_ddt().iter().all(|row| row.iter().sum::<u32>() == 64));
+        assert!(sbox.get_lat().iter().skip(1).all(|row| row[0] == 0));
+    }
+    println!("-------------------------------");
+}
+
+fn sbox_aes()
+{
+    println!("sbox_aes");
+    use cryptocol::analysis::SBox;
+
+    let table = aes_sbox();
+    assert_eq!(table[..4], [0x63, 0x7C, 0x77, 0x7B]);
+    let sbox = SBox::new(&table, 8, 8);
+    let properties = sbox.get_properties();
+    println!("{}", properties);
+    assert!(sbox.is_permutation());
+    assert_eq!(properties.differential_uniformity, 4);
+    assert_eq!(properties.nonlinearity, 112);
+    assert_eq!(properties.best_linear_bias.abs(), 16);
+    assert_eq!(properties.algebraic_degree, 7);
+    println!("-------------------------------");
+}
+
+fn sbox_small()
+{
+    println!("sbox_small");
+    use cryptocol::analysis::SBox;
+
+    // The S-box of PRESENT
+    let present = SBox::new(&[0xC, 0x5, 0x6, 0xB, 0x9, 0x0, 0xA, 0xD, 0x3, 0xE, 0xF, 0x8, 0x4, 0x7, 0x1, 0x2], 4, 4);
+    println!("PRESENT\n{}", present.get_properties());
+    assert_eq!(present.get_differential_uniformity(), 4);
+    assert_eq!(present.get_nonlinearity(), 4);
+    assert_eq!(present.get_differential_branch_number(), 3);
+
+    // The identity is linear, so that it is the weakest.
+    let table: Vec<u8> = (0..16).collect();
+    let identity = SBox::new(&table, 4, 4);
+    println!("Identity\n{}", identity.get_properties());
+    assert_eq!(identity.get_differential_uniformity(), 16);
+    assert_eq!(identity.get_nonlinearity(), 0);
+    assert_eq!(identity.get_algebraic_degree(), 1);
+    assert_eq!(identity.get_differential_branch_number(), 2);
+    assert_eq!(identity.get_linear_branch_number(), 2);
+    println!("-------------------------------");
+}
+
+fn des_trail_differential()
+{
+    println!("des_trail_differential");
+    use cryptocol::analysis::DES_TrailSearch;
+
+    let search = DES_TrailSearch::new();
+    for (rounds, weight) in [(1, 0.0), (2, 2.0), (3, 4.0)]
+    {
+        let characteristic = search.search_differential(rounds);
+        println!("{}", characteristic);
+        assert!(!characteristic.is_linear());
+        assert_eq!(characteristic.get_rounds(), rounds);
+        assert!((characteristic.get_weight() - weight).abs() < 1e-9);
+    }
+
+    // The characteristic is consistent from round to round.
+    let characteristic = search.search_differential(3);
+    let (left, right) = characteristic.get_input();
+    let round = characteristic.get_round_function();
+    assert_eq!(right, round[0].0);
+    assert_eq!(round[1].0, left ^ round[0].1);
+    assert_eq!(round[2].0, right ^ round[1].1);
+    assert_eq!(characteristic.get_output(), (round[2].0, round[1].0 ^ round[2].1));
+    println!("-------------------------------");
+}
+
+fn des_trail_linear()
+{
+    println!("des_trail_linear");
+    use cryptocol::analysis::DES_TrailSearch;
+
+    // The biases of the best linear characteristics by Matsui
+    let search = DES_TrailSearch::new();
+    for (rounds, bias) in [(3, 1.95e-1), (4, 6.10e-2), (5, 1.91e-2)]
+    {
+        let characteristic = search.search_linear(rounds);
+        println!("{}", characteristic);
+        assert!(characteristic.is_linear());
+        assert!((characteristic.get_bias().abs() - bias).abs() < 1e-3);
+    }
+    println!("-------------------------------");
+}
+
+fn des_trail_modified_sbox()
+{
+    println!("des_trail_modified_sbox");
+    use cryptocol::analysis::DES_TrailSearch;
+    use cryptocol::symmetric::DES;
+
+    // Replacing all the S-boxes with the fifth one, whose linear
+    // approximation is the best, does not make the linear characteristics
+    // of three rounds better, while replacing them with the sixth one does
+    // make them worse.
+    let original = DES_TrailSearch::new().search_linear(3).get_weight();
+    let sbox = DES::get_sbox_table();
+    let s5 = DES_TrailSearch::new_with_tables([sbox[4]; 8], DES::get_expansion_table(), DES::get_translation_table());
+    let s6 = DES_TrailSearch::new_with_tables([sbox[5]; 8], DES::get_expansion_table(), DES::get_translation_table());
+    let weight5 = s5.search_linear(3).get_weight();
+    let weight6 = s6.search_linear(3).get_weight();
+    println!("Original = {:.4}\nAll S5 = {:.4}\nAll S6 = {:.4}", original, weight5, weight6);
+    assert!((weight5 - original).abs() < 1e-9);
+    assert!(weight6 > original);
+    println!("-------------------------------");
+}
+
+fn avalanche_hash()
+{
+    println!("avalanche_hash");
+    use cryptocol::analysis::AvalancheAnalyzer;
+    use cryptocol::hash::{ MD4_Expanded, SHA2_256 };
+
+    let mut analyzer = AvalancheAnalyzer::new();
+    let mut sha = SHA2_256::new();
+    let report = analyzer.analyze(32, 32, 64, |message, hash| {
+            sha.digest(message.as_ptr(), message.len() as u64);
+            sha.get_hash_value(hash.as_mut_ptr(), hash.len());
+        });
+    println!("SHA2_256\n{}", report);
+    assert_eq!(report.sac.len(), 256);
+    assert!((report.avalanche - 0.5).abs() < 0.01);
+    assert!(report.sac_max_deviation < 0.4);
+
+    // MD4 of only the first 8 steps of 48 steps does not diffuse well.
+    let mut md4_8 = MD4_Expanded::<4, 0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 8>::new();
+    let report = analyzer.analyze(16, 16, 64, |message, hash| {
+            md4_8.digest(message.as_ptr(), message.len() as u64);
+            md4_8.get_hash_value(hash.as_mut_ptr(), hash.len());
+        });
+    println!("MD4 of 8 steps\n{}", report);
+    assert!(report.avalanche < 0.4);
+    assert_eq!(report.sac_max_deviation, 0.5);
+    println!("-------------------------------");
+}
+
+fn avalanche_block_cipher()
+{
+    println!("avalanche_block_cipher");
+    use cryptocol::analysis::AvalancheAnalyzer;
+    use cryptocol::symmetric::{ AES_128, DES, DES_Generic };
+
+    let mut analyzer = AvalancheAnalyzer::new();
+    let aes = analyzer.analyze_block_cipher(&mut AES_128::new_with_key("AES-128 test key".as_bytes()), 64);
+    println!("AES_128\n{}", aes);
+    assert!((aes.avalanche - 0.5).abs() < 0.01);
+    assert!(aes.sac_max_deviation < 0.4);
+
+    let des16 = analyzer.analyze_block_cipher(&mut DES::new_with_key_u64(0x_1234567890ABCDEF_u64), 64);
+    let des2 = analyzer.analyze_block_cipher(&mut DES_Generic::<2>::new_with_key_u64(0x_1234567890ABCDEF_u64), 64);
+    println!("DES of 16 rounds\n{}\nDES of 2 rounds\n{}", des16, des2);
+    assert!((des16.avalanche - 0.5).abs() < 0.02);
+    assert!(des2.avalanche < 0.3);
+    assert_eq!(des2.sac_max_deviation, 0.5);
+    assert_eq!(des2.bic_max_correlation.abs(), 1.0);
+    println!("-------------------------------");
+}
+
+fn avalanche_key_schedule()
+{
+    println!("avalanche_key_schedule");
+    use cryptocol::analysis::AvalancheAnalyzer;
+    use cryptocol::symmetric::{ AES_128, DES };
+
+    let mut analyzer = AvalancheAnalyzer::new();
+    let des = analyzer.analyze_key_schedule(&mut DES::new(), 32);
+    println!("DES\n{}", des);
+    // The parity bits of the key of DES do not affect the cipher-text.
+    for i in 0..64
+    {
+        if i % 8 == 7
+            { assert!(des.sac[i].iter().all(|p| *p == 0.0)); }
+        else
+            { assert!(des.sac[i].iter().any(|p| *p != 0.0)); }
+    }
+
+    let aes = analyzer.analyze_key_schedule(&mut AES_128::new(), 32);
+    println!("AES_128\n{}", aes);
+    assert_eq!(aes.input_bits, 128);
+    assert!((aes.avalanche - 0.5).abs() < 0.02);
+    println!("-------------------------------");
+}
+
+fn avalanche_custom_function()
+{
+    println!("avalanche_custom_function");
+    use cryptocol::analysis::AvalancheAnalyzer;
+
+    // A linear function always changes the same output bits.
+    let mut analyzer = AvalancheAnalyzer::new();
+    let report = analyzer.analyze(4, 4, 64, |input, output| {
+            let x = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+            output.copy_from_slice(&(x ^ x.rotate_left(7) ^ x.rotate_left(19)).to_be_bytes());
+        });
+    println!("Linear\n{}", report);
+    assert_eq!(report.avalanche, 3.0 / 32.0);
+    assert!(report.sac.iter().flatten().all(|p| *p == 0.0 || *p == 1.0));
+
+    // The output bits of AND are biased towards zero.
+    let report = analyzer.analyze(2, 1, 256, |input, output| output[0] = input[0] & input[1]);
+    println!("AND\n{}", report);
+    assert!(report.bias.iter().all(|p| *p < 0.4));
+    assert!(report.bias_max_deviation > 0.1);
+    println!("-------------------------------");
+}
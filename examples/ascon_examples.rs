@@ -0,0 +1,305 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    ascon_aead128_main();
+    ascon_hash256_main();
+    ascon_xof128_main();
+}
+
+fn ascon_aead128_main()
+{
+    ascon_aead128_known_answer();
+    ascon_aead128_encrypt_decrypt();
+    ascon_aead128_all_lengths();
+    ascon_aead128_forgery();
+    ascon_aead128_expanded();
+}
+
+fn ascon_hash256_main()
+{
+    ascon_hash256_known_answer();
+    ascon_hash256_pointer_and_slices();
+    ascon_hash256_expanded();
+}
+
+fn ascon_xof128_main()
+{
+    ascon_xof128_known_answer();
+    ascon_xof128_squeeze();
+}
+
+const MESSAGE: &str = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn key_and_nonce() -> ([u8; 16], [u8; 16])
+{
+    let mut key = [0_u8; 16];
+    let mut nonce = [0_u8; 16];
+    for i in 0..16
+    {
+        key[i] = i as u8;
+        nonce[i] = 0x10 + i as u8;
+    }
+    (key, nonce)
+}
+
+fn ascon_aead128_known_answer()
+{
+    println!("ascon_aead128_known_answer");
+    use cryptocol::symmetric::Ascon_AEAD128;
+
+    // Count = 1 of the known answer tests of Ascon-AEAD128 in NIST SP 800-232
+    let (key, nonce) = key_and_nonce();
+    let mut aead = Ascon_AEAD128::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &[], &[], &mut cipher);
+    println!("C =\t{}\nT =\t{}", to_hex(&cipher), to_hex(&tag));
+    assert!(cipher.is_empty());
+    assert_eq!(to_hex(&tag), "4F9C278211BEC9316BF68F46EE8B2EC6");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+    println!("-------------------------------");
+}
+
+fn ascon_aead128_encrypt_decrypt()
+{
+    println!("ascon_aead128_encrypt_decrypt");
+    use cryptocol::symmetric::Ascon_AEAD128;
+
+    let (key, nonce) = key_and_nonce();
+    let aad = "sensor-gateway-07".as_bytes();
+    let mut aead = Ascon_AEAD128::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, aad, MESSAGE.as_bytes(), &mut cipher);
+    println!("C =\t{}\nT =\t{}", to_hex(&cipher), to_hex(&tag));
+    assert_eq!(cipher.len(), MESSAGE.len());
+    assert_ne!(&cipher[..], MESSAGE.as_bytes());
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, aad, &cipher, tag, &mut recovered));
+    println!("M =\t{}", String::from_utf8(recovered.clone()).unwrap());
+    assert_eq!(String::from_utf8(recovered).unwrap(), MESSAGE);
+
+    // Another key gives other ciphertext and tag.
+    aead.set_key([0xA5_u8; 16]);
+    let mut other = Vec::<u8>::new();
+    let other_tag = aead.encrypt_into_vec(nonce, aad, MESSAGE.as_bytes(), &mut other);
+    assert_ne!(other, cipher);
+    assert_ne!(other_tag, tag);
+    println!("-------------------------------");
+}
+
+fn ascon_aead128_all_lengths()
+{
+    println!("ascon_aead128_all_lengths");
+    use cryptocol::symmetric::Ascon_AEAD128;
+
+    // Every length around the 16-byte rate boundaries must round-trip.
+    let (key, nonce) = key_and_nonce();
+    let mut aead = Ascon_AEAD128::new_with_key(key);
+    let data: Vec<u8> = (0..50_u8).collect();
+    for aad_len in [0_usize, 1, 15, 16, 17, 32, 33]
+    {
+        for msg_len in 0..=data.len()
+        {
+            let mut cipher = Vec::<u8>::new();
+            let tag = aead.encrypt_into_vec(nonce, &data[..aad_len], &data[..msg_len], &mut cipher);
+            assert_eq!(cipher.len(), msg_len);
+            let mut recovered = Vec::<u8>::new();
+            assert!(aead.decrypt_into_vec(nonce, &data[..aad_len], &cipher, tag, &mut recovered));
+            assert_eq!(recovered, &data[..msg_len]);
+        }
+    }
+    println!("All lengths are OK.");
+    println!("-------------------------------");
+}
+
+fn ascon_aead128_forgery()
+{
+    println!("ascon_aead128_forgery");
+    use cryptocol::symmetric::Ascon_AEAD128;
+
+    let (key, nonce) = key_and_nonce();
+    let aad = "header".as_bytes();
+    let mut aead = Ascon_AEAD128::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, aad, MESSAGE.as_bytes(), &mut cipher);
+
+    let mut recovered = Vec::<u8>::new();
+    let mut bad_cipher = cipher.clone();
+    bad_cipher[MESSAGE.len() - 1] ^= 0x80;
+    assert!(!aead.decrypt_into_vec(nonce, aad, &bad_cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+
+    let mut bad_tag = tag;
+    bad_tag[15] ^= 1;
+    assert!(!aead.decrypt_into_vec(nonce, aad, &cipher, bad_tag, &mut recovered));
+    assert!(recovered.is_empty());
+
+    assert!(!aead.decrypt_into_vec(nonce, "Header".as_bytes(), &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+
+    assert!(!aead.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+    let mut bad_nonce = nonce;
+    bad_nonce[0] ^= 1;
+    assert!(!aead.decrypt_into_vec(bad_nonce, aad, &cipher, tag, &mut recovered));
+    assert!(recovered.is_empty());
+
+    // The padding distinguishes the empty associated data from a zero byte.
+    let mut empty = Vec::<u8>::new();
+    let tag_none = aead.encrypt_into_vec(nonce, &[], &[], &mut empty);
+    let tag_zero = aead.encrypt_into_vec(nonce, &[0_u8], &[], &mut empty);
+    assert_ne!(tag_none, tag_zero);
+    println!("All forgeries are rejected.");
+    println!("-------------------------------");
+}
+
+fn ascon_aead128_expanded()
+{
+    println!("ascon_aead128_expanded");
+    use cryptocol::symmetric::{ Ascon_AEAD_Generic, Ascon_AEAD128 };
+
+    // Ascon_AEAD_Generic with the default generic parameters is Ascon-AEAD128.
+    let (key, nonce) = key_and_nonce();
+    let mut aead = Ascon_AEAD_Generic::<0x00001000808c0001, 12, 8>::new_with_key(key);
+    let mut official = Ascon_AEAD128::new_with_key(key);
+    let mut c1 = Vec::<u8>::new();
+    let mut c2 = Vec::<u8>::new();
+    assert_eq!(aead.encrypt_into_vec(nonce, &[], MESSAGE.as_bytes(), &mut c1),
+                official.encrypt_into_vec(nonce, &[], MESSAGE.as_bytes(), &mut c2));
+    assert_eq!(c1, c2);
+
+    // A variant with more rounds is a different cipher.
+    let mut my_aead = Ascon_AEAD_Generic::<0x00001000808c0001, 16, 12>::new_with_key(key);
+    let mut c3 = Vec::<u8>::new();
+    let tag = my_aead.encrypt_into_vec(nonce, &[], MESSAGE.as_bytes(), &mut c3);
+    println!("C =\t{}\nT =\t{}", to_hex(&c3), to_hex(&tag));
+    assert_ne!(c1, c3);
+    let mut recovered = Vec::<u8>::new();
+    assert!(my_aead.decrypt_into_vec(nonce, &[], &c3, tag, &mut recovered));
+    assert_eq!(recovered, MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn ascon_hash256_known_answer()
+{
+    println!("ascon_hash256_known_answer");
+    use cryptocol::hash::Ascon_Hash256;
+
+    // Count = 1 of the known answer tests of Ascon-Hash256 in NIST SP 800-232
+    let mut hash = Ascon_Hash256::new();
+    hash.digest_str("");
+    println!("Msg =\t\"\"\nHash =\t{}", hash);
+    assert_eq!(hash.to_string(), "0B3BE5850F2F6B98CAF29F8FDEA89B64A1FA70AA249B8F839BD53BAA304D92B2");
+    println!("-------------------------------");
+}
+
+fn ascon_hash256_pointer_and_slices()
+{
+    println!("ascon_hash256_pointer_and_slices");
+    use cryptocol::hash::Ascon_Hash256;
+
+    // All the interfaces give the same hash value for the same bytes.
+    let mut hash = Ascon_Hash256::new();
+    hash.digest_str(MESSAGE);
+    let expected = hash.get_hash_value_in_array();
+    println!("Msg =\t\"{}\"\nHash =\t{}", MESSAGE, hash);
+
+    hash.digest(MESSAGE.as_ptr(), MESSAGE.len() as u64);
+    assert_eq!(hash.get_hash_value_in_array(), expected);
+    hash.digest_string(&MESSAGE.to_string());
+    assert_eq!(hash.get_hash_value_in_vec(), expected.to_vec());
+    hash.digest_vec(&MESSAGE.as_bytes().to_vec());
+    let mut out = [0_u8; 32];
+    hash.get_hash_value(out.as_mut_ptr(), out.len());
+    assert_eq!(out, expected);
+
+    // Reusing the object recomputes the hash value from scratch.
+    let mut fresh = Ascon_Hash256::new();
+    for len in 0..=MESSAGE.len()
+    {
+        hash.digest_str(&MESSAGE[..len]);
+        fresh = Ascon_Hash256::new();
+        fresh.digest(MESSAGE.as_ptr(), len as u64);
+        assert_eq!(hash.to_string(), fresh.to_string());
+    }
+    assert_eq!(fresh.get_hash_value_in_array(), expected);
+    println!("-------------------------------");
+}
+
+fn ascon_hash256_expanded()
+{
+    println!("ascon_hash256_expanded");
+    use cryptocol::hash::{ Ascon_Hash_Expanded, Ascon_Hash256 };
+
+    type MyAscon = Ascon_Hash_Expanded<0x1234_5678_9abc_def0, 16>;
+    let mut my_hash = MyAscon::new();
+    my_hash.digest_str("");
+    println!("Msg =\t\"\"\nHash =\t{}", my_hash);
+    assert_eq!(my_hash.to_string(), "BDEFA1875D1217175AC68F75F8FD494C49A53BBF7E34075798E017E5EADFD26B");
+
+    let mut default_hash = Ascon_Hash_Expanded::<0x0000080100cc0002, 12>::new();
+    let mut hash = Ascon_Hash256::new();
+    default_hash.digest_str(MESSAGE);
+    hash.digest_str(MESSAGE);
+    assert_eq!(default_hash.to_string(), hash.to_string());
+    println!("-------------------------------");
+}
+
+fn ascon_xof128_known_answer()
+{
+    println!("ascon_xof128_known_answer");
+    use cryptocol::hash::Ascon_XOF128;
+
+    // Count = 1 of the known answer tests of Ascon-XOF128 in NIST SP 800-232
+    let mut xof = Ascon_XOF128::new();
+    xof.digest_str("");
+    println!("Msg =\t\"\"\nOutput =\t{}", xof);
+    assert_eq!(xof.to_string(), "473D5E6164F58B39DFD84AACDB8AE42EC2D91FED33388EE0D960D9B3993295C6");
+    println!("-------------------------------");
+}
+
+fn ascon_xof128_squeeze()
+{
+    println!("ascon_xof128_squeeze");
+    use cryptocol::hash::{ Ascon_XOF128, Ascon_Hash256 };
+
+    let mut xof = Ascon_XOF128::new();
+    xof.digest_str(MESSAGE);
+    let long = xof.squeeze_into_vec(100);
+    println!("Msg =\t\"{}\"\nOutput =\t{}", MESSAGE, to_hex(&long));
+
+    // A shorter output is the prefix of a longer output.
+    for len in 0..=long.len()
+        { assert_eq!(xof.squeeze_into_vec(len), &long[..len]); }
+    let mut out = [0_u8; 41];
+    xof.squeeze(out.as_mut_ptr(), out.len());
+    assert_eq!(&out[..], &long[..41]);
+
+    // Ascon-XOF128 and Ascon-Hash256 differ only in the initial value.
+    let mut hash = Ascon_Hash256::new();
+    hash.digest_str(MESSAGE);
+    assert_ne!(hash.get_hash_value_in_vec(), long[..32].to_vec());
+    println!("-------------------------------");
+}
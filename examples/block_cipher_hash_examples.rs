@@ -0,0 +1,214 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    block_cipher_hash_main();
+    mdc_main();
+}
+
+fn block_cipher_hash_main()
+{
+    block_cipher_hash_des();
+    block_cipher_hash_aes();
+    block_cipher_hash_des_expanded();
+    block_cipher_hash_md_strengthening();
+}
+
+fn mdc_main()
+{
+    mdc2_test_vectors();
+    mdc2_padding();
+    mdc2_des_expanded();
+    mdc4_test_vectors();
+}
+
+const FOX: &str = "The quick brown fox jumps over the lazy dog";
+
+fn block_cipher_hash_des()
+{
+    println!("block_cipher_hash_des");
+    use cryptocol::hash::{ DES_Hash, HashConstruction };
+    use cryptocol::symmetric::DES;
+
+    let mut dm = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    let mut mmo = DES_Hash::new(DES::new(), HashConstruction::MatyasMeyerOseas);
+    let mut mp = DES_Hash::new(DES::new(), HashConstruction::MiyaguchiPreneel);
+    dm.digest_str(FOX);
+    mmo.digest_str(FOX);
+    mp.digest_str(FOX);
+    println!("DM =\t{}\nMMO =\t{}\nMP =\t{}", dm, mmo, mp);
+    assert_eq!(dm.to_string(), "BC5279FEE8CBB752");
+    assert_eq!(dm.get_message_block_size(), 8);
+    assert_ne!(mmo.to_string(), mp.to_string());
+
+    // The initial chaining value changes the hash value.
+    let iv = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let mut dm_iv = DES_Hash::new_with_iv(DES::new(), HashConstruction::DaviesMeyer, &iv);
+    dm.digest_str("");
+    dm_iv.digest_str("");
+    println!("DM =\t{}\nDM_IV =\t{}", dm, dm_iv);
+    assert_eq!(dm_iv.to_string(), "7797D1966A4113D5");
+    assert_ne!(dm.to_string(), dm_iv.to_string());
+    assert_eq!(dm_iv.get_construction(), HashConstruction::DaviesMeyer);
+    println!("-------------------------------");
+}
+
+fn block_cipher_hash_aes()
+{
+    println!("block_cipher_hash_aes");
+    use cryptocol::hash::{ AES_128_Hash, AES_256_Hash, HashConstruction };
+    use cryptocol::symmetric::{ AES_128, AES_256 };
+
+    let mut mp = AES_128_Hash::new(AES_128::new(), HashConstruction::MiyaguchiPreneel);
+    mp.digest_str(FOX);
+    println!("MP =\t{}", mp);
+    assert_eq!(mp.to_string(), "9F1A337A7AC48D9498ED384991204689");
+
+    // Davies-Meyer with AES-256 takes 32 bytes of message at a time.
+    let mut dm = AES_256_Hash::new(AES_256::new(), HashConstruction::DaviesMeyer);
+    assert_eq!(dm.get_message_block_size(), 32);
+    dm.digest_str("This is an example of the method get_hash_value_in_string().");
+    println!("DM =\t{}", dm);
+    assert_eq!(dm.to_string(), "8E5E9D10AAFDD8330BE89D47D67700DE");
+    assert_eq!(dm.get_hash_value_in_vec().len(), 16);
+    println!("-------------------------------");
+}
+
+fn block_cipher_hash_des_expanded()
+{
+    println!("block_cipher_hash_des_expanded");
+    use cryptocol::hash::{ BlockCipherHash_Generic, DES_Hash, HashConstruction };
+    use cryptocol::symmetric::{ DES, DES_Generic };
+
+    let mut des8 = BlockCipherHash_Generic::new(DES_Generic::<8>::new(), HashConstruction::MatyasMeyerOseas);
+    let mut des16 = DES_Hash::new(DES::new(), HashConstruction::MatyasMeyerOseas);
+    des8.digest_str("");
+    des16.digest_str("");
+    println!("DES8 =\t{}\nDES16 =\t{}", des8, des16);
+    assert_eq!(des8.to_string(), "832128AA944894CD");
+    assert_ne!(des8.to_string(), des16.to_string());
+    println!("-------------------------------");
+}
+
+fn block_cipher_hash_md_strengthening()
+{
+    println!("block_cipher_hash_md_strengthening");
+    use cryptocol::hash::{ DES_Hash, HashConstruction };
+    use cryptocol::symmetric::DES;
+
+    // The messages which differ only in their trailing zero bytes have
+    // different hash values thanks to the length in the padding.
+    let mut hash = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    let mut values = Vec::new();
+    for length in 0..20
+    {
+        hash.digest_vec(&vec![0_u8; length]);
+        values.push(hash.get_hash_value_in_vec());
+    }
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), 20);
+    println!("-------------------------------");
+}
+
+fn mdc2_test_vectors()
+{
+    println!("mdc2_test_vectors");
+    use cryptocol::hash::MDC2;
+    use cryptocol::symmetric::DES;
+
+    let mut hash = MDC2::new(DES::new());
+    for (txt, value) in [("", "52525252525252522525252525252525"),
+                         ("Now is the time for all ", "42E50CD224BACEBA760BDD2BD409281A"),
+                         (FOX, "000ED54E093D61679AEFBEAE05BFE33A"),
+                         ("The quick brown fox jumps over the lazy cog", "775F59F8E51AEC29C57AC6AB850D58E8")]
+    {
+        hash.digest_str(txt);
+        println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+        assert_eq!(hash.to_string(), value);
+    }
+    println!("-------------------------------");
+}
+
+fn mdc2_padding()
+{
+    println!("mdc2_padding");
+    use cryptocol::hash::{ MDC2, MDC2_Generic };
+    use cryptocol::symmetric::{ DES, ISO7816_Padding, No_Padding };
+
+    // Zero padding is ambiguous.
+    let mut zero = MDC2::new(DES::new());
+    zero.digest_str("abc");
+    let abc = zero.get_hash_value_in_array();
+    zero.digest_vec(&vec![b'a', b'b', b'c', 0, 0]);
+    assert_eq!(zero.get_hash_value_in_array(), abc);
+
+    // ISO/IEC 7816-4 padding is not.
+    let mut iso = MDC2_Generic::new_with_padding(DES::new(), ISO7816_Padding);
+    iso.digest_str("abc");
+    let abc = iso.get_hash_value_in_array();
+    iso.digest_vec(&vec![b'a', b'b', b'c', 0, 0]);
+    assert_ne!(iso.get_hash_value_in_array(), abc);
+    iso.digest_str("");
+    println!("ISO =\t{}", iso);
+    assert_eq!(iso.to_string(), "4C8648C851AAFE263C94B40FF591769B");
+
+    // No padding for the messages of fixed length
+    let mut none = MDC2_Generic::new_with_padding(DES::new(), No_Padding);
+    none.digest_str("Now is the time for all ");
+    assert_eq!(none.to_string(), "42E50CD224BACEBA760BDD2BD409281A");
+    println!("-------------------------------");
+}
+
+fn mdc2_des_expanded()
+{
+    println!("mdc2_des_expanded");
+    use cryptocol::hash::MDC2_Generic;
+    use cryptocol::symmetric::DES_Generic;
+
+    let mut hash = MDC2_Generic::new(DES_Generic::<8>::new());
+    hash.digest_str("Now is the time for all ");
+    println!("Hash =\t{}", hash);
+    assert_eq!(hash.to_string(), "157837BEBB4F030D38C59DB2300DB925");
+    println!("-------------------------------");
+}
+
+fn mdc4_test_vectors()
+{
+    println!("mdc4_test_vectors");
+    use cryptocol::hash::{ MDC2, MDC4 };
+    use cryptocol::symmetric::DES;
+
+    // The expected values come from an independent implementation of
+    // Algorithm 9.47 of the Handbook of Applied Cryptography written in
+    // Python over DES of the package `cryptography`. The same
+    // implementation gives the published values of MDC-2 above.
+    let mut hash = MDC4::new(DES::new());
+    for (txt, value) in [("", "52525252525252522525252525252525"),
+                         ("abc", "E5C4CC03E792C91CBB2C1DC4E720B3D8"),
+                         ("Now is the time for all ", "B62472300AB32B36BD046EC4D1B3DCFD"),
+                         (FOX, "44F5722D6E40B93E3C98F1408978D8E7")]
+    {
+        hash.digest_str(txt);
+        println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+        assert_eq!(hash.to_string(), value);
+    }
+
+    // MDC-4 of the empty message is the same as MDC-2 because it does not
+    // compress any block and outputs the initial values.
+    let mut mdc2 = MDC2::new(DES::new());
+    hash.digest_str("");
+    mdc2.digest_str("");
+    assert_eq!(hash.to_string(), mdc2.to_string());
+    println!("-------------------------------");
+}
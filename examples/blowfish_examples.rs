@@ -0,0 +1,305 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    blowfish_main();
+    blowfish_mode_main();
+    bcrypt_main();
+}
+
+fn blowfish_main()
+{
+    blowfish_vectors();
+    blowfish_encrypt_decrypt_u64();
+    blowfish_eks_key();
+}
+
+fn blowfish_mode_main()
+{
+    blowfish_openssl();
+    blowfish_all_modes_and_paddings();
+}
+
+fn bcrypt_main()
+{
+    bcrypt_hash_with_salt();
+    bcrypt_versions();
+    bcrypt_long_password();
+    bcrypt_verify();
+    bcrypt_parse();
+    bcrypt_base64();
+}
+
+const KEY: [u8; 16] = [ 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+                        0xF0, 0xE1, 0xD2, 0xC3, 0xB4, 0xA5, 0x96, 0x87 ];
+const IV: [u8; 8] = [0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+const MESSAGE: &str = "Now is the time for all good men to come to the aid of their party.";
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn from_hex(txt: &str) -> Vec<u8>
+{
+    (0..txt.len()).step_by(2).map(|i| u8::from_str_radix(&txt[i..i+2], 16).unwrap()).collect()
+}
+
+fn blowfish_vectors()
+{
+    println!("blowfish_vectors");
+    use cryptocol::symmetric::{ BlockCipher, Blowfish };
+
+    // The test vectors of Eric Young, which Bruce Schneier publishes
+    let vectors = [ ("0000000000000000", "0000000000000000", "4EF997456198DD78"),
+                    ("FFFFFFFFFFFFFFFF", "FFFFFFFFFFFFFFFF", "51866FD5B85ECB8A"),
+                    ("3000000000000000", "1000000000000001", "7D856F9A613063F2"),
+                    ("FEDCBA9876543210", "0123456789ABCDEF", "0ACEAB0FC6A0A28D"),
+                    ("F0E1D2C3B4A5968778695A4B3C2D1E0F0011223344556677", "FEDCBA9876543210", "05044B62FA52D080") ];
+    for (key, message, expected) in vectors
+    {
+        let mut blowfish = Blowfish::new_with_key(&from_hex(key));
+        let mut block = from_hex(message);
+        blowfish.encrypt_one_block(&mut block);
+        println!("C =\t{}", to_hex(&block));
+        assert_eq!(to_hex(&block), expected);
+        blowfish.decrypt_one_block(&mut block);
+        assert_eq!(to_hex(&block), message);
+    }
+    println!("-------------------------------");
+}
+
+fn blowfish_encrypt_decrypt_u64()
+{
+    println!("blowfish_encrypt_decrypt_u64");
+    use cryptocol::symmetric::Blowfish;
+
+    let mut blowfish = Blowfish::new_with_key(&from_hex("FEDCBA9876543210"));
+    let message = u64::from_le_bytes([0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+    let cipher = blowfish.encrypt_u64(message);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "0ACEAB0FC6A0A28D");
+    assert_eq!(blowfish.decrypt_u64(cipher), message);
+
+    let messages = [message, !message, 0_u64];
+    let mut ciphers = [0_u64; 3];
+    let mut recovered = [0_u64; 3];
+    blowfish.encrypt_array_u64(&messages, &mut ciphers);
+    assert_eq!(ciphers[0], cipher);
+    blowfish.decrypt_array_u64(&ciphers, &mut recovered);
+    assert_eq!(recovered, messages);
+
+    blowfish.set_key(&[0_u8; 8]);
+    assert_eq!(to_hex(&blowfish.encrypt_u64(0).to_le_bytes()), "4EF997456198DD78");
+    println!("-------------------------------");
+}
+
+fn blowfish_eks_key()
+{
+    println!("blowfish_eks_key");
+    use cryptocol::symmetric::Blowfish;
+
+    let salt = [0x5A_u8; 16];
+    let key = "password\0".as_bytes();
+    let mut eks4 = Blowfish::new_with_eks_key(4, &salt, key);
+    let mut eks5 = Blowfish::new_with_eks_key(5, &salt, key);
+    let mut other_salt = Blowfish::new_with_eks_key(4, &[0xA5_u8; 16], key);
+    let cipher = eks4.encrypt_u64(0x_0123456789ABCDEF_u64);
+    assert_ne!(cipher, eks5.encrypt_u64(0x_0123456789ABCDEF_u64));
+    assert_ne!(cipher, other_salt.encrypt_u64(0x_0123456789ABCDEF_u64));
+    assert_ne!(cipher, Blowfish::new_with_key(key).encrypt_u64(0x_0123456789ABCDEF_u64));
+    assert_eq!(eks4.decrypt_u64(cipher), 0x_0123456789ABCDEF_u64);
+    println!("-------------------------------");
+}
+
+fn blowfish_openssl()
+{
+    println!("blowfish_openssl");
+    use cryptocol::symmetric::{ Blowfish, ModeCipher, OperationMode };
+
+    // The same results as `openssl enc -bf-cbc -K 0123456789ABCDEFF0E1D2C3B4A59687 -iv FEDCBA9876543210`
+    // and so on
+    let mut blowfish = ModeCipher::new(Blowfish::new_with_key(&KEY), OperationMode::ECB, &IV);
+    let cipher = blowfish.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "8616468CC29CF6E1291E817CC740982D267DA606EF44ECB02B8739F0130A8196F397A5958DEBCD06F719B42CDAB980B83CAFE6D9F41FA8B062CE951C468C11FDB480010860BDC0FD");
+    assert_eq!(blowfish.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut blowfish = ModeCipher::new(Blowfish::new_with_key(&KEY), OperationMode::CBC, &IV);
+    let cipher = blowfish.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "31571CB8BC4ED3DBBBC75F94DDEECD48B0438A0D0123A1AC9619B0EA6191C9B8A90264ABCFA9FF7209967C4B7D193CD40E35B4A44971EE559F4DF7460752D2A8C78BD2341D5C10EB");
+    assert_eq!(blowfish.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut blowfish = ModeCipher::new(Blowfish::new_with_key(&KEY), OperationMode::CFB, &IV);
+    let cipher = blowfish.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "9E6B56B6D860289EF3901AB601F3E81A4AC69D444D7F40D7B88A75CE5355C33ABADF713D2E974A1373D344778F8F42C8FBCD2311F5845946FD1CA0CF62F4393583D233");
+    assert_eq!(blowfish.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut blowfish = ModeCipher::new(Blowfish::new_with_key(&KEY), OperationMode::OFB, &IV);
+    let cipher = blowfish.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "9E6B56B6D860289E44B914985B7B1D271ED7C2D90425122262C2D2C4FA2B9ED657F9B5B91820DAE1C35A8E682E3D387A2A9ABB54EA0B568FC93B59CB44D43D8F6725A6");
+    assert_eq!(blowfish.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn blowfish_all_modes_and_paddings()
+{
+    println!("blowfish_all_modes_and_paddings");
+    use cryptocol::symmetric::{ Blowfish, ModeCipher, OperationMode, Padding,
+                                PKCS7_Padding, ANSI_X923_Padding, ISO7816_Padding, ISO10126_Padding };
+
+    fn round_trip<P: Padding + Copy>(padding: P)
+    {
+        for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+        {
+            let mut blowfish = ModeCipher::new_with_padding(Blowfish::new_with_key(&KEY), mode, &IV, padding);
+            for length in [0_usize, 1, 7, 8, 9, MESSAGE.len()]
+            {
+                let message = &MESSAGE.as_bytes()[..length];
+                let cipher = blowfish.encrypt(message);
+                assert_eq!(cipher.len(), blowfish.encrypted_length(length));
+                assert_eq!(blowfish.decrypt(&cipher).unwrap(), message);
+            }
+        }
+    }
+
+    round_trip(PKCS7_Padding);
+    round_trip(ANSI_X923_Padding);
+    round_trip(ISO7816_Padding);
+    round_trip(ISO10126_Padding);
+    println!("-------------------------------");
+}
+
+fn bcrypt_hash_with_salt()
+{
+    println!("bcrypt_hash_with_salt");
+    use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+
+    // The same results as crypt(3) of OpenBSD and libxcrypt
+    let salt = Bcrypt::decode_base64("CCCCCCCCCCCCCCCCCCCCC.").unwrap().try_into().unwrap();
+    let hash = Bcrypt::hash_with_salt(BcryptVersion::V2a, "U*U".as_bytes(), 5, salt);
+    println!("hash =\t{}", hash);
+    assert_eq!(hash, "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW");
+
+    let salt = Bcrypt::decode_base64("DCq7YPn5Rq63x1Lad4cll.").unwrap().try_into().unwrap();
+    let hash = Bcrypt::hash_with_salt(BcryptVersion::V2b, "".as_bytes(), 6, salt);
+    println!("hash =\t{}", hash);
+    assert_eq!(hash, "$2b$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.");
+    println!("-------------------------------");
+}
+
+fn bcrypt_versions()
+{
+    println!("bcrypt_versions");
+    use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+
+    // All the versions make the same hash except the prefix.
+    let salt = Bcrypt::decode_base64("CCCCCCCCCCCCCCCCCCCCC.").unwrap().try_into().unwrap();
+    let hash_2a = Bcrypt::hash_with_salt(BcryptVersion::V2a, "\u{ff}\u{a3}abc".as_bytes(), 4, salt);
+    let hash_2b = Bcrypt::hash_with_salt(BcryptVersion::V2b, "\u{ff}\u{a3}abc".as_bytes(), 4, salt);
+    let hash_2y = Bcrypt::hash_with_salt(BcryptVersion::V2y, "\u{ff}\u{a3}abc".as_bytes(), 4, salt);
+    assert_eq!(hash_2y, "$2y$04$CCCCCCCCCCCCCCCCCCCCC.fd7BB1jweAgFj1R4Cu36pko9ZwMJM5O");
+    assert_eq!(hash_2a[4..], hash_2y[4..]);
+    assert_eq!(hash_2b[4..], hash_2y[4..]);
+    assert!(hash_2b.starts_with("$2b$"));
+    println!("-------------------------------");
+}
+
+fn bcrypt_long_password()
+{
+    println!("bcrypt_long_password");
+    use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+
+    // Only the first 72 bytes of the password are used.
+    let salt = Bcrypt::decode_base64("abcdefghijklmnopqrstuu").unwrap().try_into().unwrap();
+    let long = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789chars after 72 are ignored";
+    let hash = Bcrypt::hash_with_salt(BcryptVersion::V2a, long.as_bytes(), 5, salt);
+    assert_eq!(hash, "$2a$05$abcdefghijklmnopqrstuu5s2v8.iXieOjg/.AySBTTZIIVFJeBui");
+    assert!(Bcrypt::verify(&long.as_bytes()[..72], &hash));
+
+    // The passwords longer than 255 bytes are treated in the same way.
+    let salt = Bcrypt::decode_base64("CCCCCCCCCCCCCCCCCCCCC.").unwrap().try_into().unwrap();
+    let hash = Bcrypt::hash_with_salt(BcryptVersion::V2b, &[b'x'; 260], 4, salt);
+    assert_eq!(hash, "$2b$04$CCCCCCCCCCCCCCCCCCCCC.ug0A3dTXhy5U.dFjx/qguZ8I4CB77BC");
+    assert!(Bcrypt::verify(&[b'x'; 72], &hash));
+    println!("-------------------------------");
+}
+
+fn bcrypt_verify()
+{
+    println!("bcrypt_verify");
+    use cryptocol::symmetric::Bcrypt;
+
+    let hash = Bcrypt::hash("correct horse battery staple".as_bytes(), 4);
+    println!("hash =\t{}", hash);
+    assert_eq!(hash.len(), 60);
+    assert!(hash.starts_with("$2b$04$"));
+    assert!(Bcrypt::verify("correct horse battery staple".as_bytes(), &hash));
+    assert!(!Bcrypt::verify("correct horse battery stapler".as_bytes(), &hash));
+
+    // The random salt makes a different hash every time.
+    assert_ne!(Bcrypt::hash("correct horse battery staple".as_bytes(), 4), hash);
+
+    // The malformed hash strings are never verified.
+    assert!(!Bcrypt::verify("U*U".as_bytes(), "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOe"));
+    assert!(!Bcrypt::verify("U*U".as_bytes(), "$2x$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"));
+    assert!(!Bcrypt::verify("U*U".as_bytes(), "$2a$03$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"));
+    assert!(!Bcrypt::verify("U*U".as_bytes(), "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOe!"));
+
+    // The hash strings of 60 bytes with multibyte characters at the offsets
+    // where they are split are not verified either, without panicking.
+    let multibyte = format!("$2a\u{e9}5${}", "C".repeat(53));
+    assert_eq!(multibyte.len(), 60);
+    assert!(!Bcrypt::verify("U*U".as_bytes(), &multibyte));
+    assert!(Bcrypt::parse(&multibyte).is_none());
+    let multibyte = format!("$2a$05${}\u{e9}{}", "C".repeat(21), "C".repeat(30));
+    assert_eq!(multibyte.len(), 60);
+    assert!(!Bcrypt::verify("U*U".as_bytes(), &multibyte));
+    println!("-------------------------------");
+}
+
+fn bcrypt_parse()
+{
+    println!("bcrypt_parse");
+    use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+
+    let (version, cost, salt, digest) = Bcrypt::parse("$2b$06$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.").unwrap();
+    assert_eq!(version, BcryptVersion::V2b);
+    assert_eq!(cost, 6);
+    assert_eq!(Bcrypt::encode_base64(&salt), "DCq7YPn5Rq63x1Lad4cll.");
+    assert_eq!(digest, Bcrypt::digest("".as_bytes(), 6, salt));
+    assert!(Bcrypt::parse("$2b$32$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.").is_none());
+    assert!(Bcrypt::parse("$2b$6$DCq7YPn5Rq63x1Lad4cll.TV4S6ytwfsfvkgY8jIucDrjc8deX1s.").is_none());
+    println!("-------------------------------");
+}
+
+fn bcrypt_base64()
+{
+    println!("bcrypt_base64");
+    use cryptocol::symmetric::Bcrypt;
+
+    for length in 0..20
+    {
+        let data: Vec<u8> = (0..length).map(|i| (i * 37 + 11) as u8).collect();
+        let txt = Bcrypt::encode_base64(&data);
+        assert_eq!(txt.len(), (length * 4_usize).div_ceil(3));
+        assert_eq!(Bcrypt::decode_base64(&txt).unwrap(), data);
+    }
+    assert_eq!(Bcrypt::encode_base64(&[0xFF_u8, 0xFF, 0xFF]), "9999");
+    assert_eq!(Bcrypt::encode_base64(&[0_u8; 3]), "....");
+    assert!(Bcrypt::decode_base64("CCCCC").is_none());
+    assert!(Bcrypt::decode_base64("CC+C").is_none());
+    println!("-------------------------------");
+}
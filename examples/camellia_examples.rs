@@ -0,0 +1,205 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    camellia_main();
+    camellia_mode_main();
+}
+
+fn camellia_main()
+{
+    camellia_rfc3713_vectors();
+    camellia_encrypt_decrypt_u128();
+    camellia_set_key();
+}
+
+fn camellia_mode_main()
+{
+    camellia_openssl();
+    camellia_all_modes_and_paddings();
+    camellia_stream();
+}
+
+const KEY: &str = "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F";
+const MESSAGE: &str = "Now is the time for all good men to come to the aid of their party.";
+const IV: [u8; 16] = [ 0x0F, 0x0E, 0x0D, 0x0C, 0x0B, 0x0A, 0x09, 0x08,
+                       0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00 ];
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn from_hex(txt: &str) -> Vec<u8>
+{
+    (0..txt.len()).step_by(2).map(|i| u8::from_str_radix(&txt[i..i+2], 16).unwrap()).collect()
+}
+
+fn camellia_rfc3713_vectors()
+{
+    println!("camellia_rfc3713_vectors");
+    use cryptocol::symmetric::{ BlockCipher, CAMELLIA_128, CAMELLIA_192, CAMELLIA_256 };
+
+    fn check<C: BlockCipher>(mut cipher: C, expected: &str)
+    {
+        let message = from_hex("0123456789ABCDEFFEDCBA9876543210");
+        let mut block = message.clone();
+        cipher.encrypt_one_block(&mut block);
+        println!("C =\t{}", to_hex(&block));
+        assert_eq!(to_hex(&block), expected);
+        cipher.decrypt_one_block(&mut block);
+        assert_eq!(block, message);
+    }
+
+    // The test vectors of Appendix A of RFC 3713
+    let key = from_hex("0123456789ABCDEFFEDCBA987654321000112233445566778899AABBCCDDEEFF");
+    check(CAMELLIA_128::new_with_key(&key[..16]), "67673138549669730857065648EABE43");
+    check(CAMELLIA_192::new_with_key(&key[..24]), "B4993401B3E996F84EE5CEE7D79B09B9");
+    check(CAMELLIA_256::new_with_key(&key), "9ACC237DFF16D76C20EF7C919E3A7509");
+    println!("-------------------------------");
+}
+
+fn camellia_encrypt_decrypt_u128()
+{
+    println!("camellia_encrypt_decrypt_u128");
+    use cryptocol::symmetric::CAMELLIA_256;
+
+    let key = from_hex("0123456789ABCDEFFEDCBA987654321000112233445566778899AABBCCDDEEFF");
+    let mut camellia = CAMELLIA_256::new_with_key(&key);
+    let message = u128::from_le_bytes(from_hex("0123456789ABCDEFFEDCBA9876543210").try_into().unwrap());
+    let cipher = camellia.encrypt_u128(message);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "9ACC237DFF16D76C20EF7C919E3A7509");
+    assert_eq!(camellia.decrypt_u128(cipher), message);
+
+    let messages = [message, !message, 0_u128];
+    let mut ciphers = [0_u128; 3];
+    let mut recovered = [0_u128; 3];
+    camellia.encrypt_array_u128(&messages, &mut ciphers);
+    assert_eq!(ciphers[0], cipher);
+    camellia.decrypt_array_u128(&ciphers, &mut recovered);
+    assert_eq!(recovered, messages);
+    println!("-------------------------------");
+}
+
+fn camellia_set_key()
+{
+    println!("camellia_set_key");
+    use cryptocol::symmetric::CAMELLIA_192;
+
+    let key = from_hex("0123456789ABCDEFFEDCBA98765432100011223344556677");
+    let message = u128::from_le_bytes(from_hex("0123456789ABCDEFFEDCBA9876543210").try_into().unwrap());
+    let mut camellia = CAMELLIA_192::new();
+    let zero_key_cipher = camellia.encrypt_u128(message);
+    camellia.set_key(&key);
+    let cipher = camellia.encrypt_u128(message);
+    assert_ne!(cipher, zero_key_cipher);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "B4993401B3E996F84EE5CEE7D79B09B9");
+    println!("-------------------------------");
+}
+
+fn camellia_openssl()
+{
+    println!("camellia_openssl");
+    use cryptocol::symmetric::{ CAMELLIA_128, CAMELLIA_192, CAMELLIA_256, ModeCipher, OperationMode };
+
+    // The same results as `openssl enc -camellia-128-cbc -K <KEY> -iv 0F0E0D0C0B0A09080706050403020100`
+    // and so on
+    let key = from_hex(KEY);
+    let mut camellia = ModeCipher::new(CAMELLIA_128::new_with_key(&key[..16]), OperationMode::CBC, &IV);
+    let cipher = camellia.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "5EAE09B92DAAB63AF95F037B7B229306FA521E7EACE3B1B94533B044B0628B22EB53FF49E0FEDDCB09934F6255B241448F2EC34103F87D3DF2417F774072E9753F4BA136DE46C8854A78624C62980526");
+    assert_eq!(camellia.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut camellia = ModeCipher::new(CAMELLIA_192::new_with_key(&key[..24]), OperationMode::CBC, &IV);
+    let cipher = camellia.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "910CB04D1F4A60CE70CD88A02E2CDBD90995FFA54A812EE25899C4A7FD446E29B2214A6E47A516BBF62E105A7634997BEAAB5B634B6BD3068BD0DC5FE736ACC95FFDD45D3FA41A62510CEF606644A66D");
+    assert_eq!(camellia.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut camellia = ModeCipher::new(CAMELLIA_256::new_with_key(&key), OperationMode::CBC, &IV);
+    let cipher = camellia.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "C7EB79F4B3C5F019084F0A93756981B7BE79615C820CCB085A5BA5D6AFB1B150A2B643C72375092431F7F6163B6692A237021B159D455988ED7E2E45DAE62E7A96EC4D7F2660E6A5083EE5BBC9AA4F43");
+    assert_eq!(camellia.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut camellia = ModeCipher::new(CAMELLIA_128::new_with_key(&key[..16]), OperationMode::OFB, &IV);
+    let cipher = camellia.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "4EA0DFCA61BBB271D5C4D83BBAB83C27E7937D9976F6D2EE45FB45A8B8D089F89656775DD619C5667EF81BEECF5576E6025CC606FD06D0C5D3EAFFC3E425A136AA0517");
+    assert_eq!(camellia.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut camellia = ModeCipher::new(CAMELLIA_256::new_with_key(&key), OperationMode::CFB, &IV);
+    let cipher = camellia.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "F7B95FF52B6C20F585593F23F68728012BAEA1CBD204F1E68D8AF584D2B29C939BC597EEE8FDDC6FECD3F0ED9C456C243B2213C0389D3D7CE1A2B76302F451F17A9ACC");
+    assert_eq!(camellia.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn camellia_all_modes_and_paddings()
+{
+    println!("camellia_all_modes_and_paddings");
+    use cryptocol::symmetric::{ BlockCipher, CAMELLIA_128, CAMELLIA_192, CAMELLIA_256,
+                                ModeCipher, OperationMode, Padding,
+                                PKCS7_Padding, ANSI_X923_Padding, ISO7816_Padding, ISO10126_Padding };
+
+    fn round_trip<C: BlockCipher + Clone, P: Padding + Copy>(cipher: C, padding: P)
+    {
+        for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+        {
+            let mut mc = ModeCipher::new_with_padding(cipher.clone(), mode, &IV, padding);
+            for length in [0_usize, 1, 15, 16, 17, MESSAGE.len()]
+            {
+                let message = &MESSAGE.as_bytes()[..length];
+                let encrypted = mc.encrypt(message);
+                assert_eq!(encrypted.len(), mc.encrypted_length(length));
+                assert_eq!(mc.decrypt(&encrypted).unwrap(), message);
+            }
+        }
+    }
+
+    fn all_paddings<C: BlockCipher + Clone>(cipher: C)
+    {
+        round_trip(cipher.clone(), PKCS7_Padding);
+        round_trip(cipher.clone(), ANSI_X923_Padding);
+        round_trip(cipher.clone(), ISO7816_Padding);
+        round_trip(cipher, ISO10126_Padding);
+    }
+
+    let key = from_hex(KEY);
+    all_paddings(CAMELLIA_128::new_with_key(&key[..16]));
+    all_paddings(CAMELLIA_192::new_with_key(&key[..24]));
+    all_paddings(CAMELLIA_256::new_with_key(&key));
+    println!("-------------------------------");
+}
+
+fn camellia_stream()
+{
+    println!("camellia_stream");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ CAMELLIA_128, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let key = from_hex(KEY);
+    let mut encryptor = Encryptor::new(CAMELLIA_128::new_with_key(&key[..16]), OperationMode::CBC, &IV, Vec::<u8>::new());
+    for chunk in MESSAGE.as_bytes().chunks(5)
+        { encryptor.write_all(chunk); }
+    let streamed = encryptor.finish().unwrap();
+    let cipher = ModeCipher::new(CAMELLIA_128::new_with_key(&key[..16]), OperationMode::CBC, &IV).encrypt(MESSAGE.as_bytes());
+    assert_eq!(streamed, cipher);
+
+    let mut decryptor = Decryptor::new(CAMELLIA_128::new_with_key(&key[..16]), OperationMode::CBC, &IV, cipher.as_slice());
+    let mut recovered = Vec::<u8>::new();
+    decryptor.read_to_end(&mut recovered).unwrap();
+    assert_eq!(recovered, MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
@@ -0,0 +1,327 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    chacha20_main();
+    xchacha20_main();
+    poly1305_main();
+    chacha20_poly1305_main();
+}
+
+fn chacha20_main()
+{
+    chacha20_keystream_block();
+    chacha20_encrypt_decrypt();
+    chacha20_encrypt_str();
+    chacha20_counter_limit();
+}
+
+fn chacha20_keystream_block()
+{
+    println!("chacha20_keystream_block");
+    use std::fmt::Write;
+    use cryptocol::symmetric::ChaCha20;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let nonce = [0x00_u8, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+    let a_chacha = ChaCha20::new_with_key(key);
+    let block = a_chacha.keystream_block(nonce, 1);
+    let mut txt = String::new();
+    for b in block
+        { write!(txt, "{:02X}", b); }
+    println!("Block =\t{}", txt);
+    assert_eq!(txt, "10F1E7E4D13B5915500FDD1FA32071C4C7D1F4C733C068030422AA9AC3D46C4ED2826446079FAA0914C2D705D98B02A2B5129CD1DE164EB9CBD083E8A2503C4E");
+    println!("-------------------------------");
+}
+
+fn chacha20_encrypt_decrypt()
+{
+    println!("chacha20_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::ChaCha20;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let nonce = [0x00_u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+    let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    println!("M =\t{}", message);
+
+    let mut a_chacha = ChaCha20::new_with_key(key);
+    a_chacha.set_counter(1);
+    let mut cipher = [0_u8; 114];
+    let length = a_chacha.encrypt(nonce, message.as_ptr(), message.len() as u64, cipher.as_mut_ptr());
+    assert_eq!(length, 114);
+    let mut txt = String::new();
+    for c in cipher
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "6E2E359A2568F98041BA0728DD0D6981E97E7AEC1D4360C20A27AFCCFD9FAE0BF91B65C5524733AB8F593DABCD62B3571639D624E65152AB8F530C359F0861D807CA0DBF500D6A6156A38E088A22B65E52BC514D16CCF806818CE91AB77937365AF90BBF74A35BE6B40B8EEDF2785E42874D");
+
+    let mut recovered = [0_u8; 114];
+    let length = a_chacha.decrypt(nonce, cipher.as_ptr(), cipher.len() as u64, recovered.as_mut_ptr());
+    assert_eq!(length, 114);
+    let txt = String::from_utf8(recovered.to_vec()).unwrap();
+    println!("D =\t{}", txt);
+    assert_eq!(txt, message);
+    println!("-------------------------------");
+}
+
+fn chacha20_encrypt_str()
+{
+    println!("chacha20_encrypt_str");
+    use cryptocol::symmetric::{ ChaCha20, ChaCha12, ChaCha8 };
+
+    let key = [0x11_u8; 32];
+    let nonce = [0x22_u8; 12];
+    let message = "Each reduced-round ChaCha gives a different keystream.";
+
+    let mut cipher20 = Vec::<u8>::new();
+    let mut cipher12 = Vec::<u8>::new();
+    let mut cipher8 = Vec::<u8>::new();
+    ChaCha20::new_with_key(key).encrypt_str_into_vec(nonce, message, &mut cipher20);
+    ChaCha12::new_with_key(key).encrypt_str_into_vec(nonce, message, &mut cipher12);
+    ChaCha8::new_with_key(key).encrypt_str_into_vec(nonce, message, &mut cipher8);
+    assert_ne!(cipher20, cipher12);
+    assert_ne!(cipher12, cipher8);
+
+    let mut recovered = Vec::<u8>::new();
+    ChaCha12::new_with_key(key).decrypt_into_vec(nonce, cipher12.as_ptr(), cipher12.len() as u64, &mut recovered);
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn chacha20_counter_limit()
+{
+    println!("chacha20_counter_limit");
+    use cryptocol::symmetric::ChaCha20;
+
+    let key = [0x33_u8; 32];
+    let nonce = [0x44_u8; 12];
+    let mut a_chacha = ChaCha20::new_with_key(key);
+    a_chacha.set_counter(u32::MAX);
+    assert!(a_chacha.is_length_valid(64));
+    assert!(!a_chacha.is_length_valid(65));
+
+    let mut cipher = Vec::<u8>::new();
+    a_chacha.encrypt_into_vec(nonce, [0_u8; 64].as_ptr(), 64, &mut cipher);
+    assert_eq!(cipher, a_chacha.keystream_block(nonce, u32::MAX).to_vec());
+
+    a_chacha.set_counter(0);
+    assert!(a_chacha.is_length_valid(1 << 38));
+    assert!(!a_chacha.is_length_valid((1 << 38) + 1));
+
+    #[cfg(test)] // It will panic.
+    chacha20_should_panic_counter_overflow();
+    println!("-------------------------------");
+}
+
+#[test]
+#[should_panic]
+fn chacha20_should_panic_counter_overflow()
+{
+    use cryptocol::symmetric::ChaCha20;
+
+    let mut a_chacha = ChaCha20::new_with_key([0x33_u8; 32]);
+    a_chacha.set_counter(u32::MAX);
+    let mut cipher = Vec::<u8>::new();
+    a_chacha.encrypt_into_vec([0x44_u8; 12], [0_u8; 65].as_ptr(), 65, &mut cipher);
+}
+
+fn xchacha20_main()
+{
+    xchacha20_encrypt_decrypt();
+}
+
+fn xchacha20_encrypt_decrypt()
+{
+    println!("xchacha20_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::XChaCha20;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = 0x80 + i as u8; }
+    let mut nonce = [0_u8; 24];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = 0x40 + i as u8; }
+    let message = "The dhole (pronounced \"dole\") is also known as the Asiatic wild dog, red dog, and whistling dog. It is about the size of a German shepherd but looks more like a long-legged fox. This highly elusive and skilled jumper is classified with wolves, coyotes, jackals, and foxes in the taxonomic family Canidae.";
+
+    let mut a_xchacha = XChaCha20::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    a_xchacha.encrypt_str_into_vec(nonce, message, &mut cipher);
+    let mut txt = String::new();
+    for c in cipher[..32].iter()
+        { write!(txt, "{:02X}", c); }
+    println!("C[..32] =\t{}", txt);
+    assert_eq!(txt, "2F717AA097099FF56C6F473BFDD6139732A20B16CCD293F4B21FE553AAD96EA6");
+
+    a_xchacha.set_counter(1);
+    a_xchacha.encrypt_str_into_vec(nonce, message, &mut cipher);
+    let mut txt = String::new();
+    for c in cipher[..32].iter()
+        { write!(txt, "{:02X}", c); }
+    println!("C[..32] =\t{}", txt);
+    assert_eq!(txt, "A56416D43F989B369E325FA384C1F33A4729110B6164BE88A5D6902E6B7564BB");
+
+    let mut recovered = Vec::<u8>::new();
+    a_xchacha.decrypt_into_vec(nonce, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn poly1305_main()
+{
+    poly1305_mac();
+    poly1305_update_in_pieces();
+}
+
+fn poly1305_mac()
+{
+    println!("poly1305_mac");
+    use std::fmt::Write;
+    use cryptocol::symmetric::Poly1305;
+
+    let key = [ 0x85_u8, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33,
+                0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5, 0x06, 0xa8,
+                0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd,
+                0x4a, 0xbf, 0xf6, 0xaf, 0x41, 0x49, 0xf5, 0x1b ];
+    let tag = Poly1305::mac(key, "Cryptographic Forum Research Group".as_bytes());
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("Tag =\t{}", txt);
+    assert_eq!(txt, "A8061DC1305136C6C22B8BAF0C0127A9");
+
+    // RFC 8439 A.3 Test Vector #11: h reaches p after the final reduction.
+    let mut key = [0_u8; 32];
+    key[0] = 0x01;
+    let mut message = [0xff_u8; 48];
+    message[16] = 0xfb;
+    message[17..32].fill(0xfe);
+    message[32..48].fill(0x01);
+    let tag = Poly1305::mac(key, &message);
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("Tag =\t{}", txt);
+    assert_eq!(txt, "00000000000000000000000000000000");
+    println!("-------------------------------");
+}
+
+fn poly1305_update_in_pieces()
+{
+    println!("poly1305_update_in_pieces");
+    use std::fmt::Write;
+    use cryptocol::symmetric::Poly1305;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    let mut message = [0_u8; 200];
+    for (i, m) in message.iter_mut().enumerate()
+        { *m = i as u8; }
+
+    let mut poly = Poly1305::new_with_key(key);
+    poly.update(&message[..3]);
+    poly.update(&message[3..20]);
+    poly.update(&message[20..32]);
+    poly.update(&message[32..199]);
+    poly.update(&message[199..]);
+    let tag = poly.finalize();
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("Tag =\t{}", txt);
+    assert_eq!(txt, "A3483759BAA2BD61B8B96682CAF7C440");
+    assert_eq!(tag, Poly1305::mac(key, &message));
+    println!("-------------------------------");
+}
+
+fn chacha20_poly1305_main()
+{
+    chacha20_poly1305_encrypt_decrypt();
+    xchacha20_poly1305_encrypt_decrypt();
+}
+
+fn chacha20_poly1305_encrypt_decrypt()
+{
+    println!("chacha20_poly1305_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::ChaCha20_Poly1305;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = 0x80 + i as u8; }
+    let nonce = [0x07_u8, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+    let aad = [0x50_u8, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7];
+    let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    let mut aead = ChaCha20_Poly1305::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &aad, message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.clone()
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "D31A8D34648E60DB7B86AFBC53EF7EC2A4ADED51296E08FEA9E2B5A736EE62D63DBEA45E8CA9671282FAFB69DA92728B1A71DE0A9E060B2905D6A5B67ECD3B3692DDBD7F2D778B8C9803AEE328091B58FAB324E4FAD675945585808B4831D7BC3FF4DEF08E4B7A9DE576D26586CEC64B6116");
+    let mut txt = String::new();
+    for t in tag
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "1AE10B594F09E26A7E902ECBD0600691");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+
+    let mut wrong_tag = tag;
+    wrong_tag[15] ^= 0x80;
+    let mut recovered = Vec::<u8>::new();
+    assert!(!aead.decrypt_into_vec(nonce, &aad, &cipher, wrong_tag, &mut recovered));
+    assert!(recovered.is_empty());
+    assert!(!aead.decrypt_into_vec(nonce, &aad[1..], &cipher, tag, &mut recovered));
+    println!("-------------------------------");
+}
+
+fn xchacha20_poly1305_encrypt_decrypt()
+{
+    println!("xchacha20_poly1305_encrypt_decrypt");
+    use std::fmt::Write;
+    use cryptocol::symmetric::XChaCha20_Poly1305;
+
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = 0x80 + i as u8; }
+    let mut nonce = [0_u8; 24];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = 0x40 + i as u8; }
+    let aad = [0x50_u8, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7];
+    let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    let mut aead = XChaCha20_Poly1305::new_with_key(key);
+    let mut cipher = Vec::<u8>::new();
+    let tag = aead.encrypt_into_vec(nonce, &aad, message.as_bytes(), &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.iter().chain(tag.iter())
+        { write!(txt, "{:02X}", c); }
+    println!("C||T =\t{}", txt);
+    assert_eq!(txt, "BD6D179D3E83D43B9576579493C0E939572A1700252BFACCBED2902C21396CBB731C7F1B0B4AA6440BF3A82F4EDA7E39AE64C6708C54C216CB96B72E1213B4522F8C9BA40DB5D945B11B69B982C1BB9E3F3FAC2BC369488F76B2383565D3FFF921F9664C97637DA9768812F615C68B13B52EC0875924C1C7987947DEAFD8780ACF49");
+
+    let mut recovered = Vec::<u8>::new();
+    assert!(aead.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
@@ -54,6 +54,9 @@ pub fn main()
 {
     // des_private_functions_main();
     des_encrypt_decrypt_u64_array_u64_main();
+    des_key_main();
+    des_bitsliced_main();
+    des_sbox_main();
     des_crypt_with_padding_pkcs7_main();
     des_crypt_with_padding_iso_main();
     des_crypt_with_padding_pkcs7_ecb_main();
@@ -5741,3 +5744,502 @@ fn des_encrypt_vec_ctr()
     assert_eq!(txt, "0123456789ABCDEF112233445566778899AABBCCDD");
     println!("-------------------------------");
 }
+
+fn des_key_main()
+{
+    des_set_key();
+    des_weak_key();
+    des_semi_weak_key();
+    des_possibly_weak_key();
+    des_weak_key_reduced_round();
+    des_odd_parity();
+    des_new_with_key_checked();
+    des_check_multiple_keys();
+}
+
+fn des_set_key()
+{
+    println!("des_set_key");
+    use cryptocol::symmetric::DES;
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let message = 0x_EFCDAB89_67452301_u64;
+    let mut a_des = DES::new();
+    a_des.set_key(key);
+    assert_eq!(a_des.get_key(), key);
+    let cipher = a_des.encrypt_u64(message);
+    println!("C_u64 =\t{:016X}", cipher);
+    assert_eq!(cipher, 0x_05B40A0F5413E885_u64);
+
+    a_des.set_key_u64(0);
+    assert_eq!(a_des.encrypt_u64(message), DES::new().encrypt_u64(message));
+    println!("-------------------------------");
+}
+
+fn des_weak_key()
+{
+    println!("des_weak_key");
+    use cryptocol::symmetric::DES;
+
+    let weak = [ 0x_01010101_01010101_u64, 0x_FEFEFEFE_FEFEFEFE, 0x_E0E0E0E0_F1F1F1F1, 0x_1F1F1F1F_0E0E0E0E,
+                 0x_00000000_00000000, 0x_E1E1E1E1_F0F0F0F0 ];     // without the parity bits
+    for w in weak
+    {
+        let mut a_des = DES::new_with_key(w.to_be_bytes());
+        assert!(a_des.is_weak_key());
+        assert!(!a_des.is_semi_weak_key());
+        assert!(!a_des.is_possibly_weak_key());
+        // Encryption with a weak key is the same as decryption.
+        let message = 0x_EFCDAB89_67452301_u64;
+        let cipher = a_des.encrypt_u64(message);
+        assert_eq!(a_des.encrypt_u64(cipher), message);
+    }
+    let a_des = DES::new_with_key([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    assert!(!a_des.is_weak_key());
+    println!("-------------------------------");
+}
+
+fn des_semi_weak_key()
+{
+    println!("des_semi_weak_key");
+    use cryptocol::symmetric::DES;
+
+    let pairs = [ (0x_011F011F_010E010E_u64, 0x_1F011F01_0E010E01_u64),
+                  (0x_01E001E0_01F101F1, 0x_E001E001_F101F101),
+                  (0x_01FE01FE_01FE01FE, 0x_FE01FE01_FE01FE01),
+                  (0x_1FE01FE0_0EF10EF1, 0x_E01FE01F_F10EF10E),
+                  (0x_1FFE1FFE_0EFE0EFE, 0x_FE1FFE1F_FE0EFE0E),
+                  (0x_E0FEE0FE_F1FEF1FE, 0x_FEE0FEE0_FEF1FEF1) ];
+    for (k1, k2) in pairs
+    {
+        let mut des1 = DES::new_with_key(k1.to_be_bytes());
+        let mut des2 = DES::new_with_key(k2.to_be_bytes());
+        assert!(des1.is_semi_weak_key());
+        assert!(des2.is_semi_weak_key());
+        assert!(!des1.is_weak_key());
+        // Encryption with one key of the pair is decryption with the other.
+        let message = 0x_EFCDAB89_67452301_u64;
+        let cipher = des1.encrypt_u64(message);
+        assert_eq!(des2.encrypt_u64(cipher), message);
+    }
+    println!("-------------------------------");
+}
+
+fn des_possibly_weak_key()
+{
+    println!("des_possibly_weak_key");
+    use cryptocol::symmetric::DES;
+
+    let possibly_weak = [ 0x_01011F1F_01010E0E_u64, 0x_1F1F0101_0E0E0101, 0x_E0E01F1F_F1F10E0E, 0x_0101E0E0_0101F1F1,
+                          0x_1F1FE0E0_0E0EF1F1, 0x_E0E00101_F1F10101, 0x_0101FEFE_0101FEFE, 0x_1F1FFEFE_0E0EFEFE,
+                          0x_1F010101_0E010101 ];
+    for p in possibly_weak
+    {
+        let a_des = DES::new_with_key(p.to_be_bytes());
+        assert!(a_des.is_possibly_weak_key());
+        assert!(!a_des.is_weak_key());
+        assert!(!a_des.is_semi_weak_key());
+    }
+    let a_des = DES::new_with_key([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    assert!(!a_des.is_possibly_weak_key());
+    println!("-------------------------------");
+}
+
+fn des_weak_key_reduced_round()
+{
+    println!("des_weak_key_reduced_round");
+    use cryptocol::symmetric::{ DES_Generic, KeyError };
+
+    // DES of a few rounds has only a few round keys, which must not make
+    // ordinary keys look weak.
+    let good = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    assert!(DES_Generic::<1>::new_with_key_checked(good).is_ok());
+    assert!(DES_Generic::<2>::new_with_key_checked(good).is_ok());
+    assert!(DES_Generic::<4>::new_with_key_checked(good).is_ok());
+    let mut a_des = DES_Generic::<3>::new();
+    assert_eq!(a_des.set_key_checked(good), Ok(()));
+    assert!(DES_Generic::<4>::check_multiple_keys(&[good, [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]]).is_ok());
+
+    // The weak keys, the semi-weak keys and the possibly weak keys are still
+    // detected whatever the number of rounds is.
+    assert_eq!(DES_Generic::<2>::new_with_key_checked([0x01_u8; 8]).err(), Some(KeyError::WeakKey));
+    assert_eq!(DES_Generic::<4>::new_with_key_checked([0x01_u8, 0xFE, 0x01, 0xFE, 0x01, 0xFE, 0x01, 0xFE]).err(), Some(KeyError::SemiWeakKey));
+    assert_eq!(DES_Generic::<4>::new_with_key_checked([0x01_u8, 0x01, 0x1F, 0x1F, 0x01, 0x01, 0x0E, 0x0E]).err(), Some(KeyError::PossiblyWeakKey));
+    assert_eq!(DES_Generic::<32>::new_with_key_checked([0x01_u8, 0x01, 0x1F, 0x1F, 0x01, 0x01, 0x0E, 0x0E]).err(), Some(KeyError::PossiblyWeakKey));
+    println!("-------------------------------");
+}
+
+fn des_odd_parity()
+{
+    println!("des_odd_parity");
+    use cryptocol::symmetric::DES;
+
+    let mut a_des = DES::new_with_key([0x12_u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+    assert!(!a_des.has_odd_parity());
+    let message = 0x_EFCDAB89_67452301_u64;
+    let cipher = a_des.encrypt_u64(message);
+    a_des.set_odd_parity();
+    assert!(a_des.has_odd_parity());
+    assert_eq!(a_des.get_key(), [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    // The parity bits are not used for encryption.
+    assert_eq!(a_des.encrypt_u64(message), cipher);
+    println!("-------------------------------");
+}
+
+fn des_new_with_key_checked()
+{
+    println!("des_new_with_key_checked");
+    use cryptocol::symmetric::{ DES, KeyError };
+
+    let good = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    assert!(DES::new_with_key_checked(good).is_ok());
+    assert_eq!(DES::new_with_key_checked([0x01_u8; 8]).err(), Some(KeyError::WeakKey));
+    assert_eq!(DES::new_with_key_checked([0_u8; 8]).err(), Some(KeyError::WeakKey));
+    assert_eq!(DES::new_with_key_checked([0x01_u8, 0xFE, 0x01, 0xFE, 0x01, 0xFE, 0x01, 0xFE]).err(), Some(KeyError::SemiWeakKey));
+    assert_eq!(DES::new_with_key_checked([0x01_u8, 0x01, 0x1F, 0x1F, 0x01, 0x01, 0x0E, 0x0E]).err(), Some(KeyError::PossiblyWeakKey));
+    assert_eq!(DES::new_with_key_checked([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF0]).err(), Some(KeyError::BadParity));
+
+    let mut a_des = DES::new_with_key_checked(good).unwrap();
+    assert_eq!(a_des.set_key_checked([0xFE_u8; 8]), Err(KeyError::WeakKey));
+    assert_eq!(a_des.get_key(), good);
+    assert_eq!(a_des.encrypt_u64(0x_EFCDAB89_67452301_u64), 0x_05B40A0F5413E885_u64);
+    let other = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    assert_eq!(a_des.set_key_checked(other), Ok(()));
+    assert_eq!(a_des.encrypt_u64(0), DES::new_with_key(other).encrypt_u64(0));
+    println!("-------------------------------");
+}
+
+fn des_check_multiple_keys()
+{
+    println!("des_check_multiple_keys");
+    use cryptocol::symmetric::{ DES, KeyError };
+
+    let k1 = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let k2 = [0x23_u8, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01];
+    let k3 = [0x45_u8, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23];
+    assert!(DES::check_multiple_keys(&[k1, k2]).is_ok());
+    assert!(DES::check_multiple_keys(&[k1, k2, k3]).is_ok());
+    assert!(DES::check_multiple_keys(&[k1, k2, k1]).is_ok());
+    assert_eq!(DES::check_multiple_keys(&[k1, k1]), Err(KeyError::DegenerateKeys));
+    assert_eq!(DES::check_multiple_keys(&[k1, k2, k2]), Err(KeyError::DegenerateKeys));
+
+    // The keys which differ only in the parity bits are the same keys.
+    let mut k1_parity = k1;
+    k1_parity[0] ^= 1;
+    assert_eq!(DES::check_multiple_keys(&[k1_parity, k1]), Err(KeyError::BadParity));
+    let mut a_des = DES::new_with_key(k1_parity);
+    let mut b_des = DES::new_with_key(k1);
+    assert_eq!(a_des.encrypt_u64(0), b_des.encrypt_u64(0));
+    assert_eq!(DES::check_multiple_keys(&[k1, [0x1F_u8, 0x1F, 0x1F, 0x1F, 0x0E, 0x0E, 0x0E, 0x0E]]), Err(KeyError::WeakKey));
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_main()
+{
+    des_bitsliced_one_block();
+    des_bitsliced_same_as_des();
+    des_bitsliced_array_u64();
+    des_bitsliced_mode_cipher();
+    des_bitsliced_desx();
+    des_bitsliced_throughput();
+}
+
+fn des_bitsliced_one_block()
+{
+    println!("des_bitsliced_one_block");
+    use cryptocol::symmetric::{ BlockCipher, DES_Bitsliced };
+
+    let mut a_des = DES_Bitsliced::new_with_key([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    let mut block = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    a_des.encrypt_one_block(&mut block);
+    assert_eq!(block, [0x85_u8, 0xE8, 0x13, 0x54, 0x0F, 0x0A, 0xB4, 0x05]);
+    a_des.decrypt_one_block(&mut block);
+    assert_eq!(block, [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+
+    a_des.set_key([0x0E_u8, 0x32, 0x92, 0x32, 0xEA, 0x6D, 0x0D, 0x73]);
+    let mut block = [0x87_u8; 8];
+    a_des.encrypt_one_block(&mut block);
+    assert_eq!(block, [0_u8; 8]);
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_same_as_des()
+{
+    println!("des_bitsliced_same_as_des");
+    use cryptocol::symmetric::{ BlockCipher, DES, DES_Bitsliced };
+    use cryptocol::random::Random;
+
+    let mut rand = Random::new();
+    for _ in 0..16
+    {
+        let key = rand.random_u64();
+        let mut a_des = DES::new_with_key_u64(key);
+        let mut b_des = DES_Bitsliced::new_with_key_u64(key);
+        assert_eq!(b_des.get_key(), key.to_le_bytes());
+        for blocks in [0_usize, 1, 2, 63, 64, 65, 200]
+        {
+            let mut message = vec![0_u8; blocks * 8];
+            for m in message.chunks_exact_mut(8)
+                { m.copy_from_slice(&rand.random_u64().to_le_bytes()); }
+            let mut expected = message.clone();
+            for e in expected.chunks_exact_mut(8)
+                { a_des.encrypt_one_block(e); }
+            let mut cipher = message.clone();
+            b_des.encrypt_blocks(&mut cipher);
+            assert_eq!(cipher, expected);
+            b_des.decrypt_blocks(&mut cipher);
+            assert_eq!(cipher, message);
+        }
+    }
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_array_u64()
+{
+    println!("des_bitsliced_array_u64");
+    use cryptocol::symmetric::{ DES, DES_Bitsliced };
+
+    let key = 0x_1234567890ABCDEF_u64;
+    let mut message = [0_u64; 130];
+    for (i, m) in message.iter_mut().enumerate()
+        { *m = (i as u64).wrapping_mul(0x_9E3779B97F4A7C15); }
+    let mut cipher = [0_u64; 130];
+    let mut expected = [0_u64; 130];
+    DES_Bitsliced::new_with_key_u64(key).encrypt_array_u64(&message, &mut cipher);
+    DES::new_with_key_u64(key).encrypt_array_u64(&message, &mut expected);
+    assert_eq!(cipher, expected);
+    let mut recovered = [0_u64; 130];
+    DES_Bitsliced::new_with_key_u64(key).decrypt_array_u64(&cipher, &mut recovered);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_mode_cipher()
+{
+    println!("des_bitsliced_mode_cipher");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ DES, DES_Bitsliced, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0xFF_u8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE];
+    let mut message = vec![0_u8; 1000];
+    for (i, m) in message.iter_mut().enumerate()
+        { *m = (i * 7) as u8; }
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut a_des = ModeCipher::new(DES::new_with_key(key), mode, &iv);
+        let mut b_des = ModeCipher::new(DES_Bitsliced::new_with_key(key), mode, &iv);
+        for length in [0_usize, 1, 8, 9, 511, 512, 513, 1000]
+        {
+            let cipher = b_des.encrypt(&message[..length]);
+            assert_eq!(cipher, a_des.encrypt(&message[..length]));
+            assert_eq!(b_des.decrypt(&cipher).unwrap(), &message[..length]);
+        }
+    }
+
+    // Encryptor and Decryptor send the whole blocks of ECB mode and CTR
+    // mode to encrypt_blocks() and decrypt_blocks() as well.
+    for mode in [OperationMode::ECB, OperationMode::CTR]
+    {
+        let mut encryptor = Encryptor::new(DES_Bitsliced::new_with_key(key), mode, &iv, Vec::<u8>::new());
+        for chunk in message.chunks(100)
+            { encryptor.write_all(chunk); }
+        let streamed = encryptor.finish().unwrap();
+        assert_eq!(streamed, ModeCipher::new(DES::new_with_key(key), mode, &iv).encrypt(&message));
+
+        let mut decryptor = Decryptor::new(DES_Bitsliced::new_with_key(key), mode, &iv, streamed.as_slice());
+        let mut recovered = Vec::<u8>::new();
+        decryptor.read_to_end(&mut recovered).unwrap();
+        assert_eq!(recovered, message);
+    }
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_desx()
+{
+    println!("des_bitsliced_desx");
+    use cryptocol::symmetric::{ DESX, DESX_Generic, DES_Bitsliced, ModeCipher, OperationMode };
+
+    let key = [ 0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+                0xF0, 0xE1, 0xD2, 0xC3, 0xB4, 0xA5, 0x96, 0x87,
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88 ];
+    let des = DES_Bitsliced::new_with_key([0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+    let desx = DESX_Generic::new(des, [0xF0_u8, 0xE1, 0xD2, 0xC3, 0xB4, 0xA5, 0x96, 0x87], [0x11_u8, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+    let message = [0x3C_u8; 777];
+    let cipher = ModeCipher::new(desx, OperationMode::ECB, &[]).encrypt(&message);
+    assert_eq!(cipher, ModeCipher::new(DESX::new_with_key(key), OperationMode::ECB, &[]).encrypt(&message));
+    println!("-------------------------------");
+}
+
+fn des_bitsliced_throughput()
+{
+    println!("des_bitsliced_throughput");
+    use std::io::Write;
+    use std::time::Instant;
+    use cryptocol::symmetric::{ BlockCipher, DES, DES_Bitsliced, Encryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let mut a_data = vec![0x5A_u8; 64 * 1024];
+    let mut b_data = a_data.clone();
+
+    let start = Instant::now();
+    DES::new_with_key(key).encrypt_blocks(&mut a_data);
+    println!("DES:\t\t{:?} for 64 KB", start.elapsed());
+    let start = Instant::now();
+    DES_Bitsliced::new_with_key(key).encrypt_blocks(&mut b_data);
+    println!("DES_Bitsliced:\t{:?} for 64 KB", start.elapsed());
+    assert_eq!(a_data, b_data);
+
+    // Encryptor of CTR mode encrypts 64 blocks at once with DES_Bitsliced.
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let start = Instant::now();
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CTR, &iv, Vec::<u8>::new());
+    encryptor.write_all(&a_data);
+    let a_cipher = encryptor.finish().unwrap();
+    println!("DES in Encryptor:\t\t{:?} for 64 KB", start.elapsed());
+    let start = Instant::now();
+    let mut encryptor = Encryptor::new(DES_Bitsliced::new_with_key(key), OperationMode::CTR, &iv, Vec::<u8>::new());
+    encryptor.write_all(&a_data);
+    let b_cipher = encryptor.finish().unwrap();
+    println!("DES_Bitsliced in Encryptor:\t{:?} for 64 KB", start.elapsed());
+    assert_eq!(a_cipher, b_cipher);
+    println!("-------------------------------");
+}
+
+fn des_sbox_main()
+{
+    des_sbox_from_rows();
+    des_set_sbox();
+    des_key_dependent_sbox();
+}
+
+fn des_sbox_from_rows()
+{
+    println!("des_sbox_from_rows");
+    use cryptocol::symmetric::{ DES, DES_RuntimeSbox };
+
+    // The S-boxes of 4 rows and 16 columns in FIPS 46-3
+    let rows = [
+        [   14,  4, 13,  1,  2, 15, 11,  8,  3, 10,  6, 12,  5,  9,  0,  7,
+             0, 15,  7,  4, 14,  2, 13,  1, 10,  6, 12, 11,  9,  5,  3,  8,
+             4,  1, 14,  8, 13,  6,  2, 11, 15, 12,  9,  7,  3, 10,  5,  0,
+            15, 12,  8,  2,  4,  9,  1,  7,  5, 11,  3, 14, 10,  0,  6, 13_u8 ],
+        [   15,  1,  8, 14,  6, 11,  3,  4,  9,  7,  2, 13, 12,  0,  5, 10,
+             3, 13,  4,  7, 15,  2,  8, 14, 12,  0,  1, 10,  6,  9, 11,  5,
+             0, 14,  7, 11, 10,  4, 13,  1,  5,  8, 12,  6,  9,  3,  2, 15,
+            13,  8, 10,  1,  3, 15,  4,  2, 11,  6,  7, 12,  0,  5, 14,  9 ],
+        [   10,  0,  9, 14,  6,  3, 15,  5,  1, 13, 12,  7, 11,  4,  2,  8,
+            13,  7,  0,  9,  3,  4,  6, 10,  2,  8,  5, 14, 12, 11, 15,  1,
+            13,  6,  4,  9,  8, 15,  3,  0, 11,  1,  2, 12,  5, 10, 14,  7,
+             1, 10, 13,  0,  6,  9,  8,  7,  4, 15, 14,  3, 11,  5,  2, 12 ],
+        [    7, 13, 14,  3,  0,  6,  9, 10,  1,  2,  8,  5, 11, 12,  4, 15,
+            13,  8, 11,  5,  6, 15,  0,  3,  4,  7,  2, 12,  1, 10, 14,  9,
+            10,  6,  9,  0, 12, 11,  7, 13, 15,  1,  3, 14,  5,  2,  8,  4,
+             3, 15,  0,  6, 10,  1, 13,  8,  9,  4,  5, 11, 12,  7,  2, 14 ],
+        [    2, 12,  4,  1,  7, 10, 11,  6,  8,  5,  3, 15, 13,  0, 14,  9,
+            14, 11,  2, 12,  4,  7, 13,  1,  5,  0, 15, 10,  3,  9,  8,  6,
+             4,  2,  1, 11, 10, 13,  7,  8, 15,  9, 12,  5,  6,  3,  0, 14,
+            11,  8, 12,  7,  1, 14,  2, 13,  6, 15,  0,  9, 10,  4,  5,  3 ],
+        [   12,  1, 10, 15,  9,  2,  6,  8,  0, 13,  3,  4, 14,  7,  5, 11,
+            10, 15,  4,  2,  7, 12,  9,  5,  6,  1, 13, 14,  0, 11,  3,  8,
+             9, 14, 15,  5,  2,  8, 12,  3,  7,  0,  4, 10,  1, 13, 11,  6,
+             4,  3,  2, 12,  9,  5, 15, 10, 11, 14,  1,  7,  6,  0,  8, 13 ],
+        [    4, 11,  2, 14, 15,  0,  8, 13,  3, 12,  9,  7,  5, 10,  6,  1,
+            13,  0, 11,  7,  4,  9,  1, 10, 14,  3,  5, 12,  2, 15,  8,  6,
+             1,  4, 11, 13, 12,  3,  7, 14, 10, 15,  6,  8,  0,  5,  9,  2,
+             6, 11, 13,  8,  1,  4, 10,  7,  9,  5,  0, 15, 14,  2,  3, 12 ],
+        [   13,  2,  8,  4,  6, 15, 11,  1, 10,  9,  3, 14,  5,  0, 12,  7,
+             1, 15, 13,  8, 10,  3,  7,  4, 12,  5,  6, 11,  0, 14,  9,  2,
+             7, 11,  4,  1,  9, 12, 14,  2,  0,  6, 10, 13, 15,  3,  5,  8,
+             2,  1, 14,  7,  4, 10,  8, 13, 15, 12,  9,  0,  3,  5,  6, 11 ]
+    ];
+    let sbox = DES_RuntimeSbox::sbox_from_rows(rows);
+    assert_eq!(sbox, DES::get_sbox_table());
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let mut a_des = DES_RuntimeSbox::new_with_key_and_sbox(key, sbox);
+    assert_eq!(a_des.encrypt_u64(0x_EFCDAB89_67452301_u64), 0x_05B40A0F5413E885_u64);
+    println!("-------------------------------");
+}
+
+fn des_set_sbox()
+{
+    println!("des_set_sbox");
+    use cryptocol::symmetric::{ DES, DES_RuntimeSbox };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let message = 0x_EFCDAB89_67452301_u64;
+    let original = DES::get_sbox_table();
+    let mut a_des = DES_RuntimeSbox::new_with_key_and_sbox(key, original);
+    assert_eq!(a_des.encrypt_u64(message), 0x_05B40A0F5413E885_u64);
+
+    // The S-boxes of DES in the reverse order
+    let mut sbox = original;
+    sbox.reverse();
+    a_des.set_sbox(sbox);
+    assert_eq!(a_des.get_sbox(), sbox);
+    let cipher = a_des.encrypt_u64(message);
+    println!("C =\t{:#018X}", cipher);
+    assert_ne!(cipher, 0x_05B40A0F5413E885_u64);
+    assert_eq!(a_des.decrypt_u64(cipher), message);
+
+    // The S-boxes survive the change of the key.
+    a_des.set_key([0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+    assert_eq!(a_des.get_sbox(), sbox);
+    a_des.set_key(key);
+    assert_eq!(a_des.encrypt_u64(message), cipher);
+
+    a_des.set_sbox(original);
+    assert_eq!(a_des.get_sbox(), original);
+    assert_eq!(a_des.encrypt_u64(message), 0x_05B40A0F5413E885_u64);
+    println!("-------------------------------");
+}
+
+fn des_key_dependent_sbox()
+{
+    println!("des_key_dependent_sbox");
+    use cryptocol::symmetric::{ DES, DES_RuntimeSbox, ModeCipher, OperationMode };
+
+    // An example of the key-dependent S-boxes: the rows of each S-box are
+    // rotated and the S-boxes are reordered according to the extra key,
+    // which keeps every row of every S-box a permutation of 0 ~ 15.
+    fn make_sbox(extra_key: [u8; 8]) -> [[u8; 64]; 8]
+    {
+        let standard = DES::get_sbox_table();
+        let mut sbox = [[0_u8; 64]; 8];
+        for (i, s) in sbox.iter_mut().enumerate()
+        {
+            let from = &standard[(i + extra_key[0] as usize) % 8];
+            let rotation = (extra_key[i] & 0b11) as usize;
+            for (input, element) in s.iter_mut().enumerate()
+            {
+                let row = ((input >> 4) & 0b10) | (input & 1);
+                let rotated = (row + rotation) % 4;
+                *element = from[(input & 0b011110) | ((rotated & 0b10) << 4) | (rotated & 1)];
+            }
+        }
+        sbox
+    }
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let extra_key = [0x5A_u8, 0x3C, 0x96, 0x0F, 0xE1, 0x72, 0x8D, 0x4B];
+    let sbox = make_sbox(extra_key);
+    for s in sbox.iter()
+    {
+        for row in 0..4
+        {
+            let mut seen = [false; 16];
+            for column in 0..16
+                { seen[s[((row & 0b10) << 4) | (column << 1) | (row & 1)] as usize] = true; }
+            assert!(seen.iter().all(|s| *s));
+        }
+    }
+
+    let message = "In the beginning God created the heavens and the earth.".as_bytes();
+    let iv = [0x87_u8, 0x65, 0x43, 0x21, 0x09, 0xBA, 0xDC, 0xFE];
+    let mut a_des = ModeCipher::new(DES_RuntimeSbox::new_with_key_and_sbox(key, sbox), OperationMode::CBC, &iv);
+    let cipher = a_des.encrypt(message);
+    assert_ne!(cipher, ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv).encrypt(message));
+    assert_eq!(a_des.decrypt(&cipher).unwrap(), message);
+    println!("-------------------------------");
+}
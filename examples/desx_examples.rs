@@ -0,0 +1,196 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    desx_main();
+    desx_mode_main();
+}
+
+fn desx_main()
+{
+    desx_new_with_key();
+    desx_same_as_des();
+    desx_encrypt_decrypt_u64();
+    desx_generic();
+}
+
+fn desx_mode_main()
+{
+    desx_cbc_openssl();
+    desx_all_modes_and_paddings();
+    desx_stream();
+}
+
+// The key of DES, the pre-whitening key and the post-whitening key in this order
+const KEY: [u8; 24] = [ 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+                        0xF0, 0xE1, 0xD2, 0xC3, 0xB4, 0xA5, 0x96, 0x87,
+                        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88 ];
+const MESSAGE: &str = "In the beginning God created the heavens and the earth. Now the earth was formless and empty.";
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn desx_new_with_key()
+{
+    println!("desx_new_with_key");
+    use cryptocol::symmetric::{ BlockCipher, DESX };
+
+    // The same result as `openssl enc -desx-cbc` with the zero IV
+    let mut desx = DESX::new_with_key(KEY);
+    let mut block = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xE7];
+    desx.encrypt_one_block(&mut block);
+    println!("C =\t{}", to_hex(&block));
+    assert_eq!(to_hex(&block), "40BC5F0E6B31907C");
+    desx.decrypt_one_block(&mut block);
+    assert_eq!(block, [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xE7]);
+    println!("-------------------------------");
+}
+
+fn desx_same_as_des()
+{
+    println!("desx_same_as_des");
+    use cryptocol::symmetric::{ BlockCipher, DES, DESX_Generic };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let pre = [0x0F_u8, 0x1E, 0x2D, 0x3C, 0x4B, 0x5A, 0x69, 0x78];
+    let post = [0x87_u8, 0x96, 0xA5, 0xB4, 0xC3, 0xD2, 0xE1, 0xF0];
+    let message = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+
+    // With the zero whitening keys, DESX is the same as DES.
+    let mut desx = DESX_Generic::new(DES::new_with_key(key), [0_u8; 8], [0_u8; 8]);
+    let mut block = message;
+    desx.encrypt_one_block(&mut block);
+    assert_eq!(to_hex(&block), "85E813540F0AB405");
+
+    // C = K2 ^ DES_K(M ^ K1)
+    let mut desx = DESX_Generic::new(DES::new_with_key(key), pre, post);
+    let mut block = message;
+    desx.encrypt_one_block(&mut block);
+    let mut expected = [0_u8; 8];
+    for i in 0..8
+        { expected[i] = message[i] ^ pre[i]; }
+    DES::new_with_key(key).encrypt_one_block(&mut expected);
+    for i in 0..8
+        { expected[i] ^= post[i]; }
+    assert_eq!(block, expected);
+    println!("-------------------------------");
+}
+
+fn desx_encrypt_decrypt_u64()
+{
+    println!("desx_encrypt_decrypt_u64");
+    use cryptocol::symmetric::DESX;
+
+    let mut desx = DESX::new_with_key(KEY);
+    let message = u64::from_le_bytes([0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xE7]);
+    let cipher = desx.encrypt_u64(message);
+    println!("C =\t{:#018X}", cipher);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "40BC5F0E6B31907C");
+    assert_eq!(desx.decrypt_u64(cipher), message);
+    println!("-------------------------------");
+}
+
+fn desx_generic()
+{
+    println!("desx_generic");
+    use cryptocol::symmetric::{ BlockCipher, DES_Generic, DESX_Generic };
+
+    // DESX over the expanded DES of 128 rounds
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let des = DES_Generic::<128>::new_with_key(key);
+    let mut desx = DESX_Generic::new(des, [0x5A_u8; 8], [0xA5_u8; 8]);
+    let message = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let mut block = message;
+    desx.encrypt_one_block(&mut block);
+    assert_ne!(block, message);
+    desx.decrypt_one_block(&mut block);
+    assert_eq!(block, message);
+    println!("-------------------------------");
+}
+
+fn desx_cbc_openssl()
+{
+    println!("desx_cbc_openssl");
+    use cryptocol::symmetric::{ DESX, ModeCipher, OperationMode, No_Padding };
+
+    // The same results as `openssl enc -desx-cbc -K <KEY> -iv 1234567890ABCDEF`
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut desx = ModeCipher::new_with_padding(DESX::new_with_key(KEY), OperationMode::CBC, &iv, No_Padding);
+    let cipher = desx.encrypt("Now is the time for all ".as_bytes());
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(to_hex(&cipher), "4DB0D0573C71B611AF36FB218947A7F83B31C4D37C09046B");
+    assert_eq!(desx.decrypt(&cipher).unwrap(), "Now is the time for all ".as_bytes());
+
+    let mut desx = ModeCipher::new(DESX::new_with_key(KEY), OperationMode::CBC, &iv);
+    let cipher = desx.encrypt("Now is the time for all".as_bytes());
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(to_hex(&cipher), "4DB0D0573C71B611AF36FB218947A7F877A2595C9A233B93");
+    assert_eq!(desx.decrypt(&cipher).unwrap(), "Now is the time for all".as_bytes());
+    println!("-------------------------------");
+}
+
+fn desx_all_modes_and_paddings()
+{
+    println!("desx_all_modes_and_paddings");
+    use cryptocol::symmetric::{ DESX, ModeCipher, OperationMode, Padding,
+                                PKCS7_Padding, ANSI_X923_Padding, ISO7816_Padding, ISO10126_Padding };
+
+    fn round_trip<P: Padding + Copy>(padding: P)
+    {
+        let iv = [0x0F_u8; 8];
+        for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+        {
+            let mut desx = ModeCipher::new_with_padding(DESX::new_with_key(KEY), mode, &iv, padding);
+            for length in [0_usize, 1, 7, 8, 9, MESSAGE.len()]
+            {
+                let message = &MESSAGE.as_bytes()[..length];
+                let cipher = desx.encrypt(message);
+                assert_eq!(cipher.len(), desx.encrypted_length(length));
+                assert_eq!(desx.decrypt(&cipher).unwrap(), message);
+            }
+        }
+    }
+
+    round_trip(PKCS7_Padding);
+    round_trip(ANSI_X923_Padding);
+    round_trip(ISO7816_Padding);
+    round_trip(ISO10126_Padding);
+    println!("-------------------------------");
+}
+
+fn desx_stream()
+{
+    println!("desx_stream");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ DESX, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut encryptor = Encryptor::new(DESX::new_with_key(KEY), OperationMode::CBC, &iv, Vec::<u8>::new());
+    for chunk in MESSAGE.as_bytes().chunks(5)
+        { encryptor.write_all(chunk); }
+    let streamed = encryptor.finish().unwrap();
+    let cipher = ModeCipher::new(DESX::new_with_key(KEY), OperationMode::CBC, &iv).encrypt(MESSAGE.as_bytes());
+    assert_eq!(streamed, cipher);
+
+    let mut decryptor = Decryptor::new(DESX::new_with_key(KEY), OperationMode::CBC, &iv, cipher.as_slice());
+    let mut recovered = Vec::<u8>::new();
+    decryptor.read_to_end(&mut recovered).unwrap();
+    assert_eq!(recovered, MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
@@ -0,0 +1,283 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    feistel_main();
+    luby_rackoff_main();
+    lai_massey_main();
+}
+
+fn feistel_main()
+{
+    feistel_small_domain_permutation();
+    feistel_unbalanced();
+    feistel_round_function_trait();
+}
+
+fn luby_rackoff_main()
+{
+    luby_rackoff_encrypt_decrypt();
+    luby_rackoff_mode_cipher();
+}
+
+fn lai_massey_main()
+{
+    lai_massey_encrypt_decrypt();
+    lai_massey_small_domain_permutation();
+}
+
+fn to_hex(data: &[u8]) -> String
+{
+    data.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+// Not invertible at all: it maps every input to 0 or 0xFF only.
+fn lossy_function(round_key: &[u8], input: &[u8], output: &mut [u8])
+{
+    let bit = (input.iter().map(|b| b.count_ones()).sum::<u32>() + round_key[0] as u32) & 1;
+    for o in output.iter_mut()
+        { *o = if bit == 1 { 0xFF } else { 0 }; }
+}
+
+fn mixing_function(round_key: &[u8], input: &[u8], output: &mut [u8])
+{
+    for (i, o) in output.iter_mut().enumerate()
+    {
+        let x = input[i % input.len()] ^ round_key[i % round_key.len()];
+        *o = x.wrapping_mul(0x9D).rotate_left(3) ^ (i as u8);
+    }
+}
+
+fn feistel_small_domain_permutation()
+{
+    println!("feistel_small_domain_permutation");
+    use cryptocol::symmetric::{ Feistel_Generic, RoundFn };
+
+    // Even with the round function which is not invertible, the Feistel
+    // network is a permutation of the 65536 blocks of 2 bytes.
+    let feistel = Feistel_Generic::<RoundFn, 2, 1, 6>::new_with_key(lossy_function, &[0x5A]);
+    let mut seen = vec![false; 1 << 16];
+    for i in 0..(1_u32 << 16)
+    {
+        let message = (i as u16).to_be_bytes();
+        let cipher = feistel.encrypt(message);
+        let index = u16::from_be_bytes(cipher) as usize;
+        assert!(!seen[index]);
+        seen[index] = true;
+        assert_eq!(feistel.decrypt(cipher), message);
+    }
+    assert!(seen.iter().all(|s| *s));
+    println!("All 65536 blocks are permuted.");
+    println!("-------------------------------");
+}
+
+fn feistel_unbalanced()
+{
+    println!("feistel_unbalanced");
+    use cryptocol::symmetric::{ Feistel_Generic, RoundFn };
+
+    let key = "unbalanced".as_bytes();
+    let message = *b"0123456789";
+
+    // Source-heavy: F modifies 3 bytes with the other 7 bytes.
+    let source_heavy = Feistel_Generic::<RoundFn, 10, 3, 12>::new_with_key(mixing_function, key);
+    let cipher = source_heavy.encrypt(message);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_ne!(cipher, message);
+    assert_eq!(source_heavy.decrypt(cipher), message);
+
+    // Target-heavy: F modifies 7 bytes with the other 3 bytes.
+    let target_heavy = Feistel_Generic::<RoundFn, 10, 7, 12>::new_with_key(mixing_function, key);
+    let cipher = target_heavy.encrypt(message);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_ne!(cipher, message);
+    assert_eq!(target_heavy.decrypt(cipher), message);
+
+    // Balanced with the same round function, for comparison
+    let balanced = Feistel_Generic::<RoundFn, 10, 5, 12>::new_with_key(mixing_function, key);
+    let cipher = balanced.encrypt(message);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(balanced.decrypt(cipher), message);
+    println!("-------------------------------");
+}
+
+fn feistel_round_function_trait()
+{
+    println!("feistel_round_function_trait");
+    use cryptocol::symmetric::{ Feistel_Generic, RoundFunction };
+
+    // A round function with its own key schedule
+    #[derive(Debug, Clone)]
+    struct RotatingKey;
+
+    impl RoundFunction for RotatingKey
+    {
+        fn key_schedule(&self, key: &[u8], round: usize) -> Vec<Vec<u8>>
+        {
+            let mut round_key = key.to_vec();
+            (0..round).map(|_| {
+                    round_key.rotate_left(1);
+                    round_key.clone()
+                }).collect()
+        }
+
+        fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+        {
+            mixing_function(round_key, input, output);
+        }
+    }
+
+    let mut feistel = Feistel_Generic::<RotatingKey, 8, 4, 16>::new_with_key(RotatingKey, &[1, 2, 3, 4, 5]);
+    let message = *b"teaching";
+    let cipher1 = feistel.encrypt(message);
+    assert_eq!(feistel.decrypt(cipher1), message);
+
+    feistel.set_key(&[5, 4, 3, 2, 1]);
+    let cipher2 = feistel.encrypt(message);
+    println!("C1 =\t{}\nC2 =\t{}", to_hex(&cipher1), to_hex(&cipher2));
+    assert_ne!(cipher1, cipher2);
+    assert_eq!(feistel.decrypt(cipher2), message);
+
+    #[cfg(test)] // It will panic.
+    feistel_should_panic_new_with_key();
+    #[cfg(test)] // It will panic.
+    lai_massey_should_panic_new_with_key();
+    println!("-------------------------------");
+}
+
+// A round function whose key schedule gives one round key too few
+#[derive(Debug, Clone)]
+struct ShortKeySchedule;
+
+impl cryptocol::symmetric::RoundFunction for ShortKeySchedule
+{
+    fn key_schedule(&self, key: &[u8], round: usize) -> Vec<Vec<u8>>
+    {
+        vec![key.to_vec(); round - 1]
+    }
+
+    fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+    {
+        mixing_function(round_key, input, output);
+    }
+}
+
+#[test]
+#[should_panic]
+fn feistel_should_panic_new_with_key()
+{
+    use cryptocol::symmetric::Feistel_Generic;
+    let _feistel = Feistel_Generic::<ShortKeySchedule, 8, 4, 16>::new_with_key(ShortKeySchedule, &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn lai_massey_should_panic_new_with_key()
+{
+    use cryptocol::symmetric::LaiMassey_Generic;
+    let _lai_massey = LaiMassey_Generic::<ShortKeySchedule, 8, 16>::new_with_key(ShortKeySchedule, &[1, 2, 3, 4, 5]);
+}
+
+fn luby_rackoff_encrypt_decrypt()
+{
+    println!("luby_rackoff_encrypt_decrypt");
+    use cryptocol::symmetric::LubyRackoff_Generic;
+
+    let lr = LubyRackoff_Generic::<16, 8>::new_with_key(Default::default(), "Luby-Rackoff key".as_bytes());
+    let message = *b"Luby and Rackoff";
+    let cipher = lr.encrypt(message);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_ne!(cipher, message);
+    assert_eq!(lr.decrypt(cipher), message);
+
+    // The output of F longer than one hash value of SHA-256
+    let lr = LubyRackoff_Generic::<100, 50, 5>::new_with_key(Default::default(), "key".as_bytes());
+    let mut message = [0_u8; 100];
+    for (i, m) in message.iter_mut().enumerate()
+        { *m = i as u8; }
+    let cipher = lr.encrypt(message);
+    assert_ne!(cipher, message);
+    assert_eq!(lr.decrypt(cipher), message);
+
+    // One bit of difference in the key changes the whole block.
+    let lr1 = LubyRackoff_Generic::<16, 8>::new_with_key(Default::default(), &[0]);
+    let lr2 = LubyRackoff_Generic::<16, 8>::new_with_key(Default::default(), &[1]);
+    let c1 = lr1.encrypt([0; 16]);
+    let c2 = lr2.encrypt([0; 16]);
+    println!("C1 =\t{}\nC2 =\t{}", to_hex(&c1), to_hex(&c2));
+    assert_ne!(c1[..8], c2[..8]);
+    assert_ne!(c1[8..], c2[8..]);
+    println!("-------------------------------");
+}
+
+fn luby_rackoff_mode_cipher()
+{
+    println!("luby_rackoff_mode_cipher");
+    use cryptocol::symmetric::{ LubyRackoff_Generic, ModeCipher, OperationMode };
+
+    type LR = LubyRackoff_Generic<16, 8>;
+    let key = "Luby-Rackoff key".as_bytes();
+    let iv = [0x42_u8; 16];
+    let message = "The Feistel network can be used in any mode of operation.".as_bytes();
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let cipher = ModeCipher::new(LR::new_with_key(Default::default(), key), mode, &iv).encrypt(message);
+        let recovered = ModeCipher::new(LR::new_with_key(Default::default(), key), mode, &iv).decrypt(&cipher).unwrap();
+        assert_eq!(recovered, message);
+    }
+    println!("-------------------------------");
+}
+
+fn lai_massey_encrypt_decrypt()
+{
+    println!("lai_massey_encrypt_decrypt");
+    use cryptocol::symmetric::{ LaiMassey_Generic, SHA2_256_RoundFunction, RoundFn };
+
+    let lm = LaiMassey_Generic::<SHA2_256_RoundFunction, 16, 8>::new_with_key(SHA2_256_RoundFunction, "key".as_bytes());
+    let message = *b"Lai-Massey block";
+    let cipher = lm.encrypt(message);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_ne!(cipher, message);
+    assert_eq!(lm.decrypt(cipher), message);
+
+    // One round only, where the orthomorphism is not applied
+    let lm = LaiMassey_Generic::<RoundFn, 8, 1>::new_with_key(mixing_function, &[7]);
+    let message = *b"one rnd!";
+    let cipher = lm.encrypt(message);
+    assert_eq!(lm.decrypt(cipher), message);
+    println!("-------------------------------");
+}
+
+fn lai_massey_small_domain_permutation()
+{
+    println!("lai_massey_small_domain_permutation");
+    use cryptocol::symmetric::{ LaiMassey_Generic, RoundFn };
+
+    // The smallest block is 4 bytes, so check the permutation on 65536
+    // blocks whose other 2 bytes are fixed.
+    let lm = LaiMassey_Generic::<RoundFn, 4, 5>::new_with_key(lossy_function, &[0xA5]);
+    let mut ciphers = Vec::with_capacity(1 << 16);
+    for i in 0..(1_u32 << 16)
+    {
+        let [a, b] = (i as u16).to_be_bytes();
+        let message = [a, 0x12, b, 0x34];
+        let cipher = lm.encrypt(message);
+        assert_eq!(lm.decrypt(cipher), message);
+        ciphers.push(u32::from_be_bytes(cipher));
+    }
+    ciphers.sort_unstable();
+    ciphers.dedup();
+    assert_eq!(ciphers.len(), 1 << 16);
+    println!("All 65536 blocks are mapped to distinct blocks.");
+    println!("-------------------------------");
+}
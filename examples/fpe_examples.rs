@@ -0,0 +1,285 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    ff1_main();
+    ff3_1_main();
+}
+
+fn ff1_main()
+{
+    ff1_aes_128_samples();
+    ff1_aes_192_samples();
+    ff1_aes_256_samples();
+    ff1_credit_card_number();
+    ff1_large_radix();
+    ff1_invalid_input();
+}
+
+fn ff3_1_main()
+{
+    ff3_1_encrypt_decrypt();
+    ff3_1_tweak();
+    ff3_1_length_limits();
+    ff3_1_generic();
+}
+
+fn from_hex(txt: &str) -> Vec<u8>
+{
+    (0..txt.len()).step_by(2).map(|i| u8::from_str_radix(&txt[i..i+2], 16).unwrap()).collect()
+}
+
+const TWEAK2: &str = "39383736353433323130";
+const TWEAK3: &str = "3737373770717273373737";
+
+fn ff1_aes_128_samples()
+{
+    println!("ff1_aes_128_samples");
+    use cryptocol::symmetric::{ AES_128, AES_128_FF1 };
+
+    // Samples 1 ~ 3 of FF1 of NIST
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3C");
+    let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 10);
+    let c1 = ff1.encrypt_str(&[], "0123456789").unwrap();
+    let c2 = ff1.encrypt_str(&from_hex(TWEAK2), "0123456789").unwrap();
+    println!("C1 =\t{}\nC2 =\t{}", c1, c2);
+    assert_eq!(c1, "2433477484");
+    assert_eq!(c2, "6124200773");
+    assert_eq!(ff1.decrypt_str(&[], &c1).unwrap(), "0123456789");
+    assert_eq!(ff1.decrypt_str(&from_hex(TWEAK2), &c2).unwrap(), "0123456789");
+
+    let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 36);
+    let c3 = ff1.encrypt_str(&from_hex(TWEAK3), "0123456789abcdefghi").unwrap();
+    println!("C3 =\t{}", c3);
+    assert_eq!(c3, "a9tv40mll9kdu509eum");
+    assert_eq!(ff1.decrypt_str(&from_hex(TWEAK3), &c3).unwrap(), "0123456789abcdefghi");
+    println!("-------------------------------");
+}
+
+fn ff1_aes_192_samples()
+{
+    println!("ff1_aes_192_samples");
+    use cryptocol::symmetric::{ AES_192, AES_192_FF1 };
+
+    // Samples 4 ~ 6 of FF1 of NIST
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3CEF4359D8D580AA4F");
+    let mut ff1 = AES_192_FF1::new(AES_192::new_with_key(&key), 10);
+    let c4 = ff1.encrypt_str(&[], "0123456789").unwrap();
+    let c5 = ff1.encrypt_str(&from_hex(TWEAK2), "0123456789").unwrap();
+    println!("C4 =\t{}\nC5 =\t{}", c4, c5);
+    assert_eq!(c4, "2830668132");
+    assert_eq!(c5, "2496655549");
+
+    let mut ff1 = AES_192_FF1::new(AES_192::new_with_key(&key), 36);
+    let c6 = ff1.encrypt_str(&from_hex(TWEAK3), "0123456789abcdefghi").unwrap();
+    println!("C6 =\t{}", c6);
+    assert_eq!(c6, "xbj3kv35jrawxv32ysr");
+    assert_eq!(ff1.decrypt_str(&from_hex(TWEAK3), &c6).unwrap(), "0123456789abcdefghi");
+    println!("-------------------------------");
+}
+
+fn ff1_aes_256_samples()
+{
+    println!("ff1_aes_256_samples");
+    use cryptocol::symmetric::{ AES_256, AES_256_FF1 };
+
+    // Samples 7 ~ 9 of FF1 of NIST
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3CEF4359D8D580AA4F7F036D6F04FC6A94");
+    let mut ff1 = AES_256_FF1::new(AES_256::new_with_key(&key), 10);
+    let c7 = ff1.encrypt_str(&[], "0123456789").unwrap();
+    let c8 = ff1.encrypt_str(&from_hex(TWEAK2), "0123456789").unwrap();
+    println!("C7 =\t{}\nC8 =\t{}", c7, c8);
+    assert_eq!(c7, "6657667009");
+    assert_eq!(c8, "1001623463");
+
+    let mut ff1 = AES_256_FF1::new(AES_256::new_with_key(&key), 36);
+    let c9 = ff1.encrypt_str(&from_hex(TWEAK3), "0123456789abcdefghi").unwrap();
+    println!("C9 =\t{}", c9);
+    assert_eq!(c9, "xs8a0azh2avyalyzuwd");
+    assert_eq!(ff1.decrypt_str(&from_hex(TWEAK3), &c9).unwrap(), "0123456789abcdefghi");
+    println!("-------------------------------");
+}
+
+fn ff1_credit_card_number()
+{
+    println!("ff1_credit_card_number");
+    use cryptocol::symmetric::{ AES_256, AES_256_FF1, CipherError };
+
+    // The first six digits and the last four digits are kept in clear, and
+    // the last four digits work as the tweak.
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3CEF4359D8D580AA4F7F036D6F04FC6A94");
+    let mut ff1 = AES_256_FF1::new(AES_256::new_with_key(&key), 10);
+    for pan in ["4111111111111111", "5500000000000004", "6011000000000004", "3530111333300000000"]
+    {
+        let len = pan.len();
+        let middle = ff1.encrypt_str(&pan.as_bytes()[len-4..], &pan[6..len-4]).unwrap();
+        let token = format!("{}{}{}", &pan[..6], middle, &pan[len-4..]);
+        println!("PAN =\t{}\nToken =\t{}", pan, token);
+        assert_eq!(token.len(), len);
+        assert!(token.chars().all(|c| c.is_ascii_digit()));
+        assert_eq!(&token[..6], &pan[..6]);
+        assert_ne!(token, pan);
+        let recovered = ff1.decrypt_str(&token.as_bytes()[len-4..], &token[6..len-4]).unwrap();
+        assert_eq!(recovered, &pan[6..len-4]);
+    }
+
+    // The middle five digits of a 15-digit card number are too few for the
+    // domain of one million. So, only the last four digits are kept.
+    let pan = "340000000000009";
+    assert_eq!(ff1.encrypt_str(&pan.as_bytes()[11..], &pan[6..11]), Err(CipherError::InvalidNumeralString));
+    let token = ff1.encrypt_str(&pan.as_bytes()[11..], &pan[..11]).unwrap() + &pan[11..];
+    println!("PAN =\t{}\nToken =\t{}", pan, token);
+    assert_eq!(ff1.decrypt_str(&token.as_bytes()[11..], &token[..11]).unwrap() + &token[11..], pan);
+    println!("-------------------------------");
+}
+
+fn ff1_large_radix()
+{
+    println!("ff1_large_radix");
+    use cryptocol::symmetric::{ AES_128, AES_128_FF1 };
+
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3C");
+    for radix in [2_u32, 3, 16, 26, 255, 256, 1000, 65535, 65536]
+    {
+        let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), radix);
+        let min_len = ff1.get_min_len();
+        for len in [min_len, min_len + 1, 33, 100]
+        {
+            let message: Vec<u16> = (0..len).map(|i| ((i as u32 * 7919 + 13) % radix) as u16).collect();
+            let cipher = ff1.encrypt(&[0x55_u8; 5], &message).unwrap();
+            assert_eq!(cipher.len(), len);
+            assert!(cipher.iter().all(|&d| (d as u32) < radix));
+            assert_ne!(cipher, message);
+            assert_eq!(ff1.decrypt(&[0x55_u8; 5], &cipher).unwrap(), message);
+        }
+        println!("radix = {}, min_len = {}: OK", radix, min_len);
+    }
+    println!("-------------------------------");
+}
+
+fn ff1_invalid_input()
+{
+    println!("ff1_invalid_input");
+    use cryptocol::symmetric::{ AES_128, AES_128_FF1, CipherError };
+
+    let key = from_hex("2B7E151628AED2A6ABF7158809CF4F3C");
+    let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 10);
+    assert_eq!(ff1.get_radix(), 10);
+    assert_eq!(ff1.get_min_len(), 6);
+
+    // Too short for the domain of one million
+    assert_eq!(ff1.encrypt_str(&[], "12345"), Err(CipherError::InvalidNumeralString));
+    // Not a decimal numeral
+    assert_eq!(ff1.encrypt_str(&[], "1234-5678"), Err(CipherError::InvalidNumeralString));
+    assert_eq!(ff1.decrypt(&[], &[1, 2, 3, 4, 5, 10]), Err(CipherError::InvalidNumeralString));
+    match ff1.encrypt_str(&[], "12a456")
+    {
+        Ok(cipher) => { println!("Cipher = {}", cipher); },
+        Err(e) => { println!("Error: {}", e); assert_eq!(e, CipherError::InvalidNumeralString); },
+    }
+    println!("-------------------------------");
+}
+
+fn ff3_1_encrypt_decrypt()
+{
+    println!("ff3_1_encrypt_decrypt");
+    use cryptocol::symmetric::{ AES_128_FF3_1, AES_192_FF3_1, AES_256_FF3_1 };
+
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+    let tweak = [0xD8_u8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A];
+    let mut ff3 = AES_128_FF3_1::new_with_key(&key, 10);
+    // The known answer of FF3-1 with the 56-bit tweak
+    let cipher = ff3.encrypt_str(tweak, "890121234567890000").unwrap();
+    println!("C =\t{}", cipher);
+    assert_eq!(cipher, "477064185124354662");
+    assert_eq!(ff3.decrypt_str(tweak, &cipher).unwrap(), "890121234567890000");
+
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A942B7E151628AED2A6");
+    let mut ff3 = AES_192_FF3_1::new_with_key(&key, 26);
+    let cipher = ff3.encrypt_str(tweak, "0123456789abcdefghi").unwrap();
+    println!("C =\t{}", cipher);
+    assert!(cipher.chars().all(|c| c.is_digit(26)));
+    assert_eq!(ff3.decrypt_str(tweak, &cipher).unwrap(), "0123456789abcdefghi");
+
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A942B7E151628AED2A6ABF7158809CF4F3C");
+    let mut ff3 = AES_256_FF3_1::new_with_key(&key, 10);
+    let ssn = "123456789";
+    let cipher = ff3.encrypt_str(tweak, ssn).unwrap();
+    println!("SSN =\t{}\nC =\t{}", ssn, cipher);
+    assert_eq!(ff3.decrypt_str(tweak, &cipher).unwrap(), ssn);
+    println!("-------------------------------");
+}
+
+fn ff3_1_tweak()
+{
+    println!("ff3_1_tweak");
+    use cryptocol::symmetric::AES_128_FF3_1;
+
+    // Every bit of the 56-bit tweak changes the ciphertext.
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+    let mut ff3 = AES_128_FF3_1::new_with_key(&key, 10);
+    let message = "890121234567890000";
+    let base = ff3.encrypt_str([0_u8; 7], message).unwrap();
+    for bit in 0..56
+    {
+        let mut tweak = [0_u8; 7];
+        tweak[bit / 8] = 0x80 >> (bit % 8);
+        let cipher = ff3.encrypt_str(tweak, message).unwrap();
+        assert_ne!(cipher, base);
+        assert_eq!(ff3.decrypt_str(tweak, &cipher).unwrap(), message);
+    }
+    println!("All the tweak bits are effective.");
+    println!("-------------------------------");
+}
+
+fn ff3_1_length_limits()
+{
+    println!("ff3_1_length_limits");
+    use cryptocol::symmetric::{ AES_128_FF3_1, CipherError };
+
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+    for (radix, min_len, max_len) in [(2_u32, 20_usize, 192_usize), (10, 6, 56), (26, 5, 40), (36, 4, 36), (65536, 2, 12)]
+    {
+        let mut ff3 = AES_128_FF3_1::new_with_key(&key, radix);
+        assert_eq!(ff3.get_min_len(), min_len);
+        assert_eq!(ff3.get_max_len(), max_len);
+        for len in [min_len, min_len + 1, max_len - 1, max_len]
+        {
+            let message: Vec<u16> = (0..len).map(|i| ((i as u32 * 7919 + 13) % radix) as u16).collect();
+            let cipher = ff3.encrypt([1_u8; 7], &message).unwrap();
+            assert_eq!(ff3.decrypt([1_u8; 7], &cipher).unwrap(), message);
+        }
+        assert_eq!(ff3.encrypt([1_u8; 7], &vec![0_u16; min_len - 1]), Err(CipherError::InvalidNumeralString));
+        assert_eq!(ff3.encrypt([1_u8; 7], &vec![0_u16; max_len + 1]), Err(CipherError::InvalidNumeralString));
+        println!("radix = {}, min_len = {}, max_len = {}: OK", radix, min_len, max_len);
+    }
+    println!("-------------------------------");
+}
+
+fn ff3_1_generic()
+{
+    println!("ff3_1_generic");
+    use cryptocol::symmetric::{ AES_128, AES_128_FF3_1, FF3_1_Generic };
+
+    // FF3_1_Generic::new() takes the block cipher keyed with the
+    // byte-reversed key.
+    let key = from_hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+    let mut rev_key = key.clone();
+    rev_key.reverse();
+    let mut ff3 = FF3_1_Generic::new(AES_128::new_with_key(&rev_key), 10);
+    let mut aes_ff3 = AES_128_FF3_1::new_with_key(&key, 10);
+    let tweak = [0x9A_u8, 0x76, 0x8A, 0x92, 0xF6, 0x0E, 0x12];
+    assert_eq!(ff3.encrypt_str(tweak, "4000001234567899").unwrap(),
+                aes_ff3.encrypt_str(tweak, "4000001234567899").unwrap());
+    println!("-------------------------------");
+}
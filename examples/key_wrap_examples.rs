@@ -0,0 +1,255 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    key_wrap_main();
+    key_wrap_with_padding_main();
+    tdes_key_wrap_main();
+}
+
+fn key_wrap_main()
+{
+    key_wrap_aes_128_rfc3394();
+    key_wrap_aes_256();
+    key_wrap_tampered();
+    key_wrap_bad_length();
+}
+
+fn key_wrap_with_padding_main()
+{
+    key_wrap_with_padding_rfc5649_20_bytes();
+    key_wrap_with_padding_rfc5649_7_bytes();
+    key_wrap_with_padding_tampered();
+}
+
+fn tdes_key_wrap_main()
+{
+    tdes_key_wrap();
+    tdes_key_wrap_no_padding();
+}
+
+fn key_wrap_aes_128_rfc3394()
+{
+    println!("key_wrap_aes_128_rfc3394");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_KeyWrap };
+
+    let mut kek = [0_u8; 16];
+    for (i, k) in kek.iter_mut().enumerate()
+        { *k = i as u8; }
+    let key_data = [0x00_u8, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+    let mut kw = AES_128_KeyWrap::new(AES_128::new_with_key(&kek));
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kw.wrap_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(kw.unwrap_into_vec(&wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+    println!("-------------------------------");
+}
+
+fn key_wrap_aes_256()
+{
+    println!("key_wrap_aes_256");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_256, AES_256_KeyWrap };
+
+    let mut kek = [0_u8; 32];
+    for (i, k) in kek.iter_mut().enumerate()
+        { *k = i as u8; }
+    let key_data = [ 0x00_u8, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+                     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F ];
+    let mut kw = AES_256_KeyWrap::new(AES_256::new_with_key(&kek));
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kw.wrap_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(kw.unwrap_into_vec(&wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+    println!("-------------------------------");
+}
+
+fn key_wrap_tampered()
+{
+    println!("key_wrap_tampered");
+    use cryptocol::symmetric::{ AES_128, AES_128_KeyWrap };
+
+    let mut kw = AES_128_KeyWrap::new(AES_128::new_with_key(&[0x5C_u8; 16]));
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kw.wrap_into_vec(&[0x11_u8; 32], &mut wrapped));
+    for i in 0..wrapped.len()
+    {
+        let mut corrupted = wrapped.clone();
+        corrupted[i] ^= 0x01;
+        let mut unwrapped = Vec::<u8>::new();
+        assert!(!kw.unwrap_into_vec(&corrupted, &mut unwrapped));
+        assert!(unwrapped.is_empty());
+    }
+    let mut other = AES_128_KeyWrap::new(AES_128::new_with_key(&[0x5D_u8; 16]));
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(!other.unwrap_into_vec(&wrapped, &mut unwrapped));
+    println!("-------------------------------");
+}
+
+fn key_wrap_bad_length()
+{
+    println!("key_wrap_bad_length");
+    use cryptocol::symmetric::{ AES_128, AES_128_KeyWrap };
+
+    let mut kw = AES_128_KeyWrap::new(AES_128::new());
+    let mut wrapped = Vec::<u8>::new();
+    assert!(!kw.wrap_into_vec(&[0_u8; 8], &mut wrapped));
+    assert!(!kw.wrap_into_vec(&[0_u8; 20], &mut wrapped));
+    assert!(wrapped.is_empty());
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(!kw.unwrap_into_vec(&[0_u8; 16], &mut unwrapped));
+    println!("-------------------------------");
+}
+
+fn key_wrap_with_padding_rfc5649_20_bytes()
+{
+    println!("key_wrap_with_padding_rfc5649_20_bytes");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_Generic, KeyWrap_Generic };
+
+    let kek = [ 0x58_u8, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70,
+                0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8 ];
+    let key_data = [ 0xC3_u8, 0x7B, 0x7E, 0x64, 0x92, 0x58, 0x43, 0x40, 0xBE, 0xD1,
+                     0x22, 0x07, 0x80, 0x89, 0x41, 0x15, 0x50, 0x68, 0xF7, 0x38 ];
+    let mut kwp = KeyWrap_Generic::new(AES_Generic::<6, 12>::new_with_key(&kek));
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kwp.wrap_with_padding_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "138BDEAA9B8FA7FC61F97742E72248EE5AE6AE5360D1AE6A5F54F373FA543B6A");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(kwp.unwrap_with_padding_into_vec(&wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+    println!("-------------------------------");
+}
+
+fn key_wrap_with_padding_rfc5649_7_bytes()
+{
+    println!("key_wrap_with_padding_rfc5649_7_bytes");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_Generic, KeyWrap_Generic };
+
+    let kek = [ 0x58_u8, 0x40, 0xDF, 0x6E, 0x29, 0xB0, 0x2A, 0xF1, 0xAB, 0x49, 0x3B, 0x70,
+                0x5B, 0xF1, 0x6E, 0xA1, 0xAE, 0x83, 0x38, 0xF4, 0xDC, 0xC1, 0x76, 0xA8 ];
+    let key_data = [0x46_u8, 0x6F, 0x72, 0x50, 0x61, 0x73, 0x69];
+    let mut kwp = KeyWrap_Generic::new(AES_Generic::<6, 12>::new_with_key(&kek));
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kwp.wrap_with_padding_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "AFBEB0F07DFBF5419200F2CCB50BB24F");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(kwp.unwrap_with_padding_into_vec(&wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+    println!("-------------------------------");
+}
+
+fn key_wrap_with_padding_tampered()
+{
+    println!("key_wrap_with_padding_tampered");
+    use cryptocol::symmetric::{ AES_128, AES_128_KeyWrap };
+
+    let mut kwp = AES_128_KeyWrap::new(AES_128::new_with_key(&[0x77_u8; 16]));
+    for length in [1_usize, 7, 8, 9, 16, 31]
+    {
+        let key_data = vec![0xAB_u8; length];
+        let mut wrapped = Vec::<u8>::new();
+        assert!(kwp.wrap_with_padding_into_vec(&key_data, &mut wrapped));
+        assert_eq!(wrapped.len(), length.div_ceil(8) * 8 + 8);
+        let mut unwrapped = Vec::<u8>::new();
+        assert!(kwp.unwrap_with_padding_into_vec(&wrapped, &mut unwrapped));
+        assert_eq!(unwrapped, key_data);
+
+        wrapped[0] ^= 0x80;
+        assert!(!kwp.unwrap_with_padding_into_vec(&wrapped, &mut unwrapped));
+        assert!(unwrapped.is_empty());
+    }
+
+    // KW and KWP are not interchangeable.
+    let mut wrapped = Vec::<u8>::new();
+    kwp.wrap_into_vec(&[0xAB_u8; 16], &mut wrapped);
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(!kwp.unwrap_with_padding_into_vec(&wrapped, &mut unwrapped));
+    println!("-------------------------------");
+}
+
+fn tdes_key_wrap()
+{
+    println!("tdes_key_wrap");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ TDES, TDES_KeyWrap };
+
+    // TKW of NIST SP 800-38F with three-key Triple DES
+    let kek = [ 0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+                0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01,
+                0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23 ];
+    let mut kw = TDES_KeyWrap::new(TDES::new_with_key(kek));
+    let key_data = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let mut wrapped = Vec::<u8>::new();
+    assert!(kw.wrap_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "FD027DC268D87D8BAE0D0574");
+
+    let key_data = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+    assert!(kw.wrap_into_vec(&key_data, &mut wrapped));
+    let mut txt = String::new();
+    for w in wrapped.iter()
+        { write!(txt, "{:02X}", w); }
+    println!("W =\t{}", txt);
+    assert_eq!(txt, "F348930FA5239634901F2BD7D1E040EBC1897EC2");
+
+    let mut unwrapped = Vec::<u8>::new();
+    assert!(kw.unwrap_into_vec(&wrapped, &mut unwrapped));
+    assert_eq!(unwrapped, key_data);
+    wrapped[19] ^= 0x10;
+    assert!(!kw.unwrap_into_vec(&wrapped, &mut unwrapped));
+    println!("-------------------------------");
+}
+
+fn tdes_key_wrap_no_padding()
+{
+    println!("tdes_key_wrap_no_padding");
+    use cryptocol::symmetric::{ TDES, TDES_KeyWrap };
+
+    let kek = [0x13_u8; 24];
+    let mut kw = TDES_KeyWrap::new(TDES::new_with_key(kek));
+    let mut wrapped = Vec::<u8>::new();
+    // KWP needs a 128-bit block cipher.
+    assert!(!kw.wrap_with_padding_into_vec(&[1_u8, 2, 3], &mut wrapped));
+    assert!(wrapped.is_empty());
+    println!("-------------------------------");
+}
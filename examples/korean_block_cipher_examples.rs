@@ -0,0 +1,279 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    seed_main();
+    aria_main();
+    lea_main();
+    korean_block_cipher_mode_main();
+}
+
+fn seed_main()
+{
+    seed_kisa_vectors();
+    seed_encrypt_decrypt_u128();
+}
+
+fn aria_main()
+{
+    aria_kisa_vectors();
+    aria_encrypt_decrypt_u128();
+}
+
+fn lea_main()
+{
+    lea_kisa_vectors();
+    lea_encrypt_decrypt_u128();
+}
+
+fn korean_block_cipher_mode_main()
+{
+    korean_block_cipher_openssl();
+    korean_block_cipher_all_modes_and_paddings();
+    korean_block_cipher_stream();
+}
+
+const MESSAGE: &str = "Now is the time for all good men to come to the aid of their party.";
+const IV: [u8; 16] = [ 0x0F, 0x0E, 0x0D, 0x0C, 0x0B, 0x0A, 0x09, 0x08,
+                       0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00 ];
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn from_hex(txt: &str) -> Vec<u8>
+{
+    (0..txt.len()).step_by(2).map(|i| u8::from_str_radix(&txt[i..i+2], 16).unwrap()).collect()
+}
+
+fn check_vector<C: cryptocol::symmetric::BlockCipher>(mut cipher: C, message: &str, expected: &str)
+{
+    let mut block = from_hex(message);
+    cipher.encrypt_one_block(&mut block);
+    println!("C =\t{}", to_hex(&block));
+    assert_eq!(to_hex(&block), expected);
+    cipher.decrypt_one_block(&mut block);
+    assert_eq!(to_hex(&block), message);
+}
+
+fn seed_kisa_vectors()
+{
+    println!("seed_kisa_vectors");
+    use cryptocol::symmetric::SEED;
+
+    // The test vectors of RFC 4269, which are the same as those of KISA
+    check_vector(SEED::new_with_key(&[0_u8; 16]), "000102030405060708090A0B0C0D0E0F", "5EBAC6E0054E166819AFF1CC6D346CDB");
+    check_vector(SEED::new_with_key(&from_hex("000102030405060708090A0B0C0D0E0F")), "00000000000000000000000000000000", "C11F22F20140505084483597E4370F43");
+    check_vector(SEED::new_with_key(&from_hex("4706480851E61BE85D74BFB3FD956185")), "83A2F8A288641FB9A4E9A5CC2F131C7D", "EE54D13EBCAE706D226BC3142CD40D4A");
+    check_vector(SEED::new_with_key(&from_hex("28DBC3BC49FFD87DCFA509B11D422BE7")), "B41E6BE2EBA84A148E2EED84593C5EC7", "9B9B7BFCD1813CB95D0B3618F40F5122");
+    println!("-------------------------------");
+}
+
+fn seed_encrypt_decrypt_u128()
+{
+    println!("seed_encrypt_decrypt_u128");
+    use cryptocol::symmetric::SEED;
+
+    let mut seed = SEED::new_with_key(&from_hex("4706480851E61BE85D74BFB3FD956185"));
+    let message = u128::from_le_bytes(from_hex("83A2F8A288641FB9A4E9A5CC2F131C7D").try_into().unwrap());
+    let cipher = seed.encrypt_u128(message);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "EE54D13EBCAE706D226BC3142CD40D4A");
+    assert_eq!(seed.decrypt_u128(cipher), message);
+
+    let messages = [message, !message, 0_u128];
+    let mut ciphers = [0_u128; 3];
+    let mut recovered = [0_u128; 3];
+    seed.encrypt_array_u128(&messages, &mut ciphers);
+    assert_eq!(ciphers[0], cipher);
+    seed.decrypt_array_u128(&ciphers, &mut recovered);
+    assert_eq!(recovered, messages);
+    println!("-------------------------------");
+}
+
+fn aria_kisa_vectors()
+{
+    println!("aria_kisa_vectors");
+    use cryptocol::symmetric::{ ARIA_128, ARIA_192, ARIA_256 };
+
+    // The test vectors of RFC 5794, which are the same as those of KISA
+    let key = from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    check_vector(ARIA_128::new_with_key(&key[..16]), "00112233445566778899AABBCCDDEEFF", "D718FBD6AB644C739DA95F3BE6451778");
+    check_vector(ARIA_192::new_with_key(&key[..24]), "00112233445566778899AABBCCDDEEFF", "26449C1805DBE7AA25A468CE263A9E79");
+    check_vector(ARIA_256::new_with_key(&key), "00112233445566778899AABBCCDDEEFF", "F92BD7C79FB72E2F2B8F80C1972D24FC");
+    println!("-------------------------------");
+}
+
+fn aria_encrypt_decrypt_u128()
+{
+    println!("aria_encrypt_decrypt_u128");
+    use cryptocol::symmetric::{ ARIA_256, ARIA_Generic };
+
+    let key = from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    let mut aria = ARIA_256::new_with_key(&key);
+    let message = u128::from_le_bytes(from_hex("00112233445566778899AABBCCDDEEFF").try_into().unwrap());
+    let cipher = aria.encrypt_u128(message);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "F92BD7C79FB72E2F2B8F80C1972D24FC");
+    assert_eq!(aria.decrypt_u128(cipher), message);
+
+    // ARIA with 256-bit key and fewer rounds
+    let mut aria = ARIA_Generic::<8, 12>::new_with_key(&key);
+    let reduced = aria.encrypt_u128(message);
+    assert_ne!(reduced, cipher);
+    assert_eq!(aria.decrypt_u128(reduced), message);
+    println!("-------------------------------");
+}
+
+fn lea_kisa_vectors()
+{
+    println!("lea_kisa_vectors");
+    use cryptocol::symmetric::{ LEA_128, LEA_192, LEA_256 };
+
+    // The test vectors of KISA and TTAK.KO-12.0223
+    let key = from_hex("0F1E2D3C4B5A69788796A5B4C3D2E1F0F0E1D2C3B4A5968778695A4B3C2D1E0F");
+    check_vector(LEA_128::new_with_key(&key[..16]), "101112131415161718191A1B1C1D1E1F", "9FC84E3528C6C6185532C7A704648BFD");
+    check_vector(LEA_192::new_with_key(&key[..24]), "202122232425262728292A2B2C2D2E2F", "6FB95E325AAD1B878CDCF5357674C6F2");
+    check_vector(LEA_256::new_with_key(&key), "303132333435363738393A3B3C3D3E3F", "D651AFF647B189C13A8900CA27F9E197");
+    println!("-------------------------------");
+}
+
+fn lea_encrypt_decrypt_u128()
+{
+    println!("lea_encrypt_decrypt_u128");
+    use cryptocol::symmetric::{ LEA_192, LEA_Generic };
+
+    let key = from_hex("0F1E2D3C4B5A69788796A5B4C3D2E1F0F0E1D2C3B4A59687");
+    let mut lea = LEA_192::new_with_key(&key);
+    let message = u128::from_le_bytes(from_hex("202122232425262728292A2B2C2D2E2F").try_into().unwrap());
+    let cipher = lea.encrypt_u128(message);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "6FB95E325AAD1B878CDCF5357674C6F2");
+    assert_eq!(lea.decrypt_u128(cipher), message);
+
+    // LEA with 192-bit key and more rounds
+    let mut lea = LEA_Generic::<6, 40>::new_with_key(&key);
+    let expanded = lea.encrypt_u128(message);
+    assert_ne!(expanded, cipher);
+    assert_eq!(lea.decrypt_u128(expanded), message);
+    println!("-------------------------------");
+}
+
+fn korean_block_cipher_openssl()
+{
+    println!("korean_block_cipher_openssl");
+    use cryptocol::symmetric::{ SEED, ARIA_128, ARIA_192, ARIA_256, ModeCipher, OperationMode };
+
+    // The same results as `openssl enc -seed-cbc -K <KEY> -iv 0F0E0D0C0B0A09080706050403020100`
+    // and so on
+    let key = from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    let mut seed = ModeCipher::new(SEED::new_with_key(&key[..16]), OperationMode::CBC, &IV);
+    let cipher = seed.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "9F6DFC77DCF48B6C659ACB236D25AB2849C05C710ECDEE4275BBF64E22EECB38121DBDB937ABFF75236A2B703A9DBFD4F48D0DF0D7B244F2BEB57F39AA59EC492AE61F47CBD2530C9F5710B1104023EC");
+    assert_eq!(seed.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut seed = ModeCipher::new(SEED::new_with_key(&key[..16]), OperationMode::CFB, &IV);
+    let cipher = seed.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "FC94FDBCE4F741CC074D8F9C10A2B1AB31B71BC6354E6A438955A2F60A4635785DC57172C264D58740266D576232AFEFD20115AF49C698331797DFF157F14AA2A2BA9C");
+    assert_eq!(seed.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut seed = ModeCipher::new(SEED::new_with_key(&key[..16]), OperationMode::OFB, &IV);
+    let cipher = seed.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "FC94FDBCE4F741CC074D8F9C10A2B1AB305E0A8CE2F705235DA56C461BCB9A7D9281C39000529E99C449AA000490A34D034609D5568789FE9D12FF2E90135F0C169D37");
+    assert_eq!(seed.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut aria = ModeCipher::new(ARIA_128::new_with_key(&key[..16]), OperationMode::CBC, &IV);
+    let cipher = aria.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "18C38BA54F2409923772F935708AE6EBD9839156859E25CF6EBA193C5CC99EE609E66CC4A70D832E0A1B48BD9811485F9741CA328AA7D59A05336D385889A82357FF47CF01092CA4620F3B0CDE24B6FE");
+    assert_eq!(aria.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut aria = ModeCipher::new(ARIA_192::new_with_key(&key[..24]), OperationMode::CBC, &IV);
+    let cipher = aria.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "AB4F1AB7D6DD61CD983191176FF0E411E45530C12FA0503BD1405ADE17729CE2D6FBCC9C1B64456EDB2A028DDCC04ECE401A5538A122D3A0C4AE5CB1ECAAD255333016C18071F34867BBA00927114578");
+    assert_eq!(aria.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut aria = ModeCipher::new(ARIA_256::new_with_key(&key), OperationMode::CBC, &IV);
+    let cipher = aria.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "D949C3B8146E7EF16C494F1BE866B6E5266FEC6E74CAA4A77854495D22656965B9A22775C38943BEF836B7BF413EAB96F428B3FEE1B192BF3D354CC557BC525401E80516DC340FFD65B3B68C307B83BE");
+    assert_eq!(aria.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+
+    let mut aria = ModeCipher::new(ARIA_128::new_with_key(&key[..16]), OperationMode::OFB, &IV);
+    let cipher = aria.encrypt(MESSAGE.as_bytes());
+    assert_eq!(to_hex(&cipher), "49AD10607F268A7787CFFF2C66B8AB0576217330E93DB396EAD8C10D2181C7250B44F8782CDD3F77E903EF5A7838AE555504D9453A1B755B62D4EC89A10A15B974D2E5");
+    assert_eq!(aria.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn korean_block_cipher_all_modes_and_paddings()
+{
+    println!("korean_block_cipher_all_modes_and_paddings");
+    use cryptocol::symmetric::{ BlockCipher, SEED, ARIA_128, ARIA_192, ARIA_256, LEA_128, LEA_192, LEA_256,
+                                ModeCipher, OperationMode, Padding,
+                                PKCS7_Padding, ANSI_X923_Padding, ISO7816_Padding, ISO10126_Padding };
+
+    fn round_trip<C: BlockCipher + Clone, P: Padding + Copy>(cipher: C, padding: P)
+    {
+        for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+        {
+            let mut mc = ModeCipher::new_with_padding(cipher.clone(), mode, &IV, padding);
+            for length in [0_usize, 1, 15, 16, 17, MESSAGE.len()]
+            {
+                let message = &MESSAGE.as_bytes()[..length];
+                let encrypted = mc.encrypt(message);
+                assert_eq!(encrypted.len(), mc.encrypted_length(length));
+                assert_eq!(mc.decrypt(&encrypted).unwrap(), message);
+            }
+        }
+    }
+
+    fn all_paddings<C: BlockCipher + Clone>(cipher: C)
+    {
+        round_trip(cipher.clone(), PKCS7_Padding);
+        round_trip(cipher.clone(), ANSI_X923_Padding);
+        round_trip(cipher.clone(), ISO7816_Padding);
+        round_trip(cipher, ISO10126_Padding);
+    }
+
+    let key = from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    all_paddings(SEED::new_with_key(&key[..16]));
+    all_paddings(ARIA_128::new_with_key(&key[..16]));
+    all_paddings(ARIA_192::new_with_key(&key[..24]));
+    all_paddings(ARIA_256::new_with_key(&key));
+    all_paddings(LEA_128::new_with_key(&key[..16]));
+    all_paddings(LEA_192::new_with_key(&key[..24]));
+    all_paddings(LEA_256::new_with_key(&key));
+    println!("-------------------------------");
+}
+
+fn korean_block_cipher_stream()
+{
+    println!("korean_block_cipher_stream");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ LEA_256, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let key = from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+    let mut encryptor = Encryptor::new(LEA_256::new_with_key(&key), OperationMode::CBC, &IV, Vec::<u8>::new());
+    for chunk in MESSAGE.as_bytes().chunks(5)
+        { encryptor.write_all(chunk); }
+    let streamed = encryptor.finish().unwrap();
+    let cipher = ModeCipher::new(LEA_256::new_with_key(&key), OperationMode::CBC, &IV).encrypt(MESSAGE.as_bytes());
+    assert_eq!(streamed, cipher);
+
+    let mut decryptor = Decryptor::new(LEA_256::new_with_key(&key), OperationMode::CBC, &IV, cipher.as_slice());
+    let mut recovered = Vec::<u8>::new();
+    decryptor.read_to_end(&mut recovered).unwrap();
+    assert_eq!(recovered, MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    cmac_main();
+    cbc_mac_main();
+    retail_mac_main();
+}
+
+fn cmac_main()
+{
+    cmac_aes_128();
+    cmac_aes_128_update_in_pieces();
+    cmac_des();
+}
+
+fn cbc_mac_main()
+{
+    cbc_mac_des_paddings();
+    cbc_mac_des_empty();
+}
+
+fn retail_mac_main()
+{
+    retail_mac_paddings();
+    retail_mac_verify_truncated();
+}
+
+fn cmac_aes_128()
+{
+    println!("cmac_aes_128");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, AES_128_CMAC };
+
+    let key = [ 0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6,
+                0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C ];
+    let message = [ 0x6B_u8, 0xC1, 0xBE, 0xE2, 0x2E, 0x40, 0x9F, 0x96, 0xE9, 0x3D, 0x7E, 0x11, 0x73, 0x93, 0x17, 0x2A,
+                    0xAE, 0x2D, 0x8A, 0x57, 0x1E, 0x03, 0xAC, 0x9C, 0x9E, 0xB7, 0x6F, 0xAC, 0x45, 0xAF, 0x8E, 0x51,
+                    0x30, 0xC8, 0x1C, 0x46, 0xA3, 0x5C, 0xE4, 0x11 ];
+    let mut cmac = AES_128_CMAC::new(AES_128::new_with_key(&key));
+    let tag = cmac.mac(&message);
+    let mut txt = String::new();
+    for t in tag.iter()
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "DFA66747DE9AE63030CA32611497C827");
+    assert!(cmac.verify(&message, &tag));
+    assert!(!cmac.verify(&message[..39], &tag));
+    println!("-------------------------------");
+}
+
+fn cmac_aes_128_update_in_pieces()
+{
+    println!("cmac_aes_128_update_in_pieces");
+    use cryptocol::symmetric::{ AES_128, AES_128_CMAC };
+
+    let key = [0x42_u8; 16];
+    let message = "CMAC can be computed piece by piece, even across block boundaries.";
+    let mut cmac = AES_128_CMAC::new(AES_128::new_with_key(&key));
+    let whole = cmac.mac(message.as_bytes());
+    cmac.update(&message.as_bytes()[..5]);
+    cmac.update(&message.as_bytes()[5..16]);
+    cmac.update(&message.as_bytes()[16..33]);
+    cmac.update(&message.as_bytes()[33..]);
+    assert_eq!(cmac.finalize(), whole);
+    println!("-------------------------------");
+}
+
+fn cmac_des()
+{
+    println!("cmac_des");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ DES, DES_CMAC };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let mut cmac = DES_CMAC::new(DES::new_with_key(key));
+    let tag = cmac.mac("The quick brown fox jumps over the lazy dog.".as_bytes());
+    let mut txt = String::new();
+    for t in tag.iter()
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "0D1F7A151C5F9A16");
+    println!("-------------------------------");
+}
+
+fn cbc_mac_des_paddings()
+{
+    println!("cbc_mac_des_paddings");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ DES, DES_CBC_MAC, ISO9797_Padding };
+
+    let key = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let paddings = [ISO9797_Padding::Method1, ISO9797_Padding::Method2, ISO9797_Padding::Method3];
+    let expected_full = ["70A30640CC76DD8B", "10E1F0F108341B6D", "2C58FB8FF12AAEAC"];
+    let expected_partial = ["E45B3AD2B7CC0856", "A924C72136149211", "B1ECD6FC8B37C392"];
+    for i in 0..3
+    {
+        let mut mac = DES_CBC_MAC::new(DES::new_with_key(key), paddings[i]);
+        let mut txt = String::new();
+        for t in mac.mac("Now is the time for all ".as_bytes())
+            { write!(txt, "{:02X}", t); }
+        println!("{:?}\t{}", paddings[i], txt);
+        assert_eq!(txt, expected_full[i]);
+
+        let mut txt = String::new();
+        for t in mac.mac("Now is the time for it".as_bytes())
+            { write!(txt, "{:02X}", t); }
+        println!("{:?}\t{}", paddings[i], txt);
+        assert_eq!(txt, expected_partial[i]);
+    }
+    println!("-------------------------------");
+}
+
+fn cbc_mac_des_empty()
+{
+    println!("cbc_mac_des_empty");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ DES, DES_CBC_MAC, ISO9797_Padding };
+
+    let key = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let mut mac = DES_CBC_MAC::new(DES::new_with_key(key), ISO9797_Padding::Method1);
+    let mut txt = String::new();
+    for t in mac.mac(&[])
+        { write!(txt, "{:02X}", t); }
+    println!("T =\t{}", txt);
+    assert_eq!(txt, "D5D44FF720683D0D");
+    println!("-------------------------------");
+}
+
+fn retail_mac_paddings()
+{
+    println!("retail_mac_paddings");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ DES, Retail_MAC, ISO9797_Padding };
+
+    let key1 = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let key2 = [0xFE_u8, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+    let paddings = [ISO9797_Padding::Method1, ISO9797_Padding::Method2, ISO9797_Padding::Method3];
+    let expected_full = ["A1C72E74EA3FA9B6", "E9086230CA3BE796", "AB059463D7A7D170"];
+    let expected_partial = ["2E2B1428CC78254F", "5A692CE64F404145", "C59F7EED328DDD69"];
+    for i in 0..3
+    {
+        let mut mac = Retail_MAC::new(DES::new_with_key(key1), DES::new_with_key(key2), paddings[i]);
+        let mut txt = String::new();
+        for t in mac.mac("Now is the time for all ".as_bytes())
+            { write!(txt, "{:02X}", t); }
+        println!("{:?}\t{}", paddings[i], txt);
+        assert_eq!(txt, expected_full[i]);
+
+        let mut txt = String::new();
+        for t in mac.mac("Now is the time for it".as_bytes())
+            { write!(txt, "{:02X}", t); }
+        println!("{:?}\t{}", paddings[i], txt);
+        assert_eq!(txt, expected_partial[i]);
+    }
+    println!("-------------------------------");
+}
+
+fn retail_mac_verify_truncated()
+{
+    println!("retail_mac_verify_truncated");
+    use cryptocol::symmetric::{ DES, Retail_MAC, ISO9797_Padding };
+
+    let key1 = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    let key2 = [0xFE_u8, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+    let mut mac = Retail_MAC::new(DES::new_with_key(key1), DES::new_with_key(key2), ISO9797_Padding::Method2);
+    let message = "0200 4761739001010010 000000010000".as_bytes();
+    let tag = mac.mac(message);
+    // Many host interfaces send only the leftmost four bytes of the MAC.
+    assert!(mac.verify(message, &tag[..4]));
+    let mut wrong = tag;
+    wrong[3] ^= 1;
+    assert!(!mac.verify(message, &wrong[..4]));
+    assert!(!mac.verify(message, &[]));
+    println!("-------------------------------");
+}
@@ -0,0 +1,800 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    mode_cipher_main();
+    cipher_error_main();
+    ciphertext_stealing_main();
+    padding_main();
+    padding_oracle_main();
+}
+
+fn mode_cipher_main()
+{
+    mode_cipher_same_as_des();
+    mode_cipher_all_modes_aes();
+    mode_cipher_encrypt_into();
+    mode_cipher_same_as_stream();
+}
+
+fn cipher_error_main()
+{
+    cipher_error_bad_length();
+    cipher_error_invalid_padding();
+    cipher_error_buffer_too_small();
+    cipher_error_display();
+}
+
+const MESSAGE: &str = "In the beginning God created the heavens and the earth. Now the earth was formless and empty.";
+
+// The methods of DES_Generic read the message by u64, so that the message
+// should be aligned to 8 bytes.
+fn aligned(message: &[u8]) -> Vec<u64>
+{
+    let mut buf = vec![0_u64; message.len().div_ceil(8)];
+    for (b, chunk) in buf.iter_mut().zip(message.chunks(8))
+    {
+        let mut block = [0_u8; 8];
+        block[..chunk.len()].copy_from_slice(chunk);
+        *b = u64::from_le_bytes(block);
+    }
+    buf
+}
+
+fn mode_cipher_same_as_des()
+{
+    println!("mode_cipher_same_as_des");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = 0x_FEDCBA09_87654321_u64;
+    for length in [0_usize, 1, 7, 8, 9, MESSAGE.len()]
+    {
+        let message = &MESSAGE.as_bytes()[..length];
+        let buf = aligned(message);
+        let ptr = buf.as_ptr() as *const u8;
+        let mut expected = vec![0_u8; length + 8];
+
+        let size = DES::new_with_key(key).encrypt_with_padding_pkcs7_ecb(ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::ECB, &[]).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+
+        let size = DES::new_with_key(key).encrypt_with_padding_pkcs7_cbc(iv, ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv.to_le_bytes()).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+
+        let size = DES::new_with_key(key).encrypt_with_padding_pkcs7_pcbc(iv, ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::PCBC, &iv.to_le_bytes()).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+
+        let size = DES::new_with_key(key).encrypt_cfb(iv, ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::CFB, &iv.to_le_bytes()).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+
+        let size = DES::new_with_key(key).encrypt_ofb(iv, ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::OFB, &iv.to_le_bytes()).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+
+        let size = DES::new_with_key(key).encrypt_ctr(iv, ptr, length as u64, expected.as_mut_ptr());
+        let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::CTR, &iv.to_le_bytes()).encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+    }
+    println!("-------------------------------");
+}
+
+fn mode_cipher_all_modes_aes()
+{
+    println!("mode_cipher_all_modes_aes");
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode };
+
+    let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    let iv = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut aes = ModeCipher::new(AES_128::new_with_key(&key), mode, &iv);
+        for length in [0_usize, 1, 15, 16, 17, MESSAGE.len()]
+        {
+            let message = &MESSAGE.as_bytes()[..length];
+            let cipher = aes.encrypt(message);
+            assert_eq!(cipher.len(), aes.encrypted_length(length));
+            // Each call starts from the initialization vector.
+            assert_eq!(aes.encrypt(message), cipher);
+            assert_eq!(aes.decrypt(&cipher).unwrap(), message);
+        }
+    }
+
+    // The CBC encryption of AES-128 in NIST SP 800-38A F.2.1 with padding.
+    let message = [0x6B_u8, 0xC1, 0xBE, 0xE2, 0x2E, 0x40, 0x9F, 0x96, 0xE9, 0x3D, 0x7E, 0x11, 0x73, 0x93, 0x17, 0x2A];
+    let mut aes = ModeCipher::new(AES_128::new_with_key(&key), OperationMode::CBC, &iv);
+    let cipher = aes.encrypt(&message);
+    assert_eq!(cipher[..16], [0x76_u8, 0x49, 0xAB, 0xAC, 0x81, 0x19, 0xB2, 0x46, 0xCE, 0xE9, 0x8E, 0x9B, 0x12, 0xE9, 0x19, 0x7D]);
+    println!("-------------------------------");
+}
+
+fn mode_cipher_encrypt_into()
+{
+    println!("mode_cipher_encrypt_into");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+    let mut cipher = [0xFF_u8; 120];
+    let size = des.encrypt_into(MESSAGE.as_bytes(), &mut cipher).unwrap();
+    assert_eq!(size, 96);
+    assert_eq!(cipher[..size], des.encrypt(MESSAGE.as_bytes()));
+    assert_eq!(cipher[size..], [0xFF_u8; 24]);
+
+    let mut message = [0_u8; 93];
+    let length = des.decrypt_into(&cipher[..size], &mut message).unwrap();
+    assert_eq!(length, 93);
+    assert_eq!(message[..length], *MESSAGE.as_bytes());
+
+    des.set_iv(&[0_u8; 8]);
+    assert_ne!(des.encrypt(MESSAGE.as_bytes()), cipher[..size]);
+    println!("-------------------------------");
+}
+
+fn mode_cipher_same_as_stream()
+{
+    println!("mode_cipher_same_as_stream");
+    use std::io::Write;
+    use cryptocol::symmetric::{ AES_256, ModeCipher, Encryptor, OperationMode };
+
+    let key = [0x60_u8; 32];
+    let iv = [0xF0_u8; 16];
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut encryptor = Encryptor::new(AES_256::new_with_key(&key), mode, &iv, Vec::<u8>::new());
+        for chunk in MESSAGE.as_bytes().chunks(5)
+            { encryptor.write_all(chunk); }
+        let streamed = encryptor.finish().unwrap();
+        let cipher = ModeCipher::new(AES_256::new_with_key(&key), mode, &iv).encrypt(MESSAGE.as_bytes());
+        assert_eq!(streamed, cipher);
+    }
+    println!("-------------------------------");
+}
+
+fn cipher_error_bad_length()
+{
+    println!("cipher_error_bad_length");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC]
+    {
+        let mut des = ModeCipher::new(DES::new_with_key(key), mode, &iv);
+        assert_eq!(des.decrypt(&[]), Err(CipherError::BadLength));
+        assert_eq!(des.decrypt(&[0_u8; 9]), Err(CipherError::BadLength));
+        let mut message = [0_u8; 16];
+        assert_eq!(des.decrypt_into(&[0_u8; 15], &mut message), Err(CipherError::BadLength));
+    }
+    // The stream modes accept any length.
+    for mode in [OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut des = ModeCipher::new(DES::new_with_key(key), mode, &iv);
+        assert_eq!(des.decrypt(&[]), Ok(Vec::new()));
+        assert_eq!(des.decrypt(&[0_u8; 9]).unwrap().len(), 9);
+    }
+    println!("-------------------------------");
+}
+
+fn cipher_error_invalid_padding()
+{
+    println!("cipher_error_invalid_padding");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+    let cipher = des.encrypt(MESSAGE.as_bytes());
+
+    // Wrong key
+    let mut wrong = ModeCipher::new(DES::new_with_key([0x01_u8; 8]), OperationMode::CBC, &iv);
+    assert!(wrong.decrypt(&cipher).is_err());
+
+    // Modifying the second last block modifies the padding of the last block.
+    let mut tampered = cipher.clone();
+    let index = tampered.len() - 9;
+    tampered[index] ^= 0x10;
+    let mut message = [0xAA_u8; 96];
+    assert_eq!(des.decrypt_into(&tampered, &mut message), Err(CipherError::InvalidPadding));
+    // Nothing is written on error.
+    assert_eq!(message, [0xAA_u8; 96]);
+    println!("-------------------------------");
+}
+
+fn cipher_error_buffer_too_small()
+{
+    println!("cipher_error_buffer_too_small");
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode, CipherError };
+
+    let mut aes = ModeCipher::new(AES_128::new_with_key(&[0x2B_u8; 16]), OperationMode::CBC, &[0x7E_u8; 16]);
+    let mut cipher = [0_u8; 95];
+    assert_eq!(aes.encrypt_into(MESSAGE.as_bytes(), &mut cipher), Err(CipherError::BufferTooSmall));
+    assert_eq!(cipher, [0_u8; 95]);
+
+    let cipher = aes.encrypt(MESSAGE.as_bytes());
+    let mut message = [0_u8; 92];
+    assert_eq!(aes.decrypt_into(&cipher, &mut message), Err(CipherError::BufferTooSmall));
+    assert_eq!(message, [0_u8; 92]);
+    let mut message = [0_u8; 93];
+    assert_eq!(aes.decrypt_into(&cipher, &mut message), Ok(93));
+    println!("-------------------------------");
+}
+
+fn cipher_error_display()
+{
+    println!("cipher_error_display");
+    use cryptocol::symmetric::CipherError;
+
+    println!("{}", CipherError::InvalidPadding);
+    assert_eq!(CipherError::InvalidPadding.to_string(), "The padding of the decrypted data is not valid.");
+    println!("{}", CipherError::BadLength);
+    assert_eq!(CipherError::BadLength.to_string(), "The length of the ciphertext is not valid for the mode of operation.");
+    println!("{}", CipherError::BufferTooSmall);
+    assert_eq!(CipherError::BufferTooSmall.to_string(), "The output buffer is too small to contain the result.");
+    let boxed: Box<dyn std::error::Error> = Box::new(CipherError::BadLength);
+    assert_eq!(boxed.to_string(), CipherError::BadLength.to_string());
+    println!("-------------------------------");
+}
+
+fn ciphertext_stealing_main()
+{
+    ciphertext_stealing_rfc3962();
+    ciphertext_stealing_cs1_cs2_cs3();
+    ciphertext_stealing_des();
+    ciphertext_stealing_stream();
+    ciphertext_stealing_too_short();
+}
+
+fn ciphertext_stealing_rfc3962()
+{
+    println!("ciphertext_stealing_rfc3962");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode };
+
+    // The test vectors of RFC 3962 Appendix B, which uses CBC-CS3.
+    let key = "chicken teriyaki".as_bytes();
+    let message = "I would like the General Gau's Chicken, please, and wonton soup.".as_bytes();
+    let expected = [
+        (17, "C6353568F2BF8CB4D8A580362DA7FF7F97"),
+        (31, "FC00783E0EFDB2C1D445D4C8EFF7ED2297687268D6ECCCC0C07B25E25ECFE5"),
+        (32, "39312523A78662D5BE7FCBCC98EBF5A897687268D6ECCCC0C07B25E25ECFE584"),
+        (47, "97687268D6ECCCC0C07B25E25ECFE584B3FFFD940C16A18C1B5549D2F838029E39312523A78662D5BE7FCBCC98EBF5"),
+        (48, "97687268D6ECCCC0C07B25E25ECFE5849DAD8BBB96C4CDC03BC103E1A194BBD839312523A78662D5BE7FCBCC98EBF5A8"),
+        (64, "97687268D6ECCCC0C07B25E25ECFE58439312523A78662D5BE7FCBCC98EBF5A84807EFE836EE89A526730DBC2F7BC8409DAD8BBB96C4CDC03BC103E1A194BBD8"),
+    ];
+    let mut aes = ModeCipher::new(AES_128::new_with_key(key), OperationMode::CBC_CS3, &[0_u8; 16]);
+    for (length, hex) in expected
+    {
+        let cipher = aes.encrypt(&message[..length]);
+        let mut txt = String::new();
+        for c in cipher.iter()
+            { write!(txt, "{:02X}", c); }
+        println!("C =\t{}", txt);
+        assert_eq!(txt, hex);
+        assert_eq!(aes.decrypt(&cipher).unwrap(), message[..length]);
+    }
+    println!("-------------------------------");
+}
+
+fn ciphertext_stealing_cs1_cs2_cs3()
+{
+    println!("ciphertext_stealing_cs1_cs2_cs3");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode };
+
+    let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    let iv = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+    let expected = [
+        (OperationMode::CBC_CS1, 17, "16DD0AA81D473DDA17B14C8F4DEE36A76C"),
+        (OperationMode::CBC_CS1, 31, "16F3BA053C2E888945DF1A296A8E1EF288974212EB11C1BF0ACCA25A104827"),
+        (OperationMode::CBC_CS1, 32, "16F3BA053C2E888945DF1A296A8E1EEE127BE162DC71A8BF839CDBAF4E1AB494"),
+        (OperationMode::CBC_CS2, 17, "DD0AA81D473DDA17B14C8F4DEE36A76C16"),
+        (OperationMode::CBC_CS2, 32, "16F3BA053C2E888945DF1A296A8E1EEE127BE162DC71A8BF839CDBAF4E1AB494"),
+        (OperationMode::CBC_CS3, 17, "DD0AA81D473DDA17B14C8F4DEE36A76C16"),
+        (OperationMode::CBC_CS3, 32, "127BE162DC71A8BF839CDBAF4E1AB49416F3BA053C2E888945DF1A296A8E1EEE"),
+    ];
+    for (mode, length, hex) in expected
+    {
+        let mut aes = ModeCipher::new(AES_128::new_with_key(&key), mode, &iv);
+        let cipher = aes.encrypt(&MESSAGE.as_bytes()[..length]);
+        let mut txt = String::new();
+        for c in cipher.iter()
+            { write!(txt, "{:02X}", c); }
+        println!("{:?} C =\t{}", mode, txt);
+        assert_eq!(txt, hex);
+        assert_eq!(aes.decrypt(&cipher).unwrap(), MESSAGE.as_bytes()[..length]);
+    }
+
+    // With one block, all of them are the same as CBC.
+    let mut cbc = ModeCipher::new(AES_128::new_with_key(&key), OperationMode::CBC, &iv);
+    let expected = cbc.encrypt(&MESSAGE.as_bytes()[..16]);
+    for mode in [OperationMode::CBC_CS1, OperationMode::CBC_CS2, OperationMode::CBC_CS3]
+    {
+        let mut aes = ModeCipher::new(AES_128::new_with_key(&key), mode, &iv);
+        for length in 16..=MESSAGE.len()
+        {
+            let cipher = aes.encrypt(&MESSAGE.as_bytes()[..length]);
+            assert_eq!(cipher.len(), length);
+            assert_eq!(aes.decrypt(&cipher).unwrap(), MESSAGE.as_bytes()[..length]);
+        }
+        assert_eq!(aes.encrypt(&MESSAGE.as_bytes()[..16]), expected[..16]);
+    }
+    println!("-------------------------------");
+}
+
+fn ciphertext_stealing_des()
+{
+    println!("ciphertext_stealing_des");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    // A fixed-size field of 13 bytes stays 13 bytes.
+    let field = "ACCOUNT-12345".as_bytes();
+    for mode in [OperationMode::CBC_CS1, OperationMode::CBC_CS2, OperationMode::CBC_CS3]
+    {
+        let mut des = ModeCipher::new(DES::new_with_key(key), mode, &iv);
+        let mut cipher = [0_u8; 13];
+        assert_eq!(des.encrypt_into(field, &mut cipher), Ok(13));
+        let mut recovered = [0_u8; 13];
+        assert_eq!(des.decrypt_into(&cipher, &mut recovered), Ok(13));
+        assert_eq!(recovered, field);
+
+        // The full blocks before the last two blocks are the same as CBC.
+        let cipher = des.encrypt(MESSAGE.as_bytes());
+        let mut cbc = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+        let expected = cbc.encrypt(MESSAGE.as_bytes());
+        assert_eq!(cipher[..80], expected[..80]);
+    }
+    println!("-------------------------------");
+}
+
+fn ciphertext_stealing_stream()
+{
+    println!("ciphertext_stealing_stream");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ AES_128, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x2B_u8; 16];
+    let iv = [0x7E_u8; 16];
+    for mode in [OperationMode::CBC_CS1, OperationMode::CBC_CS2, OperationMode::CBC_CS3]
+    {
+        for length in [16_usize, 17, 31, 32, 33, 48, MESSAGE.len()]
+        {
+            let message = &MESSAGE.as_bytes()[..length];
+            let expected = ModeCipher::new(AES_128::new_with_key(&key), mode, &iv).encrypt(message);
+            for piece in [1_usize, 7, 16, 40]
+            {
+                let mut encryptor = Encryptor::new(AES_128::new_with_key(&key), mode, &iv, Vec::<u8>::new());
+                for chunk in message.chunks(piece)
+                    { encryptor.write_all(chunk); }
+                let cipher = encryptor.finish().unwrap();
+                assert_eq!(cipher, expected);
+            }
+            let mut decryptor = Decryptor::new(AES_128::new_with_key(&key), mode, &iv, expected.as_slice());
+            let mut recovered = Vec::<u8>::new();
+            decryptor.read_to_end(&mut recovered).unwrap();
+            assert_eq!(recovered, message);
+        }
+    }
+    println!("-------------------------------");
+}
+
+fn ciphertext_stealing_too_short()
+{
+    println!("ciphertext_stealing_too_short");
+    use std::io::{ ErrorKind, Read, Write };
+    use cryptocol::symmetric::{ DES, ModeCipher, Encryptor, Decryptor, OperationMode, CipherError };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC_CS3, &iv);
+    let mut cipher = [0_u8; 8];
+    assert_eq!(des.encrypt_into(&[1_u8, 2, 3], &mut cipher), Err(CipherError::BadLength));
+    assert_eq!(des.decrypt(&[1_u8, 2, 3]), Err(CipherError::BadLength));
+
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CBC_CS1, &iv, Vec::<u8>::new());
+    encryptor.write_all(&[1_u8, 2, 3]);
+    assert_eq!(encryptor.finish().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    let mut decryptor = Decryptor::new(DES::new_with_key(key), OperationMode::CBC_CS1, &iv, &[1_u8, 2, 3][..]);
+    let mut recovered = Vec::<u8>::new();
+    assert_eq!(decryptor.read_to_end(&mut recovered).unwrap_err().kind(), ErrorKind::InvalidData);
+    println!("-------------------------------");
+}
+
+fn padding_main()
+{
+    padding_bytes();
+    padding_ansi_x923_interop();
+    padding_iso7816_same_as_des();
+    padding_iso10126_random();
+    padding_zero_and_no_padding();
+    padding_invalid();
+    padding_stream();
+}
+
+fn padding_bytes()
+{
+    println!("padding_bytes");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, Padding, PKCS7_Padding, ISO7816_Padding, ANSI_X923_Padding, ISO10126_Padding, Zero_Padding, No_Padding };
+
+    // Decrypting with No_Padding reveals the padding bytes.
+    fn last_block<P: Padding>(padding: P, message: &[u8]) -> Vec<u8>
+    {
+        let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+        let cipher = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::ECB, &[], padding).encrypt(message);
+        let plain = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::ECB, &[], No_Padding).decrypt(&cipher).unwrap();
+        plain[plain.len() - 8..].to_vec()
+    }
+
+    let message = "Hello, World!".as_bytes();
+    assert_eq!(last_block(PKCS7_Padding, message), [b'o', b'r', b'l', b'd', b'!', 0x03, 0x03, 0x03]);
+    assert_eq!(last_block(ISO7816_Padding, message), [b'o', b'r', b'l', b'd', b'!', 0x80, 0x00, 0x00]);
+    assert_eq!(last_block(ANSI_X923_Padding, message), [b'o', b'r', b'l', b'd', b'!', 0x00, 0x00, 0x03]);
+    assert_eq!(last_block(Zero_Padding, message), [b'o', b'r', b'l', b'd', b'!', 0x00, 0x00, 0x00]);
+    let block = last_block(ISO10126_Padding, message);
+    assert_eq!(block[..5], *"orld!".as_bytes());
+    assert_eq!(block[7], 0x03);
+
+    let message = "Hello, World!!!!".as_bytes();
+    assert_eq!(last_block(PKCS7_Padding, message), [0x08_u8; 8]);
+    assert_eq!(last_block(ISO7816_Padding, message), [0x80_u8, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(last_block(ANSI_X923_Padding, message), [0_u8, 0, 0, 0, 0, 0, 0, 0x08]);
+    assert_eq!(last_block(Zero_Padding, message), *"orld!!!!".as_bytes());
+    assert_eq!(last_block(No_Padding, message), *"orld!!!!".as_bytes());
+    println!("-------------------------------");
+}
+
+fn padding_ansi_x923_interop()
+{
+    println!("padding_ansi_x923_interop");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode, ANSI_X923_Padding };
+
+    let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    let iv = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+    let message = "In the beginning God created the heavens and the earth.";
+    let mut aes = ModeCipher::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, ANSI_X923_Padding);
+    let cipher = aes.encrypt(message.as_bytes());
+    let mut txt = String::new();
+    for c in cipher.iter()
+        { write!(txt, "{:02X}", c); }
+    assert_eq!(txt, "16F3BA053C2E888945DF1A296A8E1EEE127BE162DC71A8BF839CDBAF4E1AB494AA533FAA5B8882AC312AFB1A4D619F0BA1540FE1865F7AB01BC958B1D314124B");
+    assert_eq!(aes.decrypt(&cipher).unwrap(), message.as_bytes());
+    println!("-------------------------------");
+}
+
+fn padding_iso7816_same_as_des()
+{
+    println!("padding_iso7816_same_as_des");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, ISO7816_Padding };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = 0x_FEDCBA09_87654321_u64;
+    for length in [0_usize, 1, 7, 8, 9, MESSAGE.len()]
+    {
+        let message = &MESSAGE.as_bytes()[..length];
+        let buf = aligned(message);
+        let mut expected = vec![0_u8; length + 8];
+        let size = DES::new_with_key(key).encrypt_with_padding_iso_cbc(iv, buf.as_ptr() as *const u8, length as u64, expected.as_mut_ptr());
+        let mut des = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::CBC, &iv.to_le_bytes(), ISO7816_Padding);
+        let cipher = des.encrypt(message);
+        assert_eq!(cipher, expected[..size as usize]);
+        assert_eq!(des.decrypt(&cipher).unwrap(), message);
+    }
+    println!("-------------------------------");
+}
+
+fn padding_iso10126_random()
+{
+    println!("padding_iso10126_random");
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode, ISO10126_Padding, ANSI_X923_Padding };
+
+    let key = [0x2B_u8; 16];
+    let mut aes = ModeCipher::new_with_padding(AES_128::new_with_key(&key), OperationMode::ECB, &[], ISO10126_Padding);
+    let cipher1 = aes.encrypt("A".as_bytes());
+    let cipher2 = aes.encrypt("A".as_bytes());
+    // The fifteen random bytes make the ciphertexts different.
+    assert_ne!(cipher1, cipher2);
+    assert_eq!(aes.decrypt(&cipher1).unwrap(), "A".as_bytes());
+    assert_eq!(aes.decrypt(&cipher2).unwrap(), "A".as_bytes());
+
+    // ISO 10126 accepts the padding of ANSI X9.23, whose filler is zeros.
+    let cipher = ModeCipher::new_with_padding(AES_128::new_with_key(&key), OperationMode::ECB, &[], ANSI_X923_Padding).encrypt(MESSAGE.as_bytes());
+    assert_eq!(aes.decrypt(&cipher).unwrap(), MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn padding_zero_and_no_padding()
+{
+    println!("padding_zero_and_no_padding");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, Zero_Padding, No_Padding, CipherError };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut des = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::CBC, &iv, Zero_Padding);
+    assert_eq!(des.encrypted_length(13), 16);
+    assert_eq!(des.encrypted_length(16), 16);
+    assert_eq!(des.encrypt(&[]), Vec::<u8>::new());
+    let cipher = des.encrypt("Hello, World!".as_bytes());
+    assert_eq!(des.decrypt(&cipher).unwrap(), "Hello, World!".as_bytes());
+    // The trailing zeros of the message are removed with the padding.
+    let cipher = des.encrypt("Hello\0\0".as_bytes());
+    assert_eq!(des.decrypt(&cipher).unwrap(), "Hello".as_bytes());
+
+    let mut des = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::PCBC, &iv, No_Padding);
+    let message = &MESSAGE.as_bytes()[..88];
+    let cipher = des.encrypt(message);
+    assert_eq!(cipher.len(), 88);
+    assert_eq!(des.decrypt(&cipher).unwrap(), message);
+    let mut buffer = [0_u8; 100];
+    assert_eq!(des.encrypt_into(MESSAGE.as_bytes(), &mut buffer), Err(CipherError::BadLength));
+    assert_eq!(des.encrypt_into(&[], &mut buffer), Ok(0));
+    assert_eq!(des.decrypt(&cipher[..87]), Err(CipherError::BadLength));
+    println!("-------------------------------");
+}
+
+fn padding_invalid()
+{
+    println!("padding_invalid");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, ISO7816_Padding, ANSI_X923_Padding, No_Padding, CipherError };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let mut raw = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::ECB, &[], No_Padding);
+    let mut x923 = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::ECB, &[], ANSI_X923_Padding);
+    let mut iso = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::ECB, &[], ISO7816_Padding);
+
+    // The filler of ANSI X9.23 should be zeros.
+    let cipher = raw.encrypt(&[1_u8, 2, 3, 4, 5, 0, 1, 3]);
+    assert_eq!(x923.decrypt(&cipher), Err(CipherError::InvalidPadding));
+    let cipher = raw.encrypt(&[1_u8, 2, 3, 4, 5, 0, 0, 9]);
+    assert_eq!(x923.decrypt(&cipher), Err(CipherError::InvalidPadding));
+    let cipher = raw.encrypt(&[1_u8, 2, 3, 4, 5, 0, 0, 3]);
+    assert_eq!(x923.decrypt(&cipher).unwrap(), [1_u8, 2, 3, 4, 5]);
+
+    // ISO/IEC 7816-4 padding should have 0x80 in the last block.
+    let cipher = raw.encrypt(&[1_u8, 2, 3, 4, 5, 0, 0, 0]);
+    assert_eq!(iso.decrypt(&cipher), Err(CipherError::InvalidPadding));
+    let cipher = raw.encrypt(&[0_u8; 8]);
+    assert_eq!(iso.decrypt(&cipher), Err(CipherError::InvalidPadding));
+    let cipher = raw.encrypt(&[1_u8, 2, 3, 4, 0x80, 0, 0, 0]);
+    assert_eq!(iso.decrypt(&cipher).unwrap(), [1_u8, 2, 3, 4]);
+    assert_eq!(iso.decrypt(&[]), Err(CipherError::BadLength));
+    println!("-------------------------------");
+}
+
+fn padding_stream()
+{
+    println!("padding_stream");
+    use std::io::{ ErrorKind, Read, Write };
+    use cryptocol::symmetric::{ AES_128, ModeCipher, Encryptor, Decryptor, OperationMode, ANSI_X923_Padding, No_Padding };
+
+    let key = [0x2B_u8; 16];
+    let iv = [0x0F_u8; 16];
+    let mut encryptor = Encryptor::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, Vec::<u8>::new(), ANSI_X923_Padding);
+    for chunk in MESSAGE.as_bytes().chunks(7)
+        { encryptor.write_all(chunk); }
+    let streamed = encryptor.finish().unwrap();
+    let cipher = ModeCipher::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, ANSI_X923_Padding).encrypt(MESSAGE.as_bytes());
+    assert_eq!(streamed, cipher);
+
+    let mut decryptor = Decryptor::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, cipher.as_slice(), ANSI_X923_Padding);
+    let mut recovered = Vec::<u8>::new();
+    decryptor.read_to_end(&mut recovered).unwrap();
+    assert_eq!(recovered, MESSAGE.as_bytes());
+
+    let mut encryptor = Encryptor::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, Vec::<u8>::new(), No_Padding);
+    encryptor.write_all(MESSAGE.as_bytes());
+    assert_eq!(encryptor.finish().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    let mut decryptor = Decryptor::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, &[][..], No_Padding);
+    let mut recovered = Vec::<u8>::new();
+    assert_eq!(decryptor.read_to_end(&mut recovered).unwrap(), 0);
+    println!("-------------------------------");
+}
+
+fn padding_oracle_main()
+{
+    padding_oracle_des();
+    padding_oracle_mode_cipher();
+    padding_oracle_stream();
+}
+
+fn padding_oracle_des()
+{
+    println!("padding_oracle_des");
+    use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, No_Padding };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = 0x_FEDCBA09_87654321_u64;
+    let first = *"Attack a".as_bytes().first_chunk::<8>().unwrap();
+    let pkcs7_invalid = [ [b't', b' ', b'd', b'a', b'w', b'n', b'!', 0x00],  // zero
+                          [b't', b' ', b'd', b'a', b'w', b'n', b'!', 0x09],  // too long
+                          [b't', b' ', b'd', b'a', b'w', b'n', b'!', 0xFF],  // far too long
+                          [b't', b' ', b'd', b'a', b'w', 0x03, 0x02, 0x03],  // inconsistent
+                          [0x07_u8, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08] ];
+    let iso_invalid = [ [0_u8; 8],                                           // no marker
+                        [b't', b' ', b'd', b'a', b'w', b'n', b'!', 0x01],    // no marker
+                        [b't', b' ', b'd', b'a', 0x80, 0x00, 0x01, 0x00],    // garbage after marker
+                        [0x80_u8, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81] ];
+
+    for mode in [OperationMode::ECB, OperationMode::CBC, OperationMode::PCBC]
+    {
+        // The simulated padding oracle decrypts the ciphertext whose last
+        // block is decrypted into `last`, and returns everything observable:
+        // the result and the output buffer.
+        let oracle = |iso: bool, last: &[u8; 8]| -> (u64, Vec<u8>)
+        {
+            let mut plain = first.to_vec();
+            plain.extend_from_slice(last);
+            let cipher = ModeCipher::new_with_padding(DES::new_with_key(key), mode, &iv.to_le_bytes(), No_Padding).encrypt(&plain);
+            let buf = aligned(&cipher);
+            let ptr = buf.as_ptr() as *const u8;
+            let mut message = vec![0xAA_u8; 16];
+            let mut des = DES::new_with_key(key);
+            let size = match (mode, iso)
+            {
+                (OperationMode::ECB, false) => des.decrypt_with_padding_pkcs7_ecb(ptr, 16, message.as_mut_ptr()),
+                (OperationMode::ECB, true) => des.decrypt_with_padding_iso_ecb(ptr, 16, message.as_mut_ptr()),
+                (OperationMode::CBC, false) => des.decrypt_with_padding_pkcs7_cbc(iv, ptr, 16, message.as_mut_ptr()),
+                (OperationMode::CBC, true) => des.decrypt_with_padding_iso_cbc(iv, ptr, 16, message.as_mut_ptr()),
+                (OperationMode::PCBC, false) => des.decrypt_with_padding_pkcs7_pcbc(iv, ptr, 16, message.as_mut_ptr()),
+                _ => des.decrypt_with_padding_iso_pcbc(iv, ptr, 16, message.as_mut_ptr()),
+            };
+            (size, message)
+        };
+
+        let (size, message) = oracle(false, &[b't', b' ', b'd', b'a', b'w', 0x03, 0x03, 0x03]);
+        assert_eq!(message[..size as usize], *"Attack at daw".as_bytes());
+        let (size, message) = oracle(true, &[b't', b' ', b'd', b'a', b'w', 0x80, 0x00, 0x00]);
+        assert_eq!(message[..size as usize], *"Attack at daw".as_bytes());
+
+        // Every kind of the invalid padding looks exactly the same: the
+        // result is zero and the message written so far is wiped.
+        let expected = (0_u64, [0_u8, 0, 0, 0, 0, 0, 0, 0, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA].to_vec());
+        for last in pkcs7_invalid.iter()
+            { assert_eq!(oracle(false, last), expected); }
+        for last in iso_invalid.iter()
+            { assert_eq!(oracle(true, last), expected); }
+    }
+    println!("-------------------------------");
+}
+
+fn padding_oracle_mode_cipher()
+{
+    println!("padding_oracle_mode_cipher");
+    use cryptocol::symmetric::{ AES_128, ModeCipher, OperationMode, Padding, PKCS7_Padding, ISO7816_Padding, ANSI_X923_Padding, CipherError };
+
+    // Every failure returns only the error and leaves the output buffer
+    // untouched, whichever check fails.
+    const UNTOUCHED: [u8; 64] = [0x55_u8; 64];
+
+    fn attack<P: Padding + Copy>(padding: P) -> usize
+    {
+        let key = [0x2B_u8; 16];
+        let iv = [0x0F_u8; 16];
+        let mut aes = ModeCipher::new_with_padding(AES_128::new_with_key(&key), OperationMode::CBC, &iv, padding);
+        let cipher = aes.encrypt("Transfer 100 dollars to Alice.".as_bytes());
+
+        // The simulated padding oracle of the server. It returns everything
+        // observable: the result and the output buffer.
+        let oracle = |aes: &mut ModeCipher<AES_128, P>, cipher: &[u8]| -> (Result<usize, CipherError>, [u8; 64])
+        {
+            let mut message = UNTOUCHED;
+            let result = aes.decrypt_into(cipher, &mut message);
+            (result, message)
+        };
+
+        // The attacker modifies the last byte of the second last block to
+        // guess the last byte of the message. All the rejected guesses look
+        // exactly the same.
+        let mut accepted = 0;
+        for guess in 0..=255_u8
+        {
+            let mut modified = cipher.clone();
+            modified[15] ^= guess;
+            modified[14] ^= 0x5A;
+            match oracle(&mut aes, &modified)
+            {
+                (Ok(_), _) => { accepted += 1; },
+                failure => { assert_eq!(failure, (Err(CipherError::InvalidPadding), UNTOUCHED)); },
+            }
+        }
+
+        // The random garbage and the wrong key give the same failure as the
+        // modified padding.
+        let mut garbage = cipher.clone();
+        for g in garbage[16..].iter_mut()
+            { *g = g.wrapping_mul(7).wrapping_add(1); }
+        assert_eq!(oracle(&mut aes, &garbage), (Err(CipherError::InvalidPadding), UNTOUCHED));
+        let mut wrong = ModeCipher::new_with_padding(AES_128::new_with_key(&[0x2C_u8; 16]), OperationMode::CBC, &iv, padding);
+        assert!(wrong.decrypt(&cipher).is_err());
+        assert_eq!(oracle(&mut wrong, &cipher), (Err(CipherError::InvalidPadding), UNTOUCHED));
+
+        // The ciphertext of a wrong length fails before decryption, and
+        // leaves the output buffer untouched as well.
+        assert_eq!(oracle(&mut aes, &cipher[..cipher.len() - 1]), (Err(CipherError::BadLength), UNTOUCHED));
+        assert_eq!(oracle(&mut aes, &[]), (Err(CipherError::BadLength), UNTOUCHED));
+        accepted
+    }
+
+    // Only the guesses which happen to make a valid padding are accepted.
+    // At least one guess always does, for example the one making the last
+    // byte 0x01 for PKCS #7.
+    assert!((1..=2).contains(&attack(PKCS7_Padding)));
+    assert!((1..=2).contains(&attack(ANSI_X923_Padding)));
+    assert!((1..=2).contains(&attack(ISO7816_Padding)));
+
+    // The ciphertext stealing modes fail in the same way on the ciphertext
+    // shorter than one block.
+    for mode in [OperationMode::CBC_CS1, OperationMode::CBC_CS2, OperationMode::CBC_CS3]
+    {
+        let mut aes = ModeCipher::new(AES_128::new_with_key(&[0x2B_u8; 16]), mode, &[0x0F_u8; 16]);
+        let cipher = aes.encrypt("Transfer 100 dollars to Alice.".as_bytes());
+        let mut message = UNTOUCHED;
+        assert_eq!(aes.decrypt_into(&cipher[..15], &mut message), Err(CipherError::BadLength));
+        assert_eq!(message, UNTOUCHED);
+        assert!(aes.decrypt(&[]).is_err());
+    }
+    println!("-------------------------------");
+}
+
+fn padding_oracle_stream()
+{
+    println!("padding_oracle_stream");
+    use std::io::{ ErrorKind, Read };
+    use cryptocol::symmetric::{ DES, ModeCipher, Decryptor, OperationMode, CipherError, No_Padding };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let cipher = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv).encrypt(MESSAGE.as_bytes());
+
+    // The simulated padding oracle returns everything observable: the
+    // kind and the inner error, and what has been read so far.
+    let oracle = |mode: OperationMode, cipher: &[u8]| -> (ErrorKind, CipherError, Vec<u8>)
+    {
+        let mut decryptor = Decryptor::new(DES::new_with_key(key), mode, &iv, cipher);
+        let mut recovered = Vec::<u8>::new();
+        let error = decryptor.read_to_end(&mut recovered).unwrap_err();
+        (error.kind(), *error.get_ref().unwrap().downcast_ref::<CipherError>().unwrap(), recovered)
+    };
+
+    // Every failure looks exactly the same except the inner error. Only the
+    // blocks before the last one held back are given out, which the
+    // attacker could decrypt without the padding anyway.
+    let given_out = |cipher: &[u8]| -> Vec<u8>
+    {
+        let whole = cipher.len().saturating_sub(1) / 8 * 8;
+        ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::CBC, &iv, No_Padding).decrypt(&cipher[..whole]).unwrap()
+    };
+    for position in [cipher.len() - 9, cipher.len() - 10, cipher.len() - 11]
+    {
+        let mut modified = cipher.clone();
+        modified[position] ^= 0x10;
+        assert_eq!(oracle(OperationMode::CBC, &modified), (ErrorKind::InvalidData, CipherError::InvalidPadding, given_out(&modified)));
+    }
+
+    // The ciphertext of a wrong length fails in the same way, in CBC mode
+    // and in the ciphertext stealing modes.
+    let truncated = &cipher[..cipher.len() - 1];
+    assert_eq!(oracle(OperationMode::CBC, truncated), (ErrorKind::InvalidData, CipherError::BadLength, given_out(truncated)));
+    assert_eq!(oracle(OperationMode::CBC, &cipher[..7]), (ErrorKind::InvalidData, CipherError::BadLength, Vec::new()));
+    for mode in [OperationMode::CBC_CS1, OperationMode::CBC_CS2, OperationMode::CBC_CS3]
+        { assert_eq!(oracle(mode, &cipher[..7]), (ErrorKind::InvalidData, CipherError::BadLength, Vec::new())); }
+    println!("-------------------------------");
+}
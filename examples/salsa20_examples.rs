@@ -0,0 +1,304 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    salsa20_main();
+    xsalsa20_main();
+}
+
+fn salsa20_main()
+{
+    salsa20_keystream_block();
+    salsa20_encrypt_decrypt();
+    salsa20_reduced_rounds();
+    salsa20_set_counter();
+    salsa20_counter_carry();
+    salsa20_core();
+    salsa20_ctr();
+}
+
+fn xsalsa20_main()
+{
+    xsalsa20_encrypt_decrypt();
+    xsalsa20_secretbox();
+    xsalsa20_ctr();
+}
+
+const MESSAGE: &str = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn key() -> [u8; 32]
+{
+    let mut key = [0_u8; 32];
+    for (i, k) in key.iter_mut().enumerate()
+        { *k = i as u8; }
+    key
+}
+
+fn salsa20_keystream_block()
+{
+    println!("salsa20_keystream_block");
+    use cryptocol::symmetric::Salsa20;
+
+    // The test vector of Set 1, vector# 0 of eSTREAM for 256-bit key
+    let mut key = [0_u8; 32];
+    key[0] = 0x80;
+    let a_salsa = Salsa20::new_with_key(key);
+    let block = a_salsa.keystream_block([0_u8; 8], 0);
+    println!("Block =\t{}", to_hex(&block));
+    assert_eq!(to_hex(&block), "E3BE8FDD8BECA2E3EA8EF9475B29A6E7003951E1097A5C38D23B7A5FAD9F6844B22C97559E2723C7CBBD3FE4FC8D9A0744652A83E72A9C461876AF4D7EF1A117");
+    println!("-------------------------------");
+}
+
+fn salsa20_encrypt_decrypt()
+{
+    println!("salsa20_encrypt_decrypt");
+    use cryptocol::symmetric::Salsa20;
+
+    // The same result as crypto_stream_salsa20_xor() of libsodium
+    let nonce = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let mut a_salsa = Salsa20::new_with_key(key());
+    let mut cipher = [0_u8; 114];
+    let length = a_salsa.encrypt(nonce, MESSAGE.as_ptr(), MESSAGE.len() as u64, cipher.as_mut_ptr());
+    assert_eq!(length, 114);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(to_hex(&cipher), "62CC6B367D2409AFB81693EE4D8A209B4AB621C65BF3BEF26D8C8934CD95A7CF049F6A19767EEE4C0268B1B5DE3FBCC692AD6919665D0FF68083169B93C29B13CF565279B66A1EADCAD04EE4D1E39385EFAA7544756DB8BB1B281CDFF2A7C194DE87C6B0E2948ECB983E23A25C7141E672F2");
+
+    let mut recovered = [0_u8; 114];
+    let length = a_salsa.decrypt(nonce, cipher.as_ptr(), cipher.len() as u64, recovered.as_mut_ptr());
+    assert_eq!(length, 114);
+    assert_eq!(String::from_utf8(recovered.to_vec()).unwrap(), MESSAGE);
+    println!("-------------------------------");
+}
+
+fn salsa20_reduced_rounds()
+{
+    println!("salsa20_reduced_rounds");
+    use cryptocol::symmetric::{ Salsa20_12, Salsa20_8 };
+
+    // The same results as crypto_stream_salsa2012_xor() and
+    // crypto_stream_salsa208_xor() of libsodium
+    let nonce = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let mut cipher12 = Vec::<u8>::new();
+    let mut cipher8 = Vec::<u8>::new();
+    Salsa20_12::new_with_key(key()).encrypt_str_into_vec(nonce, MESSAGE, &mut cipher12);
+    Salsa20_8::new_with_key(key()).encrypt_str_into_vec(nonce, MESSAGE, &mut cipher8);
+    assert_eq!(to_hex(&cipher12), "4AA8B93D6F806186891A7D2720FA501176C1AE786C6DD27DC7157F9DAEB7316D08FF75FFE68BB3EBED3C5D246F4A8035C38E2DAB9A85EAE2ED6C5FB29F224C5CEEB74F4CD58835995CDACE85F35E7E1D816091E7B9652F337A3EB88190248F532BD8E233B1057C6988B41965D61C34F574D5");
+    assert_eq!(to_hex(&cipher8), "23513EF330A97FEB17C3C3357633C15EB531001AFE4945824AB328B5330EAAA5FEB34BA7FB474D465D3390B4D98D66C42CCFEF35C9A8AA602F636DB757AB7C3948CD119EC0FCAA45D01DD07CB2CE8CD2E47EF41849D0CAFD63565D406ED38F47F1B69B137075DBD2AF601A9678857CEC31DC");
+
+    let mut recovered = Vec::<u8>::new();
+    Salsa20_8::new_with_key(key()).decrypt_into_vec(nonce, cipher8.as_ptr(), cipher8.len() as u64, &mut recovered);
+    assert_eq!(String::from_utf8(recovered).unwrap(), MESSAGE);
+    println!("-------------------------------");
+}
+
+fn salsa20_set_counter()
+{
+    println!("salsa20_set_counter");
+    use cryptocol::symmetric::Salsa20;
+
+    // The same result as crypto_stream_salsa20_xor_ic() of libsodium with ic = 1
+    let nonce = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let mut a_salsa = Salsa20::new_with_key(key());
+    a_salsa.set_counter(1);
+    assert_eq!(a_salsa.get_counter(), 1);
+    let mut cipher = Vec::<u8>::new();
+    a_salsa.encrypt_str_into_vec(nonce, MESSAGE, &mut cipher);
+    assert_eq!(to_hex(&cipher), "ED5B4F30BC775BECD0DD1E83D2E295C9FEAF750A3377AAEE1D2555DFE2BECE94CED5CCB3AC93C09DD7720EE41E5D41EC69A90DABC4D784DCE615A3CBEF9ACDA5E92CC6C7BD51F92446AB2923FA16251AD648A5524D71E25681C4B2BCA2C42D81A01E7F99BA39506013AC1CBA94CC5044598A");
+
+    // Seeking the keystream to the third block
+    let mut whole = Vec::<u8>::new();
+    a_salsa.set_counter(0);
+    a_salsa.encrypt_vec_into_vec(nonce, &[0_u8; 256], &mut whole);
+    let mut tail = Vec::<u8>::new();
+    a_salsa.set_counter(2);
+    a_salsa.encrypt_vec_into_vec(nonce, &[0_u8; 128], &mut tail);
+    assert_eq!(tail, whole[128..]);
+    assert_eq!(a_salsa.keystream_block(nonce, 3), whole[192..]);
+    println!("-------------------------------");
+}
+
+fn salsa20_counter_carry()
+{
+    println!("salsa20_counter_carry");
+    use cryptocol::symmetric::Salsa20;
+
+    // The block counter is 64 bits long, so it carries into the high word.
+    let nonce = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let mut a_salsa = Salsa20::new_with_key(key());
+    a_salsa.set_counter(0xFFFF_FFFF);
+    let mut stream = Vec::<u8>::new();
+    a_salsa.encrypt_array_into_vec(nonce, &[0_u64; 16], &mut stream);
+    assert_eq!(to_hex(&stream), "60D0F601A5A3AEDEC240597B0138BB8272EB17D524C523F5F514D83BD721780517678BE2A6578459B8325DBFBE8650D4AE3A739423BAB1FAF0B0347BDB8BB3F8E58A3CE12A19D89B151819EEC0956AE8B8BA7DF7D537480A39B6678CBBDA10F3F095AA1BC8E860392DE7B267FB1245D1FF12EFD12887CD1C797EA18BB7261E74");
+    assert_eq!(a_salsa.keystream_block(nonce, 0x1_0000_0000), stream[64..]);
+    println!("-------------------------------");
+}
+
+fn salsa20_core()
+{
+    println!("salsa20_core");
+    use cryptocol::symmetric::Salsa20_8;
+
+    // The test vector of Section 8 of RFC 7914 for scrypt
+    let input = [ 0x7e_u8, 0x87, 0x9a, 0x21, 0x4f, 0x3e, 0xc9, 0x86, 0x7c, 0xa9, 0x40, 0xe6, 0x41, 0x71, 0x8f, 0x26,
+                  0xba, 0xee, 0x55, 0x5b, 0x8c, 0x61, 0xc1, 0xb5, 0x0d, 0xf8, 0x46, 0x11, 0x6d, 0xcd, 0x3b, 0x1d,
+                  0xee, 0x24, 0xf3, 0x19, 0xdf, 0x9b, 0x3d, 0x85, 0x14, 0x12, 0x1e, 0x4b, 0x5a, 0xc5, 0xaa, 0x32,
+                  0x76, 0x02, 0x1d, 0x29, 0x09, 0xc7, 0x48, 0x29, 0xed, 0xeb, 0xc6, 0x8d, 0xb8, 0xb8, 0xc2, 0x5e ];
+    let output = Salsa20_8::core(&input);
+    println!("Output =\t{}", to_hex(&output));
+    assert_eq!(to_hex(&output), "A41F859C6608CC993B81CACB020CEF05044B2181A2FD337DFD7B1C6396682F29B4393168E3C9E6BCFE6BC5B7A06D96BAE424CC102C91745C24AD673DC7618F81");
+    println!("-------------------------------");
+}
+
+fn salsa20_ctr()
+{
+    println!("salsa20_ctr");
+    use cryptocol::symmetric::Salsa20;
+
+    // The methods of CTR-mode names give the same results as encrypt().
+    let nonce = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let mut a_salsa = Salsa20::new_with_key(key());
+    let mut expected = Vec::<u8>::new();
+    a_salsa.encrypt_str_into_vec(nonce, MESSAGE, &mut expected);
+
+    let mut cipher = [0_u8; 114];
+    assert_eq!(a_salsa.encrypt_ctr(nonce, MESSAGE.as_ptr(), MESSAGE.len() as u64, cipher.as_mut_ptr()), 114);
+    assert_eq!(cipher.to_vec(), expected);
+    let mut cipher = [0_u8; 114];
+    a_salsa.encrypt_str_ctr(nonce, MESSAGE, cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), expected);
+    let mut cipher = [0_u8; 114];
+    a_salsa.encrypt_string_ctr(nonce, &MESSAGE.to_string(), cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), expected);
+
+    let mut recovered = [0_u8; 114];
+    assert_eq!(a_salsa.decrypt_ctr(nonce, cipher.as_ptr(), cipher.len() as u64, recovered.as_mut_ptr()), 114);
+    assert_eq!(String::from_utf8(recovered.to_vec()).unwrap(), MESSAGE);
+
+    let data = [0x1234_5678_u32; 20];
+    let mut stream = Vec::<u8>::new();
+    a_salsa.encrypt_array_into_vec(nonce, &data, &mut stream);
+    let mut cipher = [0_u8; 80];
+    assert_eq!(a_salsa.encrypt_array_ctr(nonce, &data, cipher.as_mut_ptr()), 80);
+    assert_eq!(cipher.to_vec(), stream);
+    let mut cipher = [0_u8; 80];
+    assert_eq!(a_salsa.encrypt_vec_ctr(nonce, &data.to_vec(), cipher.as_mut_ptr()), 80);
+    assert_eq!(cipher.to_vec(), stream);
+    println!("-------------------------------");
+}
+
+fn xsalsa20_encrypt_decrypt()
+{
+    println!("xsalsa20_encrypt_decrypt");
+    use cryptocol::symmetric::XSalsa20;
+
+    // The same result as crypto_stream_xsalsa20_xor() of libsodium
+    let mut nonce = [0_u8; 24];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = i as u8; }
+    let mut a_xsalsa = XSalsa20::new_with_key(key());
+    let mut cipher = Vec::<u8>::new();
+    a_xsalsa.encrypt_str_into_vec(nonce, MESSAGE, &mut cipher);
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(to_hex(&cipher), "30D704C6B8EDE627E133FD2A415D8D584D90E7A353E9A3676E4CBD8D493E3A1D2DDF5729E7ED9B29811CC65848C66AF43DD02ABBAC4A598972D047ED3AB2818EEDB78CF52ADE9022CDE490B91C09D96357290DBF95E2A83ACE7A0B9D6914F986BFF29A7C6B36BE82619EE5F1A816A8EB82FD");
+
+    let mut recovered = Vec::<u8>::new();
+    a_xsalsa.decrypt_into_vec(nonce, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+    assert_eq!(String::from_utf8(recovered).unwrap(), MESSAGE);
+
+    // The keystream from the second block
+    let mut tail = Vec::<u8>::new();
+    a_xsalsa.set_counter(1);
+    a_xsalsa.encrypt_str_into_vec(nonce, &MESSAGE[64..], &mut tail);
+    assert_eq!(tail, cipher[64..]);
+    println!("-------------------------------");
+}
+
+fn xsalsa20_secretbox()
+{
+    println!("xsalsa20_secretbox");
+    use cryptocol::symmetric::{ XSalsa20, Poly1305 };
+
+    // The same result as crypto_secretbox_easy() of NaCl and libsodium
+    let mut nonce = [0_u8; 24];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = i as u8; }
+    let mut a_xsalsa = XSalsa20::new_with_key(key());
+    let mut padded = vec![0_u8; 32];
+    padded.extend_from_slice(MESSAGE.as_bytes());
+    let mut stream = Vec::<u8>::new();
+    a_xsalsa.encrypt_vec_into_vec(nonce, &padded, &mut stream);
+    let mut poly_key = [0_u8; 32];
+    poly_key.copy_from_slice(&stream[..32]);
+    let mut boxed = Poly1305::mac(poly_key, &stream[32..]).to_vec();
+    boxed.extend_from_slice(&stream[32..]);
+    println!("Box =\t{}", to_hex(&boxed));
+    assert_eq!(to_hex(&boxed), "EF76F1B5B6391EAA3F47762E19DBA9CB129E5C26A2B98271D558AF790DE13EFB37C823B1AC4A59CF63CA02B436ABC092F0FB9AB36597CC3B83ADA9FF5A2F8B204C3404FBD3F8BA29D96D07C47514B79AB2EC86396A78ACCD609BF1F1AC1CFAA282BBB7DBF46B5A8DEB5DB22A514E89DDE22861E331A225075A06B56FFA9A7F6157E2");
+
+    // Opening the box
+    let mut first = [0_u8; 32];
+    a_xsalsa.encrypt(nonce, [0_u8; 32].as_ptr(), 32, first.as_mut_ptr());
+    assert_eq!(Poly1305::mac(first, &boxed[16..]), boxed[..16]);
+    let mut opened = Vec::<u8>::new();
+    let mut sealed = vec![0_u8; 32];
+    sealed.extend_from_slice(&boxed[16..]);
+    a_xsalsa.decrypt_into_vec(nonce, sealed.as_ptr(), sealed.len() as u64, &mut opened);
+    assert_eq!(&opened[32..], MESSAGE.as_bytes());
+    println!("-------------------------------");
+}
+
+fn xsalsa20_ctr()
+{
+    println!("xsalsa20_ctr");
+    use cryptocol::symmetric::XSalsa20;
+
+    let mut nonce = [0_u8; 24];
+    for (i, n) in nonce.iter_mut().enumerate()
+        { *n = i as u8; }
+    let mut a_xsalsa = XSalsa20::new_with_key(key());
+    let mut expected = Vec::<u8>::new();
+    a_xsalsa.encrypt_str_into_vec(nonce, MESSAGE, &mut expected);
+
+    let mut cipher = [0_u8; 114];
+    assert_eq!(a_xsalsa.encrypt_str_ctr(nonce, MESSAGE, cipher.as_mut_ptr()), 114);
+    assert_eq!(cipher.to_vec(), expected);
+    let mut cipher = [0_u8; 114];
+    a_xsalsa.encrypt_string_ctr(nonce, &MESSAGE.to_string(), cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), expected);
+    let mut recovered = [0_u8; 114];
+    a_xsalsa.decrypt_ctr(nonce, cipher.as_ptr(), cipher.len() as u64, recovered.as_mut_ptr());
+    assert_eq!(String::from_utf8(recovered.to_vec()).unwrap(), MESSAGE);
+
+    let data = [0x9ABC_DEF0_u32; 20];
+    let mut stream = Vec::<u8>::new();
+    a_xsalsa.encrypt_array_into_vec(nonce, &data, &mut stream);
+    assert_eq!(stream.len(), 80);
+    let mut cipher = [0_u8; 80];
+    a_xsalsa.encrypt_array_ctr(nonce, &data, cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), stream);
+    let mut cipher = [0_u8; 80];
+    a_xsalsa.encrypt_vec_ctr(nonce, &data.to_vec(), cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), stream);
+    let mut cipher = [0_u8; 80];
+    a_xsalsa.encrypt_ctr(nonce, data.as_ptr() as *const u8, 80, cipher.as_mut_ptr());
+    assert_eq!(cipher.to_vec(), stream);
+    println!("-------------------------------");
+}
@@ -0,0 +1,325 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    encryptor_main();
+    decryptor_main();
+}
+
+fn encryptor_main()
+{
+    encryptor_cbc_same_as_des_cbc();
+    encryptor_cfb_ofb_ctr_same_as_des();
+    encryptor_small_writes();
+    encryptor_copy();
+    encryptor_retry_after_write_error();
+}
+
+fn decryptor_main()
+{
+    decryptor_all_modes_aes();
+    decryptor_small_reads();
+    decryptor_bad_padding();
+    decryptor_bad_length();
+}
+
+const MESSAGE: &str = "In the beginning God created the heavens and the earth. Now the earth was formless and empty.";
+
+// The methods of DES_Generic read the message by u64, so that the message
+// should be aligned to 8 bytes.
+fn aligned(message: &[u8]) -> Vec<u64>
+{
+    let mut buf = vec![0_u64; message.len().div_ceil(8)];
+    for (b, chunk) in buf.iter_mut().zip(message.chunks(8))
+    {
+        let mut block = [0_u8; 8];
+        block[..chunk.len()].copy_from_slice(chunk);
+        *b = u64::from_le_bytes(block);
+    }
+    buf
+}
+
+fn encryptor_cbc_same_as_des_cbc()
+{
+    println!("encryptor_cbc_same_as_des_cbc");
+    use std::io::Write;
+    use cryptocol::symmetric::{ DES, Encryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = 0x_FEDCBA09_87654321_u64;
+    for length in [0_usize, 1, 7, 8, 9, 16, MESSAGE.len()]
+    {
+        let message = &MESSAGE.as_bytes()[..length];
+        let buf = aligned(message);
+        let mut expected = vec![0_u8; length + 8];
+        let size = DES::new_with_key(key).encrypt_with_padding_pkcs7_cbc(iv, buf.as_ptr() as *const u8, length as u64, expected.as_mut_ptr());
+        expected.truncate(size as usize);
+
+        let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv.to_le_bytes(), Vec::<u8>::new());
+        encryptor.write_all(message);
+        let cipher = encryptor.finish().unwrap();
+        assert_eq!(cipher, expected);
+    }
+    println!("-------------------------------");
+}
+
+fn encryptor_cfb_ofb_ctr_same_as_des()
+{
+    println!("encryptor_cfb_ofb_ctr_same_as_des");
+    use std::io::Write;
+    use cryptocol::symmetric::{ DES, Encryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = 0x_FEDCBA09_87654321_u64;
+    let message = MESSAGE.as_bytes();
+    let length = message.len() as u64;
+    let buf = aligned(message);
+
+    let mut expected = vec![0_u8; message.len()];
+    DES::new_with_key(key).encrypt_cfb(iv, buf.as_ptr() as *const u8, length, expected.as_mut_ptr());
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CFB, &iv.to_le_bytes(), Vec::<u8>::new());
+    encryptor.write_all(message);
+    assert_eq!(encryptor.finish().unwrap(), expected);
+
+    DES::new_with_key(key).encrypt_ofb(iv, buf.as_ptr() as *const u8, length, expected.as_mut_ptr());
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::OFB, &iv.to_le_bytes(), Vec::<u8>::new());
+    encryptor.write_all(message);
+    assert_eq!(encryptor.finish().unwrap(), expected);
+
+    DES::new_with_key(key).encrypt_ctr(iv, buf.as_ptr() as *const u8, length, expected.as_mut_ptr());
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CTR, &iv.to_le_bytes(), Vec::<u8>::new());
+    encryptor.write_all(message);
+    assert_eq!(encryptor.finish().unwrap(), expected);
+    println!("-------------------------------");
+}
+
+fn encryptor_small_writes()
+{
+    println!("encryptor_small_writes");
+    use std::io::Write;
+    use cryptocol::symmetric::{ AES_128, Encryptor, OperationMode };
+
+    let key = [0x2B_u8; 16];
+    let iv = [0x7E_u8; 16];
+    for mode in [OperationMode::CBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut at_once = Encryptor::new(AES_128::new_with_key(&key), mode, &iv, Vec::<u8>::new());
+        at_once.write_all(MESSAGE.as_bytes());
+        let expected = at_once.finish().unwrap();
+
+        for piece in [1_usize, 3, 15, 16, 17, 40]
+        {
+            let mut encryptor = Encryptor::new(AES_128::new_with_key(&key), mode, &iv, Vec::<u8>::new());
+            for chunk in MESSAGE.as_bytes().chunks(piece)
+                { encryptor.write_all(chunk); }
+            // Only whole blocks have been written so far.
+            assert_eq!(encryptor.get_ref().len() & 15, 0);
+            assert_eq!(encryptor.finish().unwrap(), expected);
+        }
+    }
+    println!("-------------------------------");
+}
+
+fn encryptor_copy()
+{
+    println!("encryptor_copy");
+    use std::io::{ copy, Read };
+    use cryptocol::symmetric::{ AES_256, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x60_u8; 32];
+    let iv = [0xF0_u8; 16];
+    let mut message = Vec::<u8>::new();
+    for i in 0..100_003_u32
+        { message.push((i * 7 + i / 3) as u8); }
+
+    let mut encryptor = Encryptor::new(AES_256::new_with_key(&key), OperationMode::CBC, &iv, Vec::<u8>::new());
+    copy(&mut message.as_slice(), &mut encryptor).unwrap();
+    let cipher = encryptor.finish().unwrap();
+    assert_eq!(cipher.len(), 100_016);
+
+    let mut decryptor = Decryptor::new(AES_256::new_with_key(&key), OperationMode::CBC, &iv, cipher.as_slice());
+    let mut recovered = Vec::<u8>::new();
+    decryptor.read_to_end(&mut recovered).unwrap();
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn encryptor_retry_after_write_error()
+{
+    println!("encryptor_retry_after_write_error");
+    use std::io::{ self, ErrorKind, Write };
+    use cryptocol::symmetric::{ DES, Encryptor, OperationMode };
+
+    // The writer which takes at most five bytes at a time, and fails every
+    // third call of write() until it has taken 64 bytes, even in the middle
+    // of a block.
+    struct Flaky { data: Vec<u8>, calls: usize }
+    impl Write for Flaky
+    {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+        {
+            self.calls += 1;
+            if self.calls % 3 == 2 && self.data.len() < 64
+                { return Err(io::Error::other("temporary failure")); }
+            let taken = buf.len().min(5);
+            self.data.extend_from_slice(&buf[..taken]);
+            Ok(taken)
+        }
+        fn flush(&mut self) -> io::Result<()>   { Ok(()) }
+    }
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    for mode in [OperationMode::CBC, OperationMode::PCBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR, OperationMode::CBC_CS3]
+    {
+        let mut encryptor = Encryptor::new(DES::new_with_key(key), mode, &iv, Vec::<u8>::new());
+        encryptor.write_all(MESSAGE.as_bytes());
+        let expected = encryptor.finish().unwrap();
+
+        // Retrying the failed writes gives the same ciphertext as if they
+        // had never failed, without any ciphertext written twice.
+        let mut encryptor = Encryptor::new(DES::new_with_key(key), mode, &iv, Flaky { data: Vec::new(), calls: 0 });
+        let mut failures = 0;
+        for chunk in MESSAGE.as_bytes().chunks(20)
+        {
+            let mut rest = chunk;
+            while !rest.is_empty()
+            {
+                match encryptor.write(rest)
+                {
+                    Ok(taken) => { rest = &rest[taken..]; },
+                    Err(e) => {
+                        assert_eq!(e.kind(), ErrorKind::Other);
+                        failures += 1;
+                    },
+                }
+            }
+            assert_eq!(encryptor.get_ref().data[..], expected[..encryptor.get_ref().data.len()]);
+        }
+        assert!(failures > 0);
+        while encryptor.flush().is_err()
+            { failures += 1; }
+        let written = encryptor.finish().unwrap().data;
+        println!("{:?}: {} failures", mode, failures);
+        assert_eq!(written, expected);
+    }
+    println!("-------------------------------");
+}
+
+fn decryptor_all_modes_aes()
+{
+    println!("decryptor_all_modes_aes");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ AES_128, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    let iv = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F];
+    for mode in [OperationMode::CBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        for length in [0_usize, 1, 15, 16, 17, 32, MESSAGE.len()]
+        {
+            let message = &MESSAGE.as_bytes()[..length];
+            let mut encryptor = Encryptor::new(AES_128::new_with_key(&key), mode, &iv, Vec::<u8>::new());
+            encryptor.write_all(message);
+            let cipher = encryptor.finish().unwrap();
+            if mode == OperationMode::CBC
+                { assert_eq!(cipher.len(), (length / 16 + 1) * 16); }
+            else
+                { assert_eq!(cipher.len(), length); }
+
+            let mut decryptor = Decryptor::new(AES_128::new_with_key(&key), mode, &iv, cipher.as_slice());
+            let mut recovered = Vec::<u8>::new();
+            decryptor.read_to_end(&mut recovered).unwrap();
+            assert_eq!(recovered, message);
+        }
+    }
+    println!("-------------------------------");
+}
+
+fn decryptor_small_reads()
+{
+    println!("decryptor_small_reads");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ DES, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    for mode in [OperationMode::CBC, OperationMode::CFB, OperationMode::OFB, OperationMode::CTR]
+    {
+        let mut encryptor = Encryptor::new(DES::new_with_key(key), mode, &iv, Vec::<u8>::new());
+        encryptor.write_all(MESSAGE.as_bytes());
+        let cipher = encryptor.finish().unwrap();
+
+        let mut decryptor = Decryptor::new(DES::new_with_key(key), mode, &iv, cipher.as_slice());
+        let mut recovered = Vec::<u8>::new();
+        let mut buf = [0_u8; 3];
+        loop
+        {
+            let read = decryptor.read(&mut buf).unwrap();
+            if read == 0
+                { break; }
+            recovered.extend_from_slice(&buf[..read]);
+        }
+        assert_eq!(recovered, MESSAGE.as_bytes());
+    }
+    println!("-------------------------------");
+}
+
+fn decryptor_bad_padding()
+{
+    println!("decryptor_bad_padding");
+    use std::io::{ ErrorKind, Read, Write };
+    use cryptocol::symmetric::{ DES, Encryptor, Decryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv, Vec::<u8>::new());
+    encryptor.write_all(MESSAGE.as_bytes());
+    let mut cipher = encryptor.finish().unwrap();
+    let last = cipher.len() - 9;
+    cipher[last] ^= 0x05;
+
+    let mut decryptor = Decryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv, cipher.as_slice());
+    let mut recovered = Vec::<u8>::new();
+    let err = decryptor.read_to_end(&mut recovered).unwrap_err();
+    println!("Error: {}", err);
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // The error does not go away, so that the data cannot be mistaken for
+    // the end of the message.
+    let mut buf = [0_u8; 16];
+    for _ in 0..3
+        { assert_eq!(decryptor.read(&mut buf).unwrap_err().kind(), ErrorKind::InvalidData); }
+    assert_eq!(decryptor.read_to_end(&mut recovered).unwrap_err().kind(), ErrorKind::InvalidData);
+    println!("-------------------------------");
+}
+
+fn decryptor_bad_length()
+{
+    println!("decryptor_bad_length");
+    use std::io::{ ErrorKind, Read };
+    use cryptocol::symmetric::{ DES, Decryptor, OperationMode };
+
+    let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    for cipher in [vec![0_u8; 0], vec![0_u8; 12]]
+    {
+        let mut decryptor = Decryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv, cipher.as_slice());
+        let mut recovered = Vec::<u8>::new();
+        let err = decryptor.read_to_end(&mut recovered).unwrap_err();
+        println!("Error: {}", err);
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(decryptor.read(&mut [0_u8; 16]).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+    println!("-------------------------------");
+}
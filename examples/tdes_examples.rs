@@ -0,0 +1,144 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    tdes_main();
+    tdes_mode_main();
+}
+
+fn tdes_main()
+{
+    tdes_new_with_key();
+    tdes_same_as_des();
+    tdes_encrypt_decrypt_u64();
+}
+
+fn tdes_mode_main()
+{
+    tdes_cbc_openssl();
+    tdes_stream();
+}
+
+// The keys K1, K2 and K3 of DES in this order
+const KEY: [u8; 24] = [ 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+                        0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01,
+                        0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23 ];
+const MESSAGE: &str = "In the beginning God created the heavens and the earth. Now the earth was formless and empty.";
+
+fn to_hex(bytes: &[u8]) -> String
+{
+    use std::fmt::Write;
+    let mut txt = String::new();
+    for b in bytes.iter()
+        { write!(txt, "{:02X}", b); }
+    txt
+}
+
+fn tdes_new_with_key()
+{
+    println!("tdes_new_with_key");
+    use cryptocol::symmetric::{ BlockCipher, TDES };
+
+    // The same result as `openssl enc -des-ede3`
+    let mut tdes = TDES::new_with_key(KEY);
+    let mut block = *b"Now is t";
+    tdes.encrypt_one_block(&mut block);
+    println!("C =\t{}", to_hex(&block));
+    assert_eq!(to_hex(&block), "314F8327FA7A09A8");
+    tdes.decrypt_one_block(&mut block);
+    assert_eq!(&block, b"Now is t");
+    println!("-------------------------------");
+}
+
+fn tdes_same_as_des()
+{
+    println!("tdes_same_as_des");
+    use cryptocol::symmetric::{ BlockCipher, DES, TDES, TDES_Generic };
+
+    let k1 = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let k2 = [0x0E_u8, 0x32, 0x92, 0x32, 0xEA, 0x6D, 0x0D, 0x73];
+    let message = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+
+    // With K1 == K2, encryption with K1 and decryption with K2 cancel
+    // each other, so Triple DES degenerates into DES with K3.
+    let mut key = [0_u8; 24];
+    key[..8].copy_from_slice(&k1);
+    key[8..16].copy_from_slice(&k1);
+    key[16..].copy_from_slice(&k2);
+    let mut block = message;
+    TDES::new_with_key(key).encrypt_one_block(&mut block);
+    let mut expected = message;
+    DES::new_with_key(k2).encrypt_one_block(&mut expected);
+    assert_eq!(block, expected);
+    assert!(DES::check_multiple_keys(&[k1, k1, k2]).is_err());
+
+    // C = E_K3(D_K2(E_K1(M)))
+    let mut tdes = TDES_Generic::new(DES::new_with_key(k1), DES::new_with_key(k2), DES::new_with_key(k1));
+    let mut block = message;
+    tdes.encrypt_one_block(&mut block);
+    let mut expected = message;
+    DES::new_with_key(k1).encrypt_one_block(&mut expected);
+    DES::new_with_key(k2).decrypt_one_block(&mut expected);
+    DES::new_with_key(k1).encrypt_one_block(&mut expected);
+    assert_eq!(block, expected);
+    println!("-------------------------------");
+}
+
+fn tdes_encrypt_decrypt_u64()
+{
+    println!("tdes_encrypt_decrypt_u64");
+    use cryptocol::symmetric::TDES;
+
+    let mut tdes = TDES::new_with_key(KEY);
+    let message = u64::from_le_bytes(*b"Now is t");
+    let cipher = tdes.encrypt_u64(message);
+    println!("C =\t{:#018X}", cipher);
+    assert_eq!(to_hex(&cipher.to_le_bytes()), "314F8327FA7A09A8");
+    assert_eq!(tdes.decrypt_u64(cipher), message);
+    println!("-------------------------------");
+}
+
+fn tdes_cbc_openssl()
+{
+    println!("tdes_cbc_openssl");
+    use cryptocol::symmetric::{ TDES, ModeCipher, OperationMode, No_Padding };
+
+    // The same result as `openssl enc -des-ede3-cbc -nopad`
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut tdes = ModeCipher::new_with_padding(TDES::new_with_key(KEY), OperationMode::CBC, &iv, No_Padding);
+    let cipher = tdes.encrypt("Now is the time for all ".as_bytes());
+    println!("C =\t{}", to_hex(&cipher));
+    assert_eq!(to_hex(&cipher), "F3C0FF026C023089656FBB169DEF7EDB30BA36075D6F0176");
+    assert_eq!(tdes.decrypt(&cipher).unwrap(), "Now is the time for all ".as_bytes());
+    println!("-------------------------------");
+}
+
+fn tdes_stream()
+{
+    println!("tdes_stream");
+    use std::io::{ Read, Write };
+    use cryptocol::symmetric::{ TDES, ModeCipher, Encryptor, Decryptor, OperationMode };
+
+    let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    let mut encryptor = Encryptor::new(TDES::new_with_key(KEY), OperationMode::CBC, &iv, Vec::<u8>::new());
+    for chunk in MESSAGE.as_bytes().chunks(10)
+        { encryptor.write_all(chunk).unwrap(); }
+    let cipher = encryptor.finish().unwrap();
+    assert_eq!(cipher, ModeCipher::new(TDES::new_with_key(KEY), OperationMode::CBC, &iv).encrypt(MESSAGE.as_bytes()));
+
+    let mut decryptor = Decryptor::new(TDES::new_with_key(KEY), OperationMode::CBC, &iv, cipher.as_slice());
+    let mut recovered = String::new();
+    decryptor.read_to_string(&mut recovered).unwrap();
+    assert_eq!(recovered, MESSAGE);
+    println!("-------------------------------");
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(missing_docs)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
+
+pub fn main()
+{
+    xts_aes_main();
+    xts_des_main();
+}
+
+fn xts_aes_main()
+{
+    xts_aes_128_ieee1619_vector_1();
+    xts_aes_128_ciphertext_stealing();
+    xts_aes_256_encrypt_sector();
+    xts_aes_128_encrypt_sectors();
+    xts_aes_128_in_place();
+    xts_aes_128_too_short();
+}
+
+fn xts_des_main()
+{
+    xts_des_not_supported();
+}
+
+fn xts_aes_128_ieee1619_vector_1()
+{
+    println!("xts_aes_128_ieee1619_vector_1");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+
+    let key = [0_u8; 16];
+    let mut xts = XTS_AES_128::new(AES_128::new_with_key(&key), AES_128::new_with_key(&key));
+    let message = [0_u8; 32];
+    let mut cipher = [0_u8; 32];
+    xts.encrypt_sector(0, message.as_ptr(), 32, cipher.as_mut_ptr());
+    let mut txt = String::new();
+    for c in cipher
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "917CF69EBD68B2EC9B9FE9A3EADDA692CD43D2F59598ED858C02C2652FBF922E");
+
+    let mut recovered = [0_u8; 32];
+    xts.decrypt_sector(0, cipher.as_ptr(), 32, recovered.as_mut_ptr());
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn xts_aes_128_ciphertext_stealing()
+{
+    println!("xts_aes_128_ciphertext_stealing");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+
+    let mut key1 = [0_u8; 16];
+    let mut key2 = [0_u8; 16];
+    for i in 0..16
+    {
+        key1[i] = i as u8;
+        key2[i] = 16 + i as u8;
+    }
+    let mut xts = XTS_AES_128::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let mut message = [0_u8; 17];
+    for (i, m) in message.iter_mut().enumerate()
+        { *m = i as u8; }
+    let mut cipher = Vec::<u8>::new();
+    xts.encrypt_sector_into_vec(0x123456789A, message.as_ptr(), message.len() as u64, &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.clone()
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "2B514EDF10ED5F8E390BC71CAAC4A0FE3C");
+
+    let mut recovered = Vec::<u8>::new();
+    xts.decrypt_sector_into_vec(0x123456789A, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+    assert_eq!(recovered, message);
+    println!("-------------------------------");
+}
+
+fn xts_aes_256_encrypt_sector()
+{
+    println!("xts_aes_256_encrypt_sector");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_256, XTS_AES_256 };
+
+    let mut key1 = [0_u8; 32];
+    let mut key2 = [0_u8; 32];
+    for i in 0..32
+    {
+        key1[i] = i as u8;
+        key2[i] = 32 + i as u8;
+    }
+    let mut xts = XTS_AES_256::new(AES_256::new_with_key(&key1), AES_256::new_with_key(&key2));
+    let message = "The quick brown fox jumps over the lazy dog.";
+    println!("M =\t{}", message);
+    let mut cipher = Vec::<u8>::new();
+    xts.encrypt_sector_into_vec(1, message.as_ptr(), message.len() as u64, &mut cipher);
+    let mut txt = String::new();
+    for c in cipher.clone()
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "85A8EC7D4B5F19D8CAB9CF2E9E7061F684415CD069872B97C9F5B5189CF29C026747CD99F37BFBC3E2C0365E");
+
+    let mut recovered = Vec::<u8>::new();
+    xts.decrypt_sector_into_vec(1, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+    assert_eq!(String::from_utf8(recovered).unwrap(), message);
+    println!("-------------------------------");
+}
+
+fn xts_aes_128_encrypt_sectors()
+{
+    println!("xts_aes_128_encrypt_sectors");
+    use std::fmt::Write;
+    use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+
+    let mut key1 = [0_u8; 16];
+    let mut key2 = [0_u8; 16];
+    for i in 0..16
+    {
+        key1[i] = i as u8;
+        key2[i] = 16 + i as u8;
+    }
+    let mut xts = XTS_AES_128::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let mut image = [0_u8; 100];
+    for (i, m) in image.iter_mut().enumerate()
+        { *m = (i % 251) as u8; }
+    let mut cipher = [0_u8; 100];
+    // Sectors 7 and 8 are 40 bytes long, and the last sector 9 is 20 bytes long.
+    assert_eq!(xts.encrypt_sectors(7, 40, image.as_ptr(), 100, cipher.as_mut_ptr()), 100);
+    let mut txt = String::new();
+    for c in cipher
+        { write!(txt, "{:02X}", c); }
+    println!("C =\t{}", txt);
+    assert_eq!(txt, "56776AA2D3D9BADAD526C90B4B5DB8C04FF489BF44846AE2897DC3E30AF765D872F68B27481B522421576EA0051AF21B08A6232A00208BEA0978646391380C532B93B42A1944A883CC06A86BAD894CC118040C3AA4A8DC9B9333D0C9F92CFB33C9DD36EA");
+
+    // Any single sector can be decrypted on its own.
+    let mut sector_8 = [0_u8; 40];
+    xts.decrypt_sector(8, cipher[40..].as_ptr(), 40, sector_8.as_mut_ptr());
+    assert_eq!(sector_8, image[40..80]);
+
+    let mut recovered = [0_u8; 100];
+    assert_eq!(xts.decrypt_sectors(7, 40, cipher.as_ptr(), 100, recovered.as_mut_ptr()), 100);
+    assert_eq!(recovered, image);
+
+    // The last sector of 10 bytes is shorter than a block.
+    assert_eq!(xts.encrypt_sectors(7, 45, image.as_ptr(), 100, cipher.as_mut_ptr()), 0);
+    println!("-------------------------------");
+}
+
+fn xts_aes_128_in_place()
+{
+    println!("xts_aes_128_in_place");
+    use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+
+    let key1 = [0x11_u8; 16];
+    let key2 = [0x22_u8; 16];
+    let mut xts = XTS_AES_128::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+    let original = [0x5A_u8; 4096 + 3];
+    let mut sector = original;
+    xts.encrypt_sector(3, sector.as_ptr(), sector.len() as u64, sector.as_mut_ptr());
+    assert_ne!(sector, original);
+    xts.decrypt_sector(3, sector.as_ptr(), sector.len() as u64, sector.as_mut_ptr());
+    assert_eq!(sector, original);
+    println!("-------------------------------");
+}
+
+fn xts_aes_128_too_short()
+{
+    println!("xts_aes_128_too_short");
+    use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+
+    let mut xts = XTS_AES_128::new(AES_128::new(), AES_128::new_with_key(&[1_u8; 16]));
+    let message = [0_u8; 15];
+    let mut cipher = Vec::<u8>::new();
+    assert_eq!(xts.encrypt_sector_into_vec(0, message.as_ptr(), 15, &mut cipher), 0);
+    assert!(cipher.is_empty());
+    println!("-------------------------------");
+}
+
+fn xts_des_not_supported()
+{
+    println!("xts_des_not_supported");
+    #[cfg(test)] // It will panic.
+    xts_should_panic_64bit_block();
+    println!("-------------------------------");
+}
+
+#[test]
+#[should_panic]
+fn xts_should_panic_64bit_block()
+{
+    use cryptocol::symmetric::{ DES, XTS_Generic };
+
+    // IEEE 1619 defines XTS only for 128-bit block ciphers.
+    let key1 = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    let key2 = [0x0E_u8, 0x32, 0x92, 0x32, 0xEA, 0x6D, 0x0D, 0x73];
+    let _xts = XTS_Generic::<DES>::new(DES::new_with_key(key1), DES::new_with_key(key2));
+}
@@ -0,0 +1,441 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the analyzer of the avalanche effect and the
+//! statistical quality of hash algorithms and ciphers
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::fmt::{ self, Display, Formatter };
+
+use crate::random::Any;
+use crate::symmetric::{ BlockCipher, KeyedBlockCipher };
+
+
+/// The analyzer of the avalanche effect and the statistical quality of hash
+/// algorithms, ciphers and any other functions from bytes to bytes
+///
+/// # Introduction
+/// A good hash algorithm or cipher changes about a half of its output bits
+/// whenever any one bit of its input is changed, and every output bit is
+/// changed independently of the others. However, if you change the
+/// constants of an algorithm, for example, by using `MD4_Expanded`,
+/// `SHA2_256_Expanded` or `DES_Generic` with your own `SHIFT`, your own
+/// permutation tables or fewer rounds, the diffusion of the algorithm may
+/// get broken. `AvalancheAnalyzer` measures the following over `N` random
+/// inputs.
+/// - Strict avalanche criterion (SAC): the probability that the output bit
+///   `j` is changed when the input bit `i` is flipped should be 1/2 for
+///   every `i` and `j`.
+/// - Bit independence criterion (BIC): the changes of the output bits `j`
+///   and `k` when the input bit `i` is flipped should be uncorrelated for
+///   every `i`, `j` and `k` such that `j` is not `k`.
+/// - Output bias: the probability that the output bit `j` is one should be
+///   1/2 for every `j`.
+///
+/// # Bit numbering
+/// The bit `0` of the input or the output is the most significant bit of
+/// its first byte, and the bit `8` is the most significant bit of its
+/// second byte, and so on.
+///
+/// # Statistical error
+/// The probabilities that the analyzer measures have the statistical error
+/// of about `0.5 / sqrt(N)`, which is in the field `standard_error` of
+/// `AvalancheReport`. The greatest deviations among many probabilities are
+/// usually four or five times as great as the standard error even for an
+/// ideal function. So, compare the result of your algorithm with that of
+/// the original algorithm with the same `N`.
+///
+/// # Reference
+/// A. F. Webster and S. E. Tavares, "On the Design of S-Boxes",
+/// CRYPTO 1985.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::analysis::AvalancheAnalyzer;
+/// use cryptocol::hash::SHA2_256;
+///
+/// let mut analyzer = AvalancheAnalyzer::new();
+/// let mut sha = SHA2_256::new();
+/// let report = analyzer.analyze(16, 32, 64, |message, hash| {
+///         sha.digest(message.as_ptr(), message.len() as u64);
+///         sha.get_hash_value(hash.as_mut_ptr(), hash.len());
+///     });
+/// println!("{}", report);
+/// assert!((report.avalanche - 0.5).abs() < 0.01);
+/// assert!(report.sac_max_deviation < 0.4);
+/// ```
+pub struct AvalancheAnalyzer
+{
+    generator: Any,
+}
+
+impl AvalancheAnalyzer
+{
+    // pub fn new() -> Self
+    /// Constructs a new object AvalancheAnalyzer whose random inputs are
+    /// generated by the pseudo-random number generator `Any`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::AvalancheAnalyzer;
+    ///
+    /// let mut analyzer = AvalancheAnalyzer::new();
+    /// // The identity function has no avalanche effect at all.
+    /// let report = analyzer.analyze(4, 4, 32, |input, output| output.copy_from_slice(input));
+    /// assert_eq!(report.avalanche, 1.0 / 32.0);
+    /// assert_eq!(report.sac_max_deviation, 0.5);
+    /// ```
+    pub fn new() -> Self
+    {
+        Self { generator: Any::new() }
+    }
+
+    // pub fn analyze<F>(&mut self, input_size: usize, output_size: usize, samples: usize, function: F) -> AvalancheReport
+    /// Analyzes a function from bytes to bytes.
+    ///
+    /// # Arguments
+    /// - `input_size` is the size of the input in bytes.
+    /// - `output_size` is the size of the output in bytes.
+    /// - `samples` is the number of the random inputs `N`.
+    /// - `function` is the function to be analyzed, which receives the
+    ///   input of `input_size` bytes and writes its output into the buffer
+    ///   of `output_size` bytes. It is called `N * (input_size * 8 + 1)`
+    ///   times.
+    ///
+    /// # Output
+    /// It returns `AvalancheReport` which can be printed as a report.
+    ///
+    /// # Features
+    /// The computation of BIC takes the time proportional to
+    /// `N * input_size * output_size^2 / 64`, which is usually less than
+    /// the time to call `function`.
+    ///
+    /// # Panics
+    /// It panics if `input_size`, `output_size` or `samples` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::AvalancheAnalyzer;
+    /// use cryptocol::hash::{ MD4, MD4_Expanded };
+    ///
+    /// let mut analyzer = AvalancheAnalyzer::new();
+    /// let mut md4 = MD4::new();
+    /// let original = analyzer.analyze(16, 16, 64, |message, hash| {
+    ///         md4.digest(message.as_ptr(), message.len() as u64);
+    ///         md4.get_hash_value(hash.as_mut_ptr(), hash.len());
+    ///     });
+    ///
+    /// // MD4 of only the first 8 steps of 48 steps
+    /// let mut md4_8 = MD4_Expanded::<4, 0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 8>::new();
+    /// let reduced = analyzer.analyze(16, 16, 64, |message, hash| {
+    ///         md4_8.digest(message.as_ptr(), message.len() as u64);
+    ///         md4_8.get_hash_value(hash.as_mut_ptr(), hash.len());
+    ///     });
+    /// println!("Original\n{}\nReduced\n{}", original, reduced);
+    /// assert!(reduced.avalanche < original.avalanche);
+    /// assert_eq!(reduced.sac_max_deviation, 0.5);
+    /// ```
+    pub fn analyze<F>(&mut self, input_size: usize, output_size: usize, samples: usize, mut function: F) -> AvalancheReport
+    where F: FnMut(&[u8], &mut [u8])
+    {
+        assert!(input_size > 0 && output_size > 0 && samples > 0, "The sizes of the input and the output and the number of the samples should be greater than zero.");
+        let input_bits = input_size * 8;
+        let output_bits = output_size * 8;
+
+        let mut inputs = vec![0_u8; input_size * samples];
+        for byte in inputs.iter_mut()
+            { *byte = self.generator.random_u8(); }
+        let mut outputs = vec![0_u8; output_size * samples];
+        let mut ones = vec![0_u32; output_bits];
+        for (input, output) in inputs.chunks_exact(input_size).zip(outputs.chunks_exact_mut(output_size))
+        {
+            function(input, output);
+            for_each_bit(output, |j| ones[j] += 1);
+        }
+
+        let mut flips = vec![vec![0_u32; output_bits]; input_bits];
+        let mut total = 0_u64;
+        let mut bic_max_correlation = 0.0_f64;
+        let mut bic_worst = (0, 0, 0);
+        let mut buffer = vec![0_u8; input_size];
+        let mut output = vec![0_u8; output_size];
+        let mut words = vec![0_u64; output_bits];   // words[j]: the changes of the output bit j of 64 samples
+        let mut both = vec![0_u32; output_bits * output_bits];
+        for (i, flip) in flips.iter_mut().enumerate()
+        {
+            both.fill(0);
+            for s in 0..samples
+            {
+                buffer.copy_from_slice(&inputs[s * input_size..(s + 1) * input_size]);
+                buffer[i / 8] ^= 0x80 >> (i % 8);
+                function(&buffer, &mut output);
+                for (o, base) in output.iter_mut().zip(outputs[s * output_size..].iter())
+                    { *o ^= base; }
+                for_each_bit(&output, |j| {
+                        flip[j] += 1;
+                        words[j] |= 1 << (s % 64);
+                        total += 1;
+                    });
+                if s % 64 == 63 || s == samples - 1
+                {
+                    for j in 0..output_bits
+                    {
+                        if words[j] == 0
+                            { continue; }
+                        for k in (j + 1)..output_bits
+                            { both[j * output_bits + k] += (words[j] & words[k]).count_ones(); }
+                    }
+                    words.fill(0);
+                }
+            }
+
+            for j in 0..output_bits
+            {
+                for k in (j + 1)..output_bits
+                {
+                    if let Some(correlation) = correlation(samples, flip[j], flip[k], both[j * output_bits + k])
+                    {
+                        if correlation.abs() > bic_max_correlation.abs()
+                        {
+                            bic_max_correlation = correlation;
+                            bic_worst = (i, j, k);
+                        }
+                    }
+                }
+            }
+        }
+
+        let n = samples as f64;
+        let sac: Vec<Vec<f64>> = flips.iter().map(|row| row.iter().map(|f| *f as f64 / n).collect()).collect();
+        let mut sac_max_deviation = 0.0_f64;
+        let mut sac_worst = (0, 0);
+        for (i, row) in sac.iter().enumerate()
+        {
+            for (j, p) in row.iter().enumerate()
+            {
+                if (p - 0.5).abs() > sac_max_deviation
+                {
+                    sac_max_deviation = (p - 0.5).abs();
+                    sac_worst = (i, j);
+                }
+            }
+        }
+        let bias: Vec<f64> = ones.iter().map(|c| *c as f64 / n).collect();
+        let mut bias_max_deviation = 0.0_f64;
+        let mut bias_worst = 0;
+        for (j, p) in bias.iter().enumerate()
+        {
+            if (p - 0.5).abs() > bias_max_deviation
+            {
+                bias_max_deviation = (p - 0.5).abs();
+                bias_worst = j;
+            }
+        }
+
+        AvalancheReport
+        {
+            samples,
+            input_bits,
+            output_bits,
+            standard_error: 0.5 / n.sqrt(),
+            avalanche: total as f64 / (n * input_bits as f64 * output_bits as f64),
+            sac,
+            sac_max_deviation,
+            sac_worst,
+            bic_max_correlation,
+            bic_worst,
+            bias,
+            bias_max_deviation,
+            bias_worst,
+        }
+    }
+
+    // pub fn analyze_block_cipher<C: BlockCipher>(&mut self, cipher: &mut C, samples: usize) -> AvalancheReport
+    /// Analyzes the encryption of one block of a block cipher with its
+    /// current key, which changes the message.
+    ///
+    /// # Arguments
+    /// - `cipher` is the block cipher to be analyzed.
+    /// - `samples` is the number of the random messages `N`.
+    ///
+    /// # Output
+    /// It returns `AvalancheReport` which can be printed as a report.
+    ///
+    /// # Panics
+    /// It panics if `samples` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::AvalancheAnalyzer;
+    /// use cryptocol::symmetric::{ DES, DES_Generic };
+    ///
+    /// let mut analyzer = AvalancheAnalyzer::new();
+    /// let key = 0x_1234567890ABCDEF_u64;
+    /// let des16 = analyzer.analyze_block_cipher(&mut DES::new_with_key_u64(key), 64);
+    /// let des3 = analyzer.analyze_block_cipher(&mut DES_Generic::<3>::new_with_key_u64(key), 64);
+    /// println!("16 rounds\n{}\n3 rounds\n{}", des16, des3);
+    /// assert!(des16.sac_max_deviation < 0.4);
+    /// assert_eq!(des3.sac_max_deviation, 0.5);
+    /// ```
+    pub fn analyze_block_cipher<C: BlockCipher>(&mut self, cipher: &mut C, samples: usize) -> AvalancheReport
+    {
+        self.analyze(C::BLOCK_SIZE, C::BLOCK_SIZE, samples, |message, cipher_text| {
+                cipher_text.copy_from_slice(message);
+                cipher.encrypt_one_block(cipher_text);
+            })
+    }
+
+    // pub fn analyze_key_schedule<C: KeyedBlockCipher>(&mut self, cipher: &mut C, samples: usize) -> AvalancheReport
+    /// Analyzes the encryption of one fixed random block of a block cipher
+    /// with random keys, which changes the key.
+    ///
+    /// # Arguments
+    /// - `cipher` is the block cipher to be analyzed.
+    /// - `samples` is the number of the random keys `N`.
+    ///
+    /// # Output
+    /// It returns `AvalancheReport` which can be printed as a report.
+    ///
+    /// # Features
+    /// The key of `cipher` is changed into one of the random keys.
+    ///
+    /// # Panics
+    /// It panics if `samples` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::AvalancheAnalyzer;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// let mut analyzer = AvalancheAnalyzer::new();
+    /// let report = analyzer.analyze_key_schedule(&mut DES::new(), 32);
+    /// println!("{}", report);
+    /// // The least significant bit of every byte of the key of DES is a
+    /// // parity bit which is not used at all.
+    /// assert!(report.sac[7].iter().all(|p| *p == 0.0));
+    /// assert!(report.sac[6].iter().any(|p| *p != 0.0));
+    /// ```
+    pub fn analyze_key_schedule<C: KeyedBlockCipher>(&mut self, cipher: &mut C, samples: usize) -> AvalancheReport
+    {
+        let mut message = vec![0_u8; C::BLOCK_SIZE];
+        for byte in message.iter_mut()
+            { *byte = self.generator.random_u8(); }
+        self.analyze(C::KEY_SIZE, C::BLOCK_SIZE, samples, |key, cipher_text| {
+                cipher.set_key_slice(key);
+                cipher_text.copy_from_slice(&message);
+                cipher.encrypt_one_block(cipher_text);
+            })
+    }
+}
+
+impl Default for AvalancheAnalyzer
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+/// The result of the analysis of `AvalancheAnalyzer`
+///
+/// All the probabilities are ideally 1/2 and all the deviations and the
+/// correlations are ideally zero. It implements `Display`, so that you can
+/// print it as a report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvalancheReport
+{
+    /// The number of the random inputs
+    pub samples: usize,
+
+    /// The number of the input bits
+    pub input_bits: usize,
+
+    /// The number of the output bits
+    pub output_bits: usize,
+
+    /// `0.5 / sqrt(samples)`, which is the statistical error of the
+    /// probabilities for an ideal function
+    pub standard_error: f64,
+
+    /// The average ratio of the changed output bits when one input bit is
+    /// flipped
+    pub avalanche: f64,
+
+    /// `sac[i][j]` is the probability that the output bit `j` is changed
+    /// when the input bit `i` is flipped.
+    pub sac: Vec<Vec<f64>>,
+
+    /// The greatest absolute value of `sac[i][j] - 0.5`
+    pub sac_max_deviation: f64,
+
+    /// The input bit `i` and the output bit `j` of `sac_max_deviation`
+    pub sac_worst: (usize, usize),
+
+    /// The correlation of the greatest absolute value between the changes
+    /// of two output bits when one input bit is flipped, which is zero if
+    /// every output bit is either always or never changed
+    pub bic_max_correlation: f64,
+
+    /// The input bit `i` and the output bits `j` and `k` of
+    /// `bic_max_correlation`
+    pub bic_worst: (usize, usize, usize),
+
+    /// `bias[j]` is the probability that the output bit `j` is one.
+    pub bias: Vec<f64>,
+
+    /// The greatest absolute value of `bias[j] - 0.5`
+    pub bias_max_deviation: f64,
+
+    /// The output bit `j` of `bias_max_deviation`
+    pub bias_worst: usize,
+}
+
+impl Display for AvalancheReport
+{
+    /// Formats the summary of the report in several lines.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        writeln!(f, "Avalanche analysis of {} samples of {} input bits and {} output bits", self.samples, self.input_bits, self.output_bits)?;
+        writeln!(f, "Standard error:\t\t{:.4}", self.standard_error)?;
+        writeln!(f, "Avalanche:\t\t{:.4}", self.avalanche)?;
+        writeln!(f, "SAC max deviation:\t{:.4} (input bit {} -> output bit {})", self.sac_max_deviation, self.sac_worst.0, self.sac_worst.1)?;
+        writeln!(f, "BIC max correlation:\t{:.4} (input bit {} -> output bits {} and {})", self.bic_max_correlation, self.bic_worst.0, self.bic_worst.1, self.bic_worst.2)?;
+        write!(f, "Bias max deviation:\t{:.4} (output bit {})", self.bias_max_deviation, self.bias_worst)
+    }
+}
+
+// Calls `action` with the position of every bit which is one.
+fn for_each_bit<A: FnMut(usize)>(bytes: &[u8], mut action: A)
+{
+    for (index, byte) in bytes.iter().enumerate()
+    {
+        let mut b = *byte;
+        while b != 0
+        {
+            let bit = b.leading_zeros() as usize;
+            action(index * 8 + bit);
+            b &= !(0x80 >> bit);
+        }
+    }
+}
+
+// The correlation of two binary variables of `n` samples which are one `a`
+// times and `b` times respectively and both `ab` times, or `None` if either
+// of them is constant
+fn correlation(n: usize, a: u32, b: u32, ab: u32) -> Option<f64>
+{
+    let n = n as f64;
+    let (pa, pb, pab) = (a as f64 / n, b as f64 / n, ab as f64 / n);
+    let variance = pa * (1.0 - pa) * pb * (1.0 - pb);
+    if variance > 0.0 { Some((pab - pa * pb) / variance.sqrt()) } else { None }
+}
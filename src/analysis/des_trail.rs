@@ -0,0 +1,628 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the search for the best differential and linear
+//! characteristics of DES and its expanded versions
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::fmt::{ self, Display, Formatter };
+
+use crate::symmetric::DES;
+use super::SBox;
+
+
+const EPSILON: f64 = 1e-9;
+
+// The trail of (w_i, c_i) for i = 1, ..., r, its weight and its sign
+type Trail = (Vec<(u32, u32)>, f64, bool);
+
+/// The search for the best differential and linear characteristics of the
+/// Feistel rounds of DES and its expanded versions
+///
+/// # Introduction
+/// The differential cryptanalysis and the linear cryptanalysis of DES are
+/// based on the characteristics of many rounds whose probabilities or
+/// correlations are as high as possible. `DES_TrailSearch` finds the best
+/// characteristic of the given number of rounds with the branch-and-bound
+/// algorithm of Matsui, which computes the best characteristics of one
+/// round, two rounds, and so on, and uses them as the bounds to prune the
+/// search for more rounds. So, you can analyze `DES_Generic` of reduced
+/// rounds or of your own S-boxes, expansion permutation and translation
+/// permutation.
+///
+/// # Weight
+/// The weight of a characteristic is the sum of the weights of its active
+/// S-boxes.
+/// - The weight of an S-box for a differential is `-log2(DDT[a][b] / 64)`,
+///   so that the probability of a differential characteristic is
+///   `2^(-weight)`.
+/// - The weight of an S-box for a linear approximation is
+///   `-log2(|LAT[a][b]| / 32)`, so that the absolute value of the
+///   correlation of a linear characteristic is `2^(-weight)` and its bias
+///   is the half of the correlation.
+///
+/// # Bit numbering
+/// All the differences and masks are `u32` values whose most significant
+/// bit is the bit at the position `1` in FIPS 46-3. The characteristics
+/// cover the Feistel rounds only, that is, from after the initial
+/// permutation to before the final swap and the final permutation.
+///
+/// # Restriction
+/// The search assumes that every bit of the right half is used by the
+/// expansion permutation, which is true of DES. Otherwise, the bits which
+/// are not used are regarded as zero.
+///
+/// # Reference
+/// Mitsuru Matsui, "On Correlation Between the Order of S-boxes and the
+/// Strength of DES", EUROCRYPT 1994.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::analysis::DES_TrailSearch;
+///
+/// let search = DES_TrailSearch::new();
+/// let differential = search.search_differential(3);
+/// println!("{}", differential);
+/// assert!((differential.get_weight() - 4.0).abs() < 1e-9);
+///
+/// let linear = search.search_linear(3);
+/// println!("{}", linear);
+/// assert!((linear.get_correlation() - (20.0_f64 / 32.0).powi(2)).abs() < 1e-9);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct DES_TrailSearch
+{
+    differential: Layer,
+    linear: Layer,
+}
+
+impl DES_TrailSearch
+{
+    // pub fn new() -> Self
+    /// Constructs a new object DES_TrailSearch for the S-boxes, the
+    /// expansion permutation and the translation permutation of DES.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::DES_TrailSearch;
+    ///
+    /// let search = DES_TrailSearch::new();
+    /// let characteristic = search.search_differential(2);
+    /// println!("{}", characteristic);
+    /// assert_eq!(characteristic.get_rounds(), 2);
+    /// ```
+    pub fn new() -> Self
+    {
+        Self::new_with_tables(DES::get_sbox_table(), DES::get_expansion_table(), DES::get_translation_table())
+    }
+
+    // pub fn new_with_tables(sbox: [[u8; 64]; 8], expansion: [u8; 48], translation: [u8; 32]) -> Self
+    /// Constructs a new object DES_TrailSearch for the given S-boxes,
+    /// expansion permutation and translation permutation.
+    ///
+    /// # Arguments
+    /// - `sbox` is the eight S-boxes in the layout of `get_sbox_table()` of
+    ///   `DES_Generic`.
+    /// - `expansion` is the expansion permutation in the layout of
+    ///   `get_expansion_table()` of `DES_Generic`.
+    /// - `translation` is the translation permutation in the layout of
+    ///   `get_translation_table()` of `DES_Generic`.
+    ///
+    /// # Panics
+    /// It panics if any element of `sbox` is not less than 16, if any
+    /// element of `expansion` is not from 1 to 32, or if `translation` is not
+    /// a permutation of the numbers from 1 to 32.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::DES_TrailSearch;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// // DES whose first S-box is replaced with the fifth one
+    /// let mut sbox = DES::get_sbox_table();
+    /// sbox[0] = sbox[4];
+    /// let search = DES_TrailSearch::new_with_tables(sbox, DES::get_expansion_table(), DES::get_translation_table());
+    /// let characteristic = search.search_linear(2);
+    /// println!("{}", characteristic);
+    /// assert!(characteristic.get_weight() < 1.0);
+    /// ```
+    pub fn new_with_tables(sbox: [[u8; 64]; 8], expansion: [u8; 48], translation: [u8; 32]) -> Self
+    {
+        assert!(expansion.iter().all(|e| (1..=32).contains(e)), "Every element of the expansion permutation should be from 1 to 32.");
+        let mut position = [0_usize; 32];   // position[q - 1] = i such that translation[i - 1] == q
+        for (i, t) in translation.iter().enumerate()
+        {
+            assert!((1..=32).contains(t) && position[*t as usize - 1] == 0, "The translation permutation should be a permutation of the numbers from 1 to 32.");
+            position[*t as usize - 1] = i + 1;
+        }
+
+        let mut differential = Layer::new(6, 4);
+        let mut linear = Layer::new(4, 6);
+        for (j, s) in sbox.iter().enumerate()
+        {
+            let s = SBox::from_des(s);
+            for t in 0..6
+            {
+                let bit = 32 - expansion[6 * j + t] as u32;
+                differential.source[j][5 - t] = bit;
+                for c in 0..64
+                {
+                    if (c >> (5 - t)) & 1 == 1
+                        { linear.spread[j][c] ^= 1 << bit; }
+                }
+            }
+            for t in 0..4
+            {
+                let bit = 32 - position[4 * j + t] as u32;
+                linear.source[j][3 - t] = bit;
+                for c in 0..16
+                {
+                    if (c >> (3 - t)) & 1 == 1
+                        { differential.spread[j][c] ^= 1 << bit; }
+                }
+            }
+
+            for (a, row) in s.get_ddt().iter().enumerate()
+            {
+                for (b, count) in row.iter().enumerate()
+                {
+                    if *count != 0
+                        { differential.table[j][a].push((b as u8, -(*count as f64 / 64.0).log2(), false)); }
+                }
+            }
+            for (a, row) in s.get_lat().iter().enumerate()
+            {
+                for (b, bias) in row.iter().enumerate()
+                {
+                    if *bias != 0
+                        { linear.table[j][b].push((a as u8, -(bias.abs() as f64 / 32.0).log2(), *bias < 0)); }
+                }
+            }
+        }
+        differential.sort();
+        linear.sort();
+        Self { differential, linear }
+    }
+
+    // pub fn search_differential(&self, rounds: usize) -> Characteristic
+    /// Finds the best differential characteristic of `rounds` rounds.
+    ///
+    /// # Arguments
+    /// `rounds` is the number of the rounds.
+    ///
+    /// # Output
+    /// It returns the differential characteristic of the greatest
+    /// probability, that is, of the least weight. If there are several, it
+    /// returns the first one that it finds.
+    ///
+    /// # Features
+    /// The search of many rounds may take a long time, especially without
+    /// optimization.
+    ///
+    /// # Panics
+    /// It panics if `rounds` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::DES_TrailSearch;
+    ///
+    /// let search = DES_TrailSearch::new();
+    /// let characteristic = search.search_differential(3);
+    /// let (left, right) = characteristic.get_input();
+    /// println!("{:08X} {:08X} with 2^-{:.2}", left, right, characteristic.get_weight());
+    /// assert!((characteristic.get_probability() - 1.0 / 16.0).abs() < 1e-9);
+    /// ```
+    pub fn search_differential(&self, rounds: usize) -> Characteristic
+    {
+        let (trail, weight, negative) = self.differential.search(rounds);
+        let (w0, w) = Layer::complete(&trail);
+        let r = trail.len();
+        Characteristic
+        {
+            linear: false,
+            input: (w0, trail[0].0),
+            output: (trail[r - 1].0, w),
+            round: trail,
+            weight,
+            negative,
+        }
+    }
+
+    // pub fn search_linear(&self, rounds: usize) -> Characteristic
+    /// Finds the best linear characteristic of `rounds` rounds.
+    ///
+    /// # Arguments
+    /// `rounds` is the number of the rounds.
+    ///
+    /// # Output
+    /// It returns the linear characteristic of the greatest absolute value
+    /// of the correlation, that is, of the least weight. If there are
+    /// several, it returns the first one that it finds.
+    ///
+    /// # Features
+    /// The search of many rounds may take a long time, especially without
+    /// optimization.
+    ///
+    /// # Panics
+    /// It panics if `rounds` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::DES_TrailSearch;
+    ///
+    /// let search = DES_TrailSearch::new();
+    /// let characteristic = search.search_linear(3);
+    /// println!("{}", characteristic);
+    /// // The linear approximation of three rounds by Matsui
+    /// assert!((characteristic.get_bias() - 0.1953125).abs() < 1e-9);
+    /// ```
+    pub fn search_linear(&self, rounds: usize) -> Characteristic
+    {
+        let (trail, weight, negative) = self.linear.search(rounds);
+        let (b0, b) = Layer::complete(&trail);
+        let r = trail.len();
+        Characteristic
+        {
+            linear: true,
+            input: (trail[0].0, b0),
+            output: (b, trail[r - 1].0),
+            round: trail.iter().map(|(beta, alpha)| (*alpha, *beta)).collect(),
+            weight,
+            negative,
+        }
+    }
+}
+
+impl Default for DES_TrailSearch
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+/// A differential or linear characteristic of the Feistel rounds of DES
+/// which `DES_TrailSearch` finds
+///
+/// It implements `Display`, so that you can print it as a report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Characteristic
+{
+    linear: bool,
+    input: (u32, u32),
+    output: (u32, u32),
+    round: Vec<(u32, u32)>,
+    weight: f64,
+    negative: bool,
+}
+
+impl Characteristic
+{
+    // pub fn is_linear(&self) -> bool
+    /// Checks whether the characteristic is linear or differential.
+    #[inline]
+    pub fn is_linear(&self) -> bool
+    {
+        self.linear
+    }
+
+    // pub fn get_rounds(&self) -> usize
+    /// Returns the number of the rounds.
+    #[inline]
+    pub fn get_rounds(&self) -> usize
+    {
+        self.round.len()
+    }
+
+    // pub fn get_input(&self) -> (u32, u32)
+    /// Returns the differences or the masks of the left half and the right
+    /// half before the first round.
+    #[inline]
+    pub fn get_input(&self) -> (u32, u32)
+    {
+        self.input
+    }
+
+    // pub fn get_output(&self) -> (u32, u32)
+    /// Returns the differences or the masks of the left half and the right
+    /// half after the last round.
+    #[inline]
+    pub fn get_output(&self) -> (u32, u32)
+    {
+        self.output
+    }
+
+    // pub fn get_round_function(&self) -> &[(u32, u32)]
+    /// Returns the differences or the masks of the input and the output of
+    /// the round function F of every round.
+    #[inline]
+    pub fn get_round_function(&self) -> &[(u32, u32)]
+    {
+        &self.round
+    }
+
+    // pub fn get_weight(&self) -> f64
+    /// Returns the weight, which is the sum of the weights of the active
+    /// S-boxes.
+    #[inline]
+    pub fn get_weight(&self) -> f64
+    {
+        self.weight
+    }
+
+    // pub fn get_probability(&self) -> f64
+    /// Returns `2^(-weight)`, which is the probability of a differential
+    /// characteristic or the absolute value of the correlation of a linear
+    /// characteristic.
+    #[inline]
+    pub fn get_probability(&self) -> f64
+    {
+        (-self.weight).exp2()
+    }
+
+    // pub fn get_correlation(&self) -> f64
+    /// Returns the correlation of a linear characteristic, which is
+    /// `2^(-weight)` with the sign of the product of the elements of LAT.
+    /// For a differential characteristic, it is the same as the probability.
+    #[inline]
+    pub fn get_correlation(&self) -> f64
+    {
+        if self.negative { -self.get_probability() } else { self.get_probability() }
+    }
+
+    // pub fn get_bias(&self) -> f64
+    /// Returns the bias of a linear characteristic, which is the half of
+    /// the correlation.
+    #[inline]
+    pub fn get_bias(&self) -> f64
+    {
+        self.get_correlation() / 2.0
+    }
+}
+
+impl Display for Characteristic
+{
+    /// Formats the characteristic as a report of several lines.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let (kind, value) = if self.linear { ("Linear", "correlation") } else { ("Differential", "probability") };
+        writeln!(f, "{} characteristic of {} rounds", kind, self.round.len())?;
+        writeln!(f, "Input:\t{:08X} {:08X}", self.input.0, self.input.1)?;
+        for (i, (input, output)) in self.round.iter().enumerate()
+            { writeln!(f, "Round {}:\t{:08X} -> {:08X}", i + 1, input, output)?; }
+        writeln!(f, "Output:\t{:08X} {:08X}", self.output.0, self.output.1)?;
+        write!(f, "Weight:\t{:.4} ({} = {:.6e})", self.weight, value, self.get_correlation())
+    }
+}
+
+
+// The propagation through the round function F of the characteristics
+// w_0, w_1, ..., w_(r+1) such that the round i determines the inputs of its
+// S-boxes from w_i, chooses their outputs which spread into c_i, and then
+// w_(i+1) = w_(i-1) ^ c_i.
+// - For the differential characteristics, w_i is the input difference of F
+//   and c_i is the output difference of F.
+// - For the linear characteristics, w_i is the output mask of F and c_i is
+//   the input mask of F.
+#[derive(Debug, Clone)]
+struct Layer
+{
+    source: Vec<Vec<u32>>,                  // source[j][bit]: the bit of w for the bit of the value determined for S-box j
+    spread: Vec<Vec<u32>>,                  // spread[j][c]: the bits of c_i for the value c chosen for S-box j
+    table: Vec<Vec<Vec<(u8, f64, bool)>>>,  // table[j][d]: (c, weight, negative) for the value d determined for S-box j
+}
+
+impl Layer
+{
+    fn new(determined: usize, chosen: usize) -> Self
+    {
+        Self
+        {
+            source: vec![vec![0; determined]; 8],
+            spread: vec![vec![0; 1 << chosen]; 8],
+            table: vec![vec![Vec::new(); 1 << determined]; 8],
+        }
+    }
+
+    fn sort(&mut self)
+    {
+        for entries in self.table.iter_mut().flatten()
+            { entries.sort_by(|x, y| x.1.total_cmp(&y.1)); }
+    }
+
+    fn determine(&self, j: usize, w: u32) -> usize
+    {
+        let mut d = 0;
+        for (bit, src) in self.source[j].iter().enumerate()
+            { d |= (((w >> src) & 1) as usize) << bit; }
+        d
+    }
+
+    // Returns w_0 and w_(r+1) of the trail of (w_i, c_i) for i = 1, ..., r.
+    fn complete(trail: &[(u32, u32)]) -> (u32, u32)
+    {
+        let r = trail.len();
+        if r == 1
+        {
+            // Any nonzero difference or mask of the left half passes
+            // through the round without any active S-box.
+            let (w1, c1) = trail[0];
+            return if w1 == 0 { (0x8000_0000, 0x8000_0000) } else { (c1, 0) };
+        }
+        (trail[1].0 ^ trail[0].1, trail[r - 2].0 ^ trail[r - 1].1)
+    }
+
+    fn search(&self, rounds: usize) -> Trail
+    {
+        assert!(rounds > 0, "The number of the rounds should be at least one.");
+        let mut bounds = vec![0.0];
+        let mut best = (Vec::new(), 0.0, false);
+        for r in 1..=rounds
+        {
+            let mut estimate = bounds[r - 1];
+            loop
+            {
+                let mut search = Search { layer: self, rounds: r, bounds: &bounds, estimate, trail: Vec::with_capacity(r), best: None };
+                search.free_round(1, 0, 0, 0, 0, 0.0, false);
+                if let Some(found) = search.best
+                {
+                    best = found;
+                    break;
+                }
+                estimate += 1.0;
+            }
+            bounds.push(best.1);
+        }
+        best
+    }
+}
+
+
+// Matsui's branch-and-bound search for the characteristics of `rounds`
+// rounds whose weights are not greater than `estimate`, where `bounds[k]`
+// is the least weight of the characteristics of k rounds.
+struct Search<'a>
+{
+    layer: &'a Layer,
+    rounds: usize,
+    bounds: &'a [f64],
+    estimate: f64,
+    trail: Vec<(u32, u32)>,
+    best: Option<Trail>,
+}
+
+impl Search<'_>
+{
+    fn record(&mut self, weight: f64, negative: bool)
+    {
+        if self.best.is_none() || weight < self.estimate - EPSILON
+        {
+            self.estimate = weight;
+            self.best = Some((self.trail.clone(), weight, negative));
+        }
+    }
+
+    // The first two rounds are free, that is, both w_i and c_i are chosen
+    // S-box by S-box while keeping the bits of w_i consistent.
+    #[allow(clippy::too_many_arguments)]
+    fn free_round(&mut self, round: usize, j: usize, assigned: u32, w: u32, c: u32, weight: f64, negative: bool)
+    {
+        if j == 8
+        {
+            self.trail.push((w, c));
+            if round == self.rounds
+            {
+                if round == 1 || self.trail[0].0 != 0 || w != 0
+                    { self.record(weight, negative); }
+            }
+            else if round == 1
+            {
+                self.free_round(2, 0, 0, 0, 0, weight, negative);
+            }
+            else if self.trail[0].0 != 0 || w != 0
+            {
+                let next = self.trail[0].0 ^ c;
+                self.fixed_round(3, next, weight, negative);
+            }
+            self.trail.pop();
+            return;
+        }
+
+        let layer = self.layer;
+        let bound = self.bounds[self.rounds - round];
+        for (d, entries) in layer.table[j].iter().enumerate()
+        {
+            let mut mask = assigned;
+            let mut value = w;
+            let mut consistent = true;
+            for (bit, src) in layer.source[j].iter().enumerate()
+            {
+                let b = ((d >> bit) & 1) as u32;
+                if (mask >> src) & 1 == 1 && (value >> src) & 1 != b
+                {
+                    consistent = false;
+                    break;
+                }
+                mask |= 1 << src;
+                value |= b << src;
+            }
+            if !consistent
+                { continue; }
+            for (chosen, wt, neg) in entries.iter()
+            {
+                if weight + wt + bound > self.estimate + EPSILON
+                    { break; }
+                self.free_round(round, j + 1, mask, value, c ^ layer.spread[j][*chosen as usize], weight + wt, negative ^ neg);
+            }
+        }
+    }
+
+    // The rounds from the third one have w_i determined by the previous
+    // rounds, so that only c_i is chosen.
+    fn fixed_round(&mut self, round: usize, w: u32, weight: f64, negative: bool)
+    {
+        let layer = self.layer;
+        let mut d = [0_usize; 8];
+        let mut rest = [0.0_f64; 9];    // rest[j]: the least weight of the S-boxes from j
+        for j in (0..8).rev()
+        {
+            d[j] = layer.determine(j, w);
+            rest[j] = rest[j + 1] + layer.table[j][d[j]][0].1;
+        }
+        if weight + rest[0] + self.bounds[self.rounds - round] > self.estimate + EPSILON
+            { return; }
+
+        if round == self.rounds
+        {
+            // The last round does not affect anything else, so that the
+            // best output of every S-box is enough.
+            let mut c = 0;
+            let mut neg = negative;
+            for (j, dj) in d.iter().enumerate()
+            {
+                let (chosen, _, n) = layer.table[j][*dj][0];
+                c ^= layer.spread[j][chosen as usize];
+                neg ^= n;
+            }
+            self.trail.push((w, c));
+            self.record(weight + rest[0], neg);
+            self.trail.pop();
+        }
+        else
+        {
+            self.fixed_sbox(round, w, &d, &rest, 0, 0, weight, negative);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fixed_sbox(&mut self, round: usize, w: u32, d: &[usize; 8], rest: &[f64; 9], j: usize, c: u32, weight: f64, negative: bool)
+    {
+        if j == 8
+        {
+            let next = self.trail[round - 2].0 ^ c;
+            self.trail.push((w, c));
+            self.fixed_round(round + 1, next, weight, negative);
+            self.trail.pop();
+            return;
+        }
+        let layer = self.layer;
+        let bound = rest[j + 1] + self.bounds[self.rounds - round];
+        for (chosen, wt, neg) in layer.table[j][d[j]].iter()
+        {
+            if weight + wt + bound > self.estimate + EPSILON
+                { break; }
+            self.fixed_sbox(round, w, d, rest, j + 1, c ^ layer.spread[j][*chosen as usize], weight + wt, negative ^ neg);
+        }
+    }
+}
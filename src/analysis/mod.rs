@@ -0,0 +1,52 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! various tools for the analysis of cryptographic algorithms
+//! 
+//! # Introduction
+//! The module that contains a few sub-modules to analyze the algorithms and
+//! their building blocks in this crate, which are useful for
+//! education and research of cryptanalysis.
+//! 
+//! # Tools
+//! - SBox: computes the difference distribution table, the linear
+//!   approximation table, the differential uniformity, the nonlinearity,
+//!   the algebraic degree and the branch numbers of an S-box.
+//!   Read [here](struct@SBox).
+//! - DES_TrailSearch: finds the best differential and linear
+//!   characteristics of DES and its expanded versions of reduced rounds.
+//!   Read [here](struct@DES_TrailSearch).
+//! - AvalancheAnalyzer: measures the strict avalanche criterion, the bit
+//!   independence criterion and the output bias of hash algorithms, ciphers
+//!   and any other functions from bytes to bytes.
+//!   Read [here](struct@AvalancheAnalyzer).
+//! 
+//! # QUICK START
+//! ```
+//! use cryptocol::analysis::{ SBox, DES_TrailSearch, AvalancheAnalyzer };
+//! use cryptocol::symmetric::DES;
+//! 
+//! let sbox = DES::get_sbox_table();
+//! for (i, s) in sbox.iter().enumerate()
+//!     { println!("S{}\n{}", i + 1, SBox::from_des(s).get_properties()); }
+//! 
+//! let search = DES_TrailSearch::new();
+//! println!("{}", search.search_differential(3));
+//! println!("{}", search.search_linear(3));
+//! 
+//! let mut analyzer = AvalancheAnalyzer::new();
+//! println!("{}", analyzer.analyze_block_cipher(&mut DES::new(), 16));
+//! ```
+
+mod sbox;
+mod des_trail;
+mod avalanche;
+
+pub use sbox::*;
+pub use des_trail::*;
+pub use avalanche::*;
@@ -0,0 +1,500 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the differential and linear properties of
+//! S-boxes
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::fmt::{ self, Display, Formatter };
+
+
+/// An S-box of up to 8 input bits and up to 8 output bits, and its
+/// differential and linear properties
+///
+/// # Introduction
+/// The S-box is the only nonlinear part of many block ciphers such as DES
+/// and AES, so that its properties bound the resistance of the whole
+/// cipher against differential and linear cryptanalysis. `SBox` computes
+/// the following properties of an S-box of `n` input bits and `m` output
+/// bits.
+/// - DDT (difference distribution table): `DDT[a][b]` is the number of the
+///   inputs `x` such that `S(x) ^ S(x ^ a) == b`.
+/// - LAT (linear approximation table): `LAT[a][b]` is the number of the
+///   inputs `x` such that `a·x == b·S(x)` minus `2^(n-1)`, where `·` is the
+///   inner product of bits. So, `LAT[a][b] / 2^n` is the bias of the linear
+///   approximation.
+/// - Differential uniformity: the greatest element of DDT except `DDT[0][0]`.
+///   The smaller, the stronger against differential cryptanalysis.
+/// - Nonlinearity: `2^(n-1)` minus the greatest absolute value of LAT
+///   except the column `b = 0`. The greater, the stronger against linear
+///   cryptanalysis.
+/// - Algebraic degree: the greatest degree of the algebraic normal forms of
+///   the output bits. The greater, the stronger against algebraic attacks
+///   and higher order differential cryptanalysis.
+/// - Differential and linear branch numbers: the least sum of the Hamming
+///   weights of the input and the output differences (masks) of the
+///   nonzero entries of DDT (LAT) except the zero difference (mask).
+///
+/// # Bit order
+/// The input and the output are the integers whose most significant bit is
+/// the first bit, which is the same as the S-boxes of DES in this crate,
+/// that is, the index of the S-box of DES is its six input bits.
+///
+/// # Reference
+/// Read [the article](https://en.wikipedia.org/wiki/Differential_cryptanalysis)
+/// and [the article](https://en.wikipedia.org/wiki/Linear_cryptanalysis)
+/// about differential and linear cryptanalysis in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::analysis::SBox;
+/// use cryptocol::symmetric::DES;
+///
+/// // The fifth S-box of DES
+/// let s5 = SBox::from_des(&DES::get_sbox_table()[4]);
+/// assert_eq!(s5.get_ddt()[0][0], 64);
+/// assert_eq!(s5.get_lat()[16][15], -20);
+/// assert_eq!(s5.get_best_linear_approximation(), (16, 15, -20));
+///
+/// let properties = s5.get_properties();
+/// println!("{}", properties);
+/// assert_eq!(properties.differential_uniformity, 16);
+/// assert_eq!(properties.nonlinearity, 12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SBox
+{
+    table: Vec<u8>,
+    input_bits: usize,
+    output_bits: usize,
+}
+
+impl SBox
+{
+    // pub fn new(table: &[u8], input_bits: usize, output_bits: usize) -> Self
+    /// Constructs a new object SBox.
+    ///
+    /// # Arguments
+    /// - `table` is the outputs of the S-box for the inputs from `0` to
+    ///   `2^input_bits - 1` in order.
+    /// - `input_bits` is the number of the input bits from `1` to `8`.
+    /// - `output_bits` is the number of the output bits from `1` to `8`.
+    ///
+    /// # Panics
+    /// It panics if `input_bits` or `output_bits` is not from `1` to `8`,
+    /// if the length of `table` is not `2^input_bits`, or if any element of
+    /// `table` is not less than `2^output_bits`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    ///
+    /// // The S-box of PRESENT
+    /// let present = SBox::new(&[0xC, 0x5, 0x6, 0xB, 0x9, 0x0, 0xA, 0xD,
+    ///                           0x3, 0xE, 0xF, 0x8, 0x4, 0x7, 0x1, 0x2], 4, 4);
+    /// assert!(present.is_permutation());
+    /// assert_eq!(present.get_differential_uniformity(), 4);
+    /// assert_eq!(present.get_nonlinearity(), 4);
+    /// assert_eq!(present.get_algebraic_degree(), 3);
+    /// assert_eq!(present.get_differential_branch_number(), 3);
+    /// ```
+    pub fn new(table: &[u8], input_bits: usize, output_bits: usize) -> Self
+    {
+        if !(1..=8).contains(&input_bits) || !(1..=8).contains(&output_bits)
+            { panic!("The numbers of the input bits and the output bits should be from 1 to 8."); }
+        assert_eq!(table.len(), 1 << input_bits, "The S-box should have 2^input_bits elements.");
+        assert!(table.iter().all(|s| (*s as usize) < (1 << output_bits)), "Every element of the S-box should be less than 2^output_bits.");
+        Self { table: table.to_vec(), input_bits, output_bits }
+    }
+
+    // pub fn from_des(sbox: &[u8; 64]) -> Self
+    /// Constructs a new object SBox from an S-box of DES.
+    ///
+    /// # Arguments
+    /// `sbox` is one of the eight S-boxes in the layout of `get_sbox_table()`
+    /// of `DES_Generic`, whose index is the six input bits.
+    ///
+    /// # Panics
+    /// It panics if any element of `sbox` is not less than 16.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// for sbox in DES::get_sbox_table()
+    /// {
+    ///     let s = SBox::from_des(&sbox);
+    ///     assert_eq!(s.get_differential_uniformity(), 16);
+    ///     assert_eq!(s.get_input_bits(), 6);
+    ///     assert_eq!(s.get_output_bits(), 4);
+    /// }
+    /// ```
+    #[inline]
+    pub fn from_des(sbox: &[u8; 64]) -> Self
+    {
+        Self::new(sbox, 6, 4)
+    }
+
+    // pub fn get_input_bits(&self) -> usize
+    /// Returns the number of the input bits.
+    #[inline]
+    pub fn get_input_bits(&self) -> usize
+    {
+        self.input_bits
+    }
+
+    // pub fn get_output_bits(&self) -> usize
+    /// Returns the number of the output bits.
+    #[inline]
+    pub fn get_output_bits(&self) -> usize
+    {
+        self.output_bits
+    }
+
+    // pub fn get_table(&self) -> &[u8]
+    /// Returns the outputs of the S-box for all the inputs in order.
+    #[inline]
+    pub fn get_table(&self) -> &[u8]
+    {
+        &self.table
+    }
+
+    // pub fn substitute(&self, input: usize) -> u8
+    /// Returns the output of the S-box for `input`.
+    ///
+    /// # Panics
+    /// It panics if `input` is not less than `2^input_bits`.
+    #[inline]
+    pub fn substitute(&self, input: usize) -> u8
+    {
+        self.table[input]
+    }
+
+    // pub fn is_permutation(&self) -> bool
+    /// Checks whether the S-box is a permutation, that is, it has as many
+    /// output bits as input bits and it is bijective.
+    pub fn is_permutation(&self) -> bool
+    {
+        if self.input_bits != self.output_bits
+            { return false; }
+        let mut seen = vec![false; self.table.len()];
+        for s in self.table.iter()
+        {
+            if seen[*s as usize]
+                { return false; }
+            seen[*s as usize] = true;
+        }
+        true
+    }
+
+    // pub fn get_ddt(&self) -> Vec<Vec<u32>>
+    /// Computes the difference distribution table.
+    ///
+    /// # Output
+    /// It returns `DDT` of `2^input_bits` rows and `2^output_bits` columns.
+    /// `DDT[a][b]` is the number of the inputs `x` such that
+    /// `S(x) ^ S(x ^ a) == b`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// let s1 = SBox::from_des(&DES::get_sbox_table()[0]);
+    /// let ddt = s1.get_ddt();
+    /// // The input difference 0x34 of S1 gives the output difference 0x2 with
+    /// // the probability 16/64.
+    /// assert_eq!(ddt[0x34][0x2], 16);
+    /// assert!(ddt.iter().all(|row| row.iter().sum::<u32>() == 64));
+    /// ```
+    pub fn get_ddt(&self) -> Vec<Vec<u32>>
+    {
+        let rows = 1 << self.input_bits;
+        let mut ddt = vec![vec![0_u32; 1 << self.output_bits]; rows];
+        for (a, row) in ddt.iter_mut().enumerate()
+        {
+            for x in 0..rows
+                { row[(self.table[x] ^ self.table[x ^ a]) as usize] += 1; }
+        }
+        ddt
+    }
+
+    // pub fn get_lat(&self) -> Vec<Vec<i32>>
+    /// Computes the linear approximation table.
+    ///
+    /// # Output
+    /// It returns `LAT` of `2^input_bits` rows and `2^output_bits` columns.
+    /// `LAT[a][b]` is the number of the inputs `x` such that
+    /// `a·x == b·S(x)` minus `2^(input_bits - 1)`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// let s5 = SBox::from_des(&DES::get_sbox_table()[4]);
+    /// let lat = s5.get_lat();
+    /// // The famous linear approximation of S5 that Matsui used
+    /// assert_eq!(lat[16][15], -20);
+    /// assert_eq!(lat[0][0], 32);
+    /// ```
+    pub fn get_lat(&self) -> Vec<Vec<i32>>
+    {
+        let rows = 1 << self.input_bits;
+        let half = (rows / 2) as i32;
+        let mut lat = vec![vec![-half; 1 << self.output_bits]; rows];
+        for (a, row) in lat.iter_mut().enumerate()
+        {
+            for (b, element) in row.iter_mut().enumerate()
+            {
+                for x in 0..rows
+                {
+                    if parity(a & x) == parity(b & self.table[x] as usize)
+                        { *element += 1; }
+                }
+            }
+        }
+        lat
+    }
+
+    // pub fn get_differential_uniformity(&self) -> u32
+    /// Computes the differential uniformity, which is the greatest element
+    /// of DDT for the nonzero input differences.
+    pub fn get_differential_uniformity(&self) -> u32
+    {
+        self.get_ddt().iter().skip(1).flatten().copied().max().unwrap_or(0)
+    }
+
+    // pub fn get_best_differential(&self) -> (usize, usize, u32)
+    /// Finds the best differential of the S-box.
+    ///
+    /// # Output
+    /// It returns `(a, b, DDT[a][b])` of the greatest element of DDT for the
+    /// nonzero input difference `a`. If there are several, it returns the
+    /// first one in the order of `a` and then `b`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// let s1 = SBox::from_des(&DES::get_sbox_table()[0]);
+    /// let (a, b, count) = s1.get_best_differential();
+    /// println!("{:02X} -> {:X} with {}/64", a, b, count);
+    /// assert_eq!(count, 16);
+    /// ```
+    pub fn get_best_differential(&self) -> (usize, usize, u32)
+    {
+        let mut best = (0, 0, 0);
+        for (a, row) in self.get_ddt().iter().enumerate().skip(1)
+        {
+            for (b, count) in row.iter().enumerate()
+            {
+                if *count > best.2
+                    { best = (a, b, *count); }
+            }
+        }
+        best
+    }
+
+    // pub fn get_best_linear_approximation(&self) -> (usize, usize, i32)
+    /// Finds the best linear approximation of the S-box.
+    ///
+    /// # Output
+    /// It returns `(a, b, LAT[a][b])` of the greatest absolute value of LAT
+    /// for the nonzero output mask `b`. If there are several, it returns the
+    /// first one in the order of `a` and then `b`.
+    pub fn get_best_linear_approximation(&self) -> (usize, usize, i32)
+    {
+        let mut best = (0, 0, 0_i32);
+        for (a, row) in self.get_lat().iter().enumerate()
+        {
+            for (b, bias) in row.iter().enumerate().skip(1)
+            {
+                if bias.abs() > best.2.abs()
+                    { best = (a, b, *bias); }
+            }
+        }
+        best
+    }
+
+    // pub fn get_nonlinearity(&self) -> u32
+    /// Computes the nonlinearity, which is `2^(input_bits - 1)` minus the
+    /// greatest absolute value of LAT for the nonzero output masks.
+    pub fn get_nonlinearity(&self) -> u32
+    {
+        let (_, _, bias) = self.get_best_linear_approximation();
+        (1_u32 << (self.input_bits - 1)) - bias.unsigned_abs()
+    }
+
+    // pub fn get_algebraic_degree(&self) -> u32
+    /// Computes the algebraic degree, which is the greatest degree of the
+    /// algebraic normal forms of the output bits.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::analysis::SBox;
+    ///
+    /// // An affine S-box has the algebraic degree 1.
+    /// let table: Vec<u8> = (0..16_u8).map(|x| x ^ 0b1010).collect();
+    /// assert_eq!(SBox::new(&table, 4, 4).get_algebraic_degree(), 1);
+    /// ```
+    pub fn get_algebraic_degree(&self) -> u32
+    {
+        let mut degree = 0;
+        for bit in 0..self.output_bits
+        {
+            // Möbius transform from the truth table into the algebraic
+            // normal form
+            let mut anf: Vec<u8> = self.table.iter().map(|s| (s >> bit) & 1).collect();
+            let mut step = 1;
+            while step < anf.len()
+            {
+                for x in 0..anf.len()
+                {
+                    if x & step != 0
+                        { anf[x] ^= anf[x ^ step]; }
+                }
+                step <<= 1;
+            }
+            for (monomial, coefficient) in anf.iter().enumerate()
+            {
+                if *coefficient == 1
+                    { degree = degree.max(monomial.count_ones()); }
+            }
+        }
+        degree
+    }
+
+    // pub fn get_differential_branch_number(&self) -> u32
+    /// Computes the differential branch number, which is the least sum of
+    /// the Hamming weights of `a` and `b` such that `a` is not zero and
+    /// `DDT[a][b]` is not zero.
+    pub fn get_differential_branch_number(&self) -> u32
+    {
+        let mut branch = u32::MAX;
+        for (a, row) in self.get_ddt().iter().enumerate().skip(1)
+        {
+            for (b, count) in row.iter().enumerate()
+            {
+                if *count != 0
+                    { branch = branch.min(a.count_ones() + b.count_ones()); }
+            }
+        }
+        branch
+    }
+
+    // pub fn get_linear_branch_number(&self) -> u32
+    /// Computes the linear branch number, which is the least sum of the
+    /// Hamming weights of `a` and `b` such that `b` is not zero and
+    /// `LAT[a][b]` is not zero.
+    pub fn get_linear_branch_number(&self) -> u32
+    {
+        let mut branch = u32::MAX;
+        for (a, row) in self.get_lat().iter().enumerate()
+        {
+            for (b, bias) in row.iter().enumerate().skip(1)
+            {
+                if *bias != 0
+                    { branch = branch.min(a.count_ones() + b.count_ones()); }
+            }
+        }
+        branch
+    }
+
+    // pub fn get_properties(&self) -> SBoxProperties
+    /// Computes all the properties of the S-box at once.
+    ///
+    /// # Output
+    /// It returns `SBoxProperties` which can be printed as a report.
+    pub fn get_properties(&self) -> SBoxProperties
+    {
+        let (a, b, count) = self.get_best_differential();
+        let (c, d, bias) = self.get_best_linear_approximation();
+        SBoxProperties
+        {
+            input_bits: self.input_bits,
+            output_bits: self.output_bits,
+            differential_uniformity: count,
+            best_differential: (a, b),
+            nonlinearity: (1_u32 << (self.input_bits - 1)) - bias.unsigned_abs(),
+            best_linear_approximation: (c, d),
+            best_linear_bias: bias,
+            algebraic_degree: self.get_algebraic_degree(),
+            differential_branch_number: self.get_differential_branch_number(),
+            linear_branch_number: self.get_linear_branch_number(),
+        }
+    }
+}
+
+
+/// The differential and linear properties of an S-box which
+/// `SBox::get_properties()` gives
+///
+/// It implements `Display`, so that you can print it as a report.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SBoxProperties
+{
+    /// The number of the input bits
+    pub input_bits: usize,
+
+    /// The number of the output bits
+    pub output_bits: usize,
+
+    /// The greatest element of DDT for the nonzero input differences
+    pub differential_uniformity: u32,
+
+    /// The input and the output differences of the best differential
+    pub best_differential: (usize, usize),
+
+    /// `2^(input_bits - 1)` minus the greatest absolute value of LAT for
+    /// the nonzero output masks
+    pub nonlinearity: u32,
+
+    /// The input and the output masks of the best linear approximation
+    pub best_linear_approximation: (usize, usize),
+
+    /// The element of LAT of the best linear approximation
+    pub best_linear_bias: i32,
+
+    /// The greatest degree of the algebraic normal forms of the output bits
+    pub algebraic_degree: u32,
+
+    /// The differential branch number
+    pub differential_branch_number: u32,
+
+    /// The linear branch number
+    pub linear_branch_number: u32,
+}
+
+impl Display for SBoxProperties
+{
+    /// Formats the properties as a report of several lines.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let size = 1_u32 << self.input_bits;
+        writeln!(f, "S-box of {} input bits and {} output bits", self.input_bits, self.output_bits)?;
+        writeln!(f, "Differential uniformity:\t{} ({:X} -> {:X} with {}/{})",
+                    self.differential_uniformity, self.best_differential.0, self.best_differential.1, self.differential_uniformity, size)?;
+        writeln!(f, "Nonlinearity:\t\t\t{} ({:X} -> {:X} with bias {}/{})",
+                    self.nonlinearity, self.best_linear_approximation.0, self.best_linear_approximation.1, self.best_linear_bias, size)?;
+        writeln!(f, "Algebraic degree:\t\t{}", self.algebraic_degree)?;
+        writeln!(f, "Differential branch number:\t{}", self.differential_branch_number)?;
+        write!(f, "Linear branch number:\t\t{}", self.linear_branch_number)
+    }
+}
+
+#[inline]
+fn parity(x: usize) -> u32
+{
+    x.count_ones() & 1
+}
@@ -0,0 +1,532 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Ascon-Hash256 and Ascon-XOF128 hash algorithms
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::ptr::copy_nonoverlapping;
+use std::slice::from_raw_parts;
+use std::fmt::{ self, Display, Formatter };
+
+use crate::number::SmallUInt;
+use crate::symmetric::ascon_permute;
+
+
+/// You have freedom of changing IV and ROUND for Ascon-Hash256.
+///
+/// # Generic Parameters
+/// - IV : the initial value that is the first word of the initial state.
+///   The default value of IV for Ascon-Hash256 is `0x0000080100cc0002`.
+/// - ROUND : the number of rounds of the Ascon permutation. The default value
+///   of it is `12`. It cannot be `0` or greater than `16`.
+#[allow(non_camel_case_types)]
+pub type Ascon_Hash_Expanded<const IV: u64 = 0x0000080100cc0002, const ROUND: usize = 12>
+                = Ascon_Hash_Generic<IV, ROUND>;
+
+/// The official Ascon-Hash256 defined in NIST SP 800-232
+#[allow(non_camel_case_types)]
+pub type Ascon_Hash256 = Ascon_Hash_Generic;
+
+/// The official Ascon-XOF128 defined in NIST SP 800-232
+#[allow(non_camel_case_types)]
+pub type Ascon_XOF128 = Ascon_Hash_Generic<0x0000080000cc0003>;
+
+
+/// Ascon-Hash256 and Ascon-XOF128 hash algorithms
+///
+/// # Introduction
+/// Ascon-Hash256 and Ascon-XOF128 are sponge-based hash algorithms built on
+/// the Ascon permutation, which is the same permutation that Ascon-AEAD128
+/// uses. The state consists of five 64-bit words, and the message is
+/// absorbed into the first word 8 bytes at a time. Ascon-Hash256 squeezes
+/// out a 256-bit hash value while Ascon-XOF128 is an extendable-output
+/// function (XOF) which can squeeze out the output of any length.
+/// The two algorithms differ only in the initial value.
+///
+/// # Vulnerability
+/// Ascon-XOF128 provides only 128-bit security regardless of the output
+/// length. So, you cannot get more security by squeezing out longer output.
+///
+/// # Byte order
+/// The bytes are loaded into and stored from the words of the state in
+/// little endianness as NIST SP 800-232 specifies.
+///
+/// # Reference
+/// Read [NIST SP 800-232](https://doi.org/10.6028/NIST.SP.800-232)
+/// about Ascon in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::hash::{ Ascon_Hash256, Ascon_XOF128 };
+///
+/// let mut hash = Ascon_Hash256::new();
+/// hash.digest_str("");
+/// println!("Msg =\t\"\"\nHash =\t{}", hash);
+/// assert_eq!(hash.to_string(), "0B3BE5850F2F6B98CAF29F8FDEA89B64A1FA70AA249B8F839BD53BAA304D92B2");
+///
+/// let mut xof = Ascon_XOF128::new();
+/// xof.digest_str("");
+/// let output = xof.squeeze_into_vec(32);
+/// println!("Msg =\t\"\"\nOutput =\t{:02X?}", output);
+/// assert_eq!(xof.to_string(), "473D5E6164F58B39DFD84AACDB8AE42EC2D91FED33388EE0D960D9B3993295C6");
+/// assert_eq!(output, xof.get_hash_value_in_vec());
+/// ```
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct Ascon_Hash_Generic<const IV: u64 = 0x0000080100cc0002, const ROUND: usize = 12>
+{
+    state: [u64; 5],
+}
+
+impl<const IV: u64, const ROUND: usize> Ascon_Hash_Generic<IV, ROUND>
+{
+    const RATE: usize = 8;
+    const OUTPUT: usize = 32;
+
+    // pub fn new() -> Self
+    /// Constructs a new object of `Ascon_Hash256` or `Ascon_XOF128`,
+    /// or a new Ascon-based hash object.
+    ///
+    /// # Output
+    /// A new object of `Ascon_Hash256` or `Ascon_XOF128`,
+    /// or a new Ascon-based hash object.
+    ///
+    /// # Panics
+    /// It panics if ROUND is `0` or greater than `16`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash_Expanded;
+    /// type MyAscon = Ascon_Hash_Expanded<0x1234_5678_9abc_def0, 16>;
+    /// let mut my_hash = MyAscon::new();
+    /// my_hash.digest_str("");
+    /// println!("Hash =\t{}", my_hash);
+    /// assert_eq!(my_hash.to_string(), "BDEFA1875D1217175AC68F75F8FD494C49A53BBF7E34075798E017E5EADFD26B");
+    /// ```
+    pub fn new() -> Self
+    {
+        if (ROUND == 0) || (ROUND > 16)
+            { panic!("ROUND should be in the range from 1 to 16."); }
+        Self { state: Self::initial_state() }
+    }
+
+    // pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    /// Computes hash value.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*const u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. So, this function is usually not called
+    /// directly in Rust. This function is provided to be called from other
+    /// programming languages such as C/C++.
+    ///
+    /// # Arguments
+    /// - `message` is pointer to const u8.
+    /// - `length_in_bytes` is the size of message in the unit of bytes, and
+    ///   data type is `u64`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let txt = "This is an example of the method digest().";
+    /// let mut hash = Ascon_Hash256::new();
+    /// hash.digest(txt.as_ptr(), txt.len() as u64);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "854E406C262747FF82C1BF799EE677F15D738A3B18625FF1FA4475B214EAC0D9");
+    /// ```
+    #[inline]
+    pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    {
+        self.absorb_raw(message, length_in_bytes as usize);
+    }
+
+    // pub fn digest_str(&mut self, message: &str)
+    /// Computes hash value of the content of string slice.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&str`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let txt = "This is an example of the method digest_str().";
+    /// let mut hash = Ascon_Hash256::new();
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "9F086A316838B8618D671DABB2BDDCFE4EAC67DD2BE8160F6F9B3FE59F44DBF1");
+    /// ```
+    #[inline]
+    pub fn digest_str(&mut self, message: &str)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_string(&mut self, message: &String)
+    /// Computes hash value of the content of String object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&String`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let txt = "This is an example of the method digest_str().".to_string();
+    /// let mut hash = Ascon_Hash256::new();
+    /// hash.digest_string(&txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "9F086A316838B8618D671DABB2BDDCFE4EAC67DD2BE8160F6F9B3FE59F44DBF1");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_string(&mut self, message: &String)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_array<T, const M: usize>(&mut self, message: &[T; M])
+    /// Computes hash value of the content of Array object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&[T; M]`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let data = [ 0x6c652074_u32.to_le(), 0x67726675_u32.to_le(), 0x6f736e65_u32.to_le(), 0x7269636d_u32.to_le() ];
+    /// let mut hash = Ascon_Hash256::new();
+    /// hash.digest_array(&data);
+    /// println!("Msg =\t{:?}\nHash =\t{}", data, hash);
+    /// assert_eq!(hash.to_string(), "64146B1A3C8BC955EBAA898D31CDFA62062DABA556C265A64D6FF29EB41C0D47");
+    /// ```
+    #[inline]
+    pub fn digest_array<T, const M: usize>(&mut self, message: &[T; M])
+    where T: SmallUInt + Copy + Clone
+    {
+        self.absorb_raw(message.as_ptr() as *const u8, M * T::size_in_bytes());
+    }
+
+    // pub fn digest_vec<T>(&mut self, message: &Vec<T>)
+    /// Computes hash value of the content of Vec object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&Vec<T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let data = vec![ 0x6c652074_u32.to_le(), 0x67726675_u32.to_le(), 0x6f736e65_u32.to_le(), 0x7269636d_u32.to_le() ];
+    /// let mut hash = Ascon_Hash256::new();
+    /// hash.digest_vec(&data);
+    /// println!("Msg =\t{:?}\nHash =\t{}", data, hash);
+    /// assert_eq!(hash.to_string(), "64146B1A3C8BC955EBAA898D31CDFA62062DABA556C265A64D6FF29EB41C0D47");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_vec<T>(&mut self, message: &Vec<T>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.absorb_raw(message.as_ptr() as *const u8, message.len() * T::size_in_bytes());
+    }
+
+    // pub fn get_hash_value(&self, hash_value: *mut u8, length: usize)
+    /// Gives a hash value to the place where `hash_value` points to.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*mut u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. It gives at most 32 bytes. If you want
+    /// to get the output of Ascon-XOF128 longer than 32 bytes, use the method
+    /// [squeeze()](struct@Ascon_Hash_Generic#method.squeeze) instead.
+    ///
+    /// # Arguments
+    /// - `hash_value` is the pointer to the place to hold the result hash value.
+    /// - `length` is the size of the place that `hash_value` points to.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "This is an example of the method get_hash_value().";
+    /// let hash_value = [0_u8; 32];
+    /// hash.digest_str(txt);
+    /// hash.get_hash_value(hash_value.as_ptr() as *mut u8, hash_value.len());
+    /// println!("Msg =\t\"{}\"\nHash =\t{:02X?}", txt, hash_value);
+    /// assert_eq!(format!("{:02X?}", hash_value), "[ED, 6D, F5, 1B, 2A, EF, FE, 34, 02, D6, 1B, 3D, 80, 20, 75, 13, ED, 32, CD, 11, 91, 2E, 91, D0, EF, 99, B7, 57, D5, 53, B7, 9D]");
+    /// ```
+    #[inline]
+    pub fn get_hash_value(&self, hash_value: *mut u8, length: usize)
+    {
+        self.squeeze(hash_value, if length < Self::OUTPUT {length} else {Self::OUTPUT});
+    }
+
+    // pub fn get_hash_value_in_string(&self) -> String
+    /// Returns a hash value in the form of String object.
+    ///
+    /// # Output
+    /// A hash value of 32 bytes in the form of String object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "This is an example of the method get_hash_value_in_string().";
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash.get_hash_value_in_string());
+    /// assert_eq!(hash.get_hash_value_in_string(), "5B54BCFB1082D0BFA30DB3FECF013F6AF47D87E5A7F1605210B91027A10614FF");
+    /// ```
+    pub fn get_hash_value_in_string(&self) -> String
+    {
+        let mut txt = String::new();
+        for byte in self.get_hash_value_in_array()
+        {
+            txt.push(Self::to_char(byte >> 4));
+            txt.push(Self::to_char(byte & 0b1111));
+        }
+        txt
+    }
+
+    // pub fn get_hash_value_in_array(&self) -> [u8; 32]
+    /// Returns a hash value in the form of array object.
+    ///
+    /// # Output
+    /// A hash value of 32 bytes in the form of array object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "This is an example of the method get_hash_value_in_array().";
+    /// hash.digest_str(txt);
+    /// let hash_value = hash.get_hash_value_in_array();
+    /// println!("Msg =\t\"{}\"\nHash =\t{:02X?}", txt, hash_value);
+    /// assert_eq!(format!("{:02X?}", hash_value), "[91, EC, F6, B3, F8, 55, 77, A3, CF, E3, ED, 3F, 4F, 3F, 64, EF, 9D, 4D, B2, 0F, FF, 9F, 96, 5A, E2, 21, 9D, 9E, 48, FE, 8D, F3]");
+    /// ```
+    pub fn get_hash_value_in_array(&self) -> [u8; 32]
+    {
+        let mut res = [0_u8; 32];
+        self.squeeze(res.as_mut_ptr(), res.len());
+        res
+    }
+
+    // pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    /// Returns a hash value in the form of Vec object.
+    ///
+    /// # Output
+    /// A hash value of 32 bytes in the form of Vec object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "This is an example of the method get_hash_value_in_array().";
+    /// hash.digest_str(txt);
+    /// let hash_value = hash.get_hash_value_in_vec();
+    /// println!("Msg =\t\"{}\"\nHash =\t{:02X?}", txt, hash_value);
+    /// assert_eq!(format!("{:02X?}", hash_value), "[91, EC, F6, B3, F8, 55, 77, A3, CF, E3, ED, 3F, 4F, 3F, 64, EF, 9D, 4D, B2, 0F, FF, 9F, 96, 5A, E2, 21, 9D, 9E, 48, FE, 8D, F3]");
+    /// ```
+    #[inline]
+    pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    {
+        self.squeeze_into_vec(Self::OUTPUT)
+    }
+
+    // pub fn put_hash_value_in_array<T, const M: usize>(&self, out: &mut [T; M])
+    /// Puts a hash value in the form of array object.
+    ///
+    /// # Argument
+    /// `out` is the array [T; M] which is the place to put the hash value.
+    ///
+    /// # Features
+    /// If `M * mem::size_of::<T>()` > `32`, it pass the output as the amount
+    /// of `32` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "This is an example of the method put_hash_value_in_array().";
+    /// let mut hash_code = [0_u64; 2];
+    /// hash.digest_str(txt);
+    /// hash.put_hash_value_in_array(&mut hash_code);
+    /// println!("Msg =\t\"{}\"\nHash =\t{:016X?}", txt, hash_code);
+    /// assert_eq!(format!("{:016X?}", hash_code), "[6DD586873DADAA28, C11AA990BA364304]");
+    /// ```
+    pub fn put_hash_value_in_array<T, const M: usize>(&self, out: &mut [T; M])
+    where T: SmallUInt + Copy + Clone
+    {
+        let out_size = T::size_in_bytes() * M;
+        self.get_hash_value(out as *mut T as *mut u8, out_size);
+    }
+
+    // pub fn squeeze(&self, output: *mut u8, length: usize)
+    /// Squeezes out the output of `length` bytes to the place where
+    /// `output` points to.
+    ///
+    /// # Features
+    /// This method is mainly for Ascon-XOF128 whose output can be of any
+    /// length. The first 32 bytes of the output are the same as what the
+    /// method `get_hash_value_in_array()` gives.
+    ///
+    /// # Arguments
+    /// - `output` is the pointer to the place to hold the output.
+    /// - `length` is the length of the output in bytes. The place that
+    ///   `output` points to should be at least `length` bytes long.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_XOF128;
+    /// let mut xof = Ascon_XOF128::new();
+    /// let txt = "This is an example of the method squeeze().";
+    /// let mut output = [0_u8; 20];
+    /// xof.digest_str(txt);
+    /// xof.squeeze(output.as_mut_ptr(), output.len());
+    /// println!("Msg =\t\"{}\"\nOutput =\t{:02X?}", txt, output);
+    /// assert_eq!(format!("{:02X?}", output), "[37, 78, 99, 16, 23, 6B, 08, 72, 68, 46, 0E, 9B, D9, D9, BD, 78, F0, 02, 8A, 88]");
+    /// ```
+    #[inline]
+    pub fn squeeze(&self, output: *mut u8, length: usize)
+    {
+        self.squeeze_raw(output, length);
+    }
+
+    // pub fn squeeze_into_vec(&self, length: usize) -> Vec<u8>
+    /// Squeezes out the output of `length` bytes in the form of Vec object.
+    ///
+    /// # Features
+    /// This method is mainly for Ascon-XOF128 whose output can be of any
+    /// length. The first 32 bytes of the output are the same as what the
+    /// method `get_hash_value_in_array()` gives.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_XOF128;
+    /// let mut xof = Ascon_XOF128::new();
+    /// let txt = "This is an example of the method squeeze_into_vec().";
+    /// xof.digest_str(txt);
+    /// let output = xof.squeeze_into_vec(48);
+    /// println!("Msg =\t\"{}\"\nOutput =\t{:02X?}", txt, output);
+    /// assert_eq!(output.len(), 48);
+    /// assert_eq!(output[..32], xof.get_hash_value_in_array());
+    /// ```
+    pub fn squeeze_into_vec(&self, length: usize) -> Vec<u8>
+    {
+        let mut state = self.state;
+        let mut res = Vec::with_capacity(length);
+        while res.len() < length
+        {
+            let block = state[0].to_le_bytes();
+            let n = if length - res.len() < Self::RATE {length - res.len()} else {Self::RATE};
+            res.extend_from_slice(&block[..n]);
+            if res.len() < length
+                { ascon_permute(&mut state, ROUND); }
+        }
+        res
+    }
+
+    // fn squeeze_raw(&self, output: *mut u8, length: usize)
+    /// Squeezes out the output of `length` bytes to where `output` points to.
+    #[inline]
+    fn squeeze_raw(&self, output: *mut u8, length: usize)
+    {
+        let res = self.squeeze_into_vec(length);
+        unsafe { copy_nonoverlapping(res.as_ptr(), output, length); }
+    }
+
+    // fn initial_state() -> [u64; 5]
+    /// Gives the initial state.
+    fn initial_state() -> [u64; 5]
+    {
+        let mut state = [IV, 0, 0, 0, 0];
+        ascon_permute(&mut state, ROUND);
+        state
+    }
+
+    // fn absorb_raw(&mut self, message: *const u8, length_in_bytes: usize)
+    /// Absorbs the message of `length_in_bytes` bytes that `message` points to.
+    #[inline]
+    fn absorb_raw(&mut self, message: *const u8, length_in_bytes: usize)
+    {
+        if length_in_bytes == 0
+            { self.absorb(&[]); }
+        else
+            { self.absorb(unsafe { from_raw_parts(message, length_in_bytes) }); }
+    }
+
+    // fn absorb(&mut self, message: &[u8])
+    /// Initializes the state and absorbs `message` with padding.
+    fn absorb(&mut self, message: &[u8])
+    {
+        let mut state = Self::initial_state();
+        let mut blocks = message.chunks_exact(Self::RATE);
+        let mut word = [0_u8; 8];
+        for block in blocks.by_ref()
+        {
+            word.copy_from_slice(block);
+            state[0] ^= u64::from_le_bytes(word);
+            ascon_permute(&mut state, ROUND);
+        }
+        let last = blocks.remainder();
+        word = [0_u8; 8];
+        word[..last.len()].copy_from_slice(last);
+        word[last.len()] = 0x01;
+        state[0] ^= u64::from_le_bytes(word);
+        ascon_permute(&mut state, ROUND);
+        self.state = state;
+    }
+
+    #[inline] fn to_char(nibble: u8) -> char    { if nibble < 10  { (b'0' + nibble) as char } else { (b'A' - 10 + nibble) as char } }
+}
+
+impl<const IV: u64, const ROUND: usize> Default for Ascon_Hash_Generic<IV, ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const IV: u64, const ROUND: usize> Display for Ascon_Hash_Generic<IV, ROUND>
+{
+    /// Formats the value using the given formatter.
+    /// You will hardly use this method directly.
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()`, and you can also print the
+    /// Ascon hash object in the macro `println!()` directly for example.
+    /// `f` is a buffer, this method must write the formatted string into it.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::Ascon_Hash256;
+    /// let mut hash = Ascon_Hash256::new();
+    /// let txt = "Display::fmt() automagically implement to_string().";
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "CFB135FA9EBD44A94063F0EB6CB6A13CAD0051574A52516F09618605DCBFBD6C");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.get_hash_value_in_string())
+    }
+}
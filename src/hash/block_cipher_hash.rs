@@ -0,0 +1,1141 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the hash functions based on block ciphers such
+//! as Davies-Meyer, Matyas-Meyer-Oseas, Miyaguchi-Preneel, MDC-2 and MDC-4
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::ptr::copy_nonoverlapping;
+use std::slice::from_raw_parts;
+use std::fmt::{ self, Display, Formatter };
+
+use crate::number::SmallUInt;
+use crate::symmetric::{ KeyedBlockCipher, Padding, Zero_Padding,
+                        DES, AES_128, AES_192, AES_256 };
+
+
+/// The hash function based on DES
+#[allow(non_camel_case_types)]
+pub type DES_Hash = BlockCipherHash_Generic<DES>;
+
+/// The hash function based on AES-128
+#[allow(non_camel_case_types)]
+pub type AES_128_Hash = BlockCipherHash_Generic<AES_128>;
+
+/// The hash function based on AES-192
+#[allow(non_camel_case_types)]
+pub type AES_192_Hash = BlockCipherHash_Generic<AES_192>;
+
+/// The hash function based on AES-256
+#[allow(non_camel_case_types)]
+pub type AES_256_Hash = BlockCipherHash_Generic<AES_256>;
+
+/// MDC-2 based on the official DES with zero padding
+pub type MDC2 = MDC2_Generic<DES>;
+
+/// MDC-4 based on the official DES with zero padding
+pub type MDC4 = MDC4_Generic<DES>;
+
+
+/// The compression functions of `BlockCipherHash_Generic`, which make
+/// the new chaining value `H_i` from the previous chaining value `H_i-1`
+/// and the message block `m_i` with the block cipher `E`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HashConstruction
+{
+    /// Davies-Meyer: `H_i = E(m_i, H_i-1) ^ H_i-1` where the message block
+    /// is the key. The message block is as long as the key of `E`.
+    DaviesMeyer,
+
+    /// Matyas-Meyer-Oseas: `H_i = E(H_i-1, m_i) ^ m_i` where the chaining
+    /// value is the key. The key of `E` should be as long as its block.
+    MatyasMeyerOseas,
+
+    /// Miyaguchi-Preneel: `H_i = E(H_i-1, m_i) ^ m_i ^ H_i-1` where the
+    /// chaining value is the key. The key of `E` should be as long as its
+    /// block.
+    MiyaguchiPreneel,
+}
+
+
+/// The single-block-length hash functions based on any block cipher which
+/// has the trait `KeyedBlockCipher`
+///
+/// # Introduction
+/// It turns the block cipher `C` into the Merkle-Damgård hash function with
+/// one of the three compression functions of
+/// [`HashConstruction`](enum@HashConstruction): Davies-Meyer,
+/// Matyas-Meyer-Oseas and Miyaguchi-Preneel. The hash value is as long as
+/// the block of `C`, and the initial chaining value is all zeros by default.
+///
+/// The message is padded with MD-strengthening: one byte `0x80`, as few
+/// zero bytes as possible and the length of the message in bits as a
+/// 64-bit big-endian integer, so that the padded message is a multiple of
+/// the message block. The message block is as long as the key of `C` in
+/// Davies-Meyer, and as long as the block of `C` in the other two.
+///
+/// `C` can be `DES_Generic` of any parameters including the expanded ones,
+/// `AES_Generic`, or any other block cipher that has the trait
+/// `KeyedBlockCipher`.
+///
+/// # Vulnerability
+/// The hash value is only as long as the block of `C`. So, the hash
+/// function based on DES has only 64-bit hash value, and you can find its
+/// collision by about 2^32 trials. Moreover, DES ignores the least
+/// significant bit of every byte of the key, and has the weak keys and
+/// the complementation property, which make the collisions and the fixed
+/// points much easier to find especially in Davies-Meyer. Use it only for
+/// legacy protocols and for teaching. If you need the longer hash value
+/// with DES, use [`MDC2_Generic`](struct@MDC2_Generic) or
+/// [`MDC4_Generic`](struct@MDC4_Generic) instead.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `KeyedBlockCipher`.
+///
+/// # Reference
+/// Read [the article](https://en.wikipedia.org/wiki/One-way_compression_function)
+/// and the chapter 9 of Handbook of Applied Cryptography about the hash
+/// functions based on block ciphers in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::hash::{ DES_Hash, AES_128_Hash, HashConstruction };
+/// use cryptocol::symmetric::{ DES, AES_128 };
+///
+/// let txt = "The quick brown fox jumps over the lazy dog";
+/// let mut dm = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+/// dm.digest_str(txt);
+/// println!("Msg =\t\"{}\"\nHash =\t{}", txt, dm);
+/// assert_eq!(dm.to_string(), "BC5279FEE8CBB752");
+///
+/// let mut mp = AES_128_Hash::new(AES_128::new(), HashConstruction::MiyaguchiPreneel);
+/// mp.digest_str(txt);
+/// println!("Msg =\t\"{}\"\nHash =\t{}", txt, mp);
+/// assert_eq!(mp.to_string(), "9F1A337A7AC48D9498ED384991204689");
+/// ```
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct BlockCipherHash_Generic<C: KeyedBlockCipher>
+{
+    cipher: C,
+    construction: HashConstruction,
+    iv: Vec<u8>,
+    hash_value: Vec<u8>,
+}
+
+impl<C: KeyedBlockCipher> BlockCipherHash_Generic<C>
+{
+    // pub fn new(cipher: C, construction: HashConstruction) -> Self
+    /// Constructs a new object BlockCipherHash_Generic whose initial
+    /// chaining value is all zeros.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher. Its key does not matter.
+    /// - `construction` is the compression function.
+    ///
+    /// # Panics
+    /// It panics if `construction` is `MatyasMeyerOseas` or
+    /// `MiyaguchiPreneel` and the key of `C` is not as long as its block.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ BlockCipherHash_Generic, HashConstruction };
+    /// use cryptocol::symmetric::DES_Generic;
+    ///
+    /// // DES with 8 rounds
+    /// let mut hash = BlockCipherHash_Generic::new(DES_Generic::<8>::new(), HashConstruction::MatyasMeyerOseas);
+    /// hash.digest_str("");
+    /// println!("Msg =\t\"\"\nHash =\t{}", hash);
+    /// assert_eq!(hash.to_string(), "832128AA944894CD");
+    /// ```
+    #[inline]
+    pub fn new(cipher: C, construction: HashConstruction) -> Self
+    {
+        Self::new_with_iv(cipher, construction, &vec![0_u8; C::BLOCK_SIZE])
+    }
+
+    // pub fn new_with_iv(cipher: C, construction: HashConstruction, iv: &[u8]) -> Self
+    /// Constructs a new object BlockCipherHash_Generic with the initial
+    /// chaining value `iv`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher. Its key does not matter.
+    /// - `construction` is the compression function.
+    /// - `iv` is the initial chaining value which is as long as the block
+    ///   of `C`.
+    ///
+    /// # Panics
+    /// - It panics if the length of `iv` is not `BLOCK_SIZE` of `C`.
+    /// - It panics if `construction` is `MatyasMeyerOseas` or
+    ///   `MiyaguchiPreneel` and the key of `C` is not as long as its block.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    ///
+    /// let iv = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    /// let mut hash = DES_Hash::new_with_iv(DES::new(), HashConstruction::DaviesMeyer, &iv);
+    /// hash.digest_str("");
+    /// println!("Msg =\t\"\"\nHash =\t{}", hash);
+    /// assert_eq!(hash.to_string(), "7797D1966A4113D5");
+    /// ```
+    pub fn new_with_iv(cipher: C, construction: HashConstruction, iv: &[u8]) -> Self
+    {
+        assert_eq!(iv.len(), C::BLOCK_SIZE, "The initial chaining value should be as long as the block.");
+        if (construction != HashConstruction::DaviesMeyer) && (C::KEY_SIZE != C::BLOCK_SIZE)
+            { panic!("Matyas-Meyer-Oseas and Miyaguchi-Preneel need the key as long as the block."); }
+        Self { cipher, construction, iv: iv.to_vec(), hash_value: iv.to_vec() }
+    }
+
+    // pub fn get_construction(&self) -> HashConstruction
+    /// Returns the compression function.
+    #[inline]
+    pub fn get_construction(&self) -> HashConstruction
+    {
+        self.construction
+    }
+
+    // pub fn get_message_block_size(&self) -> usize
+    /// Returns the size of the message block in bytes, which is the key
+    /// size of `C` in Davies-Meyer, and the block size of `C` otherwise.
+    #[inline]
+    pub fn get_message_block_size(&self) -> usize
+    {
+        if self.construction == HashConstruction::DaviesMeyer { C::KEY_SIZE } else { C::BLOCK_SIZE }
+    }
+
+    // pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    /// Computes hash value.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*const u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. So, this function is usually not called
+    /// directly in Rust. This function is provided to be called from other
+    /// programming languages such as C/C++.
+    ///
+    /// # Arguments
+    /// - `message` is pointer to const u8.
+    /// - `length_in_bytes` is the size of message in the unit of bytes, and
+    ///   data type is `u64`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "This is an example of the method digest().";
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    /// hash.digest(txt.as_ptr(), txt.len() as u64);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "D20B91B017D2D6A4");
+    /// ```
+    #[inline]
+    pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    {
+        self.digest_raw(message, length_in_bytes as usize);
+    }
+
+    // pub fn digest_str(&mut self, message: &str)
+    /// Computes hash value of the content of string slice.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&str`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "This is an example of the method digest_str().";
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "229F8EF5485C1C90");
+    /// ```
+    #[inline]
+    pub fn digest_str(&mut self, message: &str)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_string(&mut self, message: &String)
+    /// Computes hash value of the content of String object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&String`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "This is an example of the method digest_str().".to_string();
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    /// hash.digest_string(&txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "229F8EF5485C1C90");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_string(&mut self, message: &String)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_array<T, const M: usize>(&mut self, message: &[T; M])
+    /// Computes hash value of the content of Array object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&[T; M]`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ AES_128_Hash, HashConstruction };
+    /// use cryptocol::symmetric::AES_128;
+    /// let data = [ 0x6c652074_u32.to_le(), 0x67726675_u32.to_le(), 0x6f736e65_u32.to_le(), 0x7269636d_u32.to_le() ];
+    /// let mut hash = AES_128_Hash::new(AES_128::new(), HashConstruction::MatyasMeyerOseas);
+    /// hash.digest_array(&data);
+    /// println!("Msg =\t{:?}\nHash =\t{}", data, hash);
+    /// assert_eq!(hash.to_string(), "28EB2C9C9C8AC3F40350F094FE68D952");
+    /// ```
+    #[inline]
+    pub fn digest_array<T, const M: usize>(&mut self, message: &[T; M])
+    where T: SmallUInt + Copy + Clone
+    {
+        self.digest_raw(message.as_ptr() as *const u8, M * T::size_in_bytes());
+    }
+
+    // pub fn digest_vec<T>(&mut self, message: &Vec<T>)
+    /// Computes hash value of the content of Vec object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&Vec<T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ AES_128_Hash, HashConstruction };
+    /// use cryptocol::symmetric::AES_128;
+    /// let data = vec![ 0x6c652074_u32.to_le(), 0x67726675_u32.to_le(), 0x6f736e65_u32.to_le(), 0x7269636d_u32.to_le() ];
+    /// let mut hash = AES_128_Hash::new(AES_128::new(), HashConstruction::MatyasMeyerOseas);
+    /// hash.digest_vec(&data);
+    /// println!("Msg =\t{:?}\nHash =\t{}", data, hash);
+    /// assert_eq!(hash.to_string(), "28EB2C9C9C8AC3F40350F094FE68D952");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_vec<T>(&mut self, message: &Vec<T>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.digest_raw(message.as_ptr() as *const u8, message.len() * T::size_in_bytes());
+    }
+
+    // pub fn get_hash_value(&self, hash_value: *mut u8, length: usize)
+    /// Gives a hash value to the place where `hash_value` points to.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*mut u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. It gives at most `BLOCK_SIZE` bytes
+    /// of `C`.
+    ///
+    /// # Arguments
+    /// - `hash_value` is the pointer to the place to hold the result hash value.
+    /// - `length` is the size of the place that `hash_value` points to.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::MiyaguchiPreneel);
+    /// let txt = "This is an example of the method get_hash_value().";
+    /// let hash_value = [0_u8; 8];
+    /// hash.digest_str(txt);
+    /// hash.get_hash_value(hash_value.as_ptr() as *mut u8, hash_value.len());
+    /// println!("Msg =\t\"{}\"\nHash =\t{:02X?}", txt, hash_value);
+    /// assert_eq!(format!("{:02X?}", hash_value), "[6E, 38, 54, 47, 7F, A8, BE, 0D]");
+    /// ```
+    #[inline]
+    pub fn get_hash_value(&self, hash_value: *mut u8, length: usize)
+    {
+        let length = if length < self.hash_value.len() {length} else {self.hash_value.len()};
+        copy_to_raw(&self.hash_value[..length], hash_value);
+    }
+
+    // pub fn get_hash_value_in_string(&self) -> String
+    /// Returns a hash value in the form of String object.
+    ///
+    /// # Output
+    /// A hash value of `BLOCK_SIZE` bytes of `C` in the form of String
+    /// object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ AES_256_Hash, HashConstruction };
+    /// use cryptocol::symmetric::AES_256;
+    /// let mut hash = AES_256_Hash::new(AES_256::new(), HashConstruction::DaviesMeyer);
+    /// let txt = "This is an example of the method get_hash_value_in_string().";
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash.get_hash_value_in_string());
+    /// assert_eq!(hash.get_hash_value_in_string(), "8E5E9D10AAFDD8330BE89D47D67700DE");
+    /// ```
+    #[inline]
+    pub fn get_hash_value_in_string(&self) -> String
+    {
+        to_hex_string(&self.hash_value)
+    }
+
+    // pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    /// Returns a hash value in the form of Vec object.
+    ///
+    /// # Output
+    /// A hash value of `BLOCK_SIZE` bytes of `C` in the form of Vec object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ AES_192_Hash, HashConstruction };
+    /// use cryptocol::symmetric::AES_192;
+    /// let mut hash = AES_192_Hash::new(AES_192::new(), HashConstruction::DaviesMeyer);
+    /// let txt = "This is an example of the method get_hash_value_in_vec().";
+    /// hash.digest_str(txt);
+    /// let hash_value = hash.get_hash_value_in_vec();
+    /// println!("Msg =\t\"{}\"\nHash =\t{:02X?}", txt, hash_value);
+    /// assert_eq!(format!("{:02X?}", hash_value), "[96, CF, B4, 51, CD, 0B, C1, CB, 3C, 56, 38, 06, E7, 89, 1B, AC]");
+    /// ```
+    #[inline]
+    pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    {
+        self.hash_value.clone()
+    }
+
+    // pub fn put_hash_value_in_array<T, const M: usize>(&self, out: &mut [T; M])
+    /// Puts a hash value in the form of array object.
+    ///
+    /// # Argument
+    /// `out` is the array [T; M] which is the place to put the hash value.
+    ///
+    /// # Features
+    /// If `M * mem::size_of::<T>()` > `BLOCK_SIZE` of `C`, it pass the
+    /// output as the amount of `BLOCK_SIZE` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::MatyasMeyerOseas);
+    /// let txt = "This is an example of the method put_hash_value_in_array().";
+    /// let mut hash_code = [0_u32; 2];
+    /// hash.digest_str(txt);
+    /// hash.put_hash_value_in_array(&mut hash_code);
+    /// println!("Msg =\t\"{}\"\nHash =\t{:08X?}", txt, hash_code);
+    /// assert_eq!(format!("{:08X?}", hash_code), "[2F2F7504, B52E18EC]");
+    /// ```
+    pub fn put_hash_value_in_array<T, const M: usize>(&self, out: &mut [T; M])
+    where T: SmallUInt + Copy + Clone
+    {
+        let out_size = T::size_in_bytes() * M;
+        self.get_hash_value(out as *mut T as *mut u8, out_size);
+    }
+
+    // fn digest_raw(&mut self, message: *const u8, length_in_bytes: usize)
+    // Computes hash value of the message of `length_in_bytes` bytes that
+    // `message` points to.
+    #[inline]
+    fn digest_raw(&mut self, message: *const u8, length_in_bytes: usize)
+    {
+        self.absorb(message_from_raw(message, length_in_bytes));
+    }
+
+    // fn absorb(&mut self, message: &[u8])
+    // Starts from the initial chaining value and compresses `message` with
+    // MD-strengthening.
+    fn absorb(&mut self, message: &[u8])
+    {
+        let size = self.get_message_block_size();
+        self.hash_value.copy_from_slice(&self.iv);
+        let mut blocks = message.chunks_exact(size);
+        for block in blocks.by_ref()
+            { self.compress(block); }
+        let mut last = blocks.remainder().to_vec();
+        last.push(0x80);
+        while (last.len() + 8) % size != 0
+            { last.push(0); }
+        last.extend_from_slice(&((message.len() as u64).wrapping_mul(8)).to_be_bytes());
+        for block in last.chunks_exact(size)
+            { self.compress(block); }
+    }
+
+    // fn compress(&mut self, block: &[u8])
+    // Updates the chaining value with the message block `block`.
+    fn compress(&mut self, block: &[u8])
+    {
+        match self.construction
+        {
+            HashConstruction::DaviesMeyer => {
+                    let mut e = self.hash_value.clone();
+                    self.cipher.set_key_slice(block);
+                    self.cipher.encrypt_one_block(&mut e);
+                    xor_into(&mut self.hash_value, &e);
+                },
+            HashConstruction::MatyasMeyerOseas => {
+                    let mut e = block.to_vec();
+                    self.cipher.set_key_slice(&self.hash_value);
+                    self.cipher.encrypt_one_block(&mut e);
+                    xor_into(&mut e, block);
+                    self.hash_value = e;
+                },
+            HashConstruction::MiyaguchiPreneel => {
+                    let mut e = block.to_vec();
+                    self.cipher.set_key_slice(&self.hash_value);
+                    self.cipher.encrypt_one_block(&mut e);
+                    xor_into(&mut e, block);
+                    xor_into(&mut self.hash_value, &e);
+                },
+        }
+    }
+}
+
+impl<C: KeyedBlockCipher> Display for BlockCipherHash_Generic<C>
+{
+    /// Formats the value using the given formatter.
+    /// You will hardly use this method directly.
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()`, and you can also print the
+    /// hash object in the macro `println!()` directly for example.
+    /// `f` is a buffer, this method must write the formatted string into it.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::{ DES_Hash, HashConstruction };
+    /// use cryptocol::symmetric::DES;
+    /// let mut hash = DES_Hash::new(DES::new(), HashConstruction::DaviesMeyer);
+    /// let txt = "Display::fmt() automagically implement to_string().";
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "718E945B12900C2A");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.get_hash_value_in_string())
+    }
+}
+
+
+/// MDC-2, the double-block-length hash function based on a 64-bit block
+/// cipher such as DES
+///
+/// # Introduction
+/// MDC-2 (Modification Detection Code 2) of ISO/IEC 10118-2 is made of two
+/// Matyas-Meyer-Oseas compression functions which run side by side and
+/// swap the right halves of their outputs after every message block of 8
+/// bytes. The two chaining values start from `5252525252525252` and
+/// `2525252525252525`, and the second and the third bits of their first
+/// bytes are forced to `10` and `01` respectively before they are used as
+/// the keys, so that the two halves never use the same key. The hash value
+/// is the concatenation of the two chaining values, which is 128 bits long.
+///
+/// The message is padded by the padding scheme `P`. It is `Zero_Padding`
+/// by default, which is the padding of OpenSSL and many legacy banking
+/// protocols, and which does not pad the empty message or the message whose
+/// length is a multiple of 8. `ISO7816_Padding` is the padding method 2 of
+/// ISO/IEC 10118-1.
+///
+/// # Vulnerability
+/// Finding a collision of MDC-2 takes about 2^54 trials, not 2^64, and it
+/// is slow because it changes the key of DES for every encryption.
+/// Zero padding is ambiguous, that is, the messages which differ only in
+/// their trailing zero bytes have the same hash value. Use it only for
+/// the legacy protocols.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `KeyedBlockCipher`.
+///   Its block and its key should be 8 bytes long, for example
+///   `DES_Generic` of any parameters including the expanded ones.
+/// - P: the padding scheme which has the trait `Padding`. It is
+///   `Zero_Padding` by default.
+///
+/// # Reference
+/// Read [the article](https://en.wikipedia.org/wiki/MDC-2) and the
+/// algorithm 9.46 of Handbook of Applied Cryptography about MDC-2 in
+/// detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::hash::MDC2;
+/// use cryptocol::symmetric::DES;
+///
+/// let mut hash = MDC2::new(DES::new());
+/// hash.digest_str("");
+/// println!("Msg =\t\"\"\nHash =\t{}", hash);
+/// assert_eq!(hash.to_string(), "52525252525252522525252525252525");
+///
+/// let txt = "The quick brown fox jumps over the lazy dog";
+/// hash.digest_str(txt);
+/// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+/// assert_eq!(hash.to_string(), "000ED54E093D61679AEFBEAE05BFE33A");
+/// ```
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct MDC2_Generic<C: KeyedBlockCipher = DES, P: Padding = Zero_Padding>
+{
+    cipher: C,
+    padding: P,
+    hash_value: [u8; 16],
+}
+
+impl<C: KeyedBlockCipher> MDC2_Generic<C>
+{
+    // pub fn new(cipher: C) -> Self
+    /// Constructs a new object MDC2_Generic with zero padding.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher. Its key does not matter.
+    ///
+    /// # Panics
+    /// It panics if the block or the key of `C` is not 8 bytes long.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2_Generic;
+    /// use cryptocol::symmetric::DES_Generic;
+    ///
+    /// // MDC-2 based on DES with 8 rounds
+    /// let mut hash = MDC2_Generic::new(DES_Generic::<8>::new());
+    /// hash.digest_str("Now is the time for all ");
+    /// println!("Hash =\t{}", hash);
+    /// assert_eq!(hash.to_string(), "157837BEBB4F030D38C59DB2300DB925");
+    /// ```
+    #[inline]
+    pub fn new(cipher: C) -> Self
+    {
+        Self::new_with_padding(cipher, Zero_Padding)
+    }
+}
+
+impl<C: KeyedBlockCipher, P: Padding> MDC2_Generic<C, P>
+{
+    // pub fn new_with_padding(cipher: C, padding: P) -> Self
+    /// Constructs a new object MDC2_Generic with the padding scheme
+    /// `padding`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher. Its key does not matter.
+    /// - `padding` is the padding scheme.
+    ///
+    /// # Panics
+    /// It panics if the block or the key of `C` is not 8 bytes long.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2_Generic;
+    /// use cryptocol::symmetric::{ DES, ISO7816_Padding };
+    ///
+    /// let mut hash = MDC2_Generic::new_with_padding(DES::new(), ISO7816_Padding);
+    /// hash.digest_str("");
+    /// println!("Msg =\t\"\"\nHash =\t{}", hash);
+    /// assert_eq!(hash.to_string(), "4C8648C851AAFE263C94B40FF591769B");
+    /// ```
+    pub fn new_with_padding(cipher: C, padding: P) -> Self
+    {
+        if (C::BLOCK_SIZE != 8) || (C::KEY_SIZE != 8)
+            { panic!("MDC-2 needs the block cipher whose block and key are 8 bytes long."); }
+        Self { cipher, padding, hash_value: MDC_INITIAL_VALUE }
+    }
+
+    // pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    /// Computes hash value.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*const u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. So, this function is usually not called
+    /// directly in Rust. This function is provided to be called from other
+    /// programming languages such as C/C++.
+    ///
+    /// # Arguments
+    /// - `message` is pointer to const u8.
+    /// - `length_in_bytes` is the size of message in the unit of bytes, and
+    ///   data type is `u64`.
+    ///
+    /// # Panics
+    /// It panics if `P` cannot pad the message, for example, if `P` is
+    /// `No_Padding` and `length_in_bytes` is not a multiple of 8.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2;
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "Now is the time for all ";
+    /// let mut hash = MDC2::new(DES::new());
+    /// hash.digest(txt.as_ptr(), txt.len() as u64);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "42E50CD224BACEBA760BDD2BD409281A");
+    /// ```
+    #[inline]
+    pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    {
+        self.absorb(message_from_raw(message, length_in_bytes as usize));
+    }
+
+    // pub fn digest_str(&mut self, message: &str)
+    /// Computes hash value of the content of string slice.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&str`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2;
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "The quick brown fox jumps over the lazy cog";
+    /// let mut hash = MDC2::new(DES::new());
+    /// hash.digest_str(txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "775F59F8E51AEC29C57AC6AB850D58E8");
+    /// ```
+    #[inline]
+    pub fn digest_str(&mut self, message: &str)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_string(&mut self, message: &String)
+    /// Computes hash value of the content of String object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&String`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2;
+    /// use cryptocol::symmetric::DES;
+    /// let txt = "The quick brown fox jumps over the lazy cog".to_string();
+    /// let mut hash = MDC2::new(DES::new());
+    /// hash.digest_string(&txt);
+    /// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+    /// assert_eq!(hash.to_string(), "775F59F8E51AEC29C57AC6AB850D58E8");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_string(&mut self, message: &String)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_vec(&mut self, message: &Vec<u8>)
+    /// Computes hash value of the content of Vec object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    ///
+    /// # Argument
+    /// - `message` is `&Vec<u8>`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2;
+    /// use cryptocol::symmetric::DES;
+    /// let data = "Now is the time for all ".as_bytes().to_vec();
+    /// let mut hash = MDC2::new(DES::new());
+    /// hash.digest_vec(&data);
+    /// println!("Msg =\t{:?}\nHash =\t{}", data, hash);
+    /// assert_eq!(hash.to_string(), "42E50CD224BACEBA760BDD2BD409281A");
+    /// ```
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_vec(&mut self, message: &Vec<u8>)
+    {
+        self.absorb(message);
+    }
+
+    // pub fn get_hash_value_in_string(&self) -> String
+    /// Returns a hash value in the form of String object.
+    ///
+    /// # Output
+    /// A hash value of 16 bytes in the form of String object.
+    #[inline]
+    pub fn get_hash_value_in_string(&self) -> String
+    {
+        to_hex_string(&self.hash_value)
+    }
+
+    // pub fn get_hash_value_in_array(&self) -> [u8; 16]
+    /// Returns a hash value in the form of array object.
+    ///
+    /// # Output
+    /// A hash value of 16 bytes in the form of array object.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::hash::MDC2;
+    /// use cryptocol::symmetric::DES;
+    /// let mut hash = MDC2::new(DES::new());
+    /// hash.digest_str("Now is the time for all ");
+    /// let hash_value = hash.get_hash_value_in_array();
+    /// assert_eq!(hash_value[..8], [0x42, 0xE5, 0x0C, 0xD2, 0x24, 0xBA, 0xCE, 0xBA]);
+    /// ```
+    #[inline]
+    pub fn get_hash_value_in_array(&self) -> [u8; 16]
+    {
+        self.hash_value
+    }
+
+    // pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    /// Returns a hash value in the form of Vec object.
+    ///
+    /// # Output
+    /// A hash value of 16 bytes in the form of Vec object.
+    #[inline]
+    pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    {
+        self.hash_value.to_vec()
+    }
+
+    // fn absorb(&mut self, message: &[u8])
+    // Starts from the initial value and compresses `message` with padding.
+    fn absorb(&mut self, message: &[u8])
+    {
+        let (mut g, mut gg) = split_halves(&MDC_INITIAL_VALUE);
+        for block in pad_mdc(&self.padding, message).chunks_exact(8)
+            { mdc2_step(&mut self.cipher, &mut g, &mut gg, block, block); }
+        self.hash_value[..8].copy_from_slice(&g);
+        self.hash_value[8..].copy_from_slice(&gg);
+    }
+}
+
+impl<C: KeyedBlockCipher, P: Padding> Display for MDC2_Generic<C, P>
+{
+    /// Formats the value using the given formatter.
+    /// You will hardly use this method directly.
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()`, and you can also print the
+    /// MDC-2 object in the macro `println!()` directly for example.
+    /// `f` is a buffer, this method must write the formatted string into it.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.get_hash_value_in_string())
+    }
+}
+
+
+/// MDC-4, the double-block-length hash function based on a 64-bit block
+/// cipher such as DES, which is stronger and slower than MDC-2
+///
+/// # Introduction
+/// MDC-4 runs two steps of MDC-2 for every message block of 8 bytes. The
+/// first step compresses the message block with the chaining values
+/// `G` and `~G` into the intermediate values `H` and `~H`, and the second
+/// step compresses `~G` and `G` with the keys made of `H` and `~H` into
+/// the new chaining values. So, it encrypts four times per message block
+/// while MDC-2 encrypts twice. The initial values, the keys and the
+/// padding are the same as those of [`MDC2_Generic`](struct@MDC2_Generic).
+/// The hash value is 128 bits long.
+///
+/// # Vulnerability
+/// Finding a collision of MDC-4 takes more trials than MDC-2 but still
+/// much fewer than 2^64. Zero padding is ambiguous. Use it only for the
+/// legacy protocols.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `KeyedBlockCipher`.
+///   Its block and its key should be 8 bytes long.
+/// - P: the padding scheme which has the trait `Padding`. It is
+///   `Zero_Padding` by default.
+///
+/// # Reference
+/// Read the algorithm 9.47 of Handbook of Applied Cryptography about
+/// MDC-4 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::hash::{ MDC4, MDC2 };
+/// use cryptocol::symmetric::DES;
+///
+/// let txt = "The quick brown fox jumps over the lazy dog";
+/// let mut hash = MDC4::new(DES::new());
+/// hash.digest_str(txt);
+/// println!("Msg =\t\"{}\"\nHash =\t{}", txt, hash);
+/// assert_eq!(hash.to_string(), "44F5722D6E40B93E3C98F1408978D8E7");
+///
+/// // The empty message is not padded with zero padding.
+/// hash.digest_str("");
+/// assert_eq!(hash.to_string(), "52525252525252522525252525252525");
+/// ```
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct MDC4_Generic<C: KeyedBlockCipher = DES, P: Padding = Zero_Padding>
+{
+    cipher: C,
+    padding: P,
+    hash_value: [u8; 16],
+}
+
+impl<C: KeyedBlockCipher> MDC4_Generic<C>
+{
+    // pub fn new(cipher: C) -> Self
+    /// Constructs a new object MDC4_Generic with zero padding.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher. Its key does not matter.
+    ///
+    /// # Panics
+    /// It panics if the block or the key of `C` is not 8 bytes long.
+    #[inline]
+    pub fn new(cipher: C) -> Self
+    {
+        Self::new_with_padding(cipher, Zero_Padding)
+    }
+}
+
+impl<C: KeyedBlockCipher, P: Padding> MDC4_Generic<C, P>
+{
+    // pub fn new_with_padding(cipher: C, padding: P) -> Self
+    /// Constructs a new object MDC4_Generic with the padding scheme
+    /// `padding`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher. Its key does not matter.
+    /// - `padding` is the padding scheme.
+    ///
+    /// # Panics
+    /// It panics if the block or the key of `C` is not 8 bytes long.
+    pub fn new_with_padding(cipher: C, padding: P) -> Self
+    {
+        if (C::BLOCK_SIZE != 8) || (C::KEY_SIZE != 8)
+            { panic!("MDC-4 needs the block cipher whose block and key are 8 bytes long."); }
+        Self { cipher, padding, hash_value: MDC_INITIAL_VALUE }
+    }
+
+    // pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    /// Computes hash value.
+    ///
+    /// # Features
+    /// This function has the generalized interface (pointer, `*const u8`)
+    /// so as to enable other functions to wrap this function with any
+    /// convenient interface for uses. So, this function is usually not called
+    /// directly in Rust. This function is provided to be called from other
+    /// programming languages such as C/C++.
+    ///
+    /// # Arguments
+    /// - `message` is pointer to const u8.
+    /// - `length_in_bytes` is the size of message in the unit of bytes, and
+    ///   data type is `u64`.
+    ///
+    /// # Panics
+    /// It panics if `P` cannot pad the message, for example, if `P` is
+    /// `No_Padding` and `length_in_bytes` is not a multiple of 8.
+    #[inline]
+    pub fn digest(&mut self, message: *const u8, length_in_bytes: u64)
+    {
+        self.absorb(message_from_raw(message, length_in_bytes as usize));
+    }
+
+    // pub fn digest_str(&mut self, message: &str)
+    /// Computes hash value of the content of string slice.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    #[inline]
+    pub fn digest_str(&mut self, message: &str)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_string(&mut self, message: &String)
+    /// Computes hash value of the content of String object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_string(&mut self, message: &String)
+    {
+        self.absorb(message.as_bytes());
+    }
+
+    // pub fn digest_vec(&mut self, message: &Vec<u8>)
+    /// Computes hash value of the content of Vec object.
+    ///
+    /// # Features
+    /// This function is a wrapping function of `digest()`.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn digest_vec(&mut self, message: &Vec<u8>)
+    {
+        self.absorb(message);
+    }
+
+    // pub fn get_hash_value_in_string(&self) -> String
+    /// Returns a hash value of 16 bytes in the form of String object.
+    #[inline]
+    pub fn get_hash_value_in_string(&self) -> String
+    {
+        to_hex_string(&self.hash_value)
+    }
+
+    // pub fn get_hash_value_in_array(&self) -> [u8; 16]
+    /// Returns a hash value of 16 bytes in the form of array object.
+    #[inline]
+    pub fn get_hash_value_in_array(&self) -> [u8; 16]
+    {
+        self.hash_value
+    }
+
+    // pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    /// Returns a hash value of 16 bytes in the form of Vec object.
+    #[inline]
+    pub fn get_hash_value_in_vec(&self) -> Vec<u8>
+    {
+        self.hash_value.to_vec()
+    }
+
+    // fn absorb(&mut self, message: &[u8])
+    // Starts from the initial value and compresses `message` with padding.
+    fn absorb(&mut self, message: &[u8])
+    {
+        let (mut g, mut gg) = split_halves(&MDC_INITIAL_VALUE);
+        for block in pad_mdc(&self.padding, message).chunks_exact(8)
+        {
+            let (mut h, mut hh) = (g, gg);
+            mdc2_step(&mut self.cipher, &mut h, &mut hh, block, block);
+            let (old_g, old_gg) = (g, gg);
+            g = h;
+            gg = hh;
+            mdc2_step(&mut self.cipher, &mut g, &mut gg, &old_gg, &old_g);
+        }
+        self.hash_value[..8].copy_from_slice(&g);
+        self.hash_value[8..].copy_from_slice(&gg);
+    }
+}
+
+impl<C: KeyedBlockCipher, P: Padding> Display for MDC4_Generic<C, P>
+{
+    /// Formats the value using the given formatter.
+    /// You will hardly use this method directly.
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()`, and you can also print the
+    /// MDC-4 object in the macro `println!()` directly for example.
+    /// `f` is a buffer, this method must write the formatted string into it.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.get_hash_value_in_string())
+    }
+}
+
+
+/// The initial values `5252525252525252` and `2525252525252525` of MDC-2
+/// and MDC-4
+const MDC_INITIAL_VALUE: [u8; 16] = [0x52, 0x52, 0x52, 0x52, 0x52, 0x52, 0x52, 0x52,
+                                     0x25, 0x25, 0x25, 0x25, 0x25, 0x25, 0x25, 0x25];
+
+// fn mdc2_step<C: KeyedBlockCipher>(cipher: &mut C, g: &mut [u8; 8], gg: &mut [u8; 8], x: &[u8], xx: &[u8])
+// Encrypts `x` with the key made of `g` and `xx` with the key made of
+// `gg` in Matyas-Meyer-Oseas, and then swaps the right halves of the
+// results into `g` and `gg`.
+fn mdc2_step<C: KeyedBlockCipher>(cipher: &mut C, g: &mut [u8; 8], gg: &mut [u8; 8], x: &[u8], xx: &[u8])
+{
+    let mut key = *g;
+    key[0] = (key[0] & 0x9F) | 0x40;
+    let mut a = [0_u8; 8];
+    a.copy_from_slice(x);
+    cipher.set_key_slice(&key);
+    cipher.encrypt_one_block(&mut a);
+    xor_into(&mut a, x);
+
+    key = *gg;
+    key[0] = (key[0] & 0x9F) | 0x20;
+    let mut b = [0_u8; 8];
+    b.copy_from_slice(xx);
+    cipher.set_key_slice(&key);
+    cipher.encrypt_one_block(&mut b);
+    xor_into(&mut b, xx);
+
+    g[..4].copy_from_slice(&a[..4]);
+    g[4..].copy_from_slice(&b[4..]);
+    gg[..4].copy_from_slice(&b[..4]);
+    gg[4..].copy_from_slice(&a[4..]);
+}
+
+// fn pad_mdc<P: Padding>(padding: &P, message: &[u8]) -> Vec<u8>
+// Pads `message` into the multiple of 8 bytes.
+fn pad_mdc<P: Padding>(padding: &P, message: &[u8]) -> Vec<u8>
+{
+    let length = padding.padding_length(message.len(), 8)
+                        .expect("The padding scheme cannot pad the message.");
+    let mut padded = Vec::with_capacity(message.len() + length);
+    padded.extend_from_slice(message);
+    padded.resize(message.len() + length, 0);
+    padding.fill(&mut padded[message.len()..]);
+    padded
+}
+
+// fn message_from_raw<'a>(message: *const u8, length_in_bytes: usize) -> &'a [u8]
+// Makes the slice of `length_in_bytes` bytes that `message` points to.
+#[inline]
+fn message_from_raw<'a>(message: *const u8, length_in_bytes: usize) -> &'a [u8]
+{
+    if length_in_bytes == 0
+        { &[] }
+    else
+        { unsafe { from_raw_parts(message, length_in_bytes) } }
+}
+
+// fn copy_to_raw(src: &[u8], dst: *mut u8)
+// Copies `src` to where `dst` points to.
+#[inline]
+fn copy_to_raw(src: &[u8], dst: *mut u8)
+{
+    unsafe { copy_nonoverlapping(src.as_ptr(), dst, src.len()); }
+}
+
+#[inline]
+fn split_halves(value: &[u8; 16]) -> ([u8; 8], [u8; 8])
+{
+    let mut left = [0_u8; 8];
+    let mut right = [0_u8; 8];
+    left.copy_from_slice(&value[..8]);
+    right.copy_from_slice(&value[8..]);
+    (left, right)
+}
+
+#[inline]
+fn xor_into(a: &mut [u8], b: &[u8])
+{
+    for (aa, bb) in a.iter_mut().zip(b.iter())
+        { *aa ^= bb; }
+}
+
+fn to_hex_string(bytes: &[u8]) -> String
+{
+    let mut txt = String::new();
+    for byte in bytes
+        { txt.push_str(&format!("{:02X}", byte)); }
+    txt
+}
@@ -42,6 +42,8 @@
 //! - SHA-2 hash algorithms based on 256 bits --- Includes SHA-256, SHA-224, and their expanded versions. [`SHA2_Generic_256`](struct@SHA2_256_Generic)
 //! - SHA-2 hash algorithms based on 512 bits --- Includes SHA-512, SHA-384, SHA-512/256, and their expanded versions. [`SHA2_512_Generic`](struct@SHA2_512_Generic)
 //! - SHA-2 hash algorithms based on 512/t bits --- Includes 512/256, SHA-512/224, and their expanded versions. [`SHA2_512_t_Generic`](struct@SHA2_512_t_Generic)
+//! - Ascon hash algorithms based on the Ascon permutation --- Includes Ascon-Hash256, Ascon-XOF128, and their expanded versions. [`Ascon_Hash_Generic`](struct@Ascon_Hash_Generic)
+//! - Hash algorithms based on block ciphers --- Includes Davies-Meyer, Matyas-Meyer-Oseas, Miyaguchi-Preneel, MDC-2 and MDC-4 over DES, AES and any block cipher that has the trait `KeyedBlockCipher`. [`BlockCipherHash_Generic`](struct@BlockCipherHash_Generic), [`MDC2_Generic`](struct@MDC2_Generic), [`MDC4_Generic`](struct@MDC4_Generic)
 //! 
 //! # QUICK START
 //! - For `MD4`, read [here](struct@MD4_Generic#quick-start).
@@ -50,6 +52,10 @@
 //! - For `SHA-256`, read [here](struct@SHA2_256_Generic#quick-start).
 //! - For `SHA-512`, read [here](struct@SHA2_512_Generic#quick-start).
 //! - For `SHA-512/t`, read [here](struct@SHA2_512_t_Generic#quick-start).
+//! - For `Ascon-Hash256` and `Ascon-XOF128`, read [here](struct@Ascon_Hash_Generic#quick-start).
+//! - For Davies-Meyer, Matyas-Meyer-Oseas and Miyaguchi-Preneel, read [here](struct@BlockCipherHash_Generic#quick-start).
+//! - For `MDC-2`, read [here](struct@MDC2_Generic#quick-start).
+//! - For `MDC-4`, read [here](struct@MDC4_Generic#quick-start).
 //! 
 //! # Simple but Useful Applications using cryptocol
 //! - For `MD4`, try [this](struct@MD4_Generic#a-simple-but-useful-application-using-cryptocol).
@@ -232,6 +238,8 @@ mod sha1;
 mod sha2_256;
 mod sha2_512;
 mod sha2_512_t;
+mod ascon_hash;
+mod block_cipher_hash;
 
 pub use md4::*;
 pub use md5::*;
@@ -239,3 +247,5 @@ pub use sha1::*;
 pub use sha2_256::*;
 pub use sha2_512::*;
 pub use sha2_512_t::*;
+pub use ascon_hash::*;
+pub use block_cipher_hash::*;
@@ -138,3 +138,4 @@ pub mod number;
 pub mod hash;
 pub mod symmetric;
 pub mod random;
+pub mod analysis;
@@ -0,0 +1,355 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains AES block cipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+
+/// The official AES-128 symmetric-key algorithm with 128-bit key
+#[allow(non_camel_case_types)]
+pub type AES_128 = AES_Generic<4, 10>;
+
+/// The official AES-192 symmetric-key algorithm with 192-bit key
+#[allow(non_camel_case_types)]
+pub type AES_192 = AES_Generic<6, 12>;
+
+/// The official AES-256 symmetric-key algorithm with 256-bit key
+#[allow(non_camel_case_types)]
+pub type AES_256 = AES_Generic<8, 14>;
+
+/// An AES (Rijndael with 128-bit block) symmetric-key algorithm for the
+/// encryption of digital data
+///
+/// # Introduction
+/// AES was standardized by NIST in FIPS 197 in 2001. It is a substitution
+/// permutation network of 128-bit block. Each round consists of SubBytes,
+/// ShiftRows, MixColumns and AddRoundKey, and the last round omits
+/// MixColumns.
+///
+/// # Vulnerability
+/// This implementation looks up the S-box in memory, so it is not resistant
+/// to cache-timing attacks when an attacker shares the processor with you.
+///
+/// # Generic Parameters
+/// - NK: The length of key in 32-bit words. It is `4` for AES-128, `6` for
+///   AES-192 and `8` for AES-256.
+/// - ROUND: The number of rounds. It is `10` for AES-128, `12` for AES-192
+///   and `14` for AES-256. You can increase it to make your own expanded
+///   version. The round keys are expanded by the same key schedule.
+///
+/// # Byte order
+/// The block is the sequence of 16 bytes as FIPS 197 describes. The methods
+/// `encrypt_u128()` and `decrypt_u128()` read and write the block as `u128`
+/// in little endianness, that is, the first byte of the block is the least
+/// significant byte of the `u128`, just as `DES_Generic` treats `u64`.
+///
+/// # Reference
+/// Read [FIPS 197](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197-upd1.pdf)
+/// about AES in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::AES_128;
+///
+/// let key = [ 0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+///             0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F ];
+/// let mut a_aes = AES_128::new_with_key(&key);
+/// let message = 0xFFEEDDCCBBAA99887766554433221100_u128;
+/// let cipher = a_aes.encrypt_u128(message);
+/// println!("C =\t{:032X}", cipher);
+/// assert_eq!(cipher.to_le_bytes(), [ 0x69_u8, 0xC4, 0xE0, 0xD8, 0x6A, 0x7B, 0x04, 0x30,
+///                                    0xD8, 0xCD, 0xB7, 0x80, 0x70, 0xB4, 0xC5, 0x5A ]);
+/// assert_eq!(a_aes.decrypt_u128(cipher), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct AES_Generic<const NK: usize = 4, const ROUND: usize = 10>
+{
+    round_key: Vec<[u8; 16]>,
+}
+
+impl<const NK: usize, const ROUND: usize> AES_Generic<NK, ROUND>
+{
+    pub(crate) const SBOX: [u8; 256] = [
+        0x63, 0x7C, 0x77, 0x7B, 0xF2, 0x6B, 0x6F, 0xC5, 0x30, 0x01, 0x67, 0x2B, 0xFE, 0xD7, 0xAB, 0x76,
+        0xCA, 0x82, 0xC9, 0x7D, 0xFA, 0x59, 0x47, 0xF0, 0xAD, 0xD4, 0xA2, 0xAF, 0x9C, 0xA4, 0x72, 0xC0,
+        0xB7, 0xFD, 0x93, 0x26, 0x36, 0x3F, 0xF7, 0xCC, 0x34, 0xA5, 0xE5, 0xF1, 0x71, 0xD8, 0x31, 0x15,
+        0x04, 0xC7, 0x23, 0xC3, 0x18, 0x96, 0x05, 0x9A, 0x07, 0x12, 0x80, 0xE2, 0xEB, 0x27, 0xB2, 0x75,
+        0x09, 0x83, 0x2C, 0x1A, 0x1B, 0x6E, 0x5A, 0xA0, 0x52, 0x3B, 0xD6, 0xB3, 0x29, 0xE3, 0x2F, 0x84,
+        0x53, 0xD1, 0x00, 0xED, 0x20, 0xFC, 0xB1, 0x5B, 0x6A, 0xCB, 0xBE, 0x39, 0x4A, 0x4C, 0x58, 0xCF,
+        0xD0, 0xEF, 0xAA, 0xFB, 0x43, 0x4D, 0x33, 0x85, 0x45, 0xF9, 0x02, 0x7F, 0x50, 0x3C, 0x9F, 0xA8,
+        0x51, 0xA3, 0x40, 0x8F, 0x92, 0x9D, 0x38, 0xF5, 0xBC, 0xB6, 0xDA, 0x21, 0x10, 0xFF, 0xF3, 0xD2,
+        0xCD, 0x0C, 0x13, 0xEC, 0x5F, 0x97, 0x44, 0x17, 0xC4, 0xA7, 0x7E, 0x3D, 0x64, 0x5D, 0x19, 0x73,
+        0x60, 0x81, 0x4F, 0xDC, 0x22, 0x2A, 0x90, 0x88, 0x46, 0xEE, 0xB8, 0x14, 0xDE, 0x5E, 0x0B, 0xDB,
+        0xE0, 0x32, 0x3A, 0x0A, 0x49, 0x06, 0x24, 0x5C, 0xC2, 0xD3, 0xAC, 0x62, 0x91, 0x95, 0xE4, 0x79,
+        0xE7, 0xC8, 0x37, 0x6D, 0x8D, 0xD5, 0x4E, 0xA9, 0x6C, 0x56, 0xF4, 0xEA, 0x65, 0x7A, 0xAE, 0x08,
+        0xBA, 0x78, 0x25, 0x2E, 0x1C, 0xA6, 0xB4, 0xC6, 0xE8, 0xDD, 0x74, 0x1F, 0x4B, 0xBD, 0x8B, 0x8A,
+        0x70, 0x3E, 0xB5, 0x66, 0x48, 0x03, 0xF6, 0x0E, 0x61, 0x35, 0x57, 0xB9, 0x86, 0xC1, 0x1D, 0x9E,
+        0xE1, 0xF8, 0x98, 0x11, 0x69, 0xD9, 0x8E, 0x94, 0x9B, 0x1E, 0x87, 0xE9, 0xCE, 0x55, 0x28, 0xDF,
+        0x8C, 0xA1, 0x89, 0x0D, 0xBF, 0xE6, 0x42, 0x68, 0x41, 0x99, 0x2D, 0x0F, 0xB0, 0x54, 0xBB, 0x16
+    ];
+
+    const INV_SBOX: [u8; 256] = [
+        0x52, 0x09, 0x6A, 0xD5, 0x30, 0x36, 0xA5, 0x38, 0xBF, 0x40, 0xA3, 0x9E, 0x81, 0xF3, 0xD7, 0xFB,
+        0x7C, 0xE3, 0x39, 0x82, 0x9B, 0x2F, 0xFF, 0x87, 0x34, 0x8E, 0x43, 0x44, 0xC4, 0xDE, 0xE9, 0xCB,
+        0x54, 0x7B, 0x94, 0x32, 0xA6, 0xC2, 0x23, 0x3D, 0xEE, 0x4C, 0x95, 0x0B, 0x42, 0xFA, 0xC3, 0x4E,
+        0x08, 0x2E, 0xA1, 0x66, 0x28, 0xD9, 0x24, 0xB2, 0x76, 0x5B, 0xA2, 0x49, 0x6D, 0x8B, 0xD1, 0x25,
+        0x72, 0xF8, 0xF6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xD4, 0xA4, 0x5C, 0xCC, 0x5D, 0x65, 0xB6, 0x92,
+        0x6C, 0x70, 0x48, 0x50, 0xFD, 0xED, 0xB9, 0xDA, 0x5E, 0x15, 0x46, 0x57, 0xA7, 0x8D, 0x9D, 0x84,
+        0x90, 0xD8, 0xAB, 0x00, 0x8C, 0xBC, 0xD3, 0x0A, 0xF7, 0xE4, 0x58, 0x05, 0xB8, 0xB3, 0x45, 0x06,
+        0xD0, 0x2C, 0x1E, 0x8F, 0xCA, 0x3F, 0x0F, 0x02, 0xC1, 0xAF, 0xBD, 0x03, 0x01, 0x13, 0x8A, 0x6B,
+        0x3A, 0x91, 0x11, 0x41, 0x4F, 0x67, 0xDC, 0xEA, 0x97, 0xF2, 0xCF, 0xCE, 0xF0, 0xB4, 0xE6, 0x73,
+        0x96, 0xAC, 0x74, 0x22, 0xE7, 0xAD, 0x35, 0x85, 0xE2, 0xF9, 0x37, 0xE8, 0x1C, 0x75, 0xDF, 0x6E,
+        0x47, 0xF1, 0x1A, 0x71, 0x1D, 0x29, 0xC5, 0x89, 0x6F, 0xB7, 0x62, 0x0E, 0xAA, 0x18, 0xBE, 0x1B,
+        0xFC, 0x56, 0x3E, 0x4B, 0xC6, 0xD2, 0x79, 0x20, 0x9A, 0xDB, 0xC0, 0xFE, 0x78, 0xCD, 0x5A, 0xF4,
+        0x1F, 0xDD, 0xA8, 0x33, 0x88, 0x07, 0xC7, 0x31, 0xB1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xEC, 0x5F,
+        0x60, 0x51, 0x7F, 0xA9, 0x19, 0xB5, 0x4A, 0x0D, 0x2D, 0xE5, 0x7A, 0x9F, 0x93, 0xC9, 0x9C, 0xEF,
+        0xA0, 0xE0, 0x3B, 0x4D, 0xAE, 0x2A, 0xF5, 0xB0, 0xC8, 0xEB, 0xBB, 0x3C, 0x83, 0x53, 0x99, 0x61,
+        0x17, 0x2B, 0x04, 0x7E, 0xBA, 0x77, 0xD6, 0x26, 0xE1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0C, 0x7D
+    ];
+
+    /// Constructs a new object AES_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self::new_with_key(&vec![0_u8; NK * 4])
+    }
+
+    /// Constructs a new object AES_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements,
+    /// that is, 16 bytes for AES-128, 24 bytes for AES-192 and 32 bytes for
+    /// AES-256.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        let mut aes = Self { round_key: Vec::new() };
+        aes.set_key(key);
+        aes
+    }
+
+    /// Sets the key and makes the round keys.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        assert_eq!(key.len(), NK * 4, "The key of AES_Generic<{}, {}> should be {} bytes long.", NK, ROUND, NK * 4);
+        self.make_round_keys(key);
+    }
+
+    /// Encrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `message` is the block to be encrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the encrypted block in little endianness.
+    pub fn encrypt_u128(&mut self, message: u128) -> u128
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Decrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `cipher` is the block to be decrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the decrypted block in little endianness.
+    pub fn decrypt_u128(&mut self, cipher: u128) -> u128
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Encrypts the array of 128-bit data blocks independently of one another.
+    pub fn encrypt_array_u128<const N: usize>(&mut self, message: &[u128; N], cipher: &mut [u128; N])
+    {
+        for (c, m) in cipher.iter_mut().zip(message.iter())
+            { *c = self.encrypt_u128(*m); }
+    }
+
+    /// Decrypts the array of 128-bit data blocks independently of one another.
+    pub fn decrypt_array_u128<const N: usize>(&mut self, cipher: &[u128; N], message: &mut [u128; N])
+    {
+        for (m, c) in message.iter_mut().zip(cipher.iter())
+            { *m = self.decrypt_u128(*c); }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::add_round_key(block, &self.round_key[0]);
+        for round in 1..ROUND
+        {
+            Self::sub_bytes(block);
+            Self::shift_rows(block);
+            Self::mix_columns(block);
+            Self::add_round_key(block, &self.round_key[round]);
+        }
+        Self::sub_bytes(block);
+        Self::shift_rows(block);
+        Self::add_round_key(block, &self.round_key[ROUND]);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::add_round_key(block, &self.round_key[ROUND]);
+        Self::inverse_shift_rows(block);
+        Self::inverse_sub_bytes(block);
+        for round in (1..ROUND).rev()
+        {
+            Self::add_round_key(block, &self.round_key[round]);
+            Self::inverse_mix_columns(block);
+            Self::inverse_shift_rows(block);
+            Self::inverse_sub_bytes(block);
+        }
+        Self::add_round_key(block, &self.round_key[0]);
+    }
+
+    fn make_round_keys(&mut self, key: &[u8])
+    {
+        let total = 4 * (ROUND + 1);
+        let mut word = Vec::<[u8; 4]>::with_capacity(total);
+        for i in 0..NK
+            { word.push([key[4 * i], key[4 * i + 1], key[4 * i + 2], key[4 * i + 3]]); }
+        let mut rcon = 1_u8;
+        for i in NK..total
+        {
+            let mut temp = word[i - 1];
+            if i % NK == 0
+            {
+                temp.rotate_left(1);
+                for t in temp.iter_mut()
+                    { *t = Self::SBOX[*t as usize]; }
+                temp[0] ^= rcon;
+                rcon = Self::xtime(rcon);
+            }
+            else if NK > 6 && i % NK == 4
+            {
+                for t in temp.iter_mut()
+                    { *t = Self::SBOX[*t as usize]; }
+            }
+            let prev = word[i - NK];
+            word.push([prev[0] ^ temp[0], prev[1] ^ temp[1], prev[2] ^ temp[2], prev[3] ^ temp[3]]);
+        }
+
+        self.round_key.clear();
+        for round in 0..=ROUND
+        {
+            let mut round_key = [0_u8; 16];
+            for col in 0..4
+                { round_key[4 * col..4 * col + 4].copy_from_slice(&word[4 * round + col]); }
+            self.round_key.push(round_key);
+        }
+    }
+
+    #[inline]
+    fn add_round_key(block: &mut [u8; 16], round_key: &[u8; 16])
+    {
+        for (b, k) in block.iter_mut().zip(round_key.iter())
+            { *b ^= *k; }
+    }
+
+    #[inline]
+    fn sub_bytes(block: &mut [u8; 16])
+    {
+        for b in block.iter_mut()
+            { *b = Self::SBOX[*b as usize]; }
+    }
+
+    #[inline]
+    fn inverse_sub_bytes(block: &mut [u8; 16])
+    {
+        for b in block.iter_mut()
+            { *b = Self::INV_SBOX[*b as usize]; }
+    }
+
+    // The block is arranged column by column, so that the byte of row `r`
+    // and column `c` is block[r + 4 * c].
+    fn shift_rows(block: &mut [u8; 16])
+    {
+        let old = *block;
+        for row in 1..4
+        {
+            for col in 0..4
+                { block[row + 4 * col] = old[row + 4 * ((col + row) % 4)]; }
+        }
+    }
+
+    fn inverse_shift_rows(block: &mut [u8; 16])
+    {
+        let old = *block;
+        for row in 1..4
+        {
+            for col in 0..4
+                { block[row + 4 * ((col + row) % 4)] = old[row + 4 * col]; }
+        }
+    }
+
+    fn mix_columns(block: &mut [u8; 16])
+    {
+        for column in block.chunks_exact_mut(4)
+        {
+            let [a0, a1, a2, a3] = [column[0], column[1], column[2], column[3]];
+            let all = a0 ^ a1 ^ a2 ^ a3;
+            column[0] ^= all ^ Self::xtime(a0 ^ a1);
+            column[1] ^= all ^ Self::xtime(a1 ^ a2);
+            column[2] ^= all ^ Self::xtime(a2 ^ a3);
+            column[3] ^= all ^ Self::xtime(a3 ^ a0);
+        }
+    }
+
+    fn inverse_mix_columns(block: &mut [u8; 16])
+    {
+        // InvMixColumns = MixColumns after multiplying the column by
+        // {04}x^2 + {05}, which is cheaper than multiplying by {0E}, {0B},
+        // {0D} and {09} directly.
+        for column in block.chunks_exact_mut(4)
+        {
+            let u = Self::xtime(Self::xtime(column[0] ^ column[2]));
+            let v = Self::xtime(Self::xtime(column[1] ^ column[3]));
+            column[0] ^= u;
+            column[1] ^= v;
+            column[2] ^= u;
+            column[3] ^= v;
+        }
+        Self::mix_columns(block);
+    }
+
+    #[inline]
+    fn xtime(a: u8) -> u8
+    {
+        (a << 1) ^ (((a >> 7) & 1) * 0x1B)
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> Default for AES_Generic<NK, ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains ARIA block cipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::{ BlockCipher, AES_128 };
+
+
+/// The official ARIA-128 symmetric-key algorithm with 128-bit key
+#[allow(non_camel_case_types)]
+pub type ARIA_128 = ARIA_Generic<4, 12>;
+
+/// The official ARIA-192 symmetric-key algorithm with 192-bit key
+#[allow(non_camel_case_types)]
+pub type ARIA_192 = ARIA_Generic<6, 14>;
+
+/// The official ARIA-256 symmetric-key algorithm with 256-bit key
+#[allow(non_camel_case_types)]
+pub type ARIA_256 = ARIA_Generic<8, 16>;
+
+/// An ARIA symmetric-key algorithm for the encryption of digital data
+///
+/// # Introduction
+/// ARIA was developed by Korean cryptographers and KISA in 2003, and is
+/// standardized in KS X 1213 and RFC 5794. It is a substitution
+/// permutation network of 128-bit block. Each round consists of the round
+/// key addition, the substitution layer of four S-boxes and the diffusion
+/// layer of an involutive 16 x 16 binary matrix. The odd rounds and the
+/// even rounds use different substitution layers, so that encryption and
+/// decryption have the same structure.
+///
+/// # Vulnerability
+/// No practical attack against the full rounds of ARIA is known. This
+/// implementation looks up the S-boxes in memory, so it is not resistant
+/// to cache-timing attacks when an attacker shares the processor with you.
+///
+/// # Generic Parameters
+/// - NK: The length of key in 32-bit words. It is `4` for ARIA-128, `6` for
+///   ARIA-192 and `8` for ARIA-256.
+/// - ROUND: The number of rounds. It is `12` for ARIA-128, `14` for
+///   ARIA-192 and `16` for ARIA-256. The key schedule of ARIA makes at most
+///   17 round keys, so ROUND can be at most `16`.
+///
+/// # Byte order
+/// The block and the key are the sequences of bytes as RFC 5794 describes.
+/// The methods `encrypt_u128()` and `decrypt_u128()` read and write the
+/// block as `u128` in little endianness, just as `AES_Generic` does.
+///
+/// # Reference
+/// Read [RFC 5794](https://www.rfc-editor.org/rfc/rfc5794) about ARIA in
+/// detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ ARIA_128, ModeCipher, OperationMode };
+///
+/// let key = [ 0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+///             0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F ];
+/// let mut a_aria = ARIA_128::new_with_key(&key);
+/// let message = 0xFFEEDDCCBBAA99887766554433221100_u128;
+/// let cipher = a_aria.encrypt_u128(message);
+/// assert_eq!(cipher.to_le_bytes(), [ 0xD7_u8, 0x18, 0xFB, 0xD6, 0xAB, 0x64, 0x4C, 0x73,
+///                                    0x9D, 0xA9, 0x5F, 0x3B, 0xE6, 0x45, 0x17, 0x78 ]);
+/// assert_eq!(a_aria.decrypt_u128(cipher), message);
+///
+/// // All the modes of operation and the padding schemes by ModeCipher
+/// let iv = [0x0F_u8; 16];
+/// let mut cbc = ModeCipher::new(ARIA_128::new_with_key(&key), OperationMode::CBC, &iv);
+/// let cipher = cbc.encrypt("In the beginning God created the heavens and the earth.".as_bytes());
+/// assert_eq!(cbc.decrypt(&cipher).unwrap(), "In the beginning God created the heavens and the earth.".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct ARIA_Generic<const NK: usize = 4, const ROUND: usize = 12>
+{
+    encryption_key: Vec<[u8; 16]>,
+    decryption_key: Vec<[u8; 16]>,
+}
+
+impl<const NK: usize, const ROUND: usize> ARIA_Generic<NK, ROUND>
+{
+    // SB1 is the S-box of AES, and SB3 and SB4 are the inverses of SB1 and
+    // SB2 respectively.
+    const SB1: [u8; 256] = AES_128::SBOX;
+    const SB2: [u8; 256] = [
+        0xE2, 0x4E, 0x54, 0xFC, 0x94, 0xC2, 0x4A, 0xCC, 0x62, 0x0D, 0x6A, 0x46, 0x3C, 0x4D, 0x8B, 0xD1,
+        0x5E, 0xFA, 0x64, 0xCB, 0xB4, 0x97, 0xBE, 0x2B, 0xBC, 0x77, 0x2E, 0x03, 0xD3, 0x19, 0x59, 0xC1,
+        0x1D, 0x06, 0x41, 0x6B, 0x55, 0xF0, 0x99, 0x69, 0xEA, 0x9C, 0x18, 0xAE, 0x63, 0xDF, 0xE7, 0xBB,
+        0x00, 0x73, 0x66, 0xFB, 0x96, 0x4C, 0x85, 0xE4, 0x3A, 0x09, 0x45, 0xAA, 0x0F, 0xEE, 0x10, 0xEB,
+        0x2D, 0x7F, 0xF4, 0x29, 0xAC, 0xCF, 0xAD, 0x91, 0x8D, 0x78, 0xC8, 0x95, 0xF9, 0x2F, 0xCE, 0xCD,
+        0x08, 0x7A, 0x88, 0x38, 0x5C, 0x83, 0x2A, 0x28, 0x47, 0xDB, 0xB8, 0xC7, 0x93, 0xA4, 0x12, 0x53,
+        0xFF, 0x87, 0x0E, 0x31, 0x36, 0x21, 0x58, 0x48, 0x01, 0x8E, 0x37, 0x74, 0x32, 0xCA, 0xE9, 0xB1,
+        0xB7, 0xAB, 0x0C, 0xD7, 0xC4, 0x56, 0x42, 0x26, 0x07, 0x98, 0x60, 0xD9, 0xB6, 0xB9, 0x11, 0x40,
+        0xEC, 0x20, 0x8C, 0xBD, 0xA0, 0xC9, 0x84, 0x04, 0x49, 0x23, 0xF1, 0x4F, 0x50, 0x1F, 0x13, 0xDC,
+        0xD8, 0xC0, 0x9E, 0x57, 0xE3, 0xC3, 0x7B, 0x65, 0x3B, 0x02, 0x8F, 0x3E, 0xE8, 0x25, 0x92, 0xE5,
+        0x15, 0xDD, 0xFD, 0x17, 0xA9, 0xBF, 0xD4, 0x9A, 0x7E, 0xC5, 0x39, 0x67, 0xFE, 0x76, 0x9D, 0x43,
+        0xA7, 0xE1, 0xD0, 0xF5, 0x68, 0xF2, 0x1B, 0x34, 0x70, 0x05, 0xA3, 0x8A, 0xD5, 0x79, 0x86, 0xA8,
+        0x30, 0xC6, 0x51, 0x4B, 0x1E, 0xA6, 0x27, 0xF6, 0x35, 0xD2, 0x6E, 0x24, 0x16, 0x82, 0x5F, 0xDA,
+        0xE6, 0x75, 0xA2, 0xEF, 0x2C, 0xB2, 0x1C, 0x9F, 0x5D, 0x6F, 0x80, 0x0A, 0x72, 0x44, 0x9B, 0x6C,
+        0x90, 0x0B, 0x5B, 0x33, 0x7D, 0x5A, 0x52, 0xF3, 0x61, 0xA1, 0xF7, 0xB0, 0xD6, 0x3F, 0x7C, 0x6D,
+        0xED, 0x14, 0xE0, 0xA5, 0x3D, 0x22, 0xB3, 0xF8, 0x89, 0xDE, 0x71, 0x1A, 0xAF, 0xBA, 0xB5, 0x81
+    ];
+
+    const SB3: [u8; 256] = Self::invert(&Self::SB1);
+    const SB4: [u8; 256] = Self::invert(&Self::SB2);
+
+    // The constants for the key schedule, which are the fractional part of
+    // 1 / pi
+    const C: [u128; 3] = [
+        0x517CC1B727220A94FE13ABE8FA9A6EE0,
+        0x6DB14ACC9E21C820FF28B1D5EF5DE2B0,
+        0xDB92371D2126E9700324977504E8C90E
+    ];
+
+    const fn invert(sbox: &[u8; 256]) -> [u8; 256]
+    {
+        let mut inverse = [0_u8; 256];
+        let mut i = 0;
+        while i < 256
+        {
+            inverse[sbox[i] as usize] = i as u8;
+            i += 1;
+        }
+        inverse
+    }
+
+    /// Constructs a new object ARIA_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self::new_with_key(&vec![0_u8; NK * 4])
+    }
+
+    /// Constructs a new object ARIA_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements,
+    /// that is, 16 bytes for ARIA-128, 24 bytes for ARIA-192 and 32 bytes
+    /// for ARIA-256.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        let mut aria = Self { encryption_key: Vec::new(), decryption_key: Vec::new() };
+        aria.set_key(key);
+        aria
+    }
+
+    /// Sets the key and makes the round keys.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`, or if `NK` is not
+    /// `4`, `6` or `8`, or if `ROUND` is more than `16`.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        assert!(NK == 4 || NK == 6 || NK == 8, "NK of ARIA_Generic should be 4, 6 or 8.");
+        assert!(ROUND <= 16, "ROUND of ARIA_Generic should be at most 16.");
+        assert_eq!(key.len(), NK * 4, "The key of ARIA_Generic<{}, {}> should be {} bytes long.", NK, ROUND, NK * 4);
+        let mut kl = [0_u8; 16];
+        kl.copy_from_slice(&key[..16]);
+        let mut kr = [0_u8; 16];
+        kr[..key.len() - 16].copy_from_slice(&key[16..]);
+        let ck = (NK / 2 - 2) % 3;
+        let ck1 = Self::C[ck].to_be_bytes();
+        let ck2 = Self::C[(ck + 1) % 3].to_be_bytes();
+        let ck3 = Self::C[(ck + 2) % 3].to_be_bytes();
+
+        let w0 = u128::from_be_bytes(kl);
+        let w1 = u128::from_be_bytes(Self::xor(&Self::fo(&kl, &ck1), &kr));
+        let w2 = u128::from_be_bytes(Self::xor(&Self::fe(&w1.to_be_bytes(), &ck2), &kl));
+        let w3 = u128::from_be_bytes(Self::xor(&Self::fo(&w2.to_be_bytes(), &ck3), &w1.to_be_bytes()));
+
+        let w = [w0, w1, w2, w3];
+        let mut encryption_key = Vec::with_capacity(ROUND + 1);
+        for i in 0..=ROUND
+        {
+            let rotated = w[(i + 1) % 4];
+            let rotated = match i / 4
+            {
+                0 => { rotated.rotate_right(19) },
+                1 => { rotated.rotate_right(31) },
+                2 => { rotated.rotate_left(61) },
+                3 => { rotated.rotate_left(31) },
+                _ => { rotated.rotate_left(19) },
+            };
+            encryption_key.push((w[i % 4] ^ rotated).to_be_bytes());
+        }
+
+        let mut decryption_key = Vec::with_capacity(ROUND + 1);
+        decryption_key.push(encryption_key[ROUND]);
+        for i in (1..ROUND).rev()
+            { decryption_key.push(Self::diffuse(&encryption_key[i])); }
+        decryption_key.push(encryption_key[0]);
+        self.encryption_key = encryption_key;
+        self.decryption_key = decryption_key;
+    }
+
+    /// Encrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `message` is the block to be encrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the encrypted block in little endianness.
+    pub fn encrypt_u128(&mut self, message: u128) -> u128
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Decrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `cipher` is the block to be decrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the decrypted block in little endianness.
+    pub fn decrypt_u128(&mut self, cipher: u128) -> u128
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Encrypts the array of 128-bit data blocks independently of one another.
+    pub fn encrypt_array_u128<const N: usize>(&mut self, message: &[u128; N], cipher: &mut [u128; N])
+    {
+        for (c, m) in cipher.iter_mut().zip(message.iter())
+            { *c = self.encrypt_u128(*m); }
+    }
+
+    /// Decrypts the array of 128-bit data blocks independently of one another.
+    pub fn decrypt_array_u128<const N: usize>(&mut self, cipher: &[u128; N], message: &mut [u128; N])
+    {
+        for (m, c) in message.iter_mut().zip(cipher.iter())
+            { *m = self.decrypt_u128(*c); }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::crypt(block, &self.encryption_key);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::crypt(block, &self.decryption_key);
+    }
+
+    // Encryption and decryption are the same except the round keys.
+    fn crypt(block: &mut [u8; 16], round_key: &[[u8; 16]])
+    {
+        let mut state = *block;
+        for (i, key) in round_key[..ROUND - 1].iter().enumerate()
+        {
+            state = if i % 2 == 0 { Self::fo(&state, key) } else { Self::fe(&state, key) };
+        }
+        let state = Self::substitute_even(&Self::xor(&state, &round_key[ROUND - 1]));
+        *block = Self::xor(&state, &round_key[ROUND]);
+    }
+
+    // The round function of the odd rounds
+    #[inline]
+    fn fo(state: &[u8; 16], key: &[u8; 16]) -> [u8; 16]
+    {
+        Self::diffuse(&Self::substitute_odd(&Self::xor(state, key)))
+    }
+
+    // The round function of the even rounds
+    #[inline]
+    fn fe(state: &[u8; 16], key: &[u8; 16]) -> [u8; 16]
+    {
+        Self::diffuse(&Self::substitute_even(&Self::xor(state, key)))
+    }
+
+    #[inline]
+    fn xor(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16]
+    {
+        let mut out = [0_u8; 16];
+        for ((o, a), b) in out.iter_mut().zip(a.iter()).zip(b.iter())
+            { *o = *a ^ *b; }
+        out
+    }
+
+    // The substitution layer of type 1: SB1, SB2, SB3, SB4 repeatedly
+    fn substitute_odd(state: &[u8; 16]) -> [u8; 16]
+    {
+        let mut out = [0_u8; 16];
+        for (i, (o, s)) in out.iter_mut().zip(state.iter()).enumerate()
+        {
+            *o = match i % 4
+            {
+                0 => { Self::SB1[*s as usize] },
+                1 => { Self::SB2[*s as usize] },
+                2 => { Self::SB3[*s as usize] },
+                _ => { Self::SB4[*s as usize] },
+            };
+        }
+        out
+    }
+
+    // The substitution layer of type 2: SB3, SB4, SB1, SB2 repeatedly,
+    // which is the inverse of the substitution layer of type 1
+    fn substitute_even(state: &[u8; 16]) -> [u8; 16]
+    {
+        let mut out = [0_u8; 16];
+        for (i, (o, s)) in out.iter_mut().zip(state.iter()).enumerate()
+        {
+            *o = match i % 4
+            {
+                0 => { Self::SB3[*s as usize] },
+                1 => { Self::SB4[*s as usize] },
+                2 => { Self::SB1[*s as usize] },
+                _ => { Self::SB2[*s as usize] },
+            };
+        }
+        out
+    }
+
+    // The diffusion layer, which is an involution
+    fn diffuse(x: &[u8; 16]) -> [u8; 16]
+    {
+        [
+            x[3] ^ x[4] ^ x[6] ^ x[8] ^ x[9] ^ x[13] ^ x[14],
+            x[2] ^ x[5] ^ x[7] ^ x[8] ^ x[9] ^ x[12] ^ x[15],
+            x[1] ^ x[4] ^ x[6] ^ x[10] ^ x[11] ^ x[12] ^ x[15],
+            x[0] ^ x[5] ^ x[7] ^ x[10] ^ x[11] ^ x[13] ^ x[14],
+            x[0] ^ x[2] ^ x[5] ^ x[8] ^ x[11] ^ x[14] ^ x[15],
+            x[1] ^ x[3] ^ x[4] ^ x[9] ^ x[10] ^ x[14] ^ x[15],
+            x[0] ^ x[2] ^ x[7] ^ x[9] ^ x[10] ^ x[12] ^ x[13],
+            x[1] ^ x[3] ^ x[6] ^ x[8] ^ x[11] ^ x[12] ^ x[13],
+            x[0] ^ x[1] ^ x[4] ^ x[7] ^ x[10] ^ x[13] ^ x[15],
+            x[0] ^ x[1] ^ x[5] ^ x[6] ^ x[11] ^ x[12] ^ x[14],
+            x[2] ^ x[3] ^ x[5] ^ x[6] ^ x[8] ^ x[13] ^ x[15],
+            x[2] ^ x[3] ^ x[4] ^ x[7] ^ x[9] ^ x[12] ^ x[14],
+            x[1] ^ x[2] ^ x[6] ^ x[7] ^ x[9] ^ x[11] ^ x[12],
+            x[0] ^ x[3] ^ x[6] ^ x[7] ^ x[8] ^ x[10] ^ x[13],
+            x[0] ^ x[3] ^ x[4] ^ x[5] ^ x[9] ^ x[11] ^ x[14],
+            x[1] ^ x[2] ^ x[4] ^ x[5] ^ x[8] ^ x[10] ^ x[15]
+        ]
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> Default for ARIA_Generic<NK, ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> BlockCipher for ARIA_Generic<NK, ROUND>
+{
+    const BLOCK_SIZE: usize = 16;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.encrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.decrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+}
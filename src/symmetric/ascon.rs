@@ -0,0 +1,339 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the Ascon permutation and Ascon-AEAD128
+//! lightweight authenticated encryption with associated data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// The official Ascon-AEAD128 defined in NIST SP 800-232
+#[allow(non_camel_case_types)]
+pub type Ascon_AEAD128 = Ascon_AEAD_Generic;
+
+/// The round constants of the Ascon permutation. The permutation with
+/// `r` rounds uses the last `r` constants.
+const ROUND_CONSTANT: [u64; 16] = [ 0x3c, 0x2d, 0x1e, 0x0f, 0xf0, 0xe1, 0xd2, 0xc3,
+                                    0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b ];
+
+// pub(crate) fn ascon_permute(state: &mut [u64; 5], round: usize)
+/// Applies the Ascon permutation with `round` rounds to `state`.
+/// `round` must not be greater than `16`.
+pub(crate) fn ascon_permute(state: &mut [u64; 5], round: usize)
+{
+    let [mut x0, mut x1, mut x2, mut x3, mut x4] = *state;
+    for &c in ROUND_CONSTANT[16 - round..].iter()
+    {
+        // constant addition layer
+        x2 ^= c;
+
+        // substitution layer
+        x0 ^= x4;
+        x4 ^= x3;
+        x2 ^= x1;
+        let t0 = !x0 & x1;
+        let t1 = !x1 & x2;
+        let t2 = !x2 & x3;
+        let t3 = !x3 & x4;
+        let t4 = !x4 & x0;
+        x0 ^= t1;
+        x1 ^= t2;
+        x2 ^= t3;
+        x3 ^= t4;
+        x4 ^= t0;
+        x1 ^= x0;
+        x0 ^= x4;
+        x3 ^= x2;
+        x2 = !x2;
+
+        // linear diffusion layer
+        x0 ^= x0.rotate_right(19) ^ x0.rotate_right(28);
+        x1 ^= x1.rotate_right(61) ^ x1.rotate_right(39);
+        x2 ^= x2.rotate_right(1) ^ x2.rotate_right(6);
+        x3 ^= x3.rotate_right(10) ^ x3.rotate_right(17);
+        x4 ^= x4.rotate_right(7) ^ x4.rotate_right(41);
+    }
+    *state = [x0, x1, x2, x3, x4];
+}
+
+// fn xor_byte(state: &mut [u64; 5], pos: usize, byte: u8)
+/// XORs `byte` into the `pos`-th byte of `state` in little endianness.
+#[inline]
+fn xor_byte(state: &mut [u64; 5], pos: usize, byte: u8)
+{
+    state[pos >> 3] ^= (byte as u64) << ((pos & 7) << 3);
+}
+
+// fn get_byte(state: &[u64; 5], pos: usize) -> u8
+/// Gets the `pos`-th byte of `state` in little endianness.
+#[inline]
+fn get_byte(state: &[u64; 5], pos: usize) -> u8
+{
+    (state[pos >> 3] >> ((pos & 7) << 3)) as u8
+}
+
+/// Ascon-AEAD128 lightweight authenticated encryption with associated data
+///
+/// # Introduction
+/// Ascon is a family of sponge-based lightweight cryptographic algorithms
+/// which was selected by NIST for the lightweight cryptography
+/// standardization. Its state consists of five 64-bit words and is
+/// transformed by the Ascon permutation. Ascon-AEAD128 takes a 128-bit key
+/// and a 128-bit nonce, absorbs the associated data and the message
+/// 16 bytes at a time, and produces a 128-bit tag.
+///
+/// # Vulnerability
+/// Never reuse the same nonce with the same key. If a nonce is reused,
+/// the XOR of the two messages is leaked for their common length.
+///
+/// # Generic Parameters
+/// - IV: the initial value that is the first word of the initial state.
+///   The default value is `0x00001000808c0001` for Ascon-AEAD128.
+/// - ROUND_A: the number of rounds of the permutation for the
+///   initialization and the finalization. The default value is `12`.
+/// - ROUND_B: the number of rounds of the permutation for processing the
+///   associated data and the message. The default value is `8`.
+///
+/// You can make your own variant by changing the generic parameters.
+/// However, ROUND_A and ROUND_B cannot be greater than `16`.
+///
+/// # Byte order
+/// The bytes are loaded into and stored from the words of the state in
+/// little endianness as NIST SP 800-232 specifies.
+///
+/// # Reference
+/// Read [NIST SP 800-232](https://doi.org/10.6028/NIST.SP.800-232)
+/// about Ascon in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::Ascon_AEAD128;
+///
+/// let mut key = [0_u8; 16];
+/// let mut nonce = [0_u8; 16];
+/// for i in 0..16
+/// {
+///     key[i] = i as u8;
+///     nonce[i] = 0x10 + i as u8;
+/// }
+/// let mut aead = Ascon_AEAD128::new_with_key(key);
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = aead.encrypt_into_vec(nonce, &[], &[], &mut cipher);
+/// let mut txt = String::new();
+/// for t in tag
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "4F9C278211BEC9316BF68F46EE8B2EC6");
+///
+/// let aad = "sensor-07".as_bytes();
+/// let message = "temperature=21.5;humidity=40";
+/// let tag = aead.encrypt_into_vec(nonce, aad, message.as_bytes(), &mut cipher);
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(aead.decrypt_into_vec(nonce, aad, &cipher, tag, &mut recovered));
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+///
+/// cipher[0] ^= 1;
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(!aead.decrypt_into_vec(nonce, aad, &cipher, tag, &mut recovered));
+/// assert!(recovered.is_empty());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct Ascon_AEAD_Generic<const IV: u64 = 0x00001000808c0001,
+                            const ROUND_A: usize = 12, const ROUND_B: usize = 8>
+{
+    key: [u64; 2],
+}
+
+impl<const IV: u64, const ROUND_A: usize, const ROUND_B: usize>
+Ascon_AEAD_Generic<IV, ROUND_A, ROUND_B>
+{
+    const RATE: usize = 16;
+
+    /// Constructs a new object Ascon_AEAD_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    ///
+    /// # Panics
+    /// It panics if ROUND_A or ROUND_B is `0` or greater than `16`.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 16])
+    }
+
+    /// Constructs a new object Ascon_AEAD_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 16 elements.
+    ///
+    /// # Panics
+    /// It panics if ROUND_A or ROUND_B is `0` or greater than `16`.
+    pub fn new_with_key(key: [u8; 16]) -> Self
+    {
+        if (ROUND_A == 0) || (ROUND_A > 16) || (ROUND_B == 0) || (ROUND_B > 16)
+            { panic!("ROUND_A and ROUND_B should be in the range from 1 to 16."); }
+        let mut aead = Self { key: [0_u64; 2] };
+        aead.set_key(key);
+        aead
+    }
+
+    /// Sets the key.
+    pub fn set_key(&mut self, key: [u8; 16])
+    {
+        let mut k = [0_u8; 8];
+        for i in 0..2
+        {
+            k.copy_from_slice(&key[i * 8..i * 8 + 8]);
+            self.key[i] = u64::from_le_bytes(k);
+        }
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 128-bit nonce. It must be unique for the same key.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the 16-byte authentication tag.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 16], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+    {
+        let mut state = self.initialize(nonce, aad);
+        cipher.clear();
+        cipher.reserve(message.len());
+        let mut blocks = message.chunks_exact(Self::RATE);
+        for block in blocks.by_ref()
+        {
+            for (i, &b) in block.iter().enumerate()
+            {
+                xor_byte(&mut state, i, b);
+                cipher.push(get_byte(&state, i));
+            }
+            ascon_permute(&mut state, ROUND_B);
+        }
+        let last = blocks.remainder();
+        for (i, &b) in last.iter().enumerate()
+        {
+            xor_byte(&mut state, i, b);
+            cipher.push(get_byte(&state, i));
+        }
+        xor_byte(&mut state, last.len(), 0x01);
+        self.finalize(state)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 128-bit nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the 16-byte authentication tag.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty.
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 16], aad: &[u8], cipher: &[u8], tag: [u8; 16], message: &mut Vec<u8>) -> bool
+    {
+        let mut state = self.initialize(nonce, aad);
+        message.clear();
+        message.reserve(cipher.len());
+        let mut blocks = cipher.chunks_exact(Self::RATE);
+        for block in blocks.by_ref()
+        {
+            for (i, &c) in block.iter().enumerate()
+            {
+                let s = get_byte(&state, i);
+                message.push(s ^ c);
+                xor_byte(&mut state, i, s ^ c);
+            }
+            ascon_permute(&mut state, ROUND_B);
+        }
+        let last = blocks.remainder();
+        for (i, &c) in last.iter().enumerate()
+        {
+            let s = get_byte(&state, i);
+            message.push(s ^ c);
+            xor_byte(&mut state, i, s ^ c);
+        }
+        xor_byte(&mut state, last.len(), 0x01);
+        if tags_equal(&self.finalize(state), &tag)
+            { return true; }
+        for m in message.iter_mut()
+            { *m = 0; }
+        message.clear();
+        false
+    }
+
+    // fn initialize(&self, nonce: [u8; 16], aad: &[u8]) -> [u64; 5]
+    /// Initializes the state with the key and `nonce`, absorbs `aad`, and
+    /// applies the domain separation.
+    fn initialize(&self, nonce: [u8; 16], aad: &[u8]) -> [u64; 5]
+    {
+        let mut n = [0_u8; 8];
+        n.copy_from_slice(&nonce[..8]);
+        let n0 = u64::from_le_bytes(n);
+        n.copy_from_slice(&nonce[8..]);
+        let n1 = u64::from_le_bytes(n);
+        let mut state = [IV, self.key[0], self.key[1], n0, n1];
+        ascon_permute(&mut state, ROUND_A);
+        state[3] ^= self.key[0];
+        state[4] ^= self.key[1];
+
+        if !aad.is_empty()
+        {
+            let mut blocks = aad.chunks_exact(Self::RATE);
+            for block in blocks.by_ref()
+            {
+                for (i, &b) in block.iter().enumerate()
+                    { xor_byte(&mut state, i, b); }
+                ascon_permute(&mut state, ROUND_B);
+            }
+            let last = blocks.remainder();
+            for (i, &b) in last.iter().enumerate()
+                { xor_byte(&mut state, i, b); }
+            xor_byte(&mut state, last.len(), 0x01);
+            ascon_permute(&mut state, ROUND_B);
+        }
+        state[4] ^= 1 << 63;
+        state
+    }
+
+    // fn finalize(&self, state: [u64; 5]) -> [u8; 16]
+    /// Finalizes the state and returns the tag.
+    fn finalize(&self, mut state: [u64; 5]) -> [u8; 16]
+    {
+        state[2] ^= self.key[0];
+        state[3] ^= self.key[1];
+        ascon_permute(&mut state, ROUND_A);
+        let mut tag = [0_u8; 16];
+        tag[..8].copy_from_slice(&(state[3] ^ self.key[0]).to_le_bytes());
+        tag[8..].copy_from_slice(&(state[4] ^ self.key[1]).to_le_bytes());
+        tag
+    }
+}
+
+impl<const IV: u64, const ROUND_A: usize, const ROUND_B: usize> Default for Ascon_AEAD_Generic<IV, ROUND_A, ROUND_B>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
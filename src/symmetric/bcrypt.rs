@@ -0,0 +1,313 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains bcrypt password hashing
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+use std::string::String;
+
+use crate::symmetric::Blowfish;
+use crate::symmetric::poly1305::tags_equal;
+use crate::random::Random;
+
+
+/// The version prefixes of the hash strings of bcrypt
+///
+/// All the versions make the same hash for the same password, the same
+/// cost and the same salt. They differ only in the prefix of the hash
+/// string, which tells which implementation made the hash. This module
+/// takes at most 72 bytes of the password for all the versions, which is
+/// the behavior of the fixed implementations.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BcryptVersion
+{
+    /// `$2a$` --- The original version of OpenBSD, which specifies that the
+    /// password is encoded in UTF-8 and ends with the null byte.
+    V2a,
+
+    /// `$2b$` --- The version of OpenBSD since 2014, which fixed the bug
+    /// of the passwords longer than 255 bytes. It is the default version.
+    V2b,
+
+    /// `$2y$` --- The version of crypt_blowfish and PHP since 2011, which
+    /// fixed the bug of the sign extension of the non-ASCII bytes.
+    V2y,
+}
+
+impl BcryptVersion
+{
+    fn prefix(self) -> &'static str
+    {
+        match self
+        {
+            BcryptVersion::V2a => { "$2a$" },
+            BcryptVersion::V2b => { "$2b$" },
+            BcryptVersion::V2y => { "$2y$" },
+        }
+    }
+}
+
+/// The password hashing bcrypt on top of the expensive key schedule of
+/// `Blowfish`
+///
+/// # Introduction
+/// bcrypt was designed by Niels Provos and David Mazieres in 1999 for
+/// OpenBSD. It makes the state of Blowfish with EksBlowfish from the
+/// password, the salt of 16 bytes and the cost, and then encrypts the
+/// string "OrpheanBeholderScryDoubt" 64 times in ECB mode. The first 23
+/// bytes of the result are the hash. The cost makes the hashing `2^cost`
+/// times slower, so that you can raise it as the computers become faster.
+///
+/// The hash string has the format `$2b$<cost>$<salt><hash>`, where
+/// `<cost>` is two decimal digits, and `<salt>` and `<hash>` are encoded
+/// in the base64 of bcrypt, which has the alphabet
+/// `./A-Za-z0-9` and no padding, into 22 and 31 characters respectively.
+/// `Bcrypt` reads and writes the versions `$2a$`, `$2b$` and `$2y$`.
+///
+/// # Vulnerability
+/// - Only the first 72 bytes of the password are used. The longer
+///   passwords whose first 72 bytes are the same have the same hash.
+/// - The cost should be chosen so that hashing takes at least about 100
+///   milliseconds on your server. The cost `10` or more is recommended.
+///
+/// # Reference
+/// Read [more](https://en.wikipedia.org/wiki/Bcrypt) about bcrypt in
+/// detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+///
+/// let hash = Bcrypt::hash_with_salt(BcryptVersion::V2a, "U*U".as_bytes(), 5, [0x10_u8, 0x41, 0x04, 0x10, 0x41, 0x04, 0x10, 0x41, 0x04, 0x10, 0x41, 0x04, 0x10, 0x41, 0x04, 0x10]);
+/// assert_eq!(hash, "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW");
+/// assert!(Bcrypt::verify("U*U".as_bytes(), &hash));
+/// assert!(!Bcrypt::verify("U*V".as_bytes(), &hash));
+///
+/// // With the random salt
+/// let hash = Bcrypt::hash("correct horse battery staple".as_bytes(), 4);
+/// assert!(Bcrypt::verify("correct horse battery staple".as_bytes(), &hash));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Bcrypt;
+
+impl Bcrypt
+{
+    const ALPHABET: &'static [u8; 64] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+    /// Hashes the password with the random salt in the version `$2b$`.
+    ///
+    /// # Arguments
+    /// - `password` is the password in bytes. Only the first 72 bytes
+    ///   are used.
+    /// - `cost` is the logarithm of the number of the iterations to the base
+    ///   2, from `4` up to `31`.
+    ///
+    /// # Output
+    /// It returns the hash string of 60 characters.
+    ///
+    /// # Panics
+    /// It panics if `cost` is less than `4` or more than `31`.
+    pub fn hash(password: &[u8], cost: u32) -> String
+    {
+        let salt = Random::new().random_u128().to_le_bytes();
+        Self::hash_with_salt(BcryptVersion::V2b, password, cost, salt)
+    }
+
+    /// Hashes the password with the given salt in the given version.
+    ///
+    /// # Arguments
+    /// - `version` is the version prefix of the hash string.
+    /// - `password` is the password in bytes. Only the first 72 bytes
+    ///   are used.
+    /// - `cost` is the logarithm of the number of the iterations to the base
+    ///   2, from `4` up to `31`.
+    /// - `salt` is the salt of 16 bytes, which should be random and
+    ///   different for each password.
+    ///
+    /// # Output
+    /// It returns the hash string of 60 characters.
+    ///
+    /// # Panics
+    /// It panics if `cost` is less than `4` or more than `31`.
+    pub fn hash_with_salt(version: BcryptVersion, password: &[u8], cost: u32, salt: [u8; 16]) -> String
+    {
+        let digest = Self::digest(password, cost, salt);
+        let mut txt = String::with_capacity(60);
+        txt.push_str(version.prefix());
+        txt.push_str(&format!("{:02}$", cost));
+        txt.push_str(&Self::encode_base64(&salt));
+        txt.push_str(&Self::encode_base64(&digest));
+        txt
+    }
+
+    /// Verifies the password against the hash string.
+    ///
+    /// # Arguments
+    /// - `password` is the password in bytes.
+    /// - `hash` is the hash string of the version `$2a$`, `$2b$` or `$2y$`.
+    ///
+    /// # Output
+    /// It returns `true` if the password matches the hash string, and
+    /// `false` if it does not match or if the hash string is malformed.
+    /// The hashes are compared in constant time.
+    pub fn verify(password: &[u8], hash: &str) -> bool
+    {
+        match Self::parse(hash)
+        {
+            Some((_, cost, salt, digest)) => { tags_equal(&Self::digest(password, cost, salt), &digest) },
+            None => { false },
+        }
+    }
+
+    /// Parses the hash string into its components.
+    ///
+    /// # Arguments
+    /// `hash` is the hash string of the version `$2a$`, `$2b$` or `$2y$`.
+    ///
+    /// # Output
+    /// It returns the version, the cost, the salt and the hash of 23 bytes
+    /// in `Some`, or `None` if the hash string is malformed.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ Bcrypt, BcryptVersion };
+    ///
+    /// let (version, cost, _, _) = Bcrypt::parse("$2y$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW").unwrap();
+    /// assert_eq!(version, BcryptVersion::V2y);
+    /// assert_eq!(cost, 5);
+    /// assert!(Bcrypt::parse("$2x$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW").is_none());
+    /// ```
+    pub fn parse(hash: &str) -> Option<(BcryptVersion, u32, [u8; 16], [u8; 23])>
+    {
+        // A valid hash string consists of ASCII characters only, so that it
+        // can be sliced at any byte offset without panicking.
+        let bytes = hash.as_bytes();
+        if bytes.len() != 60 || !hash.is_ascii() || bytes[6] != b'$'
+            { return None; }
+        let version = match &bytes[..4]
+        {
+            b"$2a$" => { BcryptVersion::V2a },
+            b"$2b$" => { BcryptVersion::V2b },
+            b"$2y$" => { BcryptVersion::V2y },
+            _ => { return None; },
+        };
+        if !bytes[4].is_ascii_digit() || !bytes[5].is_ascii_digit()
+            { return None; }
+        let cost = ((bytes[4] - b'0') * 10 + (bytes[5] - b'0')) as u32;
+        if !(4..=31).contains(&cost)
+            { return None; }
+        let salt: [u8; 16] = Self::decode_base64(&hash[7..29])?.try_into().ok()?;
+        let digest: [u8; 23] = Self::decode_base64(&hash[29..])?.try_into().ok()?;
+        Some((version, cost, salt, digest))
+    }
+
+    /// Computes the raw hash of 23 bytes of the password.
+    ///
+    /// # Arguments
+    /// - `password` is the password in bytes. Only the first 72 bytes
+    ///   are used.
+    /// - `cost` is the logarithm of the number of the iterations to the base
+    ///   2, from `4` up to `31`.
+    /// - `salt` is the salt of 16 bytes.
+    ///
+    /// # Output
+    /// It returns the first 23 bytes of the encrypted
+    /// "OrpheanBeholderScryDoubt".
+    ///
+    /// # Panics
+    /// It panics if `cost` is less than `4` or more than `31`.
+    pub fn digest(password: &[u8], cost: u32, salt: [u8; 16]) -> [u8; 23]
+    {
+        assert!((4..=31).contains(&cost), "The cost of bcrypt should be from 4 up to 31.");
+        // The password ends with the null byte, and at most 72 bytes of it
+        // are used as the key.
+        let mut key = Vec::with_capacity(password.len() + 1);
+        key.extend_from_slice(password);
+        key.push(0);
+        key.truncate(72);
+
+        let blowfish = Blowfish::new_with_eks_key(cost, &salt, &key);
+        let mut words = [0_u32; 6];
+        for (w, b) in words.iter_mut().zip(b"OrpheanBeholderScryDoubt".chunks_exact(4))
+            { *w = u32::from_be_bytes([b[0], b[1], b[2], b[3]]); }
+        for _ in 0..64
+        {
+            for pair in words.chunks_exact_mut(2)
+                { (pair[0], pair[1]) = blowfish.encrypt_words(pair[0], pair[1]); }
+        }
+
+        let mut out = [0_u8; 24];
+        for (o, w) in out.chunks_exact_mut(4).zip(words.iter())
+            { o.copy_from_slice(&w.to_be_bytes()); }
+        let mut digest = [0_u8; 23];
+        digest.copy_from_slice(&out[..23]);
+        digest
+    }
+
+    /// Encodes the bytes in the base64 of bcrypt.
+    ///
+    /// # Arguments
+    /// `data` is the bytes to be encoded.
+    ///
+    /// # Output
+    /// It returns the encoded string without padding. Its alphabet is
+    /// `./A-Za-z0-9`, which is different from that of the standard base64.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::Bcrypt;
+    ///
+    /// assert_eq!(Bcrypt::encode_base64(&[0x10_u8, 0x41, 0x04]), "CCCC");
+    /// assert_eq!(Bcrypt::decode_base64("CCCC").unwrap(), vec![0x10_u8, 0x41, 0x04]);
+    /// ```
+    pub fn encode_base64(data: &[u8]) -> String
+    {
+        let mut txt = String::with_capacity((data.len() * 4).div_ceil(3));
+        for chunk in data.chunks(3)
+        {
+            let mut buf = [0_u8; 3];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            let n = ((buf[0] as u32) << 16) | ((buf[1] as u32) << 8) | (buf[2] as u32);
+            for i in 0..=chunk.len()
+                { txt.push(Self::ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize] as char); }
+        }
+        txt
+    }
+
+    /// Decodes the string in the base64 of bcrypt.
+    ///
+    /// # Arguments
+    /// `txt` is the string encoded in the base64 of bcrypt without padding.
+    ///
+    /// # Output
+    /// It returns the decoded bytes in `Some`, or `None` if `txt` has a
+    /// character out of the alphabet or if its length is not valid.
+    pub fn decode_base64(txt: &str) -> Option<Vec<u8>>
+    {
+        let bytes = txt.as_bytes();
+        if bytes.len() % 4 == 1
+            { return None; }
+        let mut data = Vec::with_capacity(bytes.len() * 3 / 4);
+        for chunk in bytes.chunks(4)
+        {
+            let mut n = 0_u32;
+            for (i, c) in chunk.iter().enumerate()
+            {
+                let v = Self::ALPHABET.iter().position(|a| a == c)? as u32;
+                n |= v << (18 - 6 * i);
+            }
+            for i in 0..chunk.len() - 1
+                { data.push((n >> (16 - 8 * i)) as u8); }
+        }
+        Some(data)
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Camellia block cipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::BlockCipher;
+
+
+/// The official Camellia-128 symmetric-key algorithm with 128-bit key
+#[allow(non_camel_case_types)]
+pub type CAMELLIA_128 = CAMELLIA_Generic<4>;
+
+/// The official Camellia-192 symmetric-key algorithm with 192-bit key
+#[allow(non_camel_case_types)]
+pub type CAMELLIA_192 = CAMELLIA_Generic<6>;
+
+/// The official Camellia-256 symmetric-key algorithm with 256-bit key
+#[allow(non_camel_case_types)]
+pub type CAMELLIA_256 = CAMELLIA_Generic<8>;
+
+/// A Camellia symmetric-key algorithm for the encryption of digital data
+///
+/// # Introduction
+/// Camellia was developed by Mitsubishi Electric and NTT of Japan in 2000,
+/// and is standardized in RFC 3713 and ISO/IEC 18033-3. It is recommended
+/// by CRYPTREC of Japan and NESSIE of Europe, and is used in the cipher
+/// suites of TLS. It is a Feistel network of 128-bit block with 18 rounds
+/// for 128-bit key and 24 rounds for 192-bit and 256-bit keys. After every
+/// six rounds, the logical functions `FL` and `FL^-1` are inserted, and the
+/// whitening keys are XORed at the beginning and at the end.
+///
+/// # Vulnerability
+/// No practical attack against the full rounds of Camellia is known. This
+/// implementation looks up the S-box in memory, so it is not resistant
+/// to cache-timing attacks when an attacker shares the processor with you.
+///
+/// # Generic Parameters
+/// - NK: The length of key in 32-bit words. It is `4` for Camellia-128,
+///   `6` for Camellia-192 and `8` for Camellia-256. The number of rounds
+///   is determined by `NK` because the key schedule of Camellia is
+///   designed for the exact number of rounds.
+///
+/// # Byte order
+/// The block and the key are the sequences of bytes as RFC 3713 describes.
+/// The methods `encrypt_u128()` and `decrypt_u128()` read and write the
+/// block as `u128` in little endianness, just as `AES_Generic` does.
+///
+/// # Reference
+/// Read [RFC 3713](https://www.rfc-editor.org/rfc/rfc3713) about Camellia
+/// in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ CAMELLIA_128, ModeCipher, OperationMode };
+///
+/// let key = [ 0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+///             0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10 ];
+/// let mut a_camellia = CAMELLIA_128::new_with_key(&key);
+/// let message = u128::from_le_bytes(key);
+/// let cipher = a_camellia.encrypt_u128(message);
+/// assert_eq!(cipher.to_le_bytes(), [ 0x67_u8, 0x67, 0x31, 0x38, 0x54, 0x96, 0x69, 0x73,
+///                                    0x08, 0x57, 0x06, 0x56, 0x48, 0xEA, 0xBE, 0x43 ]);
+/// assert_eq!(a_camellia.decrypt_u128(cipher), message);
+///
+/// // All the modes of operation and the padding schemes by ModeCipher
+/// let iv = [0x0F_u8; 16];
+/// let mut cbc = ModeCipher::new(CAMELLIA_128::new_with_key(&key), OperationMode::CBC, &iv);
+/// let cipher = cbc.encrypt("In the beginning God created the heavens and the earth.".as_bytes());
+/// assert_eq!(cbc.decrypt(&cipher).unwrap(), "In the beginning God created the heavens and the earth.".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct CAMELLIA_Generic<const NK: usize = 4>
+{
+    encryption_key: Vec<u64>,
+    decryption_key: Vec<u64>,
+}
+
+impl<const NK: usize> CAMELLIA_Generic<NK>
+{
+    const SBOX1: [u8; 256] = [
+        0x70, 0x82, 0x2C, 0xEC, 0xB3, 0x27, 0xC0, 0xE5, 0xE4, 0x85, 0x57, 0x35, 0xEA, 0x0C, 0xAE, 0x41,
+        0x23, 0xEF, 0x6B, 0x93, 0x45, 0x19, 0xA5, 0x21, 0xED, 0x0E, 0x4F, 0x4E, 0x1D, 0x65, 0x92, 0xBD,
+        0x86, 0xB8, 0xAF, 0x8F, 0x7C, 0xEB, 0x1F, 0xCE, 0x3E, 0x30, 0xDC, 0x5F, 0x5E, 0xC5, 0x0B, 0x1A,
+        0xA6, 0xE1, 0x39, 0xCA, 0xD5, 0x47, 0x5D, 0x3D, 0xD9, 0x01, 0x5A, 0xD6, 0x51, 0x56, 0x6C, 0x4D,
+        0x8B, 0x0D, 0x9A, 0x66, 0xFB, 0xCC, 0xB0, 0x2D, 0x74, 0x12, 0x2B, 0x20, 0xF0, 0xB1, 0x84, 0x99,
+        0xDF, 0x4C, 0xCB, 0xC2, 0x34, 0x7E, 0x76, 0x05, 0x6D, 0xB7, 0xA9, 0x31, 0xD1, 0x17, 0x04, 0xD7,
+        0x14, 0x58, 0x3A, 0x61, 0xDE, 0x1B, 0x11, 0x1C, 0x32, 0x0F, 0x9C, 0x16, 0x53, 0x18, 0xF2, 0x22,
+        0xFE, 0x44, 0xCF, 0xB2, 0xC3, 0xB5, 0x7A, 0x91, 0x24, 0x08, 0xE8, 0xA8, 0x60, 0xFC, 0x69, 0x50,
+        0xAA, 0xD0, 0xA0, 0x7D, 0xA1, 0x89, 0x62, 0x97, 0x54, 0x5B, 0x1E, 0x95, 0xE0, 0xFF, 0x64, 0xD2,
+        0x10, 0xC4, 0x00, 0x48, 0xA3, 0xF7, 0x75, 0xDB, 0x8A, 0x03, 0xE6, 0xDA, 0x09, 0x3F, 0xDD, 0x94,
+        0x87, 0x5C, 0x83, 0x02, 0xCD, 0x4A, 0x90, 0x33, 0x73, 0x67, 0xF6, 0xF3, 0x9D, 0x7F, 0xBF, 0xE2,
+        0x52, 0x9B, 0xD8, 0x26, 0xC8, 0x37, 0xC6, 0x3B, 0x81, 0x96, 0x6F, 0x4B, 0x13, 0xBE, 0x63, 0x2E,
+        0xE9, 0x79, 0xA7, 0x8C, 0x9F, 0x6E, 0xBC, 0x8E, 0x29, 0xF5, 0xF9, 0xB6, 0x2F, 0xFD, 0xB4, 0x59,
+        0x78, 0x98, 0x06, 0x6A, 0xE7, 0x46, 0x71, 0xBA, 0xD4, 0x25, 0xAB, 0x42, 0x88, 0xA2, 0x8D, 0xFA,
+        0x72, 0x07, 0xB9, 0x55, 0xF8, 0xEE, 0xAC, 0x0A, 0x36, 0x49, 0x2A, 0x68, 0x3C, 0x38, 0xF1, 0xA4,
+        0x40, 0x28, 0xD3, 0x7B, 0xBB, 0xC9, 0x43, 0xC1, 0x15, 0xE3, 0xAD, 0xF4, 0x77, 0xC7, 0x80, 0x9E
+    ];
+
+    // The constants for the key schedule, which are the fractional parts
+    // of the square roots of the first six prime numbers
+    const SIGMA: [u64; 6] = [
+        0xA09E667F3BCC908B, 0xB67AE8584CAA73B2, 0xC6EF372FE94F82BE,
+        0x54FF53A5F1D36F1C, 0x10E527FADE682D1D, 0xB05688C2B3E6C1FD
+    ];
+
+    const ROUND: usize = if NK == 4 { 18 } else { 24 };
+
+    /// Constructs a new object CAMELLIA_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self::new_with_key(&vec![0_u8; NK * 4])
+    }
+
+    /// Constructs a new object CAMELLIA_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements,
+    /// that is, 16 bytes for Camellia-128, 24 bytes for Camellia-192 and
+    /// 32 bytes for Camellia-256.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        let mut camellia = Self { encryption_key: Vec::new(), decryption_key: Vec::new() };
+        camellia.set_key(key);
+        camellia
+    }
+
+    /// Sets the key and makes the subkeys.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`, or if `NK` is not
+    /// `4`, `6` or `8`.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        assert!(NK == 4 || NK == 6 || NK == 8, "NK of CAMELLIA_Generic should be 4, 6 or 8.");
+        assert_eq!(key.len(), NK * 4, "The key of CAMELLIA_Generic<{}> should be {} bytes long.", NK, NK * 4);
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(&key[..16]);
+        let kl = u128::from_be_bytes(buf);
+        let kr = match NK
+        {
+            4 => { 0_u128 },
+            6 => {
+                let mut buf = [0_u8; 8];
+                buf.copy_from_slice(&key[16..]);
+                let right = u64::from_be_bytes(buf);
+                ((right as u128) << 64) | (!right as u128)
+            },
+            _ => {
+                buf.copy_from_slice(&key[16..]);
+                u128::from_be_bytes(buf)
+            },
+        };
+
+        let ka = Self::make_ka(kl, kr);
+        let pairs = if NK == 4
+        {
+            vec![(kl, 0), (ka, 0), (kl, 15), (ka, 15), (ka, 30), (kl, 45), (ka, 45),
+                 (ka, 60), (kl, 77), (kl, 94), (ka, 94), (kl, 111), (ka, 111)]
+        }
+        else
+        {
+            let kb = Self::make_kb(ka, kr);
+            vec![(kl, 0), (kb, 0), (kr, 15), (ka, 15), (kr, 30), (kb, 30),
+                 (kl, 45), (ka, 45), (kl, 60), (kr, 60), (kb, 60), (kl, 77),
+                 (ka, 77), (kr, 94), (ka, 94), (kl, 111), (kb, 111)]
+        };
+        let mut keys = Vec::with_capacity(pairs.len() * 2);
+        for (x, r) in pairs
+        {
+            let x: u128 = x.rotate_left(r);
+            keys.push((x >> 64) as u64);
+            keys.push(x as u64);
+        }
+        // For Camellia-128, k10 is the right half of KL <<< 60, not of
+        // KA <<< 45.
+        if NK == 4
+            { keys[13] = kl.rotate_left(60) as u64; }
+
+        // The decryption uses the subkeys in the reverse order, but each
+        // pair of the whitening keys keeps its order.
+        let mut decryption_key = keys.clone();
+        decryption_key.reverse();
+        let len = decryption_key.len();
+        decryption_key.swap(0, 1);
+        decryption_key.swap(len - 2, len - 1);
+        self.encryption_key = keys;
+        self.decryption_key = decryption_key;
+    }
+
+    fn make_ka(kl: u128, kr: u128) -> u128
+    {
+        let d = kl ^ kr;
+        let (mut d1, mut d2) = ((d >> 64) as u64, d as u64);
+        d2 ^= Self::f(d1, Self::SIGMA[0]);
+        d1 ^= Self::f(d2, Self::SIGMA[1]);
+        d1 ^= (kl >> 64) as u64;
+        d2 ^= kl as u64;
+        d2 ^= Self::f(d1, Self::SIGMA[2]);
+        d1 ^= Self::f(d2, Self::SIGMA[3]);
+        ((d1 as u128) << 64) | (d2 as u128)
+    }
+
+    fn make_kb(ka: u128, kr: u128) -> u128
+    {
+        let d = ka ^ kr;
+        let (mut d1, mut d2) = ((d >> 64) as u64, d as u64);
+        d2 ^= Self::f(d1, Self::SIGMA[4]);
+        d1 ^= Self::f(d2, Self::SIGMA[5]);
+        ((d1 as u128) << 64) | (d2 as u128)
+    }
+
+    /// Encrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `message` is the block to be encrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the encrypted block in little endianness.
+    pub fn encrypt_u128(&mut self, message: u128) -> u128
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Decrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `cipher` is the block to be decrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the decrypted block in little endianness.
+    pub fn decrypt_u128(&mut self, cipher: u128) -> u128
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Encrypts the array of 128-bit data blocks independently of one another.
+    pub fn encrypt_array_u128<const N: usize>(&mut self, message: &[u128; N], cipher: &mut [u128; N])
+    {
+        for (c, m) in cipher.iter_mut().zip(message.iter())
+            { *c = self.encrypt_u128(*m); }
+    }
+
+    /// Decrypts the array of 128-bit data blocks independently of one another.
+    pub fn decrypt_array_u128<const N: usize>(&mut self, cipher: &[u128; N], message: &mut [u128; N])
+    {
+        for (m, c) in message.iter_mut().zip(cipher.iter())
+            { *m = self.decrypt_u128(*c); }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::crypt(block, &self.encryption_key);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16])
+    {
+        Self::crypt(block, &self.decryption_key);
+    }
+
+    // Encryption and decryption are the same except the order of subkeys.
+    fn crypt(block: &mut [u8; 16], key: &[u64])
+    {
+        let state = u128::from_be_bytes(*block);
+        let mut d1 = (state >> 64) as u64 ^ key[0];
+        let mut d2 = state as u64 ^ key[1];
+        let mut idx = 2;
+        for group in 0..Self::ROUND / 6
+        {
+            if group > 0
+            {
+                d1 = Self::fl(d1, key[idx]);
+                d2 = Self::fl_inv(d2, key[idx + 1]);
+                idx += 2;
+            }
+            for _ in 0..3
+            {
+                d2 ^= Self::f(d1, key[idx]);
+                d1 ^= Self::f(d2, key[idx + 1]);
+                idx += 2;
+            }
+        }
+        d2 ^= key[idx];
+        d1 ^= key[idx + 1];
+        *block = (((d2 as u128) << 64) | (d1 as u128)).to_be_bytes();
+    }
+
+    // The round function F, which consists of the S-function and the
+    // P-function
+    fn f(input: u64, key: u64) -> u64
+    {
+        let x = (input ^ key).to_be_bytes();
+        let sbox1 = |x: u8| Self::SBOX1[x as usize];
+        let sbox2 = |x: u8| sbox1(x).rotate_left(1);
+        let sbox3 = |x: u8| sbox1(x).rotate_right(1);
+        let sbox4 = |x: u8| sbox1(x.rotate_left(1));
+        let t = [sbox1(x[0]), sbox2(x[1]), sbox3(x[2]), sbox4(x[3]),
+                 sbox2(x[4]), sbox3(x[5]), sbox4(x[6]), sbox1(x[7])];
+        u64::from_be_bytes([
+            t[0] ^ t[2] ^ t[3] ^ t[5] ^ t[6] ^ t[7],
+            t[0] ^ t[1] ^ t[3] ^ t[4] ^ t[6] ^ t[7],
+            t[0] ^ t[1] ^ t[2] ^ t[4] ^ t[5] ^ t[7],
+            t[1] ^ t[2] ^ t[3] ^ t[4] ^ t[5] ^ t[6],
+            t[0] ^ t[1] ^ t[5] ^ t[6] ^ t[7],
+            t[1] ^ t[2] ^ t[4] ^ t[6] ^ t[7],
+            t[2] ^ t[3] ^ t[4] ^ t[5] ^ t[7],
+            t[0] ^ t[3] ^ t[4] ^ t[5] ^ t[6]
+        ])
+    }
+
+    #[inline]
+    fn fl(x: u64, key: u64) -> u64
+    {
+        let (mut x1, mut x2) = ((x >> 32) as u32, x as u32);
+        let (k1, k2) = ((key >> 32) as u32, key as u32);
+        x2 ^= (x1 & k1).rotate_left(1);
+        x1 ^= x2 | k2;
+        ((x1 as u64) << 32) | (x2 as u64)
+    }
+
+    #[inline]
+    fn fl_inv(y: u64, key: u64) -> u64
+    {
+        let (mut y1, mut y2) = ((y >> 32) as u32, y as u32);
+        let (k1, k2) = ((key >> 32) as u32, key as u32);
+        y1 ^= y2 | k2;
+        y2 ^= (y1 & k1).rotate_left(1);
+        ((y1 as u64) << 32) | (y2 as u64)
+    }
+}
+
+impl<const NK: usize> Default for CAMELLIA_Generic<NK>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const NK: usize> BlockCipher for CAMELLIA_Generic<NK>
+{
+    const BLOCK_SIZE: usize = 16;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.encrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.decrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains CBC-MAC and ISO/IEC 9797-1 MAC algorithm 3
+//! (retail MAC)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, DES };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// CBC-MAC with DES, which is the MAC of FIPS 113 and ANSI X9.9
+#[allow(non_camel_case_types)]
+pub type DES_CBC_MAC = CBC_MAC_Generic<DES>;
+
+/// CBC-MAC with AES-128
+#[allow(non_camel_case_types)]
+pub type AES_128_CBC_MAC = CBC_MAC_Generic<AES_128>;
+
+/// ISO/IEC 9797-1 MAC algorithm 3 with DES, which is also known as
+/// ANSI X9.19 retail MAC
+#[allow(non_camel_case_types)]
+pub type Retail_MAC = Retail_MAC_Generic<DES>;
+
+/// The padding methods of ISO/IEC 9797-1 for CBC-MAC and retail MAC
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ISO9797_Padding
+{
+    /// Padding method 1: appends as few zero bytes as possible to make the
+    /// data a positive multiple of the block size. The empty data is padded
+    /// to one block of zeros. It is ambiguous when the length of the data
+    /// varies, because the data with trailing zeros has the same MAC.
+    Method1,
+
+    /// Padding method 2: appends one byte `0x80`, and then as few zero bytes
+    /// as possible to make the data a multiple of the block size.
+    Method2,
+
+    /// Padding method 3: puts one block that has the length of the data in
+    /// bits in big endianness in front of the data, and then appends as few
+    /// zero bytes as possible (possibly none) to make the data a multiple
+    /// of the block size.
+    Method3,
+}
+
+impl ISO9797_Padding
+{
+    pub(crate) fn pad(&self, data: &[u8], block_size: usize) -> Vec<u8>
+    {
+        let mut padded = Vec::<u8>::with_capacity(data.len() + 2 * block_size);
+        if *self == ISO9797_Padding::Method3
+        {
+            let bits = (data.len() as u128) << 3;
+            let length = bits.to_be_bytes();
+            if block_size <= 16
+                { padded.extend_from_slice(&length[16 - block_size..]); }
+            else
+            {
+                padded.resize(block_size - 16, 0);
+                padded.extend_from_slice(&length);
+            }
+        }
+        padded.extend_from_slice(data);
+        if *self == ISO9797_Padding::Method2
+            { padded.push(0x80); }
+        let rest = padded.len() % block_size;
+        if rest != 0
+            { padded.resize(padded.len() + block_size - rest, 0); }
+        if padded.is_empty()
+            { padded.resize(block_size, 0); }
+        padded
+    }
+}
+
+
+/// CBC-MAC on top of any block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// CBC-MAC encrypts the padded data in CBC mode with the zero
+/// initialization vector, and takes the last block of the ciphertext as
+/// the MAC. It is ISO/IEC 9797-1 MAC algorithm 1.
+///
+/// # Vulnerability
+/// CBC-MAC is secure only for messages of one fixed length, or with the
+/// padding method 3. Otherwise, an attacker can forge the MAC of the
+/// concatenation of messages. Use `CMAC_Generic` or `Retail_MAC_Generic`
+/// unless your protocol requires plain CBC-MAC.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///
+/// # Reference
+/// Read [ISO/IEC 9797-1](https://en.wikipedia.org/wiki/ISO/IEC_9797-1)
+/// about CBC-MAC in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ DES, DES_CBC_MAC, ISO9797_Padding };
+///
+/// let key = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+/// let mut mac = DES_CBC_MAC::new(DES::new_with_key(key), ISO9797_Padding::Method1);
+/// let tag = mac.mac("Now is the time for all ".as_bytes());
+/// let mut txt = String::new();
+/// for t in tag.iter()
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "70A30640CC76DD8B");
+/// assert!(mac.verify("Now is the time for all ".as_bytes(), &tag[..4]));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct CBC_MAC_Generic<C: BlockCipher>
+{
+    cipher: C,
+    padding: ISO9797_Padding,
+}
+
+impl<C: BlockCipher> CBC_MAC_Generic<C>
+{
+    /// Constructs a new object CBC_MAC_Generic.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `padding` is the padding method of ISO/IEC 9797-1.
+    pub fn new(cipher: C, padding: ISO9797_Padding) -> Self
+    {
+        Self { cipher, padding }
+    }
+
+    /// Computes the MAC of `data`.
+    ///
+    /// # Output
+    /// It returns the MAC which is as long as the block of `C`. You may
+    /// truncate it to the leftmost bytes as your protocol requires.
+    pub fn mac(&mut self, data: &[u8]) -> Vec<u8>
+    {
+        let padded = self.padding.pad(data, C::BLOCK_SIZE);
+        self.chain(&padded)
+    }
+
+    /// Verifies the MAC of `data` in constant time.
+    ///
+    /// # Arguments
+    /// - `data` is the authenticated data.
+    /// - `tag` is the MAC to be verified. It may be truncated to the
+    ///   leftmost bytes, but should not be empty.
+    ///
+    /// # Output
+    /// It returns `true` if `tag` is correct, and `false` otherwise.
+    pub fn verify(&mut self, data: &[u8], tag: &[u8]) -> bool
+    {
+        let computed = self.mac(data);
+        !tag.is_empty() && tag.len() <= computed.len() && tags_equal(&computed[..tag.len()], tag)
+    }
+
+    fn chain(&mut self, padded: &[u8]) -> Vec<u8>
+    {
+        let mut state = vec![0_u8; C::BLOCK_SIZE];
+        for block in padded.chunks_exact(C::BLOCK_SIZE)
+        {
+            for (s, b) in state.iter_mut().zip(block.iter())
+                { *s ^= *b; }
+            self.cipher.encrypt_one_block(&mut state);
+        }
+        state
+    }
+}
+
+
+/// ISO/IEC 9797-1 MAC algorithm 3, which is known as retail MAC, on top of
+/// any block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// Retail MAC is CBC-MAC with the key `K` whose last block is decrypted
+/// with the second key `K'` and encrypted again with `K`. With DES, the
+/// last block is effectively encrypted with two-key triple DES while the
+/// other blocks are encrypted with single DES, so that it is much stronger
+/// than CBC-MAC with single DES and almost as fast. It is widely used in
+/// banking such as ANSI X9.19 and EMV.
+///
+/// # Vulnerability
+/// The effective key length is about that of single DES against some
+/// attacks with many known MACs. Change the keys regularly.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///
+/// # Reference
+/// Read [ISO/IEC 9797-1](https://en.wikipedia.org/wiki/ISO/IEC_9797-1)
+/// about retail MAC in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ DES, Retail_MAC, ISO9797_Padding };
+///
+/// let key1 = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+/// let key2 = [0xFE_u8, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10];
+/// let mut mac = Retail_MAC::new(DES::new_with_key(key1), DES::new_with_key(key2), ISO9797_Padding::Method2);
+/// let tag = mac.mac("Now is the time for all ".as_bytes());
+/// let mut txt = String::new();
+/// for t in tag.iter()
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "E9086230CA3BE796");
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct Retail_MAC_Generic<C: BlockCipher>
+{
+    cbc_mac: CBC_MAC_Generic<C>,
+    cipher2: C,
+}
+
+impl<C: BlockCipher> Retail_MAC_Generic<C>
+{
+    /// Constructs a new object Retail_MAC_Generic.
+    ///
+    /// # Arguments
+    /// - `cipher1` is the underlying block cipher with the key `K`.
+    /// - `cipher2` is the underlying block cipher with the key `K'` which
+    ///   is used only for the last block.
+    /// - `padding` is the padding method of ISO/IEC 9797-1.
+    pub fn new(cipher1: C, cipher2: C, padding: ISO9797_Padding) -> Self
+    {
+        Self { cbc_mac: CBC_MAC_Generic::new(cipher1, padding), cipher2 }
+    }
+
+    /// Computes the MAC of `data`.
+    ///
+    /// # Output
+    /// It returns the MAC which is as long as the block of `C`. You may
+    /// truncate it to the leftmost bytes as your protocol requires.
+    pub fn mac(&mut self, data: &[u8]) -> Vec<u8>
+    {
+        let mut tag = self.cbc_mac.mac(data);
+        self.cipher2.decrypt_one_block(&mut tag);
+        self.cbc_mac.cipher.encrypt_one_block(&mut tag);
+        tag
+    }
+
+    /// Verifies the MAC of `data` in constant time.
+    ///
+    /// # Arguments
+    /// - `data` is the authenticated data.
+    /// - `tag` is the MAC to be verified. It may be truncated to the
+    ///   leftmost bytes, but should not be empty.
+    ///
+    /// # Output
+    /// It returns `true` if `tag` is correct, and `false` otherwise.
+    pub fn verify(&mut self, data: &[u8], tag: &[u8]) -> bool
+    {
+        let computed = self.mac(data);
+        !tag.is_empty() && tag.len() <= computed.len() && tags_equal(&computed[..tag.len()], tag)
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains CCM and CCM* authenticated encryption with
+//! associated data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256 };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES-128-CCM. TAG is the length of tag in bytes, and NONCE is the length
+/// of nonce in bytes.
+#[allow(non_camel_case_types)]
+pub type AES_128_CCM<const TAG: usize = 16, const NONCE: usize = 13> = CCM_Generic<AES_128, TAG, NONCE>;
+
+/// AES-256-CCM. TAG is the length of tag in bytes, and NONCE is the length
+/// of nonce in bytes.
+#[allow(non_camel_case_types)]
+pub type AES_256_CCM<const TAG: usize = 16, const NONCE: usize = 13> = CCM_Generic<AES_256, TAG, NONCE>;
+
+/// AES-128-CCM* of IEEE 802.15.4 whose nonce is 13 bytes long. TAG is the
+/// length of tag in bytes, which is one of `0`, `4`, `8` and `16`.
+#[allow(non_camel_case_types)]
+pub type AES_128_CCM_Star<const TAG: usize = 8> = CCM_Star_Generic<AES_128, TAG>;
+
+/// CCM (Counter with CBC-MAC) authenticated encryption with associated data
+/// on top of any 128-bit block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// CCM authenticates the nonce, the associated data and the message by
+/// CBC-MAC first, and then encrypts the message and the CBC-MAC value in
+/// counter mode. The length of the message is fixed in the first block of
+/// CBC-MAC, so the whole message should be known before encryption.
+///
+/// CCM* of IEEE 802.15.4, which also allows the tag of zero length, is
+/// implemented separately by [`CCM_Star_Generic`](struct@CCM_Star_Generic).
+///
+/// # Vulnerability
+/// - The nonce must be unique for the same key. Reusing a nonce reveals the
+///   XOR of two messages and breaks the authenticity.
+/// - The short tags such as 4 bytes can be forged with non-negligible
+///   probability by an attacker who can try many times.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+/// - TAG: the length of tag in bytes, which is `M` of RFC 3610. It should be
+///   one of `4`, `6`, `8`, `10`, `12`, `14` and `16`. The default value is
+///   `16`.
+/// - NONCE: the length of nonce in bytes, which is `15 - L` of RFC 3610. It
+///   should be from `7` to `13`. The longer the nonce is, the shorter the
+///   message can be. The message should be shorter than `2^(8 * (15 - NONCE))`
+///   bytes. The default value is `13`.
+///
+/// # Reference
+/// Read [RFC 3610](https://datatracker.ietf.org/doc/html/rfc3610) and
+/// [NIST SP 800-38C](https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38c.pdf)
+/// about CCM, and Annex B of IEEE 802.15.4 about CCM* in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, AES_128_CCM };
+///
+/// let mut key = [0_u8; 16];
+/// for i in 0..16
+///     { key[i] = 0x40 + i as u8; }
+/// let nonce = [0x10_u8, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+/// let aad = [0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+/// let message = [0x20_u8, 0x21, 0x22, 0x23];
+///
+/// let mut ccm = AES_128_CCM::<4, 7>::new(AES_128::new_with_key(&key));
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = ccm.encrypt_into_vec(nonce, &aad, &message, &mut cipher);
+/// let mut txt = String::new();
+/// for c in cipher.iter().chain(tag.iter())
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "7162015B4DAC255D");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(ccm.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+/// assert_eq!(recovered, message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct CCM_Generic<C: BlockCipher, const TAG: usize = 16, const NONCE: usize = 13>
+{
+    cipher: C,
+}
+
+impl<C: BlockCipher, const TAG: usize, const NONCE: usize> CCM_Generic<C, TAG, NONCE>
+{
+    /// Constructs a new object CCM_Generic.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher with the key.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16, if `TAG` is not one of
+    /// `4`, `6`, `8`, `10`, `12`, `14` and `16`, or if `NONCE` is not from
+    /// `7` to `13`.
+    pub fn new(cipher: C) -> Self
+    {
+        assert!((4..=16).contains(&TAG) && TAG & 1 == 0, "TAG of CCM_Generic should be 4, 6, 8, 10, 12, 14 or 16.");
+        Self::with_cipher(cipher)
+    }
+
+    // Checks everything but TAG, which CCM and CCM* restrict differently.
+    fn with_cipher(cipher: C) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 16, "CCM_Generic supports only 128-bit block ciphers.");
+        assert!((7..=13).contains(&NONCE), "NONCE of CCM_Generic should be from 7 to 13.");
+        Self { cipher }
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the nonce of `NONCE` bytes. It must be unique for the
+    ///   same key.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the tag of `TAG` bytes.
+    ///
+    /// # Panics
+    /// It panics if `message` is not shorter than `2^(8 * (15 - NONCE))`
+    /// bytes.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; NONCE], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; TAG]
+    {
+        assert!(Self::is_length_valid(message.len()), "The message is too long for CCM_Generic with this NONCE.");
+        let mac = self.compute_mac(&nonce, aad, message);
+        cipher.clear();
+        cipher.extend_from_slice(message);
+        self.crypt_ctr(&nonce, cipher);
+        self.seal_mac(&nonce, &mac)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the tag of `TAG` bytes.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty.
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; NONCE], aad: &[u8], cipher: &[u8], tag: [u8; TAG], message: &mut Vec<u8>) -> bool
+    {
+        message.clear();
+        if !Self::is_length_valid(cipher.len())
+            { return false; }
+        let mut plain = cipher.to_vec();
+        self.crypt_ctr(&nonce, &mut plain);
+        let mac = self.compute_mac(&nonce, aad, &plain);
+        if !tags_equal(&self.seal_mac(&nonce, &mac), &tag)
+            { return false; }
+        message.extend_from_slice(&plain);
+        true
+    }
+
+    fn is_length_valid(length: usize) -> bool
+    {
+        let l = 15 - NONCE;
+        l >= 8 || (length as u64) < (1_u64 << (8 * l))
+    }
+
+    // Makes the counter block A_i of RFC 3610.
+    fn counter_block(nonce: &[u8; NONCE], counter: u64) -> Vec<u8>
+    {
+        let l = 15 - NONCE;
+        let mut block = vec![0_u8; 16];
+        block[0] = (l - 1) as u8;
+        block[1..1 + NONCE].copy_from_slice(nonce);
+        block[1 + NONCE..].copy_from_slice(&counter.to_be_bytes()[8 - l..]);
+        block
+    }
+
+    fn crypt_ctr(&mut self, nonce: &[u8; NONCE], data: &mut [u8])
+    {
+        for (counter, chunk) in (1_u64..).zip(data.chunks_mut(16))
+        {
+            let mut keystream = Self::counter_block(nonce, counter);
+            self.cipher.encrypt_one_block(&mut keystream);
+            for (d, k) in chunk.iter_mut().zip(keystream.iter())
+                { *d ^= *k; }
+        }
+    }
+
+    // Encrypts the CBC-MAC value with the counter block A_0 and truncates it.
+    fn seal_mac(&mut self, nonce: &[u8; NONCE], mac: &[u8]) -> [u8; TAG]
+    {
+        let mut keystream = Self::counter_block(nonce, 0);
+        self.cipher.encrypt_one_block(&mut keystream);
+        let mut tag = [0_u8; TAG];
+        for ((t, m), k) in tag.iter_mut().zip(mac.iter()).zip(keystream.iter())
+            { *t = *m ^ *k; }
+        tag
+    }
+
+    fn compute_mac(&mut self, nonce: &[u8; NONCE], aad: &[u8], message: &[u8]) -> Vec<u8>
+    {
+        let mut mac = vec![0_u8; 16];
+        if TAG == 0
+            { return mac; }
+
+        let l = 15 - NONCE;
+        mac[0] = (if aad.is_empty() { 0 } else { 0x40 }) | ((((TAG - 2) / 2) as u8) << 3) | (l - 1) as u8;
+        mac[1..1 + NONCE].copy_from_slice(nonce);
+        mac[1 + NONCE..].copy_from_slice(&(message.len() as u64).to_be_bytes()[8 - l..]);
+        self.cipher.encrypt_one_block(&mut mac);
+
+        if !aad.is_empty()
+        {
+            let mut encoded = Vec::<u8>::with_capacity(aad.len() + 10);
+            let length = aad.len() as u64;
+            if length < 0xFF00
+                { encoded.extend_from_slice(&(length as u16).to_be_bytes()); }
+            else if length <= u32::MAX as u64
+            {
+                encoded.extend_from_slice(&[0xFF, 0xFE]);
+                encoded.extend_from_slice(&(length as u32).to_be_bytes());
+            }
+            else
+            {
+                encoded.extend_from_slice(&[0xFF, 0xFF]);
+                encoded.extend_from_slice(&length.to_be_bytes());
+            }
+            encoded.extend_from_slice(aad);
+            self.absorb(&mut mac, &encoded);
+        }
+        self.absorb(&mut mac, message);
+        mac
+    }
+
+    // Continues CBC-MAC with the data padded with zeros.
+    fn absorb(&mut self, mac: &mut [u8], data: &[u8])
+    {
+        for chunk in data.chunks(16)
+        {
+            for (m, d) in mac.iter_mut().zip(chunk.iter())
+                { *m ^= *d; }
+            self.cipher.encrypt_one_block(mac);
+        }
+    }
+}
+
+
+/// CCM* authenticated encryption with associated data of IEEE 802.15.4
+/// on top of any 128-bit block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// CCM* is the superset of CCM used by IEEE 802.15.4 (ZigBee and so on).
+/// It is the same as CCM with the 13-byte nonce except that it also allows
+/// the tag of zero length, in which case it only encrypts the message
+/// without any authentication. With the tag of non-zero length, the
+/// ciphertext and the tag are the same as those of `CCM_Generic`.
+///
+/// # Vulnerability
+/// - The nonce must be unique for the same key.
+/// - The tag of zero length provides no authenticity at all.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+/// - TAG: the length of tag in bytes. It should be one of `0`, `4`, `8` and
+///   `16`. The default value is `8`.
+///
+/// # Reference
+/// Read Annex B of IEEE 802.15.4 about CCM* in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ AES_128, AES_128_CCM_Star };
+///
+/// let key = [0xC0_u8; 16];
+/// let nonce = [0xAC_u8; 13];
+/// let message = "802.15.4 frame payload";
+///
+/// let mut ccm_star = AES_128_CCM_Star::<0>::new(AES_128::new_with_key(&key));
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = ccm_star.encrypt_into_vec(nonce, &[], message.as_bytes(), &mut cipher);
+/// assert_eq!(tag.len(), 0);
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(ccm_star.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct CCM_Star_Generic<C: BlockCipher, const TAG: usize = 8>
+{
+    ccm: CCM_Generic<C, TAG, 13>,
+}
+
+impl<C: BlockCipher, const TAG: usize> CCM_Star_Generic<C, TAG>
+{
+    /// Constructs a new object CCM_Star_Generic.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher with the key.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16, or if `TAG` is not one
+    /// of `0`, `4`, `8` and `16`.
+    pub fn new(cipher: C) -> Self
+    {
+        assert!(matches!(TAG, 0 | 4 | 8 | 16), "TAG of CCM_Star_Generic should be 0, 4, 8 or 16.");
+        Self { ccm: CCM_Generic::<C, TAG, 13>::with_cipher(cipher) }
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 13-byte nonce. It must be unique for the same key.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the tag of `TAG` bytes.
+    #[inline]
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 13], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; TAG]
+    {
+        self.ccm.encrypt_into_vec(nonce, aad, message, cipher)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the tag of `TAG` bytes.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty. When `TAG` is `0`, it always returns
+    /// `true`.
+    #[inline]
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 13], aad: &[u8], cipher: &[u8], tag: [u8; TAG], message: &mut Vec<u8>) -> bool
+    {
+        self.ccm.decrypt_into_vec(nonce, aad, cipher, tag, message)
+    }
+}
@@ -0,0 +1,602 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains ChaCha20 and XChaCha20 stream cipher algorithms
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::ptr::copy_nonoverlapping;
+use std::vec::Vec;
+
+use crate::number::SmallUInt;
+
+
+macro_rules! quarter_round {
+    ($s:expr, $a:expr, $b:expr, $c:expr, $d:expr) => {
+        $s[$a] = $s[$a].wrapping_add($s[$b]);   $s[$d] = ($s[$d] ^ $s[$a]).rotate_left(16);
+        $s[$c] = $s[$c].wrapping_add($s[$d]);   $s[$b] = ($s[$b] ^ $s[$c]).rotate_left(12);
+        $s[$a] = $s[$a].wrapping_add($s[$b]);   $s[$d] = ($s[$d] ^ $s[$a]).rotate_left(8);
+        $s[$c] = $s[$c].wrapping_add($s[$d]);   $s[$b] = ($s[$b] ^ $s[$c]).rotate_left(7);
+    };
+}
+
+/// The official ChaCha20 stream cipher algorithm defined in RFC 8439
+#[allow(non_camel_case_types)]
+pub type ChaCha20 = ChaCha_Generic;     // equivalent to `pub type ChaCha20 = ChaCha_Generic<20>;`
+
+/// The reduced-round ChaCha12 stream cipher algorithm
+#[allow(non_camel_case_types)]
+pub type ChaCha12 = ChaCha_Generic<12>;
+
+/// The reduced-round ChaCha8 stream cipher algorithm
+#[allow(non_camel_case_types)]
+pub type ChaCha8 = ChaCha_Generic<8>;
+
+/// The official XChaCha20 stream cipher algorithm with 192-bit nonce
+#[allow(non_camel_case_types)]
+pub type XChaCha20 = XChaCha_Generic;   // equivalent to `pub type XChaCha20 = XChaCha_Generic<20>;`
+
+/// ChaCha stream cipher algorithm that encrypts/decrypts data by XORing it
+/// with the keystream generated from a 256-bit key, a 96-bit nonce and a
+/// 32-bit block counter, and its reduced-round variants.
+///
+/// # Introduction
+/// ChaCha was designed by Daniel J. Bernstein in 2008 as a variant of
+/// Salsa20 with better diffusion per round. ChaCha20 is standardized in
+/// RFC 8439 for IETF protocols. It works with only additions, rotations and
+/// XORs of 32-bit words (so-called ARX design), so that it does not need any
+/// lookup table and runs in constant time even on machines which do not have
+/// AES hardware.
+///
+/// # Vulnerability
+/// There is no known practical attack against the full 20-round ChaCha20.
+/// However, like all the stream ciphers, the same pair of key and nonce
+/// must never be used twice. If you reuse them, XORing two ciphertexts
+/// gives XOR of two plaintexts. For the same reason, the 32-bit block
+/// counter never wraps around. Encrypting or decrypting data which needs
+/// blocks beyond the counter `2^32 - 1` panics instead of reusing the
+/// keystream from the block `0`.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds. A double round consists of one column round
+///   and one diagonal round, so ROUND should be an even number. The default
+///   value is `20`. ChaCha12 and ChaCha8 use 12 and 8 respectively.
+///
+/// # Reference
+/// Read [RFC 8439](https://datatracker.ietf.org/doc/html/rfc8439)
+/// and [more](https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant)
+/// about ChaCha20 in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::ChaCha20;
+///
+/// let key = [ 0x00_u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+///             0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+///             0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+///             0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f ];
+/// let nonce = [0x00_u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+/// let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+/// let mut a_chacha = ChaCha20::new_with_key(key);
+/// a_chacha.set_counter(1);
+/// let mut cipher = Vec::<u8>::new();
+/// a_chacha.encrypt_str_into_vec(nonce, message, &mut cipher);
+/// let mut txt = String::new();
+/// for c in cipher.clone()
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "6E2E359A2568F98041BA0728DD0D6981E97E7AEC1D4360C20A27AFCCFD9FAE0BF91B65C5524733AB8F593DABCD62B3571639D624E65152AB8F530C359F0861D807CA0DBF500D6A6156A38E088A22B65E52BC514D16CCF806818CE91AB77937365AF90BBF74A35BE6B40B8EEDF2785E42874D");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// a_chacha.decrypt_into_vec(nonce, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct ChaCha_Generic<const ROUND: usize = 20>
+{
+    key: [u32; 8],
+    counter: u32,
+}
+
+impl<const ROUND: usize> ChaCha_Generic<ROUND>
+{
+    const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+    /// Constructs a new object ChaCha_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros and the block counter to be
+    /// zero.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object ChaCha_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    ///
+    /// # Features
+    /// This method sets the key to be the given argument `key` and the block
+    /// counter to be zero.
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        let mut chacha = Self { key: [0_u32; 8], counter: 0 };
+        chacha.set_key(key);
+        chacha
+    }
+
+    /// Sets the key.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        for i in 0..8
+            { self.key[i] = u32::from_le_bytes([key[i * 4], key[i * 4 + 1], key[i * 4 + 2], key[i * 4 + 3]]); }
+    }
+
+    /// Sets the initial block counter.
+    ///
+    /// # Arguments
+    /// The argument `counter` is the block counter for the first 64-byte
+    /// block of the keystream. RFC 8439 uses `1` for encryption when the
+    /// block `0` is used for generating a one-time Poly1305 key.
+    ///
+    /// # Features
+    /// Since each block is 64 bytes long, setting the counter to be `n`
+    /// means seeking the keystream to the byte position `64 * n`.
+    #[inline]
+    pub fn set_counter(&mut self, counter: u32)
+    {
+        self.counter = counter;
+    }
+
+    /// Returns the initial block counter.
+    #[inline]
+    pub fn get_counter(&self) -> u32
+    {
+        self.counter
+    }
+
+    /// Generates the 64-byte keystream block for the given `nonce` and
+    /// `counter`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce.
+    /// - `counter` is the 32-bit block counter.
+    ///
+    /// # Output
+    /// It returns the 64-byte keystream block.
+    pub fn keystream_block(&self, nonce: [u8; 12], counter: u32) -> [u8; 64]
+    {
+        let state = self.make_state(&Self::nonce_to_words(nonce), counter);
+        let mut out = [0_u8; 64];
+        for i in 0..16
+            { out[i * 4..i * 4 + 4].copy_from_slice(&state[i].to_le_bytes()); }
+        out
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce. It should be unique for the same key.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the pointer to the buffer that will have the encrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the encrypted data in bytes, which is the
+    /// same as `length_in_bytes`.
+    ///
+    /// # Panics
+    /// It panics if the data needs keystream blocks beyond the block counter
+    /// `2^32 - 1`, that is, if the block counter plus the number of 64-byte
+    /// blocks of the data is more than `2^32`.
+    #[inline]
+    pub fn encrypt(&mut self, nonce: [u8; 12], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        self.crypt(nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce that was used for encryption.
+    /// - `cipher` is the pointer to the data to be decrypted.
+    /// - `length_in_bytes` is the length of `cipher` in bytes.
+    /// - `message` is the pointer to the buffer that will have the decrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the decrypted data in bytes, which is the
+    /// same as `length_in_bytes`.
+    ///
+    /// # Panics
+    /// It panics if the data needs keystream blocks beyond the block counter
+    /// `2^32 - 1`, that is, if the block counter plus the number of 64-byte
+    /// blocks of the data is more than `2^32`.
+    #[inline]
+    pub fn decrypt(&mut self, nonce: [u8; 12], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.crypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the data `message` into `cipher` of `Vec<u8>`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce. It should be unique for the same key.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Panics
+    /// It panics if the data needs keystream blocks beyond the block counter
+    /// `2^32 - 1`, that is, if the block counter plus the number of 64-byte
+    /// blocks of the data is more than `2^32`.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 12], message: *const u8, length_in_bytes: u64, cipher: &mut Vec<u8>)
+    {
+        cipher.clear();
+        cipher.resize(length_in_bytes as usize, 0);
+        self.crypt(nonce, message, length_in_bytes, cipher.as_mut_ptr());
+    }
+
+    /// Decrypts the data `cipher` into `message` of `Vec<u8>`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce that was used for encryption.
+    /// - `cipher` is the pointer to the data to be decrypted.
+    /// - `length_in_bytes` is the length of `cipher` in bytes.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Panics
+    /// It panics if the data needs keystream blocks beyond the block counter
+    /// `2^32 - 1`, that is, if the block counter plus the number of 64-byte
+    /// blocks of the data is more than `2^32`.
+    #[inline]
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 12], cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, cipher, length_in_bytes, message);
+    }
+
+    /// Encrypts the string `message`. You can also give `&String`.
+    #[inline]
+    pub fn encrypt_str(&mut self, nonce: [u8; 12], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_str_into_vec(&mut self, nonce: [u8; 12], message: &str, cipher: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr(), message.len() as u64, cipher);
+    }
+
+    /// Encrypts the array `message`.
+    #[inline]
+    pub fn encrypt_array<T, const M: usize>(&mut self, nonce: [u8; 12], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the array `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_array_into_vec<T, const M: usize>(&mut self, nonce: [u8; 12], message: &[T; M], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object.
+    #[inline]
+    pub fn encrypt_vec<T>(&mut self, nonce: [u8; 12], message: &[T], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object
+    /// into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_vec_into_vec<T>(&mut self, nonce: [u8; 12], message: &[T], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// HChaCha function which derives a 256-bit subkey from a 256-bit key
+    /// and a 128-bit nonce. XChaCha uses it.
+    pub(crate) fn hchacha(&self, nonce: [u8; 16]) -> [u8; 32]
+    {
+        let mut state = [0_u32; 16];
+        state[..4].copy_from_slice(&Self::SIGMA);
+        state[4..12].copy_from_slice(&self.key);
+        for i in 0..4
+            { state[12 + i] = u32::from_le_bytes([nonce[i * 4], nonce[i * 4 + 1], nonce[i * 4 + 2], nonce[i * 4 + 3]]); }
+        Self::permutate(&mut state);
+        let mut subkey = [0_u8; 32];
+        for i in 0..4
+        {
+            subkey[i * 4..i * 4 + 4].copy_from_slice(&state[i].to_le_bytes());
+            subkey[16 + i * 4..16 + i * 4 + 4].copy_from_slice(&state[12 + i].to_le_bytes());
+        }
+        subkey
+    }
+
+    /// Checks whether the block counter has enough room for the data of
+    /// `length_in_bytes` bytes starting from the current block counter.
+    ///
+    /// # Output
+    /// It returns `true` if the data needs no keystream block beyond the
+    /// block counter `2^32 - 1`. Otherwise, it returns `false`.
+    #[inline]
+    pub fn is_length_valid(&self, length_in_bytes: u64) -> bool
+    {
+        self.counter as u64 + length_in_bytes.div_ceil(64) <= 1 << 32
+    }
+
+    fn crypt(&mut self, nonce: [u8; 12], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        assert!(self.is_length_valid(length_in_bytes), "The data is too long for ChaCha_Generic with this block counter.");
+        let nonce = Self::nonce_to_words(nonce);
+        let mut counter = self.counter;
+        let mut progress = 0_usize;
+        let length = length_in_bytes as usize;
+        let mut keystream = [0_u8; 64];
+        while progress < length
+        {
+            let state = self.make_state(&nonce, counter);
+            for i in 0..16
+                { keystream[i * 4..i * 4 + 4].copy_from_slice(&state[i].to_le_bytes()); }
+            let size = if length - progress < 64 { length - progress } else { 64 };
+            let mut block = [0_u8; 64];
+            unsafe { copy_nonoverlapping(message.add(progress), block.as_mut_ptr(), size); }
+            for i in 0..size
+                { block[i] ^= keystream[i]; }
+            unsafe { copy_nonoverlapping(block.as_ptr(), cipher.add(progress), size); }
+            progress += size;
+            counter = counter.wrapping_add(1);
+        }
+        length_in_bytes
+    }
+
+    pub(crate) fn crypt_slice(&mut self, nonce: [u8; 12], data: &mut [u8])
+    {
+        // crypt() copies each block into a local buffer before writing it
+        // back, so that in-place encryption is safe.
+        let ptr = data.as_mut_ptr();
+        self.crypt(nonce, ptr as *const u8, data.len() as u64, ptr);
+    }
+
+    fn make_state(&self, nonce: &[u32; 3], counter: u32) -> [u32; 16]
+    {
+        let mut state = [0_u32; 16];
+        state[..4].copy_from_slice(&Self::SIGMA);
+        state[4..12].copy_from_slice(&self.key);
+        state[12] = counter;
+        state[13..].copy_from_slice(nonce);
+        let original = state;
+        Self::permutate(&mut state);
+        for i in 0..16
+            { state[i] = state[i].wrapping_add(original[i]); }
+        state
+    }
+
+    fn permutate(state: &mut [u32; 16])
+    {
+        for _ in 0..ROUND / 2
+        {
+            quarter_round!(state, 0, 4,  8, 12);
+            quarter_round!(state, 1, 5,  9, 13);
+            quarter_round!(state, 2, 6, 10, 14);
+            quarter_round!(state, 3, 7, 11, 15);
+            quarter_round!(state, 0, 5, 10, 15);
+            quarter_round!(state, 1, 6, 11, 12);
+            quarter_round!(state, 2, 7,  8, 13);
+            quarter_round!(state, 3, 4,  9, 14);
+        }
+    }
+
+    fn nonce_to_words(nonce: [u8; 12]) -> [u32; 3]
+    {
+        let mut words = [0_u32; 3];
+        for i in 0..3
+            { words[i] = u32::from_le_bytes([nonce[i * 4], nonce[i * 4 + 1], nonce[i * 4 + 2], nonce[i * 4 + 3]]); }
+        words
+    }
+}
+
+impl<const ROUND: usize> Default for ChaCha_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+/// XChaCha stream cipher algorithm that extends the nonce of ChaCha to
+/// 192 bits so that random nonces can be used safely.
+///
+/// # Introduction
+/// XChaCha derives a subkey from the key and the first 128 bits of the
+/// 192-bit nonce by HChaCha, and then encrypts data with ChaCha using the
+/// subkey and the remaining 64 bits of the nonce. Its 192-bit nonce is long
+/// enough to be chosen randomly without worrying about collision.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds. The default value is `20`.
+///
+/// # Reference
+/// Read [draft-irtf-cfrg-xchacha](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha)
+/// about XChaCha20 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::XChaCha20;
+///
+/// let key = [0x80_u8; 32];
+/// let nonce = [0x40_u8; 24];
+/// let message = "The XChaCha20 nonce is long enough to be random.";
+/// let mut a_xchacha = XChaCha20::new_with_key(key);
+/// let mut cipher = Vec::<u8>::new();
+/// a_xchacha.encrypt_str_into_vec(nonce, message, &mut cipher);
+/// assert_eq!(cipher.len(), message.len());
+///
+/// let mut recovered = Vec::<u8>::new();
+/// a_xchacha.decrypt_into_vec(nonce, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct XChaCha_Generic<const ROUND: usize = 20>
+{
+    chacha: ChaCha_Generic<ROUND>,
+}
+
+impl<const ROUND: usize> XChaCha_Generic<ROUND>
+{
+    /// Constructs a new object XChaCha_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros and the block counter to be
+    /// zero.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object XChaCha_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    #[inline]
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        Self { chacha: ChaCha_Generic::<ROUND>::new_with_key(key) }
+    }
+
+    /// Sets the key.
+    #[inline]
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        self.chacha.set_key(key);
+    }
+
+    /// Sets the initial block counter.
+    #[inline]
+    pub fn set_counter(&mut self, counter: u32)
+    {
+        self.chacha.set_counter(counter);
+    }
+
+    /// Returns the initial block counter.
+    #[inline]
+    pub fn get_counter(&self) -> u32
+    {
+        self.chacha.get_counter()
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 192-bit nonce.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the pointer to the buffer that will have the encrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the encrypted data in bytes.
+    pub fn encrypt(&mut self, nonce: [u8; 24], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        let (mut inner, inner_nonce) = self.derive(nonce);
+        inner.encrypt(inner_nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` by XORing it with the keystream.
+    #[inline]
+    pub fn decrypt(&mut self, nonce: [u8; 24], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.encrypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the data `message` into `cipher` of `Vec<u8>`.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 24], message: *const u8, length_in_bytes: u64, cipher: &mut Vec<u8>)
+    {
+        let (mut inner, inner_nonce) = self.derive(nonce);
+        inner.encrypt_into_vec(inner_nonce, message, length_in_bytes, cipher);
+    }
+
+    /// Decrypts the data `cipher` into `message` of `Vec<u8>`.
+    #[inline]
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 24], cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, cipher, length_in_bytes, message);
+    }
+
+    /// Encrypts the string `message`. You can also give `&String`.
+    #[inline]
+    pub fn encrypt_str(&mut self, nonce: [u8; 24], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_str_into_vec(&mut self, nonce: [u8; 24], message: &str, cipher: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr(), message.len() as u64, cipher);
+    }
+
+    /// Encrypts the array `message`.
+    #[inline]
+    pub fn encrypt_array<T, const M: usize>(&mut self, nonce: [u8; 24], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object.
+    #[inline]
+    pub fn encrypt_vec<T>(&mut self, nonce: [u8; 24], message: &[T], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    pub(crate) fn derive(&self, nonce: [u8; 24]) -> (ChaCha_Generic<ROUND>, [u8; 12])
+    {
+        let mut head = [0_u8; 16];
+        head.copy_from_slice(&nonce[..16]);
+        let mut inner = ChaCha_Generic::<ROUND>::new_with_key(self.chacha.hchacha(head));
+        inner.set_counter(self.chacha.get_counter());
+        let mut inner_nonce = [0_u8; 12];
+        inner_nonce[4..].copy_from_slice(&nonce[16..]);
+        (inner, inner_nonce)
+    }
+}
+
+impl<const ROUND: usize> Default for XChaCha_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,308 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains ChaCha20-Poly1305 and XChaCha20-Poly1305
+//! authenticated encryption with associated data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ ChaCha_Generic, XChaCha_Generic, Poly1305 };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// The official ChaCha20-Poly1305 AEAD defined in RFC 8439
+#[allow(non_camel_case_types)]
+pub type ChaCha20_Poly1305 = ChaCha_Poly1305_Generic;
+
+/// The XChaCha20-Poly1305 AEAD with 192-bit nonce
+#[allow(non_camel_case_types)]
+pub type XChaCha20_Poly1305 = XChaCha_Poly1305_Generic;
+
+/// ChaCha20-Poly1305 authenticated encryption with associated data
+///
+/// # Introduction
+/// ChaCha20-Poly1305 encrypts the message with ChaCha20 starting from the
+/// block counter `1`, and authenticates the associated data and the
+/// ciphertext with Poly1305 whose one-time key is the first 32 bytes of the
+/// keystream block `0`. The tag is 16 bytes long.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds of the underlying ChaCha. The default value
+///   is `20`.
+///
+/// # Reference
+/// Read [RFC 8439](https://datatracker.ietf.org/doc/html/rfc8439#section-2.8)
+/// about ChaCha20-Poly1305 in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::ChaCha20_Poly1305;
+///
+/// let mut key = [0_u8; 32];
+/// for i in 0..32
+///     { key[i] = 0x80 + i as u8; }
+/// let nonce = [0x07_u8, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+/// let aad = [0x50_u8, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7];
+/// let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+///
+/// let mut aead = ChaCha20_Poly1305::new_with_key(key);
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = aead.encrypt_into_vec(nonce, &aad, message.as_bytes(), &mut cipher);
+/// let mut txt = String::new();
+/// for t in tag
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "1AE10B594F09E26A7E902ECBD0600691");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(aead.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+///
+/// cipher[0] ^= 1;
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(!aead.decrypt_into_vec(nonce, &aad, &cipher, tag, &mut recovered));
+/// assert!(recovered.is_empty());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct ChaCha_Poly1305_Generic<const ROUND: usize = 20>
+{
+    chacha: ChaCha_Generic<ROUND>,
+}
+
+impl<const ROUND: usize> ChaCha_Poly1305_Generic<ROUND>
+{
+    /// Constructs a new object ChaCha_Poly1305_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object ChaCha_Poly1305_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    #[inline]
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        Self { chacha: ChaCha_Generic::<ROUND>::new_with_key(key) }
+    }
+
+    /// Sets the key.
+    #[inline]
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        self.chacha.set_key(key);
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce. It must be unique for the same key.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the 16-byte authentication tag.
+    ///
+    /// # Panics
+    /// It panics if `message` is longer than `(2^32 - 1) * 64` bytes, which
+    /// is the limit of RFC 8439 for the 32-bit block counter.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 12], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+    {
+        seal(&mut self.chacha, nonce, aad, message, cipher)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the 16-byte authentication tag.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty. It also returns `false` if `cipher` is
+    /// longer than `(2^32 - 1) * 64` bytes.
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 12], aad: &[u8], cipher: &[u8], tag: [u8; 16], message: &mut Vec<u8>) -> bool
+    {
+        open(&mut self.chacha, nonce, aad, cipher, tag, message)
+    }
+}
+
+impl<const ROUND: usize> Default for ChaCha_Poly1305_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+/// XChaCha20-Poly1305 authenticated encryption with associated data
+///
+/// # Introduction
+/// XChaCha20-Poly1305 derives a subkey from the key and the first 16 bytes
+/// of the 192-bit nonce by HChaCha20, and then works as ChaCha20-Poly1305
+/// with the subkey and the 96-bit nonce which consists of four zero bytes
+/// and the last 8 bytes of the 192-bit nonce. Since the nonce is 192 bits
+/// long, it is safe to choose it randomly.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds of the underlying ChaCha. The default value
+///   is `20`.
+///
+/// # Reference
+/// Read [draft-irtf-cfrg-xchacha](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha)
+/// about XChaCha20-Poly1305 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::XChaCha20_Poly1305;
+///
+/// let key = [0x1f_u8; 32];
+/// let nonce = [0x24_u8; 24];
+/// let aad = "header".as_bytes();
+/// let message = "A random nonce is fine with XChaCha20-Poly1305.";
+///
+/// let mut aead = XChaCha20_Poly1305::new_with_key(key);
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = aead.encrypt_into_vec(nonce, aad, message.as_bytes(), &mut cipher);
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(aead.decrypt_into_vec(nonce, aad, &cipher, tag, &mut recovered));
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct XChaCha_Poly1305_Generic<const ROUND: usize = 20>
+{
+    xchacha: XChaCha_Generic<ROUND>,
+}
+
+impl<const ROUND: usize> XChaCha_Poly1305_Generic<ROUND>
+{
+    /// Constructs a new object XChaCha_Poly1305_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object XChaCha_Poly1305_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    #[inline]
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        Self { xchacha: XChaCha_Generic::<ROUND>::new_with_key(key) }
+    }
+
+    /// Sets the key.
+    #[inline]
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        self.xchacha.set_key(key);
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 192-bit nonce.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the 16-byte authentication tag.
+    ///
+    /// # Panics
+    /// It panics if `message` is longer than `(2^32 - 1) * 64` bytes, which
+    /// is the limit of RFC 8439 for the 32-bit block counter.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 24], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+    {
+        let (mut chacha, inner_nonce) = self.xchacha.derive(nonce);
+        seal(&mut chacha, inner_nonce, aad, message, cipher)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty. It also returns `false` if `cipher` is
+    /// longer than `(2^32 - 1) * 64` bytes.
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 24], aad: &[u8], cipher: &[u8], tag: [u8; 16], message: &mut Vec<u8>) -> bool
+    {
+        let (mut chacha, inner_nonce) = self.xchacha.derive(nonce);
+        open(&mut chacha, inner_nonce, aad, cipher, tag, message)
+    }
+}
+
+impl<const ROUND: usize> Default for XChaCha_Poly1305_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+fn seal<const ROUND: usize>(chacha: &mut ChaCha_Generic<ROUND>, nonce: [u8; 12], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+{
+    chacha.set_counter(1);
+    assert!(chacha.is_length_valid(message.len() as u64), "The message is too long for ChaCha_Poly1305_Generic.");
+    cipher.clear();
+    cipher.extend_from_slice(message);
+    chacha.crypt_slice(nonce, cipher);
+    compute_tag(chacha, nonce, aad, cipher)
+}
+
+fn open<const ROUND: usize>(chacha: &mut ChaCha_Generic<ROUND>, nonce: [u8; 12], aad: &[u8], cipher: &[u8], tag: [u8; 16], message: &mut Vec<u8>) -> bool
+{
+    message.clear();
+    chacha.set_counter(1);
+    if !chacha.is_length_valid(cipher.len() as u64)
+        { return false; }
+    if !tags_equal(&compute_tag(chacha, nonce, aad, cipher), &tag)
+        { return false; }
+    message.extend_from_slice(cipher);
+    chacha.crypt_slice(nonce, message);
+    true
+}
+
+fn compute_tag<const ROUND: usize>(chacha: &ChaCha_Generic<ROUND>, nonce: [u8; 12], aad: &[u8], cipher: &[u8]) -> [u8; 16]
+{
+    let block = chacha.keystream_block(nonce, 0);
+    let mut one_time_key = [0_u8; 32];
+    one_time_key.copy_from_slice(&block[..32]);
+    let mut poly = Poly1305::new_with_key(one_time_key);
+    poly.update_padded(aad);
+    poly.update_padded(cipher);
+    poly.update(&(aad.len() as u64).to_le_bytes());
+    poly.update(&(cipher.len() as u64).to_le_bytes());
+    poly.finalize()
+}
@@ -0,0 +1,224 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the enumerators CipherError and KeyError for
+//! indicating the errors of encryption, decryption and keys
+
+// #![allow(missing_docs)]
+// #![allow(rustdoc::missing_doc_code_examples)]
+
+use std::fmt::{ self, Display, Formatter, Debug };
+use std::error::Error;
+use CipherError::{ InvalidPadding, BadLength, BufferTooSmall, InvalidNumeralString };
+use KeyError::{ WeakKey, SemiWeakKey, PossiblyWeakKey, BadParity, DegenerateKeys };
+
+/// In encryption and decryption with the slice-based API such as
+/// `ModeCipher`, errors can occur. In this case, the enumerator
+/// `CipherError` will indicate what kind of error occurred.
+///
+/// # Example 1
+/// ```
+/// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+/// match des.decrypt(&[0_u8; 12])
+/// {
+///     Ok(message) => { println!("message = {:?}", message); },
+///     Err(e) => {
+///             println!("Error: {}", e);
+///             assert_eq!(e.to_string(), "The length of the ciphertext is not valid for the mode of operation.");
+///             assert_eq!(e, CipherError::BadLength);
+///         }
+/// }
+/// ```
+///
+/// # Example 2
+/// ```
+/// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+/// let mut cipher = des.encrypt("Hello, World!".as_bytes());
+/// cipher[7] ^= 0x01;  // The last padding byte will be changed.
+/// match des.decrypt(&cipher)
+/// {
+///     Ok(message) => { println!("message = {:?}", message); },
+///     Err(e) => {
+///             println!("Error: {}", e);
+///             assert_eq!(e.to_string(), "The padding of the decrypted data is not valid.");
+///             assert_eq!(e, CipherError::InvalidPadding);
+///         }
+/// }
+/// ```
+///
+/// # Example 3
+/// ```
+/// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+/// let mut cipher = [0_u8; 8];
+/// match des.encrypt_into("Hello, World!".as_bytes(), &mut cipher)
+/// {
+///     Ok(size) => { println!("size = {}", size); },
+///     Err(e) => {
+///             println!("Error: {}", e);
+///             assert_eq!(e.to_string(), "The output buffer is too small to contain the result.");
+///             assert_eq!(e, CipherError::BufferTooSmall);
+///         }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CipherError
+{
+    /// Indicates that the padding of the decrypted data is not valid.
+    /// It usually means that the ciphertext was modified, or that the key
+    /// or the initialization vector is wrong.
+    InvalidPadding,
+
+    /// Indicates that the length of the ciphertext is not valid for the
+    /// mode of operation. For example, the ciphertext of CBC mode should
+    /// be a positive multiple of the block size.
+    BadLength,
+
+    /// Indicates that the output buffer is too small to contain the result.
+    BufferTooSmall,
+
+    /// Indicates that the numeral string for format-preserving encryption
+    /// such as `FF1_Generic` is not valid. Either a numeral of it is not
+    /// less than the radix, or its length is out of the allowed range.
+    InvalidNumeralString,
+}
+
+
+
+impl Display for CipherError
+{
+    // fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    /// Formats the value using the given formatter.
+    ///
+    /// # Arguments
+    /// `f` is a buffer, this method must write the formatted string into it,
+    /// and is of the type `&mut Formatter`.
+    ///
+    /// # Features
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()` and the macro `println!()`.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::CipherError;
+    ///
+    /// println!("CipherError::BufferTooSmall: {}", CipherError::BufferTooSmall);
+    /// assert_eq!(CipherError::BufferTooSmall.to_string(), "The output buffer is too small to contain the result.");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let txt = match *self
+        {
+            InvalidPadding => "The padding of the decrypted data is not valid.",
+            BadLength => "The length of the ciphertext is not valid for the mode of operation.",
+            BufferTooSmall => "The output buffer is too small to contain the result.",
+            InvalidNumeralString => "The numeral string is not valid for the radix and the length limits.",
+        };
+        // `write!` is like `format!`, but it will write the formatted string
+        // into a buffer (the first argument)
+        write!(f, "{}", txt)
+    }
+}
+
+impl Error for CipherError {}
+
+
+
+/// In checking the keys with the methods such as
+/// `DES_Generic::new_with_key_checked()`, the keys can be rejected. In this
+/// case, the enumerator `KeyError` will indicate why the keys were rejected.
+///
+/// # Example
+/// ```
+/// use cryptocol::symmetric::{ DES, KeyError };
+///
+/// match DES::new_with_key_checked([0xFE_u8, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE, 0xFE])
+/// {
+///     Ok(_) => { println!("The key is accepted."); },
+///     Err(e) => {
+///             println!("Error: {}", e);
+///             assert_eq!(e.to_string(), "The key is a weak key.");
+///             assert_eq!(e, KeyError::WeakKey);
+///         }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KeyError
+{
+    /// Indicates that the key is a weak key, with which encryption is the
+    /// same as decryption.
+    WeakKey,
+
+    /// Indicates that the key is a semi-weak key, with which encryption is
+    /// the same as decryption with another key.
+    SemiWeakKey,
+
+    /// Indicates that the key is a possibly weak key, which makes only
+    /// four distinct round keys.
+    PossiblyWeakKey,
+
+    /// Indicates that the parity bits of the key are not correct.
+    BadParity,
+
+    /// Indicates that two adjacent keys of multiple encryption are the
+    /// same, so that the multiple encryption degenerates into weaker one.
+    DegenerateKeys,
+}
+
+
+
+impl Display for KeyError
+{
+    // fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    /// Formats the value using the given formatter.
+    ///
+    /// # Arguments
+    /// `f` is a buffer, this method must write the formatted string into it,
+    /// and is of the type `&mut Formatter`.
+    ///
+    /// # Features
+    /// Automagically the function `to_string()` will be implemented. So, you
+    /// can use the function `to_string()` and the macro `println!()`.
+    /// [Read more](https://doc.rust-lang.org/core/fmt/trait.Display.html#tymethod.fmt)
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::KeyError;
+    ///
+    /// println!("KeyError::BadParity: {}", KeyError::BadParity);
+    /// assert_eq!(KeyError::BadParity.to_string(), "The parity bits of the key are not correct.");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let txt = match *self
+        {
+            WeakKey => "The key is a weak key.",
+            SemiWeakKey => "The key is a semi-weak key.",
+            PossiblyWeakKey => "The key is a possibly weak key.",
+            BadParity => "The parity bits of the key are not correct.",
+            DegenerateKeys => "The adjacent keys of multiple encryption are the same.",
+        };
+        // `write!` is like `format!`, but it will write the formatted string
+        // into a buffer (the first argument)
+        write!(f, "{}", txt)
+    }
+}
+
+impl Error for KeyError {}
@@ -0,0 +1,204 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains CMAC (OMAC1) message authentication code
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256, DES };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES-128-CMAC defined in NIST SP 800-38B and RFC 4493
+#[allow(non_camel_case_types)]
+pub type AES_128_CMAC = CMAC_Generic<AES_128>;
+
+/// AES-256-CMAC defined in NIST SP 800-38B
+#[allow(non_camel_case_types)]
+pub type AES_256_CMAC = CMAC_Generic<AES_256>;
+
+/// DES-CMAC, which is CMAC with 64-bit block
+#[allow(non_camel_case_types)]
+pub type DES_CMAC = CMAC_Generic<DES>;
+
+/// CMAC (OMAC1) message authentication code on top of any 64-bit or 128-bit
+/// block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// CMAC is CBC-MAC whose last block is masked by one of two subkeys which
+/// are derived from the key. The subkey `K1` is used when the last block is
+/// complete, and the subkey `K2` is used when the last block is padded with
+/// `0x80` and zeros. Thanks to the subkeys, CMAC is secure for messages of
+/// variable lengths unlike plain CBC-MAC. OMAC1 is the original name of
+/// CMAC.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be either 8 bytes or 16 bytes.
+///
+/// # Reference
+/// Read [NIST SP 800-38B](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38B.pdf)
+/// and [RFC 4493](https://datatracker.ietf.org/doc/html/rfc4493)
+/// about CMAC in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, AES_128_CMAC };
+///
+/// let key = [ 0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6,
+///             0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C ];
+/// let mut cmac = AES_128_CMAC::new(AES_128::new_with_key(&key));
+/// let tag = cmac.mac(&[]);
+/// let mut txt = String::new();
+/// for t in tag.iter()
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "BB1D6929E95937287FA37D129B756746");
+/// assert!(cmac.verify(&[], &tag));
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct CMAC_Generic<C: BlockCipher>
+{
+    cipher: C,
+    k1: Vec<u8>,
+    k2: Vec<u8>,
+    state: Vec<u8>,
+    buffer: Vec<u8>,
+}
+
+impl<C: BlockCipher> CMAC_Generic<C>
+{
+    /// Constructs a new object CMAC_Generic.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher with the key.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is neither 8 nor 16.
+    pub fn new(cipher: C) -> Self
+    {
+        assert!(C::BLOCK_SIZE == 8 || C::BLOCK_SIZE == 16, "CMAC_Generic supports only 64-bit and 128-bit block ciphers.");
+        let mut cipher = cipher;
+        let mut l = vec![0_u8; C::BLOCK_SIZE];
+        cipher.encrypt_one_block(&mut l);
+        let k1 = double(&l);
+        let k2 = double(&k1);
+        let state = vec![0_u8; C::BLOCK_SIZE];
+        let buffer = Vec::with_capacity(C::BLOCK_SIZE);
+        Self { cipher, k1, k2, state, buffer }
+    }
+
+    /// Feeds `data` to the authenticator.
+    ///
+    /// # Arguments
+    /// The argument `data` is the slice of data to be authenticated.
+    /// You can call this method several times. The result is the same as
+    /// if the concatenation of all the pieces were fed at once.
+    pub fn update(&mut self, data: &[u8])
+    {
+        let size = C::BLOCK_SIZE;
+        let mut data = data;
+        while !data.is_empty()
+        {
+            // The buffered block is processed only when more data comes,
+            // because the last block is treated differently.
+            if self.buffer.len() == size
+            {
+                for (s, b) in self.state.iter_mut().zip(self.buffer.iter())
+                    { *s ^= *b; }
+                self.cipher.encrypt_one_block(&mut self.state);
+                self.buffer.clear();
+            }
+            let take = if data.len() < size - self.buffer.len() { data.len() } else { size - self.buffer.len() };
+            self.buffer.extend_from_slice(&data[..take]);
+            data = &data[take..];
+        }
+    }
+
+    /// Returns the tag of all the data fed so far.
+    ///
+    /// # Output
+    /// It returns the tag which is as long as the block of `C`. You may
+    /// truncate it to the leftmost bytes as your protocol requires.
+    ///
+    /// # Features
+    /// After this method, the object is ready for the next message with the
+    /// same key.
+    pub fn finalize(&mut self) -> Vec<u8>
+    {
+        let size = C::BLOCK_SIZE;
+        let mut tag = self.state.clone();
+        for (t, b) in tag.iter_mut().zip(self.buffer.iter())
+            { *t ^= *b; }
+        if self.buffer.len() == size
+        {
+            for (t, k) in tag.iter_mut().zip(self.k1.iter())
+                { *t ^= *k; }
+        }
+        else
+        {
+            tag[self.buffer.len()] ^= 0x80;
+            for (t, k) in tag.iter_mut().zip(self.k2.iter())
+                { *t ^= *k; }
+        }
+        self.cipher.encrypt_one_block(&mut tag);
+        self.state.fill(0);
+        self.buffer.clear();
+        tag
+    }
+
+    /// Computes the tag of `data` at once.
+    pub fn mac(&mut self, data: &[u8]) -> Vec<u8>
+    {
+        self.update(data);
+        self.finalize()
+    }
+
+    /// Verifies the tag of `data` in constant time.
+    ///
+    /// # Arguments
+    /// - `data` is the authenticated data.
+    /// - `tag` is the tag to be verified. It may be truncated to the
+    ///   leftmost bytes, but should not be empty.
+    ///
+    /// # Output
+    /// It returns `true` if `tag` is correct, and `false` otherwise.
+    pub fn verify(&mut self, data: &[u8], tag: &[u8]) -> bool
+    {
+        let computed = self.mac(data);
+        !tag.is_empty() && tag.len() <= computed.len() && tags_equal(&computed[..tag.len()], tag)
+    }
+
+    pub(crate) fn get_cipher_mut(&mut self) -> &mut C
+    {
+        &mut self.cipher
+    }
+}
+
+// Multiplies the block by x in GF(2^128) modulo x^128 + x^7 + x^2 + x + 1
+// or in GF(2^64) modulo x^64 + x^4 + x^3 + x + 1. The first byte is the
+// most significant byte.
+pub(crate) fn double(block: &[u8]) -> Vec<u8>
+{
+    let feedback = if block.len() == 16 { 0x87_u8 } else { 0x1B_u8 };
+    let mut out = vec![0_u8; block.len()];
+    let mut carry = 0_u8;
+    for (o, b) in out.iter_mut().zip(block.iter()).rev()
+    {
+        *o = (*b << 1) | carry;
+        carry = *b >> 7;
+    }
+    let last = out.len() - 1;
+    out[last] ^= feedback & 0_u8.wrapping_sub(carry);
+    out
+}
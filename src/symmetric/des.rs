@@ -14,13 +14,15 @@
 // #![warn(rustdoc::missing_doc_code_examples)]
 
 
-use std::ptr::copy_nonoverlapping;
+use std::ptr::{ copy_nonoverlapping, write_bytes };
 use std::vec::Vec;
 // use std::slice::from_raw_parts;
 // use std::fmt::{ self, Debug, Display, Formatter };
 // use std::collections::HashMap;
 
 use crate::number::{ SmallUInt, IntUnion, LongUnion };
+use crate::symmetric::KeyError;
+use crate::symmetric::padding::{ check_pkcs7_padding, check_iso7816_padding };
 
 // Converts bit number into 0-based bit number in Little Endianness.
 macro_rules! convert {
@@ -305,6 +307,8 @@ pub type DES = DES_Generic;    // equivalent to `pub type DES = DES_Expanded;`
 ///   S-box tables have been rearranged to be the 2-dimensional array.
 ///   You can cange S-Box by changing these constants.
 ///   *The change of these constants may hurt the security a lot.*
+///   If you need the S-boxes given at runtime, for example, the S-boxes
+///   derived from the key, use `DES_RuntimeSbox_Generic` instead.
 /// - EP01 ~ EP48: Expansion permutation constants.
 /// - TP01 ~ TP32: Translation permutation constans.
 /// 
@@ -1022,8 +1026,20 @@ S756, S757, S758, S759, S760, S761, S762, S763
     /// The argument `key` is the array of u8 that has 8 elements.
     /// 
     /// # Features
-    /// This method sets the key to be the given argument `key`.
+    /// This method sets the key to be the given argument `key`, and makes
+    /// the round keys from it again. Until ver. 0.9.3, this method did not
+    /// make the round keys again so that the previous key was still used
+    /// for encryption and decryption. Read `BreakingChanges.md`.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
     /// 
+    /// let mut des = DES::new();
+    /// des.set_key([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    /// let mut expected = DES::new_with_key([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    /// assert_eq!(des.encrypt_u64(0x_1234567890ABCDEF_u64), expected.encrypt_u64(0x_1234567890ABCDEF_u64));
+    /// ```
     pub fn set_key(&mut self, key: [u8; 8])
     {
         let mut i = 0_usize;
@@ -1032,6 +1048,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
             self.key.set_ubyte_(i, val);
             i += 1;
         }
+        self.make_round_keys();
     }
 
     /// Sets the key.
@@ -1040,11 +1057,316 @@ S756, S757, S758, S759, S760, S761, S762, S763
     /// The argument `key` is of `u64`.
     /// 
     /// # Features
-    /// This method sets the key to be the given argument `key`.
+    /// This method sets the key to be the given argument `key`, and makes
+    /// the round keys from it again. Until ver. 0.9.3, this method did not
+    /// make the round keys again so that the previous key was still used
+    /// for encryption and decryption. Read `BreakingChanges.md`.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
+    /// 
+    /// let mut des = DES::new_with_key_u64(0x_1234567890ABCDEF_u64);
+    /// des.set_key_u64(0x_F1DFBC9B79573413_u64);
+    /// let mut expected = DES::new_with_key_u64(0x_F1DFBC9B79573413_u64);
+    /// assert_eq!(des.encrypt_u64(0x_1234567890ABCDEF_u64), expected.encrypt_u64(0x_1234567890ABCDEF_u64));
+    /// ```
     #[inline]
     pub fn set_key_u64(&mut self, key: u64)
     {
         self.key.set(key);
+        self.make_round_keys();
+    }
+
+    /// Gets the key.
+    /// 
+    /// # Output
+    /// It returns the key as the array of u8 that has 8 elements.
+    pub fn get_key(&self) -> [u8; 8]
+    {
+        let mut key = [0_u8; 8];
+        for (i, k) in key.iter_mut().enumerate()
+            { *k = self.key.get_ubyte_(i); }
+        key
+    }
+
+    /// Gets the S-boxes of the generic parameters S000 ~ S763.
+    /// 
+    /// # Output
+    /// It returns the eight S-boxes. Each S-box is an array of 64 four-bit
+    /// numbers whose index is the six input bits of the S-box.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
+    /// 
+    /// let sbox = DES::get_sbox_table();
+    /// assert_eq!(sbox[0][..4], [14, 0, 4, 15]);
+    /// ```
+    #[inline]
+    pub fn get_sbox_table() -> [[u8; 64]; 8]
+    {
+        Self::SBOX
+    }
+
+    /// Gets the expansion permutation table of the generic parameters
+    /// EP01 ~ EP48.
+    /// 
+    /// # Output
+    /// It returns the 48 positions of the input bits in the same numbering
+    /// as FIPS 46-3, that is, the first bit is the most significant bit and
+    /// its position is `1`. The `i`-th output bit of the expansion is the
+    /// input bit at the position of the `i`-th element.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
+    /// 
+    /// let expansion = DES::get_expansion_table();
+    /// assert_eq!(expansion[..6], [32, 1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn get_expansion_table() -> [u8; 48]
+    {
+        [
+            EP01, EP02, EP03, EP04, EP05, EP06, EP07, EP08,
+            EP09, EP10, EP11, EP12, EP13, EP14, EP15, EP16,
+            EP17, EP18, EP19, EP20, EP21, EP22, EP23, EP24,
+            EP25, EP26, EP27, EP28, EP29, EP30, EP31, EP32,
+            EP33, EP34, EP35, EP36, EP37, EP38, EP39, EP40,
+            EP41, EP42, EP43, EP44, EP45, EP46, EP47, EP48
+        ]
+    }
+
+    /// Gets the translation permutation table P of the generic parameters
+    /// TP01 ~ TP32.
+    /// 
+    /// # Output
+    /// It returns the 32 positions of the input bits in the same numbering
+    /// as FIPS 46-3, that is, the first bit is the most significant bit and
+    /// its position is `1`. The `i`-th output bit of the permutation is the
+    /// input bit at the position of the `i`-th element.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
+    /// 
+    /// let translation = DES::get_translation_table();
+    /// assert_eq!(translation[..4], [16, 7, 20, 21]);
+    /// ```
+    #[inline]
+    pub fn get_translation_table() -> [u8; 32]
+    {
+        [
+            TP01, TP02, TP03, TP04, TP05, TP06, TP07, TP08,
+            TP09, TP10, TP11, TP12, TP13, TP14, TP15, TP16,
+            TP17, TP18, TP19, TP20, TP21, TP22, TP23, TP24,
+            TP25, TP26, TP27, TP28, TP29, TP30, TP31, TP32
+        ]
+    }
+
+    /// Constructs a new object DES_Generic only with a good key.
+    /// 
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 8 elements.
+    /// 
+    /// # Output
+    /// It returns the new object, or the error of `check_key()` if `key`
+    /// is a weak key, a semi-weak key or a possibly weak key, or if its
+    /// parity bits are not correct.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, KeyError };
+    /// 
+    /// assert!(DES::new_with_key_checked([0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]).is_ok());
+    /// assert_eq!(DES::new_with_key_checked([0x01_u8, 0x1F, 0x01, 0x1F, 0x01, 0x0E, 0x01, 0x0E]).err(), Some(KeyError::SemiWeakKey));
+    /// assert_eq!(DES::new_with_key_checked([0x12_u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]).err(), Some(KeyError::BadParity));
+    /// ```
+    pub fn new_with_key_checked(key: [u8; 8]) -> Result<Self, KeyError>
+    {
+        let des = Self::new_with_key(key);
+        des.check_key()?;
+        Ok(des)
+    }
+
+    /// Sets the key only if it is a good key.
+    /// 
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 8 elements.
+    /// 
+    /// # Output
+    /// It returns the error of `check_key()` and keeps the current key if
+    /// `key` is a weak key, a semi-weak key or a possibly weak key, or if
+    /// its parity bits are not correct.
+    pub fn set_key_checked(&mut self, key: [u8; 8]) -> Result<(), KeyError>
+    {
+        let des = Self::new_with_key_checked(key)?;
+        self.key = des.key;
+        self.round_key = des.round_key;
+        Ok(())
+    }
+
+    /// Checks the current key.
+    /// 
+    /// # Output
+    /// It returns `KeyError::WeakKey`, `KeyError::SemiWeakKey` or
+    /// `KeyError::PossiblyWeakKey` if the key is one of them, or
+    /// `KeyError::BadParity` if the key does not have odd parity.
+    /// Otherwise, it returns `Ok(())`.
+    pub fn check_key(&self) -> Result<(), KeyError>
+    {
+        if self.is_weak_key()
+            { return Err(KeyError::WeakKey); }
+        if self.is_semi_weak_key()
+            { return Err(KeyError::SemiWeakKey); }
+        if self.is_possibly_weak_key()
+            { return Err(KeyError::PossiblyWeakKey); }
+        if !self.has_odd_parity()
+            { return Err(KeyError::BadParity); }
+        Ok(())
+    }
+
+    /// Checks whether or not the current key is a weak key.
+    /// 
+    /// # Output
+    /// It returns `true` if both halves of the key after the permutation
+    /// PC-1 consist of the same bit repeated, so that all the round keys
+    /// are the same and encryption is the same as decryption. The official
+    /// DES has four weak keys such as `0101010101010101` and
+    /// `FEFEFEFEFEFEFEFE` regardless of the parity bits.
+    /// 
+    /// # Features
+    /// It examines the two halves of the key rather than the round keys, so
+    /// that the result does not depend on the number of rounds `ROUND` or
+    /// the shifts `SHIFT`. So, the expanded versions of DES with only a few
+    /// rounds do not reject ordinary keys.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, DES_Generic };
+    /// 
+    /// assert!(DES::new_with_key_u64(0x_FEFEFEFE_FEFEFEFE_u64).is_weak_key());
+    /// assert!(DES_Generic::<2>::new_with_key_u64(0x_FEFEFEFE_FEFEFEFE_u64).is_weak_key());
+    /// assert!(!DES_Generic::<2>::new_with_key_u64(0x_133457799BBCDFF1_u64).is_weak_key());
+    /// ```
+    pub fn is_weak_key(&self) -> bool
+    {
+        self.get_period_of_halfkeys() == 1
+    }
+
+    /// Checks whether or not the current key is a semi-weak key.
+    /// 
+    /// # Output
+    /// It returns `true` if both halves of the key after the permutation
+    /// PC-1 repeat every two bits but the key is not a weak key, so that
+    /// the round keys have only two distinct values. The official DES has
+    /// twelve semi-weak keys which make six pairs such as
+    /// `011F011F010E010E` and `1F011F010E010E01`. Encryption with one key
+    /// of a pair is the same as decryption with the other key.
+    /// 
+    /// # Features
+    /// Like `is_weak_key()`, the result does not depend on the number of
+    /// rounds `ROUND` or the shifts `SHIFT`.
+    pub fn is_semi_weak_key(&self) -> bool
+    {
+        self.get_period_of_halfkeys() == 2
+    }
+
+    /// Checks whether or not the current key is a possibly weak key.
+    /// 
+    /// # Output
+    /// It returns `true` if both halves of the key after the permutation
+    /// PC-1 repeat every four bits but the key is neither a weak key nor a
+    /// semi-weak key, so that the round keys have only four distinct values
+    /// at most. It includes the forty-eight possibly weak keys of the
+    /// official DES listed in the literature such as `01011F1F01010E0E`.
+    /// It also includes the other keys such as `1F0101010E010101` whose
+    /// halves repeat every four bits as well. So, there are 240 such keys
+    /// regardless of the parity bits.
+    /// 
+    /// # Features
+    /// Like `is_weak_key()`, the result does not depend on the number of
+    /// rounds `ROUND` or the shifts `SHIFT`.
+    pub fn is_possibly_weak_key(&self) -> bool
+    {
+        self.get_period_of_halfkeys() == 4
+    }
+
+    /// Checks whether or not each byte of the current key has odd parity.
+    /// 
+    /// # Output
+    /// It returns `true` if each byte of the key has an odd number of `1`
+    /// bits. The least significant bit of each byte is the parity bit, which
+    /// is not used for encryption.
+    pub fn has_odd_parity(&self) -> bool
+    {
+        self.get_key().iter().all(|k| k.count_ones() & 1 == 1)
+    }
+
+    /// Sets the parity bit of each byte of the current key so that each
+    /// byte has odd parity.
+    /// 
+    /// # Features
+    /// It changes only the least significant bit of each byte, which is not
+    /// used for encryption. So, the round keys do not change.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::DES;
+    /// 
+    /// let mut des = DES::new_with_key([0x12_u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+    /// assert!(!des.has_odd_parity());
+    /// des.set_odd_parity();
+    /// assert_eq!(des.get_key(), [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+    /// assert!(des.check_key().is_ok());
+    /// ```
+    pub fn set_odd_parity(&mut self)
+    {
+        for i in 0..8
+        {
+            let k = self.key.get_ubyte_(i) & 0xFE;
+            self.key.set_ubyte_(i, k | ((k.count_ones() as u8 & 1) ^ 1));
+        }
+    }
+
+    /// Checks the keys of multiple encryption such as 2DES and 3DES.
+    /// 
+    /// # Arguments
+    /// The argument `keys` is the slice of the keys in the order of use.
+    /// For example, it is `[K1, K2, K3]` for 3DES in EDE.
+    /// 
+    /// # Output
+    /// It returns the error of `check_key()` of any key, or
+    /// `KeyError::DegenerateKeys` if two adjacent keys are the same except
+    /// the parity bits. For example, 3DES with `K1 == K2` degenerates into
+    /// single DES with `K3`, because encryption with `K1` and decryption
+    /// with `K2` cancel each other. `K1 == K3` is allowed because it is
+    /// two-key 3DES.
+    /// 
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, KeyError };
+    /// 
+    /// let k1 = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+    /// let k2 = [0x23_u8, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01];
+    /// assert!(DES::check_multiple_keys(&[k1, k2, k1]).is_ok());
+    /// assert_eq!(DES::check_multiple_keys(&[k1, k1, k2]), Err(KeyError::DegenerateKeys));
+    /// ```
+    pub fn check_multiple_keys(keys: &[[u8; 8]]) -> Result<(), KeyError>
+    {
+        let mut previous: Option<Self> = None;
+        for key in keys
+        {
+            let des = Self::new_with_key_checked(*key)?;
+            if let Some(prev) = previous
+            {
+                if prev.round_key == des.round_key
+                    { return Err(KeyError::DegenerateKeys); }
+            }
+            previous = Some(des);
+        }
+        Ok(())
     }
 
     /// Encrypts the data with the padding defined in PKCS #7.
@@ -1126,16 +1448,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
         }
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        let padding_bytes = decoded_union.get_ubyte_(7);
-        let message_bytes = 8 - padding_bytes as usize;
-        for i in (message_bytes)..8
-        {
-            if decoded_union.get_ubyte_(i) != padding_bytes
-                { return 0; }
-        }
-        unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-        progress + message_bytes as u64
+        Self::put_unpadded(decoded, check_pkcs7_padding, message, progress)
     }
 
     pub fn decrypt_with_padding_pkcs7_into_vec(&mut self, cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
@@ -1156,20 +1469,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
         }
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = self.decrypt_u64(block);
-        decoded_union.set(decoded);
-        let padding_bytes = decoded_union.get_ubyte_(7);
-        let message_bytes = 8 - padding_bytes as usize;
-        for i in (message_bytes)..8
-        {
-            if decoded_union.get_ubyte_(i) != padding_bytes
-            { 
-                message.clear();
-                return;
-            }
-        }
-        decoded_union.set(decoded);
-        for i in 0..message_bytes
-            { message.push(decoded_union.get_ubyte_(i)); }
+        Self::push_unpadded(decoded, check_pkcs7_padding, message);
     }
 
     pub fn encrypt_with_padding_iso(&mut self, message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
@@ -1246,23 +1546,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
 
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        for i in 0..8_usize
-        {
-            if decoded_union.get_ubyte_(7-i) == 0
-                { continue; }
-            if decoded_union.get_ubyte_(7-i) == 0b_1000_0000_u8
-            {
-                let message_bytes = 7-i;
-                unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-                return progress + message_bytes as u64
-            }
-            else
-            {
-                return 0;
-            }
-        }
-        return 0;
+        Self::put_unpadded(decoded, check_iso7816_padding, message, progress)
     }
 
     pub fn decrypt_with_padding_iso_into_vec(&mut self, cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
@@ -1283,22 +1567,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
 
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = self.decrypt_u64(block);
-        decoded_union.set(decoded);
-        for i in 0..8_usize
-        {
-            if decoded_union.get_ubyte_(7-i) == 0
-                { continue; }
-            if decoded_union.get_ubyte_(7-i) == 0b_1000_0000_u8
-            {
-                let message_bytes = 7-i;
-                for j in 0..message_bytes
-                    { message.push(decoded_union.get_ubyte_(j)); }
-            }
-            else
-            {
-                message.clear();
-            }
-        }
+        Self::push_unpadded(decoded, check_iso7816_padding, message);
     }
 
     #[inline]
@@ -1524,18 +1793,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
         }
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = iv ^ self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        let padding_bytes = decoded_union.get_ubyte_(7);
-        if padding_bytes > 8
-            { return 0; }
-        let message_bytes = 8 - padding_bytes as usize;
-        for i in (message_bytes)..8
-        {
-            if decoded_union.get_ubyte_(i) != padding_bytes
-                { return 0; }
-        }
-        unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-        progress + message_bytes as u64
+        Self::put_unpadded(decoded, check_pkcs7_padding, message, progress)
     }
 
     pub fn encrypt_with_padding_iso_cbc(&mut self, iv: u64, message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
@@ -1580,23 +1838,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
 
         block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         decoded = iv ^ self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        for i in 0..8_usize
-        {
-            if decoded_union.get_ubyte_(7-i) == 0
-                { continue; }
-            if decoded_union.get_ubyte_(7-i) == 0b_1000_0000_u8
-            {
-                let message_bytes = 7-i;
-                unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-                return progress + message_bytes as u64
-            }
-            else
-            {
-                return 0;
-            }
-        }
-        return 0;
+        Self::put_unpadded(decoded, check_iso7816_padding, message, progress)
     }
 
     #[inline]
@@ -1694,16 +1936,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
 
         let block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         let decoded = iv ^ self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        let padding_bytes = decoded_union.get_ubyte_(7);
-        let message_bytes = 8 - padding_bytes as usize;
-        for i in (message_bytes)..8
-        {
-            if decoded_union.get_ubyte_(i) != padding_bytes
-                { return 0; }
-        }
-        unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-        progress + message_bytes as u64
+        Self::put_unpadded(decoded, check_pkcs7_padding, message, progress)
     }
 
     pub fn encrypt_with_padding_iso_pcbc(&mut self, mut iv: u64, message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
@@ -1747,23 +1980,7 @@ S756, S757, S758, S759, S760, S761, S762, S763
 
         let block = unsafe { *(cipher.add(progress as usize) as *const u64 ) };
         let decoded = iv ^ self.decrypt_u64(block);
-        let decoded_union = LongUnion::new_with(decoded);
-        for i in 0..8_usize
-        {
-            if decoded_union.get_ubyte_(7-i) == 0
-                { continue; }
-            if decoded_union.get_ubyte_(7-i) == 0b_1000_0000_u8
-            {
-                let message_bytes = 7-i;
-                unsafe { copy_nonoverlapping(&decoded as *const u64 as *const u8, message.add(progress as usize), message_bytes); }
-                return progress + message_bytes as u64
-            }
-            else
-            {
-                return 0;
-            }
-        }
-        return 0;
+        Self::put_unpadded(decoded, check_iso7816_padding, message, progress)
     }
 
     #[inline]
@@ -2069,6 +2286,90 @@ S756, S757, S758, S759, S760, S761, S762, S763
         self.get_block()
     }
 
+    // Encrypts or decrypts one block of u64 with the S-boxes `sbox` given
+    // at runtime instead of S000 ~ S763. DES_RuntimeSbox_Generic uses it.
+    pub(crate) fn crypt_u64_with_sbox(&mut self, block: u64, sbox: &[[u8; 64]; 8], decrypt: bool) -> u64
+    {
+        self.set_block(block);
+        self.permutate_initially();
+        for i in 0..ROUND
+        {
+            let round = if decrypt { ROUND - 1 - i } else { i };
+            let right = self.block.get_uint_(1);
+            let left = self.block.get_uint_(0) ^ self.f_with_sbox(round, right, sbox);
+            self.block.set_uint_(0, right);
+            self.block.set_uint_(1, left);
+        }
+        let left = self.block.get_uint_(0);
+        let right = self.block.get_uint_(1);
+        self.block.set_uint_(0, right);
+        self.block.set_uint_(1, left);
+        self.permutate_finally();
+        self.get_block()
+    }
+
+    // Gets the longer period of the two halves of the key after PC-1, which
+    // is 1, 2 or 4 for the weak keys, the semi-weak keys and the possibly
+    // weak keys respectively, and 28 for the other keys.
+    fn get_period_of_halfkeys(&self) -> usize
+    {
+        let (left, right) = self.split();
+        Self::get_period_of_halfkey(left).max(Self::get_period_of_halfkey(right))
+    }
+
+    // Gets the period of the 28-bit half of the key in the layout of split().
+    fn get_period_of_halfkey(half: IntUnion) -> usize
+    {
+        let mut rotated = half;
+        rotate_halfkey!(rotated, false);
+        if rotated.get() == half.get()
+            { return 1; }
+        let mut rotated = half;
+        rotate_halfkey!(rotated, true);
+        if rotated.get() == half.get()
+            { return 2; }
+        rotate_halfkey!(rotated, true);
+        if rotated.get() == half.get()
+            { return 4; }
+        28
+    }
+
+    // Checks the padding of the last decrypted block `decoded` in constant
+    // time and writes the message bytes of it to `message` at `progress`.
+    // It returns the total length of the message. On any invalid padding,
+    // it wipes what has been written to `message` and returns 0, which is
+    // the single error result, so that the caller cannot tell which check
+    // of the padding failed.
+    fn put_unpadded(decoded: u64, check: fn(&[u8]) -> (usize, u8), message: *mut u8, progress: u64) -> u64
+    {
+        let block = decoded.to_ne_bytes();
+        let (padding, valid) = check(&block);
+        if valid == 0
+        {
+            unsafe { write_bytes(message, 0, progress as usize); }
+            return 0;
+        }
+        let message_bytes = 8 - padding;
+        unsafe { copy_nonoverlapping(block.as_ptr(), message.add(progress as usize), message_bytes); }
+        progress + message_bytes as u64
+    }
+
+    // Checks the padding of the last decrypted block `decoded` in constant
+    // time and pushes the message bytes of it to `message`. On any invalid
+    // padding, it wipes and clears `message`.
+    fn push_unpadded(decoded: u64, check: fn(&[u8]) -> (usize, u8), message: &mut Vec<u8>)
+    {
+        let block = decoded.to_ne_bytes();
+        let (padding, valid) = check(&block);
+        if valid == 0
+        {
+            message.fill(0);
+            message.clear();
+            return;
+        }
+        message.extend_from_slice(&block[..8 - padding]);
+    }
+
     fn encrypt_block(&mut self)
     {
         self.permutate_initially();
@@ -2121,6 +2422,22 @@ S756, S757, S758, S759, S760, S761, S762, S763
         self.translate(out)
     }
 
+    fn f_with_sbox(&self, round: usize, right: u32, sbox: &[[u8; 64]; 8]) -> u32
+    {
+        let expanded = self.expand(right);
+        let indices = expanded ^ self.round_key[round];
+        let mut idx = [0_usize; 8];
+        slice_index!(indices, idx);
+        let mut out = 0_u32;
+        for i in 0..4
+        {
+            let left = i * 2;
+            let right = left + 1;
+            combine_pieces!(out, ((sbox[left][idx[left]] << 4) | sbox[right][idx[right]]) as u32);
+        }
+        self.translate(out)
+    }
+
     fn make_round_keys(&mut self)
     {
         let (mut left, mut right) = self.split();
@@ -0,0 +1,477 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the bitsliced constant-time DES
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::BlockCipher;
+
+
+/// The bitsliced DES, which encrypts and decrypts 64 blocks in parallel
+/// in constant time
+///
+/// # Note
+/// *This descryption about DES is according to big endianness.*
+/// MSB (Most Significant Bit) is the first bit and LSB (Least Significant Bit)
+/// is the 64th bit in this descryption.
+///
+/// # Introduction
+/// `DES_Bitsliced` is an alternative backend of the official DES, which
+/// gives the same results as `DES`. It transposes 64 blocks into 64 words
+/// of `u64` so that the `i`-th word has the `i`-th bits of all the 64
+/// blocks. Then, the permutations become the mere renaming of the words,
+/// and the S-boxes become the boolean circuits of the words. So, one pass
+/// of the boolean circuits encrypts or decrypts 64 blocks at once.
+///
+/// It has the trait `BlockCipher` and overrides `encrypt_blocks()` and
+/// `decrypt_blocks()`. So, if you plug it into `ModeCipher`, `Encryptor`
+/// or `Decryptor`, the bulk paths of ECB mode and CTR mode encrypt and
+/// decrypt 64 blocks at once. `Encryptor` gathers the whole blocks of
+/// each call of `write()`, so that writing large chunks such as by
+/// `std::io::copy()` makes the most of it.
+///
+/// # Vulnerability
+/// `DES_Generic` looks up the S-box tables with the indices derived from
+/// the key and the data, and its permutations have branches depending on
+/// the data, which may leak the key through the cache timing.
+/// `DES_Bitsliced` has neither table lookups indexed by secret data nor
+/// branches depending on secret data, so its running time does not
+/// depend on the key and the data. However, it does not make DES itself
+/// secure. The key of DES is only 56 bits long and its block is only 64
+/// bits long.
+///
+/// The modes of operation which chain blocks such as CBC, PCBC and CFB
+/// encrypt one block at a time, in which case `DES_Bitsliced` is still
+/// constant-time but 64 times slower than its bulk paths because it
+/// processes 64 slots for one block.
+///
+/// # Features
+/// It supports only the standard parameters of DES. The expanded versions
+/// of `DES_Generic` are not supported.
+///
+/// # Reference
+/// Read [more](https://link.springer.com/chapter/10.1007/BFb0052352) about
+/// bitslicing, which was introduced by Eli Biham.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ DES, DES_Bitsliced, ModeCipher, OperationMode };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x87_u8, 0x65, 0x43, 0x21, 0x09, 0xBA, 0xDC, 0xFE];
+/// let message = [0x5A_u8; 1000];
+/// let mut bitsliced = ModeCipher::new(DES_Bitsliced::new_with_key(key), OperationMode::CTR, &iv);
+/// let cipher = bitsliced.encrypt(&message);
+/// assert_eq!(cipher, ModeCipher::new(DES::new_with_key(key), OperationMode::CTR, &iv).encrypt(&message));
+/// assert_eq!(bitsliced.decrypt(&cipher).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct DES_Bitsliced
+{
+    key: [u8; 8],
+    round_key: [u64; 16],
+}
+
+impl DES_Bitsliced
+{
+    // The tables are 1-based in big endianness as in FIPS 46-3.
+    const IP: [u8; 64] = [
+        58, 50, 42, 34, 26, 18, 10, 2, 60, 52, 44, 36, 28, 20, 12, 4,
+        62, 54, 46, 38, 30, 22, 14, 6, 64, 56, 48, 40, 32, 24, 16, 8,
+        57, 49, 41, 33, 25, 17,  9, 1, 59, 51, 43, 35, 27, 19, 11, 3,
+        61, 53, 45, 37, 29, 21, 13, 5, 63, 55, 47, 39, 31, 23, 15, 7
+    ];
+
+    const EP: [u8; 48] = [
+        32,  1,  2,  3,  4,  5,  4,  5,  6,  7,  8,  9,
+         8,  9, 10, 11, 12, 13, 12, 13, 14, 15, 16, 17,
+        16, 17, 18, 19, 20, 21, 20, 21, 22, 23, 24, 25,
+        24, 25, 26, 27, 28, 29, 28, 29, 30, 31, 32,  1
+    ];
+
+    const TP: [u8; 32] = [
+        16,  7, 20, 21, 29, 12, 28, 17,  1, 15, 23, 26,  5, 18, 31, 10,
+         2,  8, 24, 14, 32, 27,  3,  9, 19, 13, 30,  6, 22, 11,  4, 25
+    ];
+
+    const PC1: [u8; 56] = [
+        57, 49, 41, 33, 25, 17,  9,  1, 58, 50, 42, 34, 26, 18,
+        10,  2, 59, 51, 43, 35, 27, 19, 11,  3, 60, 52, 44, 36,
+        63, 55, 47, 39, 31, 23, 15,  7, 62, 54, 46, 38, 30, 22,
+        14,  6, 61, 53, 45, 37, 29, 21, 13,  5, 28, 20, 12,  4
+    ];
+
+    const PC2: [u8; 48] = [
+        14, 17, 11, 24,  1,  5,  3, 28, 15,  6, 21, 10,
+        23, 19, 12,  4, 26,  8, 16,  7, 27, 20, 13,  2,
+        41, 52, 31, 37, 47, 55, 30, 40, 51, 45, 33, 48,
+        44, 49, 39, 56, 34, 53, 46, 42, 50, 36, 29, 32
+    ];
+
+    const SHIFT: [usize; 16] = [1, 1, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1];
+
+    // Each S-box is 4 rows of 16 columns as in FIPS 46-3.
+    const SBOX: [[u8; 64]; 8] = [
+        [   14,  4, 13,  1,  2, 15, 11,  8,  3, 10,  6, 12,  5,  9,  0,  7,
+             0, 15,  7,  4, 14,  2, 13,  1, 10,  6, 12, 11,  9,  5,  3,  8,
+             4,  1, 14,  8, 13,  6,  2, 11, 15, 12,  9,  7,  3, 10,  5,  0,
+            15, 12,  8,  2,  4,  9,  1,  7,  5, 11,  3, 14, 10,  0,  6, 13 ],
+        [   15,  1,  8, 14,  6, 11,  3,  4,  9,  7,  2, 13, 12,  0,  5, 10,
+             3, 13,  4,  7, 15,  2,  8, 14, 12,  0,  1, 10,  6,  9, 11,  5,
+             0, 14,  7, 11, 10,  4, 13,  1,  5,  8, 12,  6,  9,  3,  2, 15,
+            13,  8, 10,  1,  3, 15,  4,  2, 11,  6,  7, 12,  0,  5, 14,  9 ],
+        [   10,  0,  9, 14,  6,  3, 15,  5,  1, 13, 12,  7, 11,  4,  2,  8,
+            13,  7,  0,  9,  3,  4,  6, 10,  2,  8,  5, 14, 12, 11, 15,  1,
+            13,  6,  4,  9,  8, 15,  3,  0, 11,  1,  2, 12,  5, 10, 14,  7,
+             1, 10, 13,  0,  6,  9,  8,  7,  4, 15, 14,  3, 11,  5,  2, 12 ],
+        [    7, 13, 14,  3,  0,  6,  9, 10,  1,  2,  8,  5, 11, 12,  4, 15,
+            13,  8, 11,  5,  6, 15,  0,  3,  4,  7,  2, 12,  1, 10, 14,  9,
+            10,  6,  9,  0, 12, 11,  7, 13, 15,  1,  3, 14,  5,  2,  8,  4,
+             3, 15,  0,  6, 10,  1, 13,  8,  9,  4,  5, 11, 12,  7,  2, 14 ],
+        [    2, 12,  4,  1,  7, 10, 11,  6,  8,  5,  3, 15, 13,  0, 14,  9,
+            14, 11,  2, 12,  4,  7, 13,  1,  5,  0, 15, 10,  3,  9,  8,  6,
+             4,  2,  1, 11, 10, 13,  7,  8, 15,  9, 12,  5,  6,  3,  0, 14,
+            11,  8, 12,  7,  1, 14,  2, 13,  6, 15,  0,  9, 10,  4,  5,  3 ],
+        [   12,  1, 10, 15,  9,  2,  6,  8,  0, 13,  3,  4, 14,  7,  5, 11,
+            10, 15,  4,  2,  7, 12,  9,  5,  6,  1, 13, 14,  0, 11,  3,  8,
+             9, 14, 15,  5,  2,  8, 12,  3,  7,  0,  4, 10,  1, 13, 11,  6,
+             4,  3,  2, 12,  9,  5, 15, 10, 11, 14,  1,  7,  6,  0,  8, 13 ],
+        [    4, 11,  2, 14, 15,  0,  8, 13,  3, 12,  9,  7,  5, 10,  6,  1,
+            13,  0, 11,  7,  4,  9,  1, 10, 14,  3,  5, 12,  2, 15,  8,  6,
+             1,  4, 11, 13, 12,  3,  7, 14, 10, 15,  6,  8,  0,  5,  9,  2,
+             6, 11, 13,  8,  1,  4, 10,  7,  9,  5,  0, 15, 14,  2,  3, 12 ],
+        [   13,  2,  8,  4,  6, 15, 11,  1, 10,  9,  3, 14,  5,  0, 12,  7,
+             1, 15, 13,  8, 10,  3,  7,  4, 12,  5,  6, 11,  0, 14,  9,  2,
+             7, 11,  4,  1,  9, 12, 14,  2,  0,  6, 10, 13, 15,  3,  5,  8,
+             2,  1, 14,  7,  4, 10,  8, 13, 15, 12,  9,  0,  3,  5,  6, 11 ]
+    ];
+
+    // The columns of the S-boxes. The `c`-th bit of `COLUMN[s][t][r]` is
+    // the `t`-th output bit (MSB first) of the S-box `s` at the row `r` and
+    // the column `c`.
+    const COLUMN: [[[u16; 4]; 4]; 8] = Self::make_columns();
+
+    const fn make_columns() -> [[[u16; 4]; 4]; 8]
+    {
+        let mut column = [[[0_u16; 4]; 4]; 8];
+        let mut s = 0;
+        while s < 8
+        {
+            let mut i = 0;
+            while i < 64
+            {
+                let out = Self::SBOX[s][i];
+                let mut t = 0;
+                while t < 4
+                {
+                    column[s][t][i / 16] |= (((out >> (3 - t)) & 1) as u16) << (i % 16);
+                    t += 1;
+                }
+                i += 1;
+            }
+            s += 1;
+        }
+        column
+    }
+
+    /// Constructs a new object DES_Bitsliced.
+    ///
+    /// # Features
+    /// This method sets the key to be [0, 0, 0, 0, 0, 0, 0, 0].
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 8])
+    }
+
+    /// Constructs a new object DES_Bitsliced.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 8 elements.
+    ///
+    /// # Features
+    /// This method sets the key to be the given argument `key`.
+    pub fn new_with_key(key: [u8; 8]) -> Self
+    {
+        let mut des = Self { key, round_key: [0_u64; 16] };
+        des.make_round_keys();
+        des
+    }
+
+    /// Constructs a new object DES_Bitsliced.
+    ///
+    /// # Arguments
+    /// The argument `key` is of `u64` in the same byte order as
+    /// `DES_Generic::new_with_key_u64()`.
+    ///
+    /// # Features
+    /// This method sets the key to be the given argument `key`.
+    #[inline]
+    pub fn new_with_key_u64(key: u64) -> Self
+    {
+        Self::new_with_key(key.to_le_bytes())
+    }
+
+    /// Sets the key.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 8 elements.
+    ///
+    /// # Features
+    /// This method sets the key to be the given argument `key`, and makes
+    /// the round keys from it again.
+    pub fn set_key(&mut self, key: [u8; 8])
+    {
+        self.key = key;
+        self.make_round_keys();
+    }
+
+    /// Sets the key.
+    ///
+    /// # Arguments
+    /// The argument `key` is of `u64` in the same byte order as
+    /// `DES_Generic::set_key_u64()`.
+    #[inline]
+    pub fn set_key_u64(&mut self, key: u64)
+    {
+        self.set_key(key.to_le_bytes());
+    }
+
+    /// Gets the key.
+    ///
+    /// # Output
+    /// It returns the key as the array of u8 that has 8 elements.
+    #[inline]
+    pub fn get_key(&self) -> [u8; 8]
+    {
+        self.key
+    }
+
+    /// Encrypts the array of `u64` in ECB manner.
+    ///
+    /// # Arguments
+    /// - `message` is the array of the blocks of `u64` in the same byte
+    ///   order as `DES_Generic::encrypt_array_u64()`.
+    /// - `cipher` is the array to which the encrypted blocks will be
+    ///   written.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, DES_Bitsliced };
+    ///
+    /// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    /// let mut message = [0_u64; 100];
+    /// for (i, m) in message.iter_mut().enumerate()
+    ///     { *m = (i as u64).wrapping_mul(0x9E3779B97F4A7C15); }
+    /// let mut cipher = [0_u64; 100];
+    /// DES_Bitsliced::new_with_key(key).encrypt_array_u64(&message, &mut cipher);
+    /// let mut expected = [0_u64; 100];
+    /// DES::new_with_key(key).encrypt_array_u64(&message, &mut expected);
+    /// assert_eq!(cipher, expected);
+    /// ```
+    pub fn encrypt_array_u64<const N: usize>(&mut self, message: &[u64; N], cipher: &mut [u64; N])
+    {
+        let mut blocks = [0_u8; 512];
+        for (m, c) in message.chunks(64).zip(cipher.chunks_mut(64))
+        {
+            let blocks = &mut blocks[..m.len() * 8];
+            for (b, m) in blocks.chunks_exact_mut(8).zip(m.iter())
+                { b.copy_from_slice(&m.to_le_bytes()); }
+            self.crypt(blocks, false);
+            for (c, b) in c.iter_mut().zip(blocks.chunks_exact(8))
+                { *c = u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]); }
+        }
+    }
+
+    /// Decrypts the array of `u64` in ECB manner.
+    ///
+    /// # Arguments
+    /// - `cipher` is the array of the encrypted blocks of `u64` in the same
+    ///   byte order as `DES_Generic::decrypt_array_u64()`.
+    /// - `message` is the array to which the decrypted blocks will be
+    ///   written.
+    pub fn decrypt_array_u64<const N: usize>(&mut self, cipher: &[u64; N], message: &mut [u64; N])
+    {
+        let mut blocks = [0_u8; 512];
+        for (c, m) in cipher.chunks(64).zip(message.chunks_mut(64))
+        {
+            let blocks = &mut blocks[..c.len() * 8];
+            for (b, c) in blocks.chunks_exact_mut(8).zip(c.iter())
+                { b.copy_from_slice(&c.to_le_bytes()); }
+            self.crypt(blocks, true);
+            for (m, b) in m.iter_mut().zip(blocks.chunks_exact(8))
+                { *m = u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]); }
+        }
+    }
+
+    // Makes the sixteen round keys of 48 bits. The `i`-th bit of a round
+    // key is its (i + 1)-th bit in big endianness.
+    fn make_round_keys(&mut self)
+    {
+        let mut cd = [0_u8; 56];
+        for (c, p) in cd.iter_mut().zip(Self::PC1.iter())
+            { *c = Self::bit_of(&self.key, *p); }
+        for (round_key, shift) in self.round_key.iter_mut().zip(Self::SHIFT.iter())
+        {
+            cd[..28].rotate_left(*shift);
+            cd[28..].rotate_left(*shift);
+            *round_key = 0;
+            for (i, p) in Self::PC2.iter().enumerate()
+                { *round_key |= (cd[*p as usize - 1] as u64) << i; }
+        }
+    }
+
+    // Returns the `position`-th bit (1-based in big endianness) of `bytes`.
+    #[inline]
+    fn bit_of(bytes: &[u8], position: u8) -> u8
+    {
+        let position = position as usize - 1;
+        (bytes[position / 8] >> (7 - position % 8)) & 1
+    }
+
+    // Encrypts or decrypts `blocks` which are at most 64 blocks.
+    fn crypt(&self, blocks: &mut [u8], decrypt: bool)
+    {
+        // After the transposition, the `i`-th bits (1-based in big
+        // endianness) of all the blocks are in `slice[(i - 1) ^ 7]`.
+        let mut slice = [0_u64; 64];
+        for (s, block) in slice.iter_mut().zip(blocks.chunks_exact(8))
+            { *s = u64::from_le_bytes([block[0], block[1], block[2], block[3], block[4], block[5], block[6], block[7]]); }
+        Self::transpose(&mut slice);
+
+        let mut data = [0_u64; 64];
+        for (d, p) in data.iter_mut().zip(Self::IP.iter())
+            { *d = slice[(*p as usize - 1) ^ 7]; }
+        let (left, right) = data.split_at_mut(32);
+        for round in 0..16
+        {
+            let round_key = if decrypt { self.round_key[15 - round] } else { self.round_key[round] };
+            let f = Self::f(right, round_key);
+            for (l, f) in left.iter_mut().zip(f.iter())
+                { *l ^= *f; }
+            left.swap_with_slice(right);
+        }
+        left.swap_with_slice(right);
+
+        for (d, p) in data.iter().zip(Self::IP.iter())
+            { slice[(*p as usize - 1) ^ 7] = *d; }
+        Self::transpose(&mut slice);
+        for (block, s) in blocks.chunks_exact_mut(8).zip(slice.iter())
+            { block.copy_from_slice(&s.to_le_bytes()); }
+    }
+
+    // Transposes the 64 x 64 bit matrix whose `j`-th row is `matrix[j]`
+    // and whose `k`-th column is the `k`-th bits of the rows.
+    fn transpose(matrix: &mut [u64; 64])
+    {
+        let mut width = 32;
+        let mut mask = 0x_00000000_FFFFFFFF_u64;
+        while width != 0
+        {
+            for w in (0..64).filter(|w| w & width == 0)
+            {
+                let t = ((matrix[w] >> width) ^ matrix[w + width]) & mask;
+                matrix[w + width] ^= t;
+                matrix[w] ^= t << width;
+            }
+            width >>= 1;
+            mask ^= mask << width;
+        }
+    }
+
+    // The round function of 64 blocks in parallel
+    fn f(right: &[u64], round_key: u64) -> [u64; 32]
+    {
+        let mut out = [0_u64; 32];
+        for s in 0..8
+            { Self::sbox(s, right, round_key, &mut out); }
+        let mut f = [0_u64; 32];
+        for (f, p) in f.iter_mut().zip(Self::TP.iter())
+            { *f = out[*p as usize - 1]; }
+        f
+    }
+
+    // Evaluates the S-box `s` as a boolean circuit, and writes its four
+    // output bits to `out[4 * s..4 * s + 4]`. The middle four input bits
+    // make the sixteen minterms, which are combined by the masks made of
+    // the constants of the S-box into the four rows, and the outer two
+    // input bits select the row by multiplexers. So, the circuit has
+    // neither table lookups nor branches depending on the input.
+    #[inline]
+    fn sbox(s: usize, right: &[u64], round_key: u64, out: &mut [u64; 32])
+    {
+        let mut input = [0_u64; 6];
+        for (k, x) in input.iter_mut().enumerate()
+        {
+            let i = s * 6 + k;
+            *x = right[Self::EP[i] as usize - 1] ^ ((round_key >> i) & 1).wrapping_neg();
+        }
+        let high = [!input[1] & !input[2], !input[1] & input[2], input[1] & !input[2], input[1] & input[2]];
+        let low = [!input[3] & !input[4], !input[3] & input[4], input[3] & !input[4], input[3] & input[4]];
+        let mut minterm = [0_u64; 16];
+        for (c, m) in minterm.iter_mut().enumerate()
+            { *m = high[c >> 2] & low[c & 0b11]; }
+
+        for (o, columns) in out[s * 4..s * 4 + 4].iter_mut().zip(Self::COLUMN[s].iter())
+        {
+            let mut row = [0_u64; 4];
+            for (r, set) in row.iter_mut().zip(columns.iter())
+            {
+                for (c, m) in minterm.iter().enumerate()
+                    { *r |= *m & ((*set as u64 >> c) & 1).wrapping_neg(); }
+            }
+            let even = row[0] ^ ((row[0] ^ row[1]) & input[5]);
+            let odd = row[2] ^ ((row[2] ^ row[3]) & input[5]);
+            *o = even ^ ((even ^ odd) & input[0]);
+        }
+    }
+}
+
+impl Default for DES_Bitsliced
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl BlockCipher for DES_Bitsliced
+{
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        assert_eq!(block.len(), 8);
+        self.crypt(block, false);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        assert_eq!(block.len(), 8);
+        self.crypt(block, true);
+    }
+
+    fn encrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % 8, 0);
+        for chunk in blocks.chunks_mut(512)
+            { self.crypt(chunk, false); }
+    }
+
+    fn decrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % 8, 0);
+        for chunk in blocks.chunks_mut(512)
+            { self.crypt(chunk, true); }
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains DES with the S-boxes given at runtime
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::{ BlockCipher, DES_Generic };
+
+
+/// DES with the S-boxes given at runtime, whose other parameters are the
+/// same as the official DES
+#[allow(non_camel_case_types)]
+pub type DES_RuntimeSbox = DES_RuntimeSbox_Generic;
+
+/// DES whose eight S-boxes are given at runtime instead of the generic
+/// parameters S000 ~ S763 of `DES_Generic`
+///
+/// # Introduction
+/// `DES_Generic` fixes its S-boxes at compile time by the generic
+/// parameters S000 ~ S763, so that it can look them up as constants.
+/// Some variants of DES, however, choose the S-boxes after compilation,
+/// for example, DES with the S-boxes derived from the key by Biham and
+/// Biryukov. `DES_RuntimeSbox_Generic` keeps its own S-boxes and uses
+/// them instead of those of `DES_Generic`. Its key schedule, permutations
+/// and rounds are the same as those of `DES_Generic<ROUND, SHIFT>`.
+///
+/// `DES_RuntimeSbox_Generic` has the trait `BlockCipher`. So, you can use
+/// it with `ModeCipher`, `Encryptor` and `Decryptor` in all the modes of
+/// operation.
+///
+/// # Vulnerability
+/// The security of DES depends on its S-boxes a lot. Randomly chosen
+/// S-boxes are usually much weaker against the differential and linear
+/// cryptanalysis than the S-boxes of the official DES. Use it only for
+/// study or for the compatibility with the existing systems.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds. The default value is `16`.
+/// - SHIFT: the shift schedule of the key. It is the same as `SHIFT` of
+///   `DES_Generic`.
+///
+/// # Reference
+/// Read [more](https://en.wikipedia.org/wiki/Data_Encryption_Standard)
+/// about DES and its S-boxes in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ DES, DES_RuntimeSbox };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let message = 0x_EFCDAB89_67452301_u64;
+///
+/// // With the S-boxes of the official DES, it is the same as DES.
+/// let mut a_des = DES_RuntimeSbox::new_with_key_and_sbox(key, DES::get_sbox_table());
+/// assert_eq!(a_des.encrypt_u64(message), DES::new_with_key(key).encrypt_u64(message));
+///
+/// // The S-boxes of DES in the reverse order
+/// let mut sbox = DES::get_sbox_table();
+/// sbox.reverse();
+/// a_des.set_sbox(sbox);
+/// let cipher = a_des.encrypt_u64(message);
+/// assert_ne!(cipher, DES::new_with_key(key).encrypt_u64(message));
+/// assert_eq!(a_des.decrypt_u64(cipher), message);
+/// ```
+#[allow(non_camel_case_types)]
+pub struct DES_RuntimeSbox_Generic<const ROUND: usize = 16, const SHIFT: u128 = 0b_1000000100000011>
+{
+    des: DES_Generic<ROUND, SHIFT>,
+    sbox: [[u8; 64]; 8],
+}
+
+impl<const ROUND: usize, const SHIFT: u128> DES_RuntimeSbox_Generic<ROUND, SHIFT>
+{
+    /// Constructs a new object DES_RuntimeSbox_Generic.
+    ///
+    /// # Arguments
+    /// - `key` is the array of u8 that has 8 elements.
+    /// - `sbox` is the eight S-boxes. Each S-box is an array of 64 four-bit
+    ///   numbers whose index is the six input bits of the S-box, which is
+    ///   the same layout as the generic parameters S000 ~ S763 of
+    ///   `DES_Generic`. If your S-boxes are of 4 rows and 16 columns as in
+    ///   FIPS 46-3, rearrange them with `sbox_from_rows()`.
+    ///
+    /// # Panics
+    /// It panics if any element of `sbox` is not less than 16.
+    pub fn new_with_key_and_sbox(key: [u8; 8], sbox: [[u8; 64]; 8]) -> Self
+    {
+        let mut des = Self { des: DES_Generic::<ROUND, SHIFT>::new_with_key(key), sbox: [[0_u8; 64]; 8] };
+        des.set_sbox(sbox);
+        des
+    }
+
+    /// Sets the key.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 8 elements.
+    ///
+    /// # Features
+    /// The S-boxes are kept.
+    #[inline]
+    pub fn set_key(&mut self, key: [u8; 8])
+    {
+        self.des.set_key(key);
+    }
+
+    /// Sets the S-boxes.
+    ///
+    /// # Arguments
+    /// `sbox` is the eight S-boxes in the same layout as the argument `sbox`
+    /// of `new_with_key_and_sbox()`.
+    ///
+    /// # Features
+    /// The key and the round keys are kept.
+    ///
+    /// # Panics
+    /// It panics if any element of `sbox` is not less than 16.
+    pub fn set_sbox(&mut self, sbox: [[u8; 64]; 8])
+    {
+        assert!(sbox.iter().flatten().all(|s| *s < 16), "Every element of S-boxes should be a four-bit number.");
+        self.sbox = sbox;
+    }
+
+    /// Gets the S-boxes which are being used.
+    ///
+    /// # Output
+    /// It returns the eight S-boxes in the same layout as the argument
+    /// `sbox` of `new_with_key_and_sbox()`.
+    #[inline]
+    pub fn get_sbox(&self) -> [[u8; 64]; 8]
+    {
+        self.sbox
+    }
+
+    /// Rearranges the S-boxes of 4 rows and 16 columns as in FIPS 46-3
+    /// into the layout of this crate.
+    ///
+    /// # Arguments
+    /// `rows` is the eight S-boxes. Each S-box is its four rows of 16
+    /// columns in a row. The row is determined by the first and the last
+    /// input bits, and the column is determined by the middle four input
+    /// bits.
+    ///
+    /// # Output
+    /// It returns the eight S-boxes whose index is the six input bits, which
+    /// is the layout of the generic parameters S000 ~ S763 of `DES_Generic`
+    /// and the argument `sbox` of `new_with_key_and_sbox()`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, DES_RuntimeSbox };
+    ///
+    /// let mut rows = [[0_u8; 64]; 8];
+    /// rows[0] = [ 14,  4, 13,  1,  2, 15, 11,  8,  3, 10,  6, 12,  5,  9,  0,  7,
+    ///              0, 15,  7,  4, 14,  2, 13,  1, 10,  6, 12, 11,  9,  5,  3,  8,
+    ///              4,  1, 14,  8, 13,  6,  2, 11, 15, 12,  9,  7,  3, 10,  5,  0,
+    ///             15, 12,  8,  2,  4,  9,  1,  7,  5, 11,  3, 14, 10,  0,  6, 13 ];
+    /// let sbox = DES_RuntimeSbox::sbox_from_rows(rows);
+    /// assert_eq!(sbox[0], DES::get_sbox_table()[0]);
+    /// ```
+    pub fn sbox_from_rows(rows: [[u8; 64]; 8]) -> [[u8; 64]; 8]
+    {
+        let mut sbox = [[0_u8; 64]; 8];
+        for (s, r) in sbox.iter_mut().zip(rows.iter())
+        {
+            for (input, element) in s.iter_mut().enumerate()
+            {
+                let row = ((input >> 4) & 0b10) | (input & 1);
+                let column = (input >> 1) & 0b1111;
+                *element = r[row * 16 + column];
+            }
+        }
+        sbox
+    }
+
+    /// Encrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `message` is the block of `u64` in the same byte order as
+    /// `DES_Generic::encrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the encrypted block of `u64`.
+    #[inline]
+    pub fn encrypt_u64(&mut self, message: u64) -> u64
+    {
+        self.des.crypt_u64_with_sbox(message, &self.sbox, false)
+    }
+
+    /// Decrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `cipher` is the block of `u64` in the same byte order as
+    /// `DES_Generic::decrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the decrypted block of `u64`.
+    #[inline]
+    pub fn decrypt_u64(&mut self, cipher: u64) -> u64
+    {
+        self.des.crypt_u64_with_sbox(cipher, &self.sbox, true)
+    }
+}
+
+impl<const ROUND: usize, const SHIFT: u128> BlockCipher for DES_RuntimeSbox_Generic<ROUND, SHIFT>
+{
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 8];
+        buf.copy_from_slice(block);
+        let cipher = self.encrypt_u64(u64::from_le_bytes(buf));
+        block.copy_from_slice(&cipher.to_le_bytes());
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 8];
+        buf.copy_from_slice(block);
+        let message = self.decrypt_u64(u64::from_le_bytes(buf));
+        block.copy_from_slice(&message.to_le_bytes());
+    }
+}
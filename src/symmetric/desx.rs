@@ -0,0 +1,205 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains DESX, which is DES with key whitening
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::{ BlockCipher, DES };
+
+
+/// The official DESX of RSA, which is compatible with BSAFE and OpenSSL
+#[allow(non_camel_case_types)]
+pub type DESX = DESX_Generic<DES>;
+
+/// DESX, which is DES with the pre-whitening key and the post-whitening key
+///
+/// # Introduction
+/// DESX was designed by Ron Rivest in order to make DES stronger against
+/// the exhaustive key search cheaply. It XORs the message with the
+/// pre-whitening key `K1` before DES and XORs the result with the
+/// post-whitening key `K2` after DES.
+///
+/// `C = K2 ^ DES_K(M ^ K1)`
+///
+/// The key of DESX is 24 bytes long: the key `K` of DES, the
+/// pre-whitening key `K1` and the post-whitening key `K2` in this order.
+/// It is the same layout as RSA BSAFE and OpenSSL (`desx-cbc`) use.
+///
+/// `DESX_Generic` has the trait `BlockCipher`. So, you can use it with
+/// `ModeCipher`, `Encryptor` and `Decryptor` in all the modes of operation
+/// and with all the padding schemes that they support, and with the other
+/// generic constructions such as `CMAC_Generic`.
+///
+/// # Vulnerability
+/// The effective key length of DESX against the exhaustive key search is
+/// about 118 bits, but it is not stronger than DES against the
+/// differential and linear cryptanalysis. Its block is still 64 bits long,
+/// so the collisions of blocks become likely after about 2^32 blocks
+/// (32 GB) with the same key. Use it only for the compatibility with the
+/// existing systems.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`, and
+///   whose block is 8 bytes long. It is `DES` for the official DESX, but
+///   it can be any expanded version of `DES_Generic`.
+///
+/// # Reference
+/// Read [more](https://en.wikipedia.org/wiki/DES-X) about DESX in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ DESX, ModeCipher, OperationMode };
+///
+/// let key = [ 0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+///             0xF0, 0xE1, 0xD2, 0xC3, 0xB4, 0xA5, 0x96, 0x87,
+///             0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88 ];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut desx = ModeCipher::new(DESX::new_with_key(key), OperationMode::CBC, &iv);
+/// let cipher = desx.encrypt("Now is the time for all".as_bytes());
+/// let mut txt = String::new();
+/// for c in cipher.iter()
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "4DB0D0573C71B611AF36FB218947A7F877A2595C9A233B93");
+/// assert_eq!(desx.decrypt(&cipher).unwrap(), "Now is the time for all".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct DESX_Generic<C: BlockCipher = DES>
+{
+    cipher: C,
+    pre_whitening: [u8; 8],
+    post_whitening: [u8; 8],
+}
+
+impl DESX_Generic<DES>
+{
+    /// Constructs a new object DESX with the key of 24 bytes.
+    ///
+    /// # Arguments
+    /// `key` is the key of DES, the pre-whitening key and the
+    /// post-whitening key in this order, each of which is 8 bytes long.
+    pub fn new_with_key(key: [u8; 24]) -> Self
+    {
+        let mut des_key = [0_u8; 8];
+        des_key.copy_from_slice(&key[..8]);
+        let mut pre_whitening = [0_u8; 8];
+        pre_whitening.copy_from_slice(&key[8..16]);
+        let mut post_whitening = [0_u8; 8];
+        post_whitening.copy_from_slice(&key[16..]);
+        Self::new(DES::new_with_key(des_key), pre_whitening, post_whitening)
+    }
+}
+
+impl<C: BlockCipher> DESX_Generic<C>
+{
+    /// Constructs a new object DESX_Generic.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key `K`.
+    /// - `pre_whitening` is the pre-whitening key `K1`, which is XORed
+    ///   with the message before `cipher`.
+    /// - `post_whitening` is the post-whitening key `K2`, which is XORed
+    ///   with the result of `cipher`.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 8.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, DESX_Generic };
+    ///
+    /// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    /// let mut desx = DESX_Generic::new(DES::new_with_key(key), [0_u8; 8], [0_u8; 8]);
+    /// // DESX with zero whitening keys is the same as DES.
+    /// assert_eq!(desx.encrypt_u64(0x_EFCDAB89_67452301_u64), DES::new_with_key(key).encrypt_u64(0x_EFCDAB89_67452301_u64));
+    /// ```
+    pub fn new(cipher: C, pre_whitening: [u8; 8], post_whitening: [u8; 8]) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 8, "DESX_Generic supports only 64-bit block ciphers.");
+        Self { cipher, pre_whitening, post_whitening }
+    }
+
+    /// Encrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `message` is the block of `u64` in the same byte order as
+    /// `DES_Generic::encrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the encrypted block of `u64`.
+    pub fn encrypt_u64(&mut self, message: u64) -> u64
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_one_block(&mut block);
+        u64::from_le_bytes(block)
+    }
+
+    /// Decrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `cipher` is the block of `u64` in the same byte order as
+    /// `DES_Generic::decrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the decrypted block of `u64`.
+    pub fn decrypt_u64(&mut self, cipher: u64) -> u64
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_one_block(&mut block);
+        u64::from_le_bytes(block)
+    }
+}
+
+impl<C: BlockCipher> BlockCipher for DESX_Generic<C>
+{
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        assert_eq!(block.len(), 8);
+        for (b, k) in block.iter_mut().zip(self.pre_whitening.iter())
+            { *b ^= *k; }
+        self.cipher.encrypt_one_block(block);
+        for (b, k) in block.iter_mut().zip(self.post_whitening.iter())
+            { *b ^= *k; }
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        assert_eq!(block.len(), 8);
+        for (b, k) in block.iter_mut().zip(self.post_whitening.iter())
+            { *b ^= *k; }
+        self.cipher.decrypt_one_block(block);
+        for (b, k) in block.iter_mut().zip(self.pre_whitening.iter())
+            { *b ^= *k; }
+    }
+
+    fn encrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % 8, 0);
+        for (b, k) in blocks.iter_mut().zip(self.pre_whitening.iter().cycle())
+            { *b ^= *k; }
+        self.cipher.encrypt_blocks(blocks);
+        for (b, k) in blocks.iter_mut().zip(self.post_whitening.iter().cycle())
+            { *b ^= *k; }
+    }
+
+    fn decrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % 8, 0);
+        for (b, k) in blocks.iter_mut().zip(self.post_whitening.iter().cycle())
+            { *b ^= *k; }
+        self.cipher.decrypt_blocks(blocks);
+        for (b, k) in blocks.iter_mut().zip(self.pre_whitening.iter().cycle())
+            { *b ^= *k; }
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains EAX authenticated encryption with associated
+//! data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256 };
+use crate::symmetric::cmac::CMAC_Generic;
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES-128-EAX. TAG is the length of tag in bytes.
+#[allow(non_camel_case_types)]
+pub type AES_128_EAX<const TAG: usize = 16> = EAX_Generic<AES_128, TAG>;
+
+/// AES-256-EAX. TAG is the length of tag in bytes.
+#[allow(non_camel_case_types)]
+pub type AES_256_EAX<const TAG: usize = 16> = EAX_Generic<AES_256, TAG>;
+
+/// EAX authenticated encryption with associated data on top of any block
+/// cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// EAX was designed by Bellare, Rogaway and Wagner. It authenticates the
+/// nonce, the associated data and the ciphertext with OMAC, each of which
+/// is tweaked differently, and encrypts the message in counter mode whose
+/// initial counter is the OMAC value of the nonce. Unlike CCM, the nonce
+/// can be of any length, and the length of the message does not need to be
+/// known in advance.
+///
+/// # Vulnerability
+/// - The nonce must be unique for the same key.
+/// - The short tags can be forged with non-negligible probability by an
+///   attacker who can try many times.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be either 8 bytes or 16 bytes.
+/// - TAG: the length of tag in bytes. It should be from `1` up to the block
+///   size of `C`. The default value is `16`.
+///
+/// # Reference
+/// Read [the EAX paper](https://web.cs.ucdavis.edu/~rogaway/papers/eax.pdf)
+/// about EAX in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, AES_128_EAX };
+///
+/// let key = [ 0x91_u8, 0x94, 0x5D, 0x3F, 0x4D, 0xCB, 0xEE, 0x0B,
+///             0xF4, 0x5E, 0xF5, 0x22, 0x55, 0xF0, 0x95, 0xA4 ];
+/// let nonce = [ 0xBE_u8, 0xCA, 0xF0, 0x43, 0xB0, 0xA2, 0x3D, 0x84,
+///               0x31, 0x94, 0xBA, 0x97, 0x2C, 0x66, 0xDE, 0xBD ];
+/// let header = [ 0xFA_u8, 0x3B, 0xFD, 0x48, 0x06, 0xEB, 0x53, 0xFA ];
+/// let message = [ 0xF7_u8, 0xFB ];
+///
+/// let mut eax = AES_128_EAX::<16>::new(AES_128::new_with_key(&key));
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = eax.encrypt_into_vec(&nonce, &header, &message, &mut cipher);
+/// let mut txt = String::new();
+/// for c in cipher.iter().chain(tag.iter())
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "19DD5C4C9331049D0BDAB0277408F67967E5");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(eax.decrypt_into_vec(&nonce, &header, &cipher, tag, &mut recovered));
+/// assert_eq!(recovered, message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct EAX_Generic<C: BlockCipher, const TAG: usize = 16>
+{
+    mac: CMAC_Generic<C>,
+}
+
+impl<C: BlockCipher, const TAG: usize> EAX_Generic<C, TAG>
+{
+    /// Constructs a new object EAX_Generic.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher with the key.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is neither 8 nor 16, or if `TAG`
+    /// is zero or greater than the block size of `C`.
+    pub fn new(cipher: C) -> Self
+    {
+        assert!(C::BLOCK_SIZE == 8 || C::BLOCK_SIZE == 16, "EAX_Generic supports only 64-bit and 128-bit block ciphers.");
+        assert!(TAG > 0 && TAG <= C::BLOCK_SIZE, "TAG of EAX_Generic should be from 1 up to the block size.");
+        Self { mac: CMAC_Generic::new(cipher) }
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the nonce of any length. It must be unique for the same
+    ///   key.
+    /// - `aad` is the associated data, which is the header in the EAX
+    ///   paper. It is authenticated but not encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the tag of `TAG` bytes.
+    pub fn encrypt_into_vec(&mut self, nonce: &[u8], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; TAG]
+    {
+        let n = self.omac(0, nonce);
+        let h = self.omac(1, aad);
+        cipher.clear();
+        cipher.extend_from_slice(message);
+        self.crypt_ctr(&n, cipher);
+        let c = self.omac(2, cipher);
+        Self::make_tag(&n, &h, &c)
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the tag of `TAG` bytes.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty.
+    pub fn decrypt_into_vec(&mut self, nonce: &[u8], aad: &[u8], cipher: &[u8], tag: [u8; TAG], message: &mut Vec<u8>) -> bool
+    {
+        message.clear();
+        let n = self.omac(0, nonce);
+        let h = self.omac(1, aad);
+        let c = self.omac(2, cipher);
+        if !tags_equal(&Self::make_tag(&n, &h, &c), &tag)
+            { return false; }
+        message.extend_from_slice(cipher);
+        self.crypt_ctr(&n, message);
+        true
+    }
+
+    fn make_tag(n: &[u8], h: &[u8], c: &[u8]) -> [u8; TAG]
+    {
+        let mut tag = [0_u8; TAG];
+        for (i, t) in tag.iter_mut().enumerate()
+            { *t = n[i] ^ h[i] ^ c[i]; }
+        tag
+    }
+
+    // Encrypts data in counter mode whose counter is the whole block in
+    // big endianness.
+    fn crypt_ctr(&mut self, initial_counter: &[u8], data: &mut [u8])
+    {
+        let mut counter = initial_counter.to_vec();
+        for chunk in data.chunks_mut(C::BLOCK_SIZE)
+        {
+            let mut keystream = counter.clone();
+            self.mac.get_cipher_mut().encrypt_one_block(&mut keystream);
+            for (d, k) in chunk.iter_mut().zip(keystream.iter())
+                { *d ^= *k; }
+            for c in counter.iter_mut().rev()
+            {
+                *c = c.wrapping_add(1);
+                if *c != 0
+                    { break; }
+            }
+        }
+    }
+
+    // OMAC^t(data) = OMAC([t]_n || data) where [t]_n is the block whose last
+    // byte is t and the other bytes are zeros.
+    fn omac(&mut self, t: u8, data: &[u8]) -> Vec<u8>
+    {
+        let mut block = vec![0_u8; C::BLOCK_SIZE];
+        block[C::BLOCK_SIZE - 1] = t;
+        self.mac.update(&block);
+        self.mac.update(data);
+        self.mac.finalize()
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the generic Feistel network and Lai-Massey
+//! scheme with user-supplied round functions
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::hash::SHA2_256;
+use crate::symmetric::BlockCipher;
+
+
+/// Luby-Rackoff construction, which is the Feistel network whose round
+/// function is based on SHA-256. Four rounds make it a strong pseudorandom
+/// permutation.
+#[allow(non_camel_case_types)]
+pub type LubyRackoff_Generic<const BLOCK: usize, const LEFT: usize, const ROUND: usize = 4>
+                = Feistel_Generic<SHA2_256_RoundFunction, BLOCK, LEFT, ROUND>;
+
+/// The type of the plain function which can be used as the round function
+/// `F(round_key, input, output)`.
+pub type RoundFn = fn(&[u8], &[u8], &mut [u8]);
+
+/// The trait for the round function and the key schedule of
+/// `Feistel_Generic` and `LaiMassey_Generic`
+///
+/// You implement this trait to supply your own round function `F` and
+/// your own key schedule. The plain function of the type `RoundFn` also has
+/// this trait with the default key schedule.
+///
+/// # Example
+/// ```
+/// use cryptocol::symmetric::{ RoundFunction, Feistel_Generic };
+///
+/// // A toy round function for teaching, which is NOT secure at all.
+/// #[derive(Debug, Clone)]
+/// struct ToyFunction;
+///
+/// impl RoundFunction for ToyFunction
+/// {
+///     fn key_schedule(&self, key: &[u8], round: usize) -> Vec<Vec<u8>>
+///     {
+///         (0..round).map(|i| vec![key[i % key.len()].rotate_left(i as u32)]).collect()
+///     }
+///
+///     fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+///     {
+///         for (i, o) in output.iter_mut().enumerate()
+///             { *o = input[i % input.len()].wrapping_mul(0x1D) ^ round_key[0]; }
+///     }
+/// }
+///
+/// let mut toy = Feistel_Generic::<ToyFunction, 4, 2, 8>::new_with_key(ToyFunction, &[0x12, 0x34, 0x56]);
+/// let cipher = toy.encrypt([0x01, 0x23, 0x45, 0x67]);
+/// assert_eq!(toy.decrypt(cipher), [0x01, 0x23, 0x45, 0x67]);
+/// ```
+pub trait RoundFunction
+{
+    // fn key_schedule(&self, key: &[u8], round: usize) -> Vec<Vec<u8>>
+    /// Derives `round` round keys from `key`.
+    ///
+    /// # Features
+    /// By default, the `i`-th round key is `key` followed by one byte `i`,
+    /// which is suitable for the round functions based on hash functions
+    /// or on other pseudorandom functions.
+    fn key_schedule(&self, key: &[u8], round: usize) -> Vec<Vec<u8>>
+    {
+        (0..round).map(|i| {
+                let mut round_key = key.to_vec();
+                round_key.push(i as u8);
+                round_key
+            }).collect()
+    }
+
+    // fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+    /// Computes the round function `F` of `input` with `round_key`, and
+    /// writes the result to the whole `output`.
+    ///
+    /// # Features
+    /// `output` may have a length different from that of `input` in the
+    /// unbalanced Feistel network. `F` does not have to be invertible.
+    fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8]);
+}
+
+impl RoundFunction for RoundFn
+{
+    #[inline]
+    fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+    {
+        self(round_key, input, output);
+    }
+}
+
+
+/// The round function based on SHA-256 for Luby-Rackoff construction
+///
+/// # Features
+/// The output of `F` is the concatenation of
+/// SHA-256(`round_key` || `input` || `j`) for `j` = 0, 1, 2, ... where `j`
+/// is a 32-bit big-endian integer, truncated to the length of `output`.
+/// It uses the default key schedule.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SHA2_256_RoundFunction;
+
+impl RoundFunction for SHA2_256_RoundFunction
+{
+    fn f(&self, round_key: &[u8], input: &[u8], output: &mut [u8])
+    {
+        let mut data = Vec::with_capacity(round_key.len() + input.len() + 4);
+        data.extend_from_slice(round_key);
+        data.extend_from_slice(input);
+        let len = data.len();
+        let mut hash = SHA2_256::new();
+        let mut hash_value = [0_u8; 32];
+        for (j, chunk) in output.chunks_mut(32).enumerate()
+        {
+            data.truncate(len);
+            data.extend_from_slice(&(j as u32).to_be_bytes());
+            hash.digest(data.as_ptr(), data.len() as u64);
+            hash.get_hash_value(hash_value.as_mut_ptr(), hash_value.len());
+            chunk.copy_from_slice(&hash_value[..chunk.len()]);
+        }
+    }
+}
+
+
+/// Generic Feistel network, balanced or unbalanced, with the round function
+/// `F` which has the trait `RoundFunction`
+///
+/// # Introduction
+/// The block of `BLOCK` bytes is divided into the left part of `LEFT`
+/// bytes and the right part of `BLOCK - LEFT` bytes. Every round computes
+/// `L ^= F(round_key, R)` and then rotates the block to the left by `LEFT`
+/// bytes so that the block becomes `R || L`. So, when `LEFT * 2 == BLOCK`,
+/// it is the balanced Feistel network `(L, R) -> (R, L ^ F(R))` such as
+/// DES, and otherwise it is the unbalanced Feistel network. The last round
+/// also rotates the block, unlike DES which undoes the last swap.
+///
+/// The Feistel network is a permutation whatever `F` is, and decryption
+/// uses the same `F` with the round keys in reverse order. So, it is good
+/// for teaching and for building permutations of small domains. With
+/// `SHA2_256_RoundFunction`, it is Luby-Rackoff construction
+/// [`LubyRackoff_Generic`](type@LubyRackoff_Generic).
+///
+/// It has the trait `BlockCipher`, so it can be plugged into the modes of
+/// operation such as `ModeCipher`.
+///
+/// # Vulnerability
+/// The security depends entirely on `F`, the key schedule and the number of
+/// rounds. Luby-Rackoff construction needs at least three rounds to be a
+/// pseudorandom permutation and four rounds to be a strong one, but only up
+/// to about 2^(n/4) queries where `n` is the block size in bits. So, a
+/// small block is never secure against an adversary who can query many
+/// blocks.
+///
+/// # Generic Parameters
+/// - F: the round function which has the trait `RoundFunction`.
+/// - BLOCK: the block size in bytes.
+/// - LEFT: the size of the left part in bytes, which `F` modifies in each
+///   round. It should be from `1` to `BLOCK - 1`.
+/// - ROUND: the number of rounds. It cannot be `0`.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ Feistel_Generic, RoundFn };
+///
+/// fn round_function(round_key: &[u8], input: &[u8], output: &mut [u8])
+/// {
+///     for (i, o) in output.iter_mut().enumerate()
+///         { *o = input[i].rotate_left(3) ^ round_key[i % round_key.len()]; }
+/// }
+///
+/// type Toy = Feistel_Generic<RoundFn, 8, 4, 16>;
+/// let mut toy = Toy::new_with_key(round_function, "teaching".as_bytes());
+/// let message = *b"Feistel!";
+/// let cipher = toy.encrypt(message);
+/// assert_ne!(cipher, message);
+/// assert_eq!(toy.decrypt(cipher), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct Feistel_Generic<F: RoundFunction, const BLOCK: usize, const LEFT: usize, const ROUND: usize>
+{
+    function: F,
+    round_key: Vec<Vec<u8>>,
+}
+
+impl<F: RoundFunction, const BLOCK: usize, const LEFT: usize, const ROUND: usize>
+Feistel_Generic<F, BLOCK, LEFT, ROUND>
+{
+    /// Constructs a new object Feistel_Generic with the empty key.
+    ///
+    /// # Panics
+    /// It panics if `LEFT` is not from `1` to `BLOCK - 1`, if `ROUND`
+    /// is `0`, or if the key schedule does not give `ROUND` round keys.
+    #[inline]
+    pub fn new(function: F) -> Self
+    {
+        Self::new_with_key(function, &[])
+    }
+
+    /// Constructs a new object Feistel_Generic.
+    ///
+    /// # Arguments
+    /// - `function` is the round function.
+    /// - `key` is the key which is given to the key schedule of `function`.
+    ///
+    /// # Panics
+    /// It panics if `LEFT` is not from `1` to `BLOCK - 1`, if `ROUND`
+    /// is `0`, or if the key schedule does not give `ROUND` round keys.
+    pub fn new_with_key(function: F, key: &[u8]) -> Self
+    {
+        if (LEFT == 0) || (LEFT >= BLOCK) || (ROUND == 0)
+            { panic!("LEFT should be from 1 to BLOCK - 1, and ROUND cannot be 0."); }
+        let round_key = function.key_schedule(key, ROUND);
+        assert_eq!(round_key.len(), ROUND, "The key schedule should give ROUND round keys.");
+        Self { function, round_key }
+    }
+
+    /// Sets the key by running the key schedule of the round function.
+    ///
+    /// # Panics
+    /// It panics if the key schedule does not give `ROUND` round keys.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        self.round_key = self.function.key_schedule(key, ROUND);
+        assert_eq!(self.round_key.len(), ROUND, "The key schedule should give ROUND round keys.");
+    }
+
+    /// Encrypts one block.
+    pub fn encrypt(&self, message: [u8; BLOCK]) -> [u8; BLOCK]
+    {
+        let mut block = message;
+        let mut t = [0_u8; LEFT];
+        for round_key in self.round_key.iter()
+        {
+            self.function.f(round_key, &block[LEFT..], &mut t);
+            for (b, tt) in block[..LEFT].iter_mut().zip(t.iter())
+                { *b ^= tt; }
+            block.rotate_left(LEFT);
+        }
+        block
+    }
+
+    /// Decrypts one block.
+    pub fn decrypt(&self, cipher: [u8; BLOCK]) -> [u8; BLOCK]
+    {
+        let mut block = cipher;
+        let mut t = [0_u8; LEFT];
+        for round_key in self.round_key.iter().rev()
+        {
+            block.rotate_right(LEFT);
+            self.function.f(round_key, &block[LEFT..], &mut t);
+            for (b, tt) in block[..LEFT].iter_mut().zip(t.iter())
+                { *b ^= tt; }
+        }
+        block
+    }
+}
+
+impl<F: RoundFunction, const BLOCK: usize, const LEFT: usize, const ROUND: usize>
+BlockCipher for Feistel_Generic<F, BLOCK, LEFT, ROUND>
+{
+    const BLOCK_SIZE: usize = BLOCK;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut b = [0_u8; BLOCK];
+        b.copy_from_slice(block);
+        block.copy_from_slice(&self.encrypt(b));
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut b = [0_u8; BLOCK];
+        b.copy_from_slice(block);
+        block.copy_from_slice(&self.decrypt(b));
+    }
+}
+
+
+/// Generic Lai-Massey scheme with the round function `F` which has the
+/// trait `RoundFunction`
+///
+/// # Introduction
+/// Lai-Massey scheme is the structure of IDEA and FOX. The block of `BLOCK`
+/// bytes is divided into two halves `L` and `R`, and every round computes
+/// `t = F(round_key, L ^ R)`, `L = σ(L ^ t)` and `R = R ^ t`, where the
+/// orthomorphism `σ` maps the two quarters `(a, b)` of the half to
+/// `(b, a ^ b)`. The last round does not apply `σ`. Like the Feistel
+/// network, it is a permutation whatever `F` is.
+///
+/// It has the trait `BlockCipher`, so it can be plugged into the modes of
+/// operation such as `ModeCipher`.
+///
+/// # Vulnerability
+/// The security depends entirely on `F`, the key schedule and the number of
+/// rounds. Without `σ`, `L ^ R` would be kept through all the rounds.
+///
+/// # Generic Parameters
+/// - F: the round function which has the trait `RoundFunction`. Its input
+///   and output are as long as a half block.
+/// - BLOCK: the block size in bytes. It should be a multiple of 4.
+/// - ROUND: the number of rounds. It cannot be `0`.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ LaiMassey_Generic, SHA2_256_RoundFunction };
+///
+/// let mut lm = LaiMassey_Generic::<SHA2_256_RoundFunction, 16, 8>::new_with_key(SHA2_256_RoundFunction, "key".as_bytes());
+/// let message = *b"Lai-Massey block";
+/// let cipher = lm.encrypt(message);
+/// assert_ne!(cipher, message);
+/// assert_eq!(lm.decrypt(cipher), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct LaiMassey_Generic<F: RoundFunction, const BLOCK: usize, const ROUND: usize>
+{
+    function: F,
+    round_key: Vec<Vec<u8>>,
+}
+
+impl<F: RoundFunction, const BLOCK: usize, const ROUND: usize> LaiMassey_Generic<F, BLOCK, ROUND>
+{
+    const HALF: usize = BLOCK / 2;
+    const QUARTER: usize = BLOCK / 4;
+
+    /// Constructs a new object LaiMassey_Generic with the empty key.
+    ///
+    /// # Panics
+    /// It panics if `BLOCK` is not a positive multiple of 4, if `ROUND`
+    /// is `0`, or if the key schedule does not give `ROUND` round keys.
+    #[inline]
+    pub fn new(function: F) -> Self
+    {
+        Self::new_with_key(function, &[])
+    }
+
+    /// Constructs a new object LaiMassey_Generic.
+    ///
+    /// # Arguments
+    /// - `function` is the round function.
+    /// - `key` is the key which is given to the key schedule of `function`.
+    ///
+    /// # Panics
+    /// It panics if `BLOCK` is not a positive multiple of 4, if `ROUND`
+    /// is `0`, or if the key schedule does not give `ROUND` round keys.
+    pub fn new_with_key(function: F, key: &[u8]) -> Self
+    {
+        if (BLOCK == 0) || (BLOCK % 4 != 0) || (ROUND == 0)
+            { panic!("BLOCK should be a positive multiple of 4, and ROUND cannot be 0."); }
+        let round_key = function.key_schedule(key, ROUND);
+        assert_eq!(round_key.len(), ROUND, "The key schedule should give ROUND round keys.");
+        Self { function, round_key }
+    }
+
+    /// Sets the key by running the key schedule of the round function.
+    ///
+    /// # Panics
+    /// It panics if the key schedule does not give `ROUND` round keys.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        self.round_key = self.function.key_schedule(key, ROUND);
+        assert_eq!(self.round_key.len(), ROUND, "The key schedule should give ROUND round keys.");
+    }
+
+    /// Encrypts one block.
+    pub fn encrypt(&self, message: [u8; BLOCK]) -> [u8; BLOCK]
+    {
+        let mut block = message;
+        for (i, round_key) in self.round_key.iter().enumerate()
+        {
+            self.mix(round_key, &mut block);
+            if i + 1 < ROUND
+            {
+                // σ(a, b) = (b, a ^ b)
+                let (a, b) = block[..Self::HALF].split_at_mut(Self::QUARTER);
+                for (aa, bb) in a.iter_mut().zip(b.iter())
+                    { *aa ^= bb; }
+                block[..Self::HALF].rotate_left(Self::QUARTER);
+            }
+        }
+        block
+    }
+
+    /// Decrypts one block.
+    pub fn decrypt(&self, cipher: [u8; BLOCK]) -> [u8; BLOCK]
+    {
+        let mut block = cipher;
+        for (i, round_key) in self.round_key.iter().enumerate().rev()
+        {
+            if i + 1 < ROUND
+            {
+                // σ^-1(c, d) = (c ^ d, c)
+                block[..Self::HALF].rotate_right(Self::QUARTER);
+                let (a, b) = block[..Self::HALF].split_at_mut(Self::QUARTER);
+                for (aa, bb) in a.iter_mut().zip(b.iter())
+                    { *aa ^= bb; }
+            }
+            self.mix(round_key, &mut block);
+        }
+        block
+    }
+
+    // fn mix(&self, round_key: &[u8], block: &mut [u8; BLOCK])
+    // XORs `F(round_key, L ^ R)` into both halves, which is an involution.
+    fn mix(&self, round_key: &[u8], block: &mut [u8; BLOCK])
+    {
+        let (l, r) = block.split_at_mut(Self::HALF);
+        let d: Vec<u8> = l.iter().zip(r.iter()).map(|(ll, rr)| ll ^ rr).collect();
+        let mut t = vec![0_u8; Self::HALF];
+        self.function.f(round_key, &d, &mut t);
+        for ((ll, rr), tt) in l.iter_mut().zip(r.iter_mut()).zip(t.iter())
+        {
+            *ll ^= tt;
+            *rr ^= tt;
+        }
+    }
+}
+
+impl<F: RoundFunction, const BLOCK: usize, const ROUND: usize>
+BlockCipher for LaiMassey_Generic<F, BLOCK, ROUND>
+{
+    const BLOCK_SIZE: usize = BLOCK;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut b = [0_u8; BLOCK];
+        b.copy_from_slice(block);
+        block.copy_from_slice(&self.encrypt(b));
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut b = [0_u8; BLOCK];
+        b.copy_from_slice(block);
+        block.copy_from_slice(&self.decrypt(b));
+    }
+}
@@ -0,0 +1,752 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains FF1 and FF3-1 format-preserving encryption
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, CipherError, AES_Generic, AES_128, AES_192, AES_256 };
+
+
+/// FF1 with AES-128
+#[allow(non_camel_case_types)]
+pub type AES_128_FF1 = FF1_Generic<AES_128>;
+
+/// FF1 with AES-192
+#[allow(non_camel_case_types)]
+pub type AES_192_FF1 = FF1_Generic<AES_192>;
+
+/// FF1 with AES-256
+#[allow(non_camel_case_types)]
+pub type AES_256_FF1 = FF1_Generic<AES_256>;
+
+/// FF3-1 with AES-128
+#[allow(non_camel_case_types)]
+pub type AES_128_FF3_1 = FF3_1_Generic<AES_128>;
+
+/// FF3-1 with AES-192
+#[allow(non_camel_case_types)]
+pub type AES_192_FF3_1 = FF3_1_Generic<AES_192>;
+
+/// FF3-1 with AES-256
+#[allow(non_camel_case_types)]
+pub type AES_256_FF3_1 = FF3_1_Generic<AES_256>;
+
+/// FF1 format-preserving encryption on top of any 128-bit block cipher that
+/// has the trait `BlockCipher`
+///
+/// # Introduction
+/// Format-preserving encryption (FPE) encrypts a numeral string into
+/// another numeral string of the same length and the same radix. For
+/// example, a 16-digit decimal credit card number is encrypted into another
+/// 16-digit decimal number, so that the ciphertext can be stored in the
+/// place where the plaintext was stored. FF1 is a ten-round Feistel
+/// network whose round function is based on CBC-MAC of the underlying block
+/// cipher, and it takes a tweak of any length.
+///
+/// The numerals are given as `u16` so that the radix can be from `2` up to
+/// `65536`. For the radix up to `36`, the methods `encrypt_str()` and
+/// `decrypt_str()` take and give the numeral strings as text whose
+/// numerals are `0` ~ `9` and `a` ~ `z`.
+///
+/// # Vulnerability
+/// - FPE is deterministic. Encrypting the same numeral string with the same
+///   tweak always gives the same result. Use the tweak to make it vary,
+///   for example with the last four digits of a credit card number which
+///   are not encrypted.
+/// - When the domain, which is radix to the power of the length, is small,
+///   an adversary can build the whole codebook. So, NIST SP 800-38G
+///   requires the domain to be at least one million, and this struct
+///   rejects the shorter numeral strings.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+///
+/// # Reference
+/// Read [NIST SP 800-38G Rev. 1](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38Gr1-draft.pdf)
+/// about FF1 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ AES_128, AES_128_FF1 };
+///
+/// let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+/// let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 10);
+/// let cipher = ff1.encrypt_str(&[], "0123456789").unwrap();
+/// assert_eq!(cipher, "2433477484");
+/// assert_eq!(ff1.decrypt_str(&[], &cipher).unwrap(), "0123456789");
+///
+/// // Tokenizing a card number while keeping the last four digits
+/// let pan = "4111111111111111";
+/// let token = ff1.encrypt_str(&pan.as_bytes()[12..], &pan[..12]).unwrap() + &pan[12..];
+/// println!("PAN =\t{}\nToken =\t{}", pan, token);
+/// assert_eq!(token.len(), 16);
+/// assert!(token.chars().all(|c| c.is_ascii_digit()));
+/// assert_eq!(ff1.decrypt_str(&token.as_bytes()[12..], &token[..12]).unwrap() + &token[12..], pan);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct FF1_Generic<C: BlockCipher>
+{
+    cipher: C,
+    radix: u32,
+    min_len: usize,
+}
+
+impl<C: BlockCipher> FF1_Generic<C>
+{
+    const ROUND: usize = 10;
+
+    /// Constructs a new object FF1_Generic.
+    ///
+    /// # Arguments
+    /// - `cipher` is the block cipher with the key.
+    /// - `radix` is the base of the numerals. It should be from `2` to
+    ///   `65536`.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16, or if `radix` is out
+    /// of the range.
+    pub fn new(cipher: C, radix: u32) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 16, "FF1_Generic supports only 128-bit block ciphers.");
+        assert!((2..=65536).contains(&radix), "The radix should be from 2 to 65536.");
+        Self { cipher, radix, min_len: min_len(radix) }
+    }
+
+    /// Returns the radix.
+    #[inline]
+    pub fn get_radix(&self) -> u32
+    {
+        self.radix
+    }
+
+    /// Returns the minimum length of the numeral strings, which is the
+    /// smallest length that makes the domain at least one million.
+    #[inline]
+    pub fn get_min_len(&self) -> usize
+    {
+        self.min_len
+    }
+
+    /// Returns the maximum length of the numeral strings, which is
+    /// `2^32 - 1` for FF1.
+    #[inline]
+    pub fn get_max_len(&self) -> usize
+    {
+        u32::MAX as usize
+    }
+
+    /// Encrypts the numeral string `message` with `tweak`.
+    ///
+    /// # Arguments
+    /// - `tweak` is the tweak of any length.
+    /// - `message` is the numeral string whose numerals are less than the
+    ///   radix.
+    ///
+    /// # Output
+    /// It returns the encrypted numeral string of the same length.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a numeral is not
+    /// less than the radix, or if the length of `message` is out of the
+    /// range from `get_min_len()` to `get_max_len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ AES_128, AES_128_FF1, CipherError };
+    ///
+    /// let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    /// let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 10);
+    /// let tweak = [0x39_u8, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x30];
+    /// let cipher = ff1.encrypt(&tweak, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// assert_eq!(cipher, [6, 1, 2, 4, 2, 0, 0, 7, 7, 3]);
+    /// assert_eq!(ff1.encrypt(&tweak, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 10]), Err(CipherError::InvalidNumeralString));
+    /// assert_eq!(ff1.encrypt(&tweak, &[0, 1, 2, 3, 4]), Err(CipherError::InvalidNumeralString));
+    /// ```
+    pub fn encrypt(&mut self, tweak: &[u8], message: &[u16]) -> Result<Vec<u16>, CipherError>
+    {
+        self.check(message)?;
+        let n = message.len();
+        let u = n / 2;
+        let v = n - u;
+        let mut a = message[..u].to_vec();
+        let mut b = message[u..].to_vec();
+        let (len_b, len_d) = self.byte_lengths(v);
+        let p = self.make_p(u, n, tweak.len());
+        for i in 0..Self::ROUND
+        {
+            let m = if i & 1 == 0 {u} else {v};
+            let y = self.round_function(&p, tweak, i, &b, len_b, len_d);
+            let c = add_mod(&a, &to_numerals(&y, self.radix, m), self.radix);
+            a = b;
+            b = c;
+        }
+        a.append(&mut b);
+        Ok(a)
+    }
+
+    /// Decrypts the numeral string `cipher` with `tweak`.
+    ///
+    /// # Arguments
+    /// - `tweak` is the tweak that was used for encryption.
+    /// - `cipher` is the encrypted numeral string.
+    ///
+    /// # Output
+    /// It returns the decrypted numeral string of the same length.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a numeral is not
+    /// less than the radix, or if the length of `cipher` is out of the
+    /// range from `get_min_len()` to `get_max_len()`.
+    pub fn decrypt(&mut self, tweak: &[u8], cipher: &[u16]) -> Result<Vec<u16>, CipherError>
+    {
+        self.check(cipher)?;
+        let n = cipher.len();
+        let u = n / 2;
+        let v = n - u;
+        let mut a = cipher[..u].to_vec();
+        let mut b = cipher[u..].to_vec();
+        let (len_b, len_d) = self.byte_lengths(v);
+        let p = self.make_p(u, n, tweak.len());
+        for i in (0..Self::ROUND).rev()
+        {
+            let m = if i & 1 == 0 {u} else {v};
+            let y = self.round_function(&p, tweak, i, &a, len_b, len_d);
+            let c = sub_mod(&b, &to_numerals(&y, self.radix, m), self.radix);
+            b = a;
+            a = c;
+        }
+        a.append(&mut b);
+        Ok(a)
+    }
+
+    /// Encrypts the numeral string `message` given as text.
+    ///
+    /// # Arguments
+    /// - `tweak` is the tweak of any length.
+    /// - `message` is the text whose characters are the numerals `0` ~ `9`
+    ///   and `a` ~ `z`. The capital letters are regarded as the small
+    ///   letters.
+    ///
+    /// # Output
+    /// It returns the encrypted text whose letters are small letters.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a character is not
+    /// a numeral of the radix, or if the length of `message` is out of the
+    /// range.
+    ///
+    /// # Panics
+    /// It panics if the radix is greater than `36`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ AES_128, AES_128_FF1 };
+    ///
+    /// let key = [0x2B_u8, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+    /// let mut ff1 = AES_128_FF1::new(AES_128::new_with_key(&key), 36);
+    /// let tweak = [0x37_u8, 0x37, 0x37, 0x37, 0x70, 0x71, 0x72, 0x73, 0x37, 0x37, 0x37];
+    /// let cipher = ff1.encrypt_str(&tweak, "0123456789abcdefghi").unwrap();
+    /// assert_eq!(cipher, "a9tv40mll9kdu509eum");
+    /// assert_eq!(ff1.decrypt_str(&tweak, &cipher).unwrap(), "0123456789abcdefghi");
+    /// ```
+    pub fn encrypt_str(&mut self, tweak: &[u8], message: &str) -> Result<String, CipherError>
+    {
+        let numerals = str_to_numerals(message, self.radix)?;
+        Ok(numerals_to_string(&self.encrypt(tweak, &numerals)?, self.radix))
+    }
+
+    /// Decrypts the numeral string `cipher` given as text.
+    ///
+    /// # Arguments
+    /// - `tweak` is the tweak that was used for encryption.
+    /// - `cipher` is the text whose characters are the numerals `0` ~ `9`
+    ///   and `a` ~ `z`.
+    ///
+    /// # Output
+    /// It returns the decrypted text whose letters are small letters.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a character is not
+    /// a numeral of the radix, or if the length of `cipher` is out of the
+    /// range.
+    ///
+    /// # Panics
+    /// It panics if the radix is greater than `36`.
+    pub fn decrypt_str(&mut self, tweak: &[u8], cipher: &str) -> Result<String, CipherError>
+    {
+        let numerals = str_to_numerals(cipher, self.radix)?;
+        Ok(numerals_to_string(&self.decrypt(tweak, &numerals)?, self.radix))
+    }
+
+    // Checks the length and the numerals of `x`.
+    fn check(&self, x: &[u16]) -> Result<(), CipherError>
+    {
+        if (x.len() < self.min_len) || (x.len() > self.get_max_len())
+                || x.iter().any(|&d| d as u32 >= self.radix)
+            { return Err(CipherError::InvalidNumeralString); }
+        Ok(())
+    }
+
+    // Returns `b`, the number of bytes of the numeral string of length `v`,
+    // and `d`, the number of bytes of the output of the round function.
+    fn byte_lengths(&self, v: usize) -> (usize, usize)
+    {
+        let max = to_bytes(&vec![(self.radix - 1) as u16; v], self.radix, 2 * v + 1);
+        let bits = match max.iter().position(|&x| x != 0)
+        {
+            Some(pos) => { (max.len() - pos) * 8 - max[pos].leading_zeros() as usize },
+            None => { 0 },
+        };
+        let len_b = bits.div_ceil(8);
+        (len_b, 4 * len_b.div_ceil(4) + 4)
+    }
+
+    // Makes the first block `P` of the input of the round function.
+    fn make_p(&self, u: usize, n: usize, t: usize) -> [u8; 16]
+    {
+        let mut p = [1_u8, 2, 1, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        p[3..6].copy_from_slice(&self.radix.to_be_bytes()[1..]);
+        p[7] = u as u8;
+        p[8..12].copy_from_slice(&(n as u32).to_be_bytes());
+        p[12..].copy_from_slice(&(t as u32).to_be_bytes());
+        p
+    }
+
+    // Computes `S` of the `i`-th round from the half `x`.
+    fn round_function(&mut self, p: &[u8; 16], tweak: &[u8], i: usize, x: &[u16], len_b: usize, len_d: usize) -> Vec<u8>
+    {
+        let zeros = (16 - (tweak.len() + len_b + 1) % 16) % 16;
+        let mut q = Vec::with_capacity(tweak.len() + zeros + 1 + len_b);
+        q.extend_from_slice(tweak);
+        q.resize(tweak.len() + zeros, 0);
+        q.push(i as u8);
+        q.extend_from_slice(&to_bytes(x, self.radix, len_b));
+
+        // PRF, which is CBC-MAC with the zero initialization vector
+        let mut r = *p;
+        self.cipher.encrypt_one_block(&mut r);
+        for block in q.chunks_exact(16)
+        {
+            for (rr, bb) in r.iter_mut().zip(block.iter())
+                { *rr ^= bb; }
+            self.cipher.encrypt_one_block(&mut r);
+        }
+
+        let mut s = r.to_vec();
+        let mut j = 1_u32;
+        while s.len() < len_d
+        {
+            let mut block = r;
+            for (bb, jj) in block[12..].iter_mut().zip(j.to_be_bytes().iter())
+                { *bb ^= jj; }
+            self.cipher.encrypt_one_block(&mut block);
+            s.extend_from_slice(&block);
+            j += 1;
+        }
+        s.truncate(len_d);
+        s
+    }
+}
+
+
+/// FF3-1 format-preserving encryption on top of any 128-bit block cipher
+/// that has the trait `BlockCipher`
+///
+/// # Introduction
+/// FF3-1 is an eight-round Feistel network whose round function is one
+/// encryption of the underlying block cipher. It takes a 56-bit tweak.
+/// It is faster than FF1, but the length of the numeral strings is limited
+/// to `2 * floor(log_radix(2^96))`, for example `56` for decimal numbers.
+///
+/// FF3-1 uses the byte-reversed key for the underlying block cipher. So,
+/// if you construct it with `new()`, the block cipher should be keyed with
+/// the byte-reversed key. For AES, `new_with_key()` does it for you.
+///
+/// The numerals are given as `u16` so that the radix can be from `2` up to
+/// `65536`. For the radix up to `36`, the methods `encrypt_str()` and
+/// `decrypt_str()` take and give the numeral strings as text whose
+/// numerals are `0` ~ `9` and `a` ~ `z`.
+///
+/// # Vulnerability
+/// - FPE is deterministic. Encrypting the same numeral string with the same
+///   tweak always gives the same result.
+/// - FF3-1 replaced FF3, whose 64-bit tweak made it vulnerable to the
+///   attack of Durak and Vaudenay. FF3 is not provided.
+/// - The domain should be at least one million, so this struct rejects
+///   the shorter numeral strings.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+///
+/// # Reference
+/// Read [NIST SP 800-38G Rev. 1](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38Gr1-draft.pdf)
+/// about FF3-1 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::AES_128_FF3_1;
+///
+/// let key = [0xEF_u8, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94];
+/// let tweak = [0xD8_u8, 0xE7, 0x92, 0x0A, 0xFA, 0x33, 0x0A];
+/// let mut ff3 = AES_128_FF3_1::new_with_key(&key, 10);
+/// let ssn = "123456789";
+/// let cipher = ff3.encrypt_str(tweak, ssn).unwrap();
+/// println!("SSN =\t{}\nCipher =\t{}", ssn, cipher);
+/// assert_eq!(cipher.len(), 9);
+/// assert_eq!(ff3.decrypt_str(tweak, &cipher).unwrap(), ssn);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct FF3_1_Generic<C: BlockCipher>
+{
+    cipher: C,
+    radix: u32,
+    min_len: usize,
+    max_len: usize,
+}
+
+impl<C: BlockCipher> FF3_1_Generic<C>
+{
+    const ROUND: usize = 8;
+
+    /// Constructs a new object FF3_1_Generic.
+    ///
+    /// # Arguments
+    /// - `cipher` is the block cipher keyed with the byte-reversed key.
+    /// - `radix` is the base of the numerals. It should be from `2` to
+    ///   `65536`.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16, or if `radix` is out
+    /// of the range.
+    pub fn new(cipher: C, radix: u32) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 16, "FF3_1_Generic supports only 128-bit block ciphers.");
+        assert!((2..=65536).contains(&radix), "The radix should be from 2 to 65536.");
+        let mut k = 0;
+        let mut power = radix as u128;
+        while power <= (1_u128 << 96)
+        {
+            k += 1;
+            power *= radix as u128;
+        }
+        Self { cipher, radix, min_len: min_len(radix), max_len: 2 * k }
+    }
+
+    /// Returns the radix.
+    #[inline]
+    pub fn get_radix(&self) -> u32
+    {
+        self.radix
+    }
+
+    /// Returns the minimum length of the numeral strings, which is the
+    /// smallest length that makes the domain at least one million.
+    #[inline]
+    pub fn get_min_len(&self) -> usize
+    {
+        self.min_len
+    }
+
+    /// Returns the maximum length of the numeral strings, which is
+    /// `2 * floor(log_radix(2^96))`.
+    #[inline]
+    pub fn get_max_len(&self) -> usize
+    {
+        self.max_len
+    }
+
+    /// Encrypts the numeral string `message` with `tweak`.
+    ///
+    /// # Arguments
+    /// - `tweak` is the 56-bit tweak.
+    /// - `message` is the numeral string whose numerals are less than the
+    ///   radix.
+    ///
+    /// # Output
+    /// It returns the encrypted numeral string of the same length.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a numeral is not
+    /// less than the radix, or if the length of `message` is out of the
+    /// range from `get_min_len()` to `get_max_len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ AES_128_FF3_1, CipherError };
+    ///
+    /// let key = [0xEF_u8, 0x43, 0x59, 0xD8, 0xD5, 0x80, 0xAA, 0x4F, 0x7F, 0x03, 0x6D, 0x6F, 0x04, 0xFC, 0x6A, 0x94];
+    /// let mut ff3 = AES_128_FF3_1::new_with_key(&key, 2);
+    /// assert_eq!(ff3.get_min_len(), 20);
+    /// assert_eq!(ff3.get_max_len(), 192);
+    /// let message = [1_u16; 20];
+    /// let cipher = ff3.encrypt([0_u8; 7], &message).unwrap();
+    /// assert_eq!(ff3.decrypt([0_u8; 7], &cipher).unwrap(), message);
+    /// assert_eq!(ff3.encrypt([0_u8; 7], &[1_u16; 19]), Err(CipherError::InvalidNumeralString));
+    /// ```
+    pub fn encrypt(&mut self, tweak: [u8; 7], message: &[u16]) -> Result<Vec<u16>, CipherError>
+    {
+        self.check(message)?;
+        let n = message.len();
+        let u = n.div_ceil(2);
+        let v = n - u;
+        let (t_l, t_r) = Self::split_tweak(tweak);
+        // The halves are kept in reversed order, as FF3-1 takes the numeral
+        // strings in reversed order.
+        let mut a: Vec<u16> = message[..u].iter().rev().copied().collect();
+        let mut b: Vec<u16> = message[u..].iter().rev().copied().collect();
+        for i in 0..Self::ROUND
+        {
+            let (m, w) = if i & 1 == 0 {(u, t_r)} else {(v, t_l)};
+            let y = self.round_function(w, i, &b);
+            let c = add_mod(&a, &to_numerals(&y, self.radix, m), self.radix);
+            a = b;
+            b = c;
+        }
+        let mut res: Vec<u16> = a.into_iter().rev().collect();
+        res.extend(b.into_iter().rev());
+        Ok(res)
+    }
+
+    /// Decrypts the numeral string `cipher` with `tweak`.
+    ///
+    /// # Arguments
+    /// - `tweak` is the 56-bit tweak that was used for encryption.
+    /// - `cipher` is the encrypted numeral string.
+    ///
+    /// # Output
+    /// It returns the decrypted numeral string of the same length.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a numeral is not
+    /// less than the radix, or if the length of `cipher` is out of the
+    /// range from `get_min_len()` to `get_max_len()`.
+    pub fn decrypt(&mut self, tweak: [u8; 7], cipher: &[u16]) -> Result<Vec<u16>, CipherError>
+    {
+        self.check(cipher)?;
+        let n = cipher.len();
+        let u = n.div_ceil(2);
+        let v = n - u;
+        let (t_l, t_r) = Self::split_tweak(tweak);
+        let mut a: Vec<u16> = cipher[..u].iter().rev().copied().collect();
+        let mut b: Vec<u16> = cipher[u..].iter().rev().copied().collect();
+        for i in (0..Self::ROUND).rev()
+        {
+            let (m, w) = if i & 1 == 0 {(u, t_r)} else {(v, t_l)};
+            let y = self.round_function(w, i, &a);
+            let c = sub_mod(&b, &to_numerals(&y, self.radix, m), self.radix);
+            b = a;
+            a = c;
+        }
+        let mut res: Vec<u16> = a.into_iter().rev().collect();
+        res.extend(b.into_iter().rev());
+        Ok(res)
+    }
+
+    /// Encrypts the numeral string `message` given as text.
+    ///
+    /// # Arguments
+    /// - `tweak` is the 56-bit tweak.
+    /// - `message` is the text whose characters are the numerals `0` ~ `9`
+    ///   and `a` ~ `z`. The capital letters are regarded as the small
+    ///   letters.
+    ///
+    /// # Output
+    /// It returns the encrypted text whose letters are small letters.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a character is not
+    /// a numeral of the radix, or if the length of `message` is out of the
+    /// range.
+    ///
+    /// # Panics
+    /// It panics if the radix is greater than `36`.
+    pub fn encrypt_str(&mut self, tweak: [u8; 7], message: &str) -> Result<String, CipherError>
+    {
+        let numerals = str_to_numerals(message, self.radix)?;
+        Ok(numerals_to_string(&self.encrypt(tweak, &numerals)?, self.radix))
+    }
+
+    /// Decrypts the numeral string `cipher` given as text.
+    ///
+    /// # Arguments
+    /// - `tweak` is the 56-bit tweak that was used for encryption.
+    /// - `cipher` is the text whose characters are the numerals `0` ~ `9`
+    ///   and `a` ~ `z`.
+    ///
+    /// # Output
+    /// It returns the decrypted text whose letters are small letters.
+    ///
+    /// # Errors
+    /// It returns `CipherError::InvalidNumeralString` if a character is not
+    /// a numeral of the radix, or if the length of `cipher` is out of the
+    /// range.
+    ///
+    /// # Panics
+    /// It panics if the radix is greater than `36`.
+    pub fn decrypt_str(&mut self, tweak: [u8; 7], cipher: &str) -> Result<String, CipherError>
+    {
+        let numerals = str_to_numerals(cipher, self.radix)?;
+        Ok(numerals_to_string(&self.decrypt(tweak, &numerals)?, self.radix))
+    }
+
+    // Checks the length and the numerals of `x`.
+    fn check(&self, x: &[u16]) -> Result<(), CipherError>
+    {
+        if (x.len() < self.min_len) || (x.len() > self.max_len)
+                || x.iter().any(|&d| d as u32 >= self.radix)
+            { return Err(CipherError::InvalidNumeralString); }
+        Ok(())
+    }
+
+    // Splits the 56-bit tweak into the 32-bit `T_L` and `T_R`.
+    fn split_tweak(tweak: [u8; 7]) -> ([u8; 4], [u8; 4])
+    {
+        ([tweak[0], tweak[1], tweak[2], tweak[3] & 0xF0],
+            [tweak[4], tweak[5], tweak[6], tweak[3] << 4])
+    }
+
+    // Computes `S` of the `i`-th round from the reversed half `rev_x`.
+    fn round_function(&mut self, w: [u8; 4], i: usize, rev_x: &[u16]) -> [u8; 16]
+    {
+        let mut p = [0_u8; 16];
+        p[..4].copy_from_slice(&w);
+        p[3] ^= i as u8;
+        p[4..].copy_from_slice(&to_bytes(rev_x, self.radix, 12));
+        p.reverse();
+        self.cipher.encrypt_one_block(&mut p);
+        p.reverse();
+        p
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> FF3_1_Generic<AES_Generic<NK, ROUND>>
+{
+    /// Constructs a new object FF3_1_Generic with AES.
+    ///
+    /// # Arguments
+    /// - `key` is the key of FF3-1. The underlying AES is keyed with the
+    ///   byte-reversed `key`.
+    /// - `radix` is the base of the numerals. It should be from `2` to
+    ///   `65536`.
+    ///
+    /// # Panics
+    /// It panics if `radix` is out of the range.
+    pub fn new_with_key(key: &[u8], radix: u32) -> Self
+    {
+        let mut rev_key = key.to_vec();
+        rev_key.reverse();
+        Self::new(AES_Generic::<NK, ROUND>::new_with_key(&rev_key), radix)
+    }
+}
+
+
+// Returns the smallest length not less than 2 that makes the domain at
+// least one million.
+fn min_len(radix: u32) -> usize
+{
+    let mut len = 1;
+    let mut domain = radix as u64;
+    while (len < 2) || (domain < 1_000_000)
+    {
+        len += 1;
+        domain *= radix as u64;
+    }
+    len
+}
+
+// Converts the numeral string `x` into the big-endian byte string of
+// `len` bytes, dropping the overflow.
+fn to_bytes(x: &[u16], radix: u32, len: usize) -> Vec<u8>
+{
+    let mut bytes = vec![0_u8; len];
+    for &d in x.iter()
+    {
+        let mut carry = d as u32;
+        for byte in bytes.iter_mut().rev()
+        {
+            let t = *byte as u32 * radix + carry;
+            *byte = t as u8;
+            carry = t >> 8;
+        }
+    }
+    bytes
+}
+
+// Converts the big-endian byte string `bytes` modulo radix to the power
+// of `m` into the numeral string of length `m`.
+fn to_numerals(bytes: &[u8], radix: u32, m: usize) -> Vec<u16>
+{
+    let mut rest = bytes.to_vec();
+    let mut x = vec![0_u16; m];
+    for d in x.iter_mut().rev()
+    {
+        let mut remainder = 0_u32;
+        for byte in rest.iter_mut()
+        {
+            let t = (remainder << 8) | *byte as u32;
+            *byte = (t / radix) as u8;
+            remainder = t % radix;
+        }
+        *d = remainder as u16;
+    }
+    x
+}
+
+// Adds the numeral strings of the same length modulo radix to the power
+// of the length.
+fn add_mod(a: &[u16], b: &[u16], radix: u32) -> Vec<u16>
+{
+    let mut c = vec![0_u16; a.len()];
+    let mut carry = 0_u32;
+    for ((cc, &aa), &bb) in c.iter_mut().zip(a.iter()).zip(b.iter()).rev()
+    {
+        let t = aa as u32 + bb as u32 + carry;
+        carry = if t >= radix {1} else {0};
+        *cc = (t - carry * radix) as u16;
+    }
+    c
+}
+
+// Subtracts the numeral strings of the same length modulo radix to the
+// power of the length.
+fn sub_mod(a: &[u16], b: &[u16], radix: u32) -> Vec<u16>
+{
+    let mut c = vec![0_u16; a.len()];
+    let mut borrow = 0_u32;
+    for ((cc, &aa), &bb) in c.iter_mut().zip(a.iter()).zip(b.iter()).rev()
+    {
+        let t = aa as u32 + radix - bb as u32 - borrow;
+        borrow = if t < radix {1} else {0};
+        *cc = (t + borrow * radix - radix) as u16;
+    }
+    c
+}
+
+// Converts the text `txt` into the numeral string.
+fn str_to_numerals(txt: &str, radix: u32) -> Result<Vec<u16>, CipherError>
+{
+    assert!(radix <= 36, "The text form is available only for the radix up to 36.");
+    txt.chars()
+        .map(|c| c.to_digit(radix).map(|d| d as u16).ok_or(CipherError::InvalidNumeralString))
+        .collect()
+}
+
+// Converts the numeral string `x` into the text.
+fn numerals_to_string(x: &[u16], radix: u32) -> String
+{
+    x.iter().map(|&d| char::from_digit(d as u32, radix).unwrap()).collect()
+}
@@ -0,0 +1,261 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains AES-GCM-SIV nonce misuse-resistant
+//! authenticated encryption with associated data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::AES_Generic;
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES-128-GCM-SIV defined in RFC 8452
+#[allow(non_camel_case_types)]
+pub type AES_128_GCM_SIV = AES_GCM_SIV_Generic<4, 10>;
+
+/// AES-256-GCM-SIV defined in RFC 8452
+#[allow(non_camel_case_types)]
+pub type AES_256_GCM_SIV = AES_GCM_SIV_Generic<8, 14>;
+
+/// AES-GCM-SIV nonce misuse-resistant authenticated encryption with
+/// associated data
+///
+/// # Introduction
+/// AES-GCM-SIV derives the message-authentication key and the
+/// message-encryption key from the key-generating key and the nonce for
+/// each message. It authenticates the associated data and the message with
+/// POLYVAL, encrypts the result to make the tag, and then encrypts the
+/// message in counter mode whose initial counter is made from the tag.
+/// Since the initial counter depends on the message itself, reusing a nonce
+/// reveals only whether the same message was encrypted with the same
+/// associated data and the same nonce, and nothing else.
+///
+/// # Vulnerability
+/// - Although it is resistant to nonce misuse, you should still use unique
+///   nonces whenever you can, because repeated nonces reveal repeated
+///   messages.
+/// - The message and the associated data should not be longer than 2^36
+///   bytes.
+///
+/// # Generic Parameters
+/// - NK: The length of the key-generating key in 32-bit words. It is `4`
+///   for AES-128-GCM-SIV and `8` for AES-256-GCM-SIV.
+/// - ROUND: The number of rounds of the underlying AES.
+///
+/// # Reference
+/// Read [RFC 8452](https://datatracker.ietf.org/doc/html/rfc8452)
+/// about AES-GCM-SIV in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::AES_128_GCM_SIV;
+///
+/// let mut key = [0_u8; 16];
+/// key[0] = 1;
+/// let mut nonce = [0_u8; 12];
+/// nonce[0] = 3;
+/// let message = [1_u8, 0, 0, 0, 0, 0, 0, 0];
+///
+/// let mut aead = AES_128_GCM_SIV::new_with_key(&key);
+/// let mut cipher = Vec::<u8>::new();
+/// let tag = aead.encrypt_into_vec(nonce, &[], &message, &mut cipher);
+/// let mut txt = String::new();
+/// for c in cipher.iter().chain(tag.iter())
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "B5D839330AC7B786578782FFF6013B815B287C22493A364C");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(aead.decrypt_into_vec(nonce, &[], &cipher, tag, &mut recovered));
+/// assert_eq!(recovered, message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct AES_GCM_SIV_Generic<const NK: usize = 4, const ROUND: usize = 10>
+{
+    key_generator: AES_Generic<NK, ROUND>,
+}
+
+impl<const NK: usize, const ROUND: usize> AES_GCM_SIV_Generic<NK, ROUND>
+{
+    const MAX_LENGTH: u64 = 1 << 36;
+
+    /// Constructs a new object AES_GCM_SIV_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self { key_generator: AES_Generic::<NK, ROUND>::new() }
+    }
+
+    /// Constructs a new object AES_GCM_SIV_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the key-generating key of `NK * 4` bytes.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        Self { key_generator: AES_Generic::<NK, ROUND>::new_with_key(key) }
+    }
+
+    /// Sets the key-generating key.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        self.key_generator.set_key(key);
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce.
+    /// - `aad` is the associated data which is authenticated but not
+    ///   encrypted.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the 16-byte authentication tag.
+    ///
+    /// # Panics
+    /// It panics if `message` or `aad` is longer than 2^36 bytes.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 12], aad: &[u8], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+    {
+        assert!(message.len() as u64 <= Self::MAX_LENGTH && aad.len() as u64 <= Self::MAX_LENGTH, "The message and the associated data of AES_GCM_SIV_Generic should not be longer than 2^36 bytes.");
+        let (auth_key, encryptor) = self.derive_keys(&nonce);
+        let tag = Self::make_tag(&encryptor, auth_key, &nonce, aad, message);
+        cipher.clear();
+        cipher.extend_from_slice(message);
+        Self::crypt_ctr(&encryptor, &tag, cipher);
+        tag
+    }
+
+    /// Verifies `tag` and decrypts `cipher` only if the tag is correct.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 96-bit nonce that was used for encryption.
+    /// - `aad` is the associated data that was used for encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `tag` is the 16-byte authentication tag.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if the tag is correct. Otherwise, it returns `false`
+    /// and `message` will be empty.
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 12], aad: &[u8], cipher: &[u8], tag: [u8; 16], message: &mut Vec<u8>) -> bool
+    {
+        message.clear();
+        if cipher.len() as u64 > Self::MAX_LENGTH || aad.len() as u64 > Self::MAX_LENGTH
+            { return false; }
+        let (auth_key, encryptor) = self.derive_keys(&nonce);
+        let mut plain = cipher.to_vec();
+        Self::crypt_ctr(&encryptor, &tag, &mut plain);
+        if !tags_equal(&Self::make_tag(&encryptor, auth_key, &nonce, aad, &plain), &tag)
+            { return false; }
+        message.extend_from_slice(&plain);
+        true
+    }
+
+    // Derives the message-authentication key and the message-encryption key
+    // from the first 8 bytes of each encrypted block LE32(i) || nonce.
+    fn derive_keys(&self, nonce: &[u8; 12]) -> (u128, AES_Generic<NK, ROUND>)
+    {
+        let mut material = Vec::<u8>::with_capacity(16 + NK * 4);
+        let blocks = 2 + (NK * 4).div_ceil(8);
+        for i in 0..blocks
+        {
+            let mut block = [0_u8; 16];
+            block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+            self.key_generator.encrypt_block(&mut block);
+            material.extend_from_slice(&block[..8]);
+        }
+        let mut auth_key = [0_u8; 16];
+        auth_key.copy_from_slice(&material[..16]);
+        let encryptor = AES_Generic::<NK, ROUND>::new_with_key(&material[16..16 + NK * 4]);
+        (u128::from_le_bytes(auth_key), encryptor)
+    }
+
+    fn make_tag(encryptor: &AES_Generic<NK, ROUND>, auth_key: u128, nonce: &[u8; 12], aad: &[u8], message: &[u8]) -> [u8; 16]
+    {
+        let mut s = 0_u128;
+        s = Self::polyval(auth_key, s, aad);
+        s = Self::polyval(auth_key, s, message);
+        let length = ((message.len() as u128 * 8) << 64) | (aad.len() as u128 * 8);
+        s = Self::dot(s ^ length, auth_key);
+
+        let mut tag = s.to_le_bytes();
+        for (t, n) in tag.iter_mut().zip(nonce.iter())
+            { *t ^= *n; }
+        tag[15] &= 0x7F;
+        encryptor.encrypt_block(&mut tag);
+        tag
+    }
+
+    fn crypt_ctr(encryptor: &AES_Generic<NK, ROUND>, tag: &[u8; 16], data: &mut [u8])
+    {
+        let mut counter_block = *tag;
+        counter_block[15] |= 0x80;
+        let mut counter = u32::from_le_bytes([counter_block[0], counter_block[1], counter_block[2], counter_block[3]]);
+        for chunk in data.chunks_mut(16)
+        {
+            let mut keystream = counter_block;
+            keystream[..4].copy_from_slice(&counter.to_le_bytes());
+            encryptor.encrypt_block(&mut keystream);
+            for (d, k) in chunk.iter_mut().zip(keystream.iter())
+                { *d ^= *k; }
+            counter = counter.wrapping_add(1);
+        }
+    }
+
+    // Continues POLYVAL from the state `s` with the data padded with zeros.
+    fn polyval(h: u128, s: u128, data: &[u8]) -> u128
+    {
+        let mut s = s;
+        for chunk in data.chunks(16)
+        {
+            let mut block = [0_u8; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            s = Self::dot(s ^ u128::from_le_bytes(block), h);
+        }
+        s
+    }
+
+    // dot(a, b) = a * b * x^(-128) in GF(2^128) modulo
+    // x^128 + x^127 + x^126 + x^121 + 1, in constant time.
+    fn dot(a: u128, b: u128) -> u128
+    {
+        const REDUCTION: u128 = (1 << 127) | (1 << 126) | (1 << 125) | (1 << 120);
+        let mut r = 0_u128;
+        for i in 0..128
+        {
+            r ^= a & 0_u128.wrapping_sub((b >> i) & 1);
+            r = (r >> 1) ^ (REDUCTION & 0_u128.wrapping_sub(r & 1));
+        }
+        r
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> Default for AES_GCM_SIV_Generic<NK, ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Key Wrap and Key Wrap with Padding
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256, TDES };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES Key Wrap with AES-128 key-encryption key
+#[allow(non_camel_case_types)]
+pub type AES_128_KeyWrap = KeyWrap_Generic<AES_128>;
+
+/// AES Key Wrap with AES-256 key-encryption key
+#[allow(non_camel_case_types)]
+pub type AES_256_KeyWrap = KeyWrap_Generic<AES_256>;
+
+/// TDEA Key Wrap (TKW) of NIST SP 800-38F with three-key Triple DES
+/// key-encryption key, whose semiblocks are 32 bits long
+#[allow(non_camel_case_types)]
+pub type TDES_KeyWrap = KeyWrap_Generic<TDES>;
+
+/// Key Wrap (KW) and Key Wrap with Padding (KWP) on top of any block cipher
+/// that has the trait `BlockCipher`
+///
+/// # Introduction
+/// Key Wrap encrypts keys with the key-encryption key (KEK) so that they
+/// can be stored or transported, and detects any modification of the
+/// wrapped keys with the integrity check value. The key data is divided
+/// into semiblocks, which are half as long as the block of the underlying
+/// block cipher, and it is mixed by six rounds of the block cipher. The
+/// wrapped key is one semiblock longer than the key data.
+///
+/// - KW of RFC 3394 wraps the key data whose length is a multiple of the
+///   semiblock and at least two semiblocks.
+/// - KWP of RFC 5649 wraps the key data of any length from one byte. It
+///   records the length of the key data in the alternative initial value,
+///   so it works only with 128-bit block ciphers such as AES.
+///
+/// With 64-bit block ciphers, the semiblock is 32 bits long and the
+/// integrity check value is `A6A6A6A6`. With Triple DES (`TDES`), it is TKW
+/// of NIST SP 800-38F. With the other 64-bit block ciphers such as single
+/// DES, it follows the same algorithm but it is not standard.
+///
+/// # Vulnerability
+/// - Key Wrap is deterministic. Wrapping the same key with the same KEK
+///   always gives the same result.
+/// - The security of the wrapped keys cannot be higher than that of the
+///   KEK. Triple DES KEK is not acceptable for new designs, and single DES
+///   KEK is not acceptable at all.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be either 8 bytes or 16 bytes.
+///
+/// # Reference
+/// Read [RFC 3394](https://datatracker.ietf.org/doc/html/rfc3394),
+/// [RFC 5649](https://datatracker.ietf.org/doc/html/rfc5649) and
+/// [NIST SP 800-38F](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38F.pdf)
+/// about Key Wrap in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, AES_128_KeyWrap };
+///
+/// let mut kek = [0_u8; 16];
+/// for i in 0..16
+///     { kek[i] = i as u8; }
+/// let key_data = [ 0x00_u8, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+///                  0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF ];
+/// let mut kw = AES_128_KeyWrap::new(AES_128::new_with_key(&kek));
+/// let mut wrapped = Vec::<u8>::new();
+/// assert!(kw.wrap_into_vec(&key_data, &mut wrapped));
+/// let mut txt = String::new();
+/// for w in wrapped.iter()
+///     { write!(txt, "{:02X}", w); }
+/// assert_eq!(txt, "1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5");
+///
+/// let mut unwrapped = Vec::<u8>::new();
+/// assert!(kw.unwrap_into_vec(&wrapped, &mut unwrapped));
+/// assert_eq!(unwrapped, key_data);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct KeyWrap_Generic<C: BlockCipher>
+{
+    cipher: C,
+}
+
+impl<C: BlockCipher> KeyWrap_Generic<C>
+{
+    const HALF: usize = C::BLOCK_SIZE / 2;
+
+    /// Constructs a new object KeyWrap_Generic.
+    ///
+    /// # Arguments
+    /// `cipher` is the underlying block cipher with the key-encryption key.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is neither 8 nor 16.
+    pub fn new(cipher: C) -> Self
+    {
+        assert!(C::BLOCK_SIZE == 8 || C::BLOCK_SIZE == 16, "KeyWrap_Generic supports only 64-bit and 128-bit block ciphers.");
+        Self { cipher }
+    }
+
+    /// Wraps `key` by KW of RFC 3394.
+    ///
+    /// # Arguments
+    /// - `key` is the key data to be wrapped. Its length should be a
+    ///   multiple of the semiblock, which is 8 bytes for AES and 4 bytes
+    ///   for Triple DES, and at least two semiblocks.
+    /// - `wrapped` is the `Vec<u8>` that will have the wrapped key, which
+    ///   is one semiblock longer than `key`.
+    ///
+    /// # Output
+    /// It returns `true` on success. It returns `false` and leaves
+    /// `wrapped` empty if the length of `key` is not acceptable.
+    pub fn wrap_into_vec(&mut self, key: &[u8], wrapped: &mut Vec<u8>) -> bool
+    {
+        wrapped.clear();
+        if key.len() & (Self::HALF - 1) != 0 || key.len() < 2 * Self::HALF
+            { return false; }
+        let icv = vec![0xA6_u8; Self::HALF];
+        self.wrap(&icv, key, wrapped);
+        true
+    }
+
+    /// Unwraps `wrapped` by KW of RFC 3394 and checks its integrity.
+    ///
+    /// # Arguments
+    /// - `wrapped` is the wrapped key.
+    /// - `key` is the `Vec<u8>` that will have the unwrapped key data.
+    ///
+    /// # Output
+    /// It returns `true` if `wrapped` is authentic. Otherwise, it returns
+    /// `false` and `key` will be empty.
+    pub fn unwrap_into_vec(&mut self, wrapped: &[u8], key: &mut Vec<u8>) -> bool
+    {
+        key.clear();
+        if wrapped.len() & (Self::HALF - 1) != 0 || wrapped.len() < 3 * Self::HALF
+            { return false; }
+        let icv = vec![0xA6_u8; Self::HALF];
+        let a = self.unwrap(wrapped, key);
+        if !tags_equal(&a, &icv)
+        {
+            key.fill(0);
+            key.clear();
+            return false;
+        }
+        true
+    }
+
+    /// Wraps `key` by KWP of RFC 5649.
+    ///
+    /// # Arguments
+    /// - `key` is the key data of any length from 1 byte up to 2^32 - 1
+    ///   bytes.
+    /// - `wrapped` is the `Vec<u8>` that will have the wrapped key.
+    ///
+    /// # Output
+    /// It returns `true` on success. It returns `false` and leaves
+    /// `wrapped` empty if `key` is empty or too long, or if the block size
+    /// of `C` is not 16.
+    pub fn wrap_with_padding_into_vec(&mut self, key: &[u8], wrapped: &mut Vec<u8>) -> bool
+    {
+        wrapped.clear();
+        if C::BLOCK_SIZE != 16 || key.is_empty() || key.len() as u64 > u32::MAX as u64
+            { return false; }
+        let mut aiv = vec![0xA6_u8, 0x59, 0x59, 0xA6];
+        aiv.extend_from_slice(&(key.len() as u32).to_be_bytes());
+        let mut padded = key.to_vec();
+        padded.resize(key.len().div_ceil(8) * 8, 0);
+        if padded.len() == 8
+        {
+            let mut block = aiv;
+            block.extend_from_slice(&padded);
+            self.cipher.encrypt_one_block(&mut block);
+            wrapped.extend_from_slice(&block);
+        }
+        else
+            { self.wrap(&aiv, &padded, wrapped); }
+        true
+    }
+
+    /// Unwraps `wrapped` by KWP of RFC 5649 and checks its integrity.
+    ///
+    /// # Arguments
+    /// - `wrapped` is the wrapped key.
+    /// - `key` is the `Vec<u8>` that will have the unwrapped key data.
+    ///
+    /// # Output
+    /// It returns `true` if `wrapped` is authentic. Otherwise, it returns
+    /// `false` and `key` will be empty.
+    pub fn unwrap_with_padding_into_vec(&mut self, wrapped: &[u8], key: &mut Vec<u8>) -> bool
+    {
+        key.clear();
+        if C::BLOCK_SIZE != 16 || wrapped.len() & 7 != 0 || wrapped.len() < 16
+            { return false; }
+        let a = if wrapped.len() == 16
+        {
+            let mut block = wrapped.to_vec();
+            self.cipher.decrypt_one_block(&mut block);
+            key.extend_from_slice(&block[8..]);
+            block[..8].to_vec()
+        }
+        else
+            { self.unwrap(wrapped, key) };
+
+        let length = u32::from_be_bytes([a[4], a[5], a[6], a[7]]) as usize;
+        let mut valid = tags_equal(&a[..4], &[0xA6, 0x59, 0x59, 0xA6]);
+        valid &= length <= key.len() && length + 8 > key.len();
+        if valid
+        {
+            let mut padding = 0_u8;
+            for k in key[length..].iter()
+                { padding |= *k; }
+            valid &= padding == 0;
+        }
+        if !valid
+        {
+            key.fill(0);
+            key.clear();
+            return false;
+        }
+        key.truncate(length);
+        true
+    }
+
+    // The wrapping function W of NIST SP 800-38F.
+    fn wrap(&mut self, iv: &[u8], key: &[u8], wrapped: &mut Vec<u8>)
+    {
+        let half = Self::HALF;
+        let n = key.len() / half;
+        let mut a = iv.to_vec();
+        let mut r = key.to_vec();
+        let mut block = vec![0_u8; C::BLOCK_SIZE];
+        for j in 0..6
+        {
+            for i in 0..n
+            {
+                block[..half].copy_from_slice(&a);
+                block[half..].copy_from_slice(&r[i * half..(i + 1) * half]);
+                self.cipher.encrypt_one_block(&mut block);
+                a.copy_from_slice(&block[..half]);
+                Self::xor_counter(&mut a, (n * j + i + 1) as u64);
+                r[i * half..(i + 1) * half].copy_from_slice(&block[half..]);
+            }
+        }
+        wrapped.extend_from_slice(&a);
+        wrapped.extend_from_slice(&r);
+    }
+
+    // The unwrapping function W^-1 of NIST SP 800-38F. It puts the key data
+    // into `key` and returns the recovered initial value.
+    fn unwrap(&mut self, wrapped: &[u8], key: &mut Vec<u8>) -> Vec<u8>
+    {
+        let half = Self::HALF;
+        let n = wrapped.len() / half - 1;
+        let mut a = wrapped[..half].to_vec();
+        key.extend_from_slice(&wrapped[half..]);
+        let mut block = vec![0_u8; C::BLOCK_SIZE];
+        for j in (0..6).rev()
+        {
+            for i in (0..n).rev()
+            {
+                Self::xor_counter(&mut a, (n * j + i + 1) as u64);
+                block[..half].copy_from_slice(&a);
+                block[half..].copy_from_slice(&key[i * half..(i + 1) * half]);
+                self.cipher.decrypt_one_block(&mut block);
+                a.copy_from_slice(&block[..half]);
+                key[i * half..(i + 1) * half].copy_from_slice(&block[half..]);
+            }
+        }
+        a
+    }
+
+    // XORs the counter t in big endianness into the semiblock.
+    fn xor_counter(a: &mut [u8], t: u64)
+    {
+        let counter = t.to_be_bytes();
+        let len = a.len();
+        for (x, c) in a.iter_mut().zip(counter[8 - len..].iter())
+            { *x ^= *c; }
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains LEA block cipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::BlockCipher;
+
+
+/// The official LEA-128 symmetric-key algorithm with 128-bit key
+#[allow(non_camel_case_types)]
+pub type LEA_128 = LEA_Generic<4, 24>;
+
+/// The official LEA-192 symmetric-key algorithm with 192-bit key
+#[allow(non_camel_case_types)]
+pub type LEA_192 = LEA_Generic<6, 28>;
+
+/// The official LEA-256 symmetric-key algorithm with 256-bit key
+#[allow(non_camel_case_types)]
+pub type LEA_256 = LEA_Generic<8, 32>;
+
+/// A LEA (Lightweight Encryption Algorithm) symmetric-key algorithm for
+/// the encryption of digital data
+///
+/// # Introduction
+/// LEA was developed by NSR (National Security Research Institute) of
+/// Korea in 2013, and is standardized in TTAK.KO-12.0223 and ISO/IEC
+/// 29192-2. It is an ARX (Addition, Rotation and XOR) block cipher of
+/// 128-bit block, which has no S-box. It is designed to be fast in
+/// software on both high-end and low-end processors.
+///
+/// # Vulnerability
+/// No practical attack against the full rounds of LEA is known. It has no
+/// table lookup, so its running time does not depend on the key and the
+/// data.
+///
+/// # Generic Parameters
+/// - NK: The length of key in 32-bit words. It is `4` for LEA-128, `6` for
+///   LEA-192 and `8` for LEA-256.
+/// - ROUND: The number of rounds. It is `24` for LEA-128, `28` for LEA-192
+///   and `32` for LEA-256. You can change it to make your own expanded
+///   version. The round keys are made by the same key schedule.
+///
+/// # Byte order
+/// The block and the key are the sequences of bytes, which LEA reads as
+/// 32-bit words in little endianness. The methods `encrypt_u128()` and
+/// `decrypt_u128()` read and write the block as `u128` in little
+/// endianness, just as `AES_Generic` does.
+///
+/// # Reference
+/// Read [more](https://en.wikipedia.org/wiki/LEA_(cipher)) about LEA in
+/// detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ LEA_128, ModeCipher, OperationMode };
+///
+/// let key = [ 0x0F_u8, 0x1E, 0x2D, 0x3C, 0x4B, 0x5A, 0x69, 0x78,
+///             0x87, 0x96, 0xA5, 0xB4, 0xC3, 0xD2, 0xE1, 0xF0 ];
+/// let mut a_lea = LEA_128::new_with_key(&key);
+/// let message = 0x1F1E1D1C1B1A19181716151413121110_u128;
+/// let cipher = a_lea.encrypt_u128(message);
+/// assert_eq!(cipher.to_le_bytes(), [ 0x9F_u8, 0xC8, 0x4E, 0x35, 0x28, 0xC6, 0xC6, 0x18,
+///                                    0x55, 0x32, 0xC7, 0xA7, 0x04, 0x64, 0x8B, 0xFD ]);
+/// assert_eq!(a_lea.decrypt_u128(cipher), message);
+///
+/// // All the modes of operation and the padding schemes by ModeCipher
+/// let iv = [0x0F_u8; 16];
+/// let mut cbc = ModeCipher::new(LEA_128::new_with_key(&key), OperationMode::CBC, &iv);
+/// let cipher = cbc.encrypt("In the beginning God created the heavens and the earth.".as_bytes());
+/// assert_eq!(cbc.decrypt(&cipher).unwrap(), "In the beginning God created the heavens and the earth.".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct LEA_Generic<const NK: usize = 4, const ROUND: usize = 24>
+{
+    round_key: Vec<[u32; 6]>,
+}
+
+impl<const NK: usize, const ROUND: usize> LEA_Generic<NK, ROUND>
+{
+    // The constants for the key schedule, which are made from the square
+    // root of 766995, the ASCII code of "LEA"
+    const DELTA: [u32; 8] = [
+        0xC3EFE9DB, 0x44626B02, 0x79E27C8A, 0x78DF30EC,
+        0x715EA49E, 0xC785DA0A, 0xE04EF22A, 0xE5C40957
+    ];
+
+    const ROTATION: [u32; 6] = [1, 3, 6, 11, 13, 17];
+
+    /// Constructs a new object LEA_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self::new_with_key(&vec![0_u8; NK * 4])
+    }
+
+    /// Constructs a new object LEA_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements,
+    /// that is, 16 bytes for LEA-128, 24 bytes for LEA-192 and 32 bytes for
+    /// LEA-256.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        let mut lea = Self { round_key: Vec::new() };
+        lea.set_key(key);
+        lea
+    }
+
+    /// Sets the key and makes the round keys.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has `NK * 4` elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `NK * 4`, or if `NK` is not
+    /// `4`, `6` or `8`.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        assert!(NK == 4 || NK == 6 || NK == 8, "NK of LEA_Generic should be 4, 6 or 8.");
+        assert_eq!(key.len(), NK * 4, "The key of LEA_Generic<{}, {}> should be {} bytes long.", NK, ROUND, NK * 4);
+        let mut t = [0_u32; 8];
+        for (t, k) in t.iter_mut().zip(key.chunks_exact(4))
+            { *t = u32::from_le_bytes([k[0], k[1], k[2], k[3]]); }
+        self.round_key = Vec::with_capacity(ROUND);
+        for i in 0..ROUND
+        {
+            let delta = Self::DELTA[i % NK];
+            let mut round_key = [0_u32; 6];
+            match NK
+            {
+                4 => {
+                    for (j, t) in t.iter_mut().take(4).enumerate()
+                        { *t = t.wrapping_add(delta.rotate_left((i + j) as u32)).rotate_left(Self::ROTATION[j]); }
+                    round_key = [t[0], t[1], t[2], t[1], t[3], t[1]];
+                },
+                6 => {
+                    for (j, t) in t.iter_mut().take(6).enumerate()
+                        { *t = t.wrapping_add(delta.rotate_left((i + j) as u32)).rotate_left(Self::ROTATION[j]); }
+                    round_key.copy_from_slice(&t[..6]);
+                },
+                _ => {
+                    for (j, rk) in round_key.iter_mut().enumerate()
+                    {
+                        let idx = (6 * i + j) % 8;
+                        t[idx] = t[idx].wrapping_add(delta.rotate_left((i + j) as u32)).rotate_left(Self::ROTATION[j]);
+                        *rk = t[idx];
+                    }
+                },
+            }
+            self.round_key.push(round_key);
+        }
+    }
+
+    /// Encrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `message` is the block to be encrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the encrypted block in little endianness.
+    pub fn encrypt_u128(&mut self, message: u128) -> u128
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Decrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `cipher` is the block to be decrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the decrypted block in little endianness.
+    pub fn decrypt_u128(&mut self, cipher: u128) -> u128
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Encrypts the array of 128-bit data blocks independently of one another.
+    pub fn encrypt_array_u128<const N: usize>(&mut self, message: &[u128; N], cipher: &mut [u128; N])
+    {
+        for (c, m) in cipher.iter_mut().zip(message.iter())
+            { *c = self.encrypt_u128(*m); }
+    }
+
+    /// Decrypts the array of 128-bit data blocks independently of one another.
+    pub fn decrypt_array_u128<const N: usize>(&mut self, cipher: &[u128; N], message: &mut [u128; N])
+    {
+        for (m, c) in message.iter_mut().zip(cipher.iter())
+            { *m = self.decrypt_u128(*c); }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16])
+    {
+        let mut x = Self::to_words(block);
+        for rk in self.round_key.iter()
+        {
+            x = [
+                ((x[0] ^ rk[0]).wrapping_add(x[1] ^ rk[1])).rotate_left(9),
+                ((x[1] ^ rk[2]).wrapping_add(x[2] ^ rk[3])).rotate_right(5),
+                ((x[2] ^ rk[4]).wrapping_add(x[3] ^ rk[5])).rotate_right(3),
+                x[0]
+            ];
+        }
+        Self::from_words(block, &x);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16])
+    {
+        let mut x = Self::to_words(block);
+        for rk in self.round_key.iter().rev()
+        {
+            let x0 = x[3];
+            let x1 = x[0].rotate_right(9).wrapping_sub(x0 ^ rk[0]) ^ rk[1];
+            let x2 = x[1].rotate_left(5).wrapping_sub(x1 ^ rk[2]) ^ rk[3];
+            let x3 = x[2].rotate_left(3).wrapping_sub(x2 ^ rk[4]) ^ rk[5];
+            x = [x0, x1, x2, x3];
+        }
+        Self::from_words(block, &x);
+    }
+
+    #[inline]
+    fn to_words(block: &[u8; 16]) -> [u32; 4]
+    {
+        let mut x = [0_u32; 4];
+        for (x, b) in x.iter_mut().zip(block.chunks_exact(4))
+            { *x = u32::from_le_bytes([b[0], b[1], b[2], b[3]]); }
+        x
+    }
+
+    #[inline]
+    fn from_words(block: &mut [u8; 16], x: &[u32; 4])
+    {
+        for (b, x) in block.chunks_exact_mut(4).zip(x.iter())
+            { b.copy_from_slice(&x.to_le_bytes()); }
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> Default for LEA_Generic<NK, ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> BlockCipher for LEA_Generic<NK, ROUND>
+{
+    const BLOCK_SIZE: usize = 16;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.encrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.decrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+}
@@ -23,25 +23,185 @@
 //! This module provides several kinds of symmetric-key algorithms for the encryption/decryption of digital data:
 //! - DES encryption/decryption algorithms --- Includes DES and its expanded versions. `DES_Generic`
 // ! (struct@DES_Generic)
+//! - Bitsliced DES encryption/decryption algorithm --- The constant-time DES which encrypts 64 blocks in parallel.
+//!   [`DES_Bitsliced`](struct@DES_Bitsliced)
+//! - DES with the S-boxes given at runtime --- For the key-dependent S-boxes and so on.
+//!   [`DES_RuntimeSbox_Generic`](struct@DES_RuntimeSbox_Generic)
+//! - DESX encryption/decryption algorithm --- DES with the pre-whitening key and the post-whitening key.
+//!   [`DESX_Generic`](struct@DESX_Generic)
+//! - Triple DES (TDEA) encryption/decryption algorithm --- Three-key Triple DES in the EDE mode.
+//!   [`TDES_Generic`](struct@TDES_Generic)
 //! - NDES encryption/decryption algorithms --- Includes 2DES, 3DES and its expanded versions. `NDES_Generic`
 // ! (struct@NDES_Generic)
-//! - AES encryption/decryption algorithms --- Includes AES and its expanded versions. `AES_Generic`
-// ! (struct@AES_Generic)
+//! - AES encryption/decryption algorithms --- Includes AES and its expanded versions.
+//!   [`AES_Generic`](struct@AES_Generic)
 //! - NAES encryption/decryption algorithms --- Includes 2AES, 3AES and its expanded versions. `NAES_Generic`
 // ! (struct@NAES_Generic)
+//! - SEED encryption/decryption algorithm --- The Korean standard block cipher of RFC 4269.
+//!   [`SEED_Generic`](struct@SEED_Generic)
+//! - ARIA encryption/decryption algorithms --- Includes ARIA-128, ARIA-192 and ARIA-256 of RFC 5794.
+//!   [`ARIA_Generic`](struct@ARIA_Generic)
+//! - LEA encryption/decryption algorithms --- Includes LEA-128, LEA-192 and LEA-256.
+//!   [`LEA_Generic`](struct@LEA_Generic)
+//! - Camellia encryption/decryption algorithms --- Includes Camellia-128, Camellia-192 and Camellia-256 of RFC 3713.
+//!   [`CAMELLIA_Generic`](struct@CAMELLIA_Generic)
+//! - Blowfish encryption/decryption algorithm --- Includes the expensive key schedule EksBlowfish.
+//!   [`Blowfish`](struct@Blowfish)
+//! - bcrypt password hashing --- Includes the versions `$2a$`, `$2b$` and `$2y$`.
+//!   [`Bcrypt`](struct@Bcrypt)
+//! - ChaCha stream cipher algorithms --- Includes ChaCha20, ChaCha12, ChaCha8, and XChaCha20.
+//!   [`ChaCha_Generic`](struct@ChaCha_Generic), [`XChaCha_Generic`](struct@XChaCha_Generic)
+//! - Salsa stream cipher algorithms --- Includes Salsa20, Salsa20/12, Salsa20/8, and XSalsa20.
+//!   [`Salsa_Generic`](struct@Salsa_Generic), [`XSalsa_Generic`](struct@XSalsa_Generic)
+//! - Poly1305 one-time message authentication code. [`Poly1305`](struct@Poly1305)
+//! - ChaCha20-Poly1305 authenticated encryption --- Includes ChaCha20-Poly1305 and XChaCha20-Poly1305.
+//!   [`ChaCha_Poly1305_Generic`](struct@ChaCha_Poly1305_Generic), [`XChaCha_Poly1305_Generic`](struct@XChaCha_Poly1305_Generic)
+//! - Ascon lightweight authenticated encryption --- Includes Ascon-AEAD128.
+//!   [`Ascon_AEAD_Generic`](struct@Ascon_AEAD_Generic)
+//! - XTS mode of operation for sector-based storage encryption --- Works with
+//!   any block cipher that has the trait [`BlockCipher`](trait@BlockCipher).
+//!   [`XTS_Generic`](struct@XTS_Generic)
+//! - CCM and CCM* authenticated encryption --- Works with any 128-bit block
+//!   cipher that has the trait `BlockCipher`. [`CCM_Generic`](struct@CCM_Generic)
+//!   and [`CCM_Star_Generic`](struct@CCM_Star_Generic)
+//! - EAX authenticated encryption --- Works with any block cipher that has
+//!   the trait `BlockCipher`. [`EAX_Generic`](struct@EAX_Generic)
+//! - SIV misuse-resistant authenticated encryption --- Includes AES-SIV and
+//!   works with any 128-bit block cipher that has the trait `BlockCipher`.
+//!   [`SIV_Generic`](struct@SIV_Generic)
+//! - AES-GCM-SIV misuse-resistant authenticated encryption --- Includes
+//!   AES-128-GCM-SIV and AES-256-GCM-SIV. [`AES_GCM_SIV_Generic`](struct@AES_GCM_SIV_Generic)
+//! - Block cipher based message authentication codes --- Includes CMAC (OMAC1),
+//!   CBC-MAC and ISO/IEC 9797-1 MAC algorithm 3 (retail MAC).
+//!   [`CMAC_Generic`](struct@CMAC_Generic), [`CBC_MAC_Generic`](struct@CBC_MAC_Generic),
+//!   [`Retail_MAC_Generic`](struct@Retail_MAC_Generic)
+//! - Key Wrap --- Includes KW of RFC 3394 and KWP of RFC 5649, and works with
+//!   any block cipher that has the trait `BlockCipher`. [`KeyWrap_Generic`](struct@KeyWrap_Generic)
+//! - Format-preserving encryption --- Includes FF1 and FF3-1 of NIST SP 800-38G,
+//!   and works with any 128-bit block cipher that has the trait `BlockCipher`.
+//!   [`FF1_Generic`](struct@FF1_Generic), [`FF3_1_Generic`](struct@FF3_1_Generic)
+//! - Generic Feistel network and Lai-Massey scheme --- Includes the balanced
+//!   and unbalanced Feistel networks, Luby-Rackoff construction based on
+//!   SHA-256 and Lai-Massey scheme with user-supplied round functions.
+//!   [`Feistel_Generic`](struct@Feistel_Generic), [`LaiMassey_Generic`](struct@LaiMassey_Generic)
+//! - Slice-based encryption/decryption with error reporting --- Works in
+//!   ECB, CBC, PCBC, CFB, OFB and CTR modes with any block cipher that has
+//!   the trait `BlockCipher`. [`ModeCipher`](struct@ModeCipher),
+//!   [`CipherError`](enum@CipherError)
+//! - Padding schemes for ECB, CBC and PCBC modes --- Includes PKCS #7,
+//!   ISO/IEC 7816-4, ANSI X9.23, ISO 10126, zero padding and no padding.
+//!   [`Padding`](trait@Padding)
+//! - Streaming encryption/decryption --- Implements `std::io::Write` and
+//!   `std::io::Read` in the same modes as `ModeCipher`.
+//!   [`Encryptor`](struct@Encryptor), [`Decryptor`](struct@Decryptor)
 //! 
 //! # QUICK START
 //! - For `DES`, read [here](struct@DES_Generic#quick-start).
+//! - For the bitsliced `DES`, read [here](struct@DES_Bitsliced#quick-start).
+//! - For `DES_RuntimeSbox`, read [here](struct@DES_RuntimeSbox_Generic#quick-start).
+//! - For `DESX`, read [here](struct@DESX_Generic#quick-start).
+//! - For `TDES`, read [here](struct@TDES_Generic#quick-start).
 // ! - For `NDES`, read [here](struct@NDES_Generic#quick-start).
-// ! - For `AES`, read [here](struct@AES_Generic#quick-start).
+//! - For `AES`, read [here](struct@AES_Generic#quick-start).
 // ! - For `NAES`, read [here](struct@NAES_Generic#quick-start).
+//! - For `SEED`, read [here](struct@SEED_Generic#quick-start).
+//! - For `ARIA`, read [here](struct@ARIA_Generic#quick-start).
+//! - For `LEA`, read [here](struct@LEA_Generic#quick-start).
+//! - For `Camellia`, read [here](struct@CAMELLIA_Generic#quick-start).
+//! - For `Blowfish`, read [here](struct@Blowfish#quick-start).
+//! - For `Bcrypt`, read [here](struct@Bcrypt#quick-start).
+//! - For `ChaCha20`, read [here](struct@ChaCha_Generic#quick-start).
+//! - For `XChaCha20`, read [here](struct@XChaCha_Generic#quick-start).
+//! - For `Salsa20`, read [here](struct@Salsa_Generic#quick-start).
+//! - For `XSalsa20`, read [here](struct@XSalsa_Generic#quick-start).
+//! - For `Poly1305`, read [here](struct@Poly1305#quick-start).
+//! - For `ChaCha20_Poly1305`, read [here](struct@ChaCha_Poly1305_Generic#quick-start).
+//! - For `Ascon_AEAD128`, read [here](struct@Ascon_AEAD_Generic#quick-start).
+//! - For `XTS`, read [here](struct@XTS_Generic#quick-start).
+//! - For `CCM`, read [here](struct@CCM_Generic#quick-start).
+//! - For `EAX`, read [here](struct@EAX_Generic#quick-start).
+//! - For `AES-SIV`, read [here](struct@SIV_Generic#quick-start).
+//! - For `AES-GCM-SIV`, read [here](struct@AES_GCM_SIV_Generic#quick-start).
+//! - For `CMAC`, read [here](struct@CMAC_Generic#quick-start).
+//! - For `CBC-MAC`, read [here](struct@CBC_MAC_Generic#quick-start).
+//! - For `Retail MAC`, read [here](struct@Retail_MAC_Generic#quick-start).
+//! - For `Key Wrap`, read [here](struct@KeyWrap_Generic#quick-start).
+//! - For `FF1`, read [here](struct@FF1_Generic#quick-start).
+//! - For `FF3-1`, read [here](struct@FF3_1_Generic#quick-start).
+//! - For the generic Feistel network, read [here](struct@Feistel_Generic#quick-start).
+//! - For Lai-Massey scheme, read [here](struct@LaiMassey_Generic#quick-start).
+//! - For slice-based encryption/decryption, read [here](struct@ModeCipher#quick-start).
+//! - For streaming encryption/decryption, read [here](struct@Encryptor#quick-start).
+//! - For padding schemes, read [here](trait@Padding#quick-start).
 
+mod trait_block_cipher;
+mod cipher_errors;
+mod trait_impl_for_des;
+mod trait_impl_for_aes;
 mod des;
+mod des_bitsliced;
+mod des_runtime_sbox;
+mod desx;
+mod tdes;
 // mod ndes;
-// mod aes;
+mod aes;
 // mod naes;
+mod seed;
+mod aria;
+mod lea;
+mod camellia;
+mod blowfish;
+mod bcrypt;
+mod chacha20;
+mod salsa20;
+mod poly1305;
+mod chacha20_poly1305;
+mod ascon;
+mod xts;
+mod ccm;
+mod eax;
+mod cmac;
+mod cbc_mac;
+mod key_wrap;
+mod fpe;
+mod feistel;
+mod padding;
+mod operation_mode;
+mod stream;
+mod siv;
+mod gcm_siv;
 
+pub use trait_block_cipher::*;
+pub use cipher_errors::*;
 pub use des::*;
+pub use des_bitsliced::*;
+pub use des_runtime_sbox::*;
+pub use desx::*;
+pub use tdes::*;
 // pub use ndes::*;
-// pub use aes::*;
+pub use aes::*;
 // pub use naes::*;
+pub use seed::*;
+pub use aria::*;
+pub use lea::*;
+pub use camellia::*;
+pub use blowfish::*;
+pub use bcrypt::*;
+pub use chacha20::*;
+pub use salsa20::*;
+pub use poly1305::*;
+pub use chacha20_poly1305::*;
+pub use ascon::*;
+pub use xts::*;
+pub use ccm::*;
+pub use eax::*;
+pub use siv::*;
+pub use gcm_siv::*;
+pub use cmac::*;
+pub use cbc_mac::*;
+pub use key_wrap::*;
+pub use fpe::*;
+pub use feistel::*;
+pub use padding::*;
+pub use operation_mode::*;
+pub use stream::*;
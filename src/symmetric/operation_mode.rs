@@ -0,0 +1,648 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the modes of operation for any block cipher
+//! that has the trait BlockCipher, and the slice-based API on top of them
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, CipherError, Padding, PKCS7_Padding };
+
+
+/// The modes of operation that `ModeCipher`, `Encryptor` and `Decryptor`
+/// support
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OperationMode
+{
+    /// Electronic codebook mode with padding. With the default padding
+    /// `PKCS7_Padding`, the ciphertext is one to `BLOCK_SIZE` bytes longer
+    /// than the message, and it is compatible with
+    /// `DES_Generic::encrypt_with_padding_pkcs7_ecb()`. The initialization
+    /// vector is not used.
+    ECB,
+
+    /// Cipher block chaining mode with padding. With the default padding
+    /// `PKCS7_Padding`, the ciphertext is one to `BLOCK_SIZE` bytes longer
+    /// than the message, and it is compatible with
+    /// `DES_Generic::encrypt_with_padding_pkcs7_cbc()`.
+    CBC,
+
+    /// Propagating cipher block chaining mode with padding. With the
+    /// default padding `PKCS7_Padding`, the ciphertext is one to
+    /// `BLOCK_SIZE` bytes longer than the message, and it is compatible with
+    /// `DES_Generic::encrypt_with_padding_pkcs7_pcbc()`.
+    PCBC,
+
+    /// Cipher block chaining mode with ciphertext stealing of variant
+    /// CS1 of NIST SP 800-38A Addendum. The ciphertext is as long as the
+    /// message, which should be at least one block long. The partial last
+    /// block of ciphertext comes right before the full last block.
+    CBC_CS1,
+
+    /// Cipher block chaining mode with ciphertext stealing of variant
+    /// CS2 of NIST SP 800-38A Addendum. It is the same as `CBC_CS1` when
+    /// the length of the message is a multiple of the block size, and the
+    /// same as `CBC_CS3` otherwise.
+    CBC_CS2,
+
+    /// Cipher block chaining mode with ciphertext stealing of variant
+    /// CS3 of NIST SP 800-38A Addendum, which is also used by Kerberos
+    /// of RFC 3962. The last two blocks of ciphertext are always swapped,
+    /// so that the full last block comes right before the last block that
+    /// may be partial.
+    CBC_CS3,
+
+    /// Cipher feedback mode of full block feedback. The ciphertext is as
+    /// long as the message. It is compatible with
+    /// `DES_Generic::encrypt_cfb()`.
+    CFB,
+
+    /// Output feedback mode. The ciphertext is as long as the message.
+    /// It is compatible with `DES_Generic::encrypt_ofb()`.
+    OFB,
+
+    /// Counter mode. The initialization vector is the nonce which is
+    /// regarded as a little-endian integer, and the counter of the `n`-th
+    /// block is the nonce plus `n`. The ciphertext is as long as the
+    /// message. It is compatible with `DES_Generic::encrypt_ctr()`.
+    CTR,
+}
+
+impl OperationMode
+{
+    // ECB, CBC and PCBC modes need padding, and the decryptor keeps the
+    // last block until the end.
+    pub(crate) fn is_padded(&self) -> bool
+    {
+        matches!(*self, OperationMode::ECB | OperationMode::CBC | OperationMode::PCBC)
+    }
+
+    // CBC_CS1, CBC_CS2 and CBC_CS3 modes steal ciphertext from the second
+    // last block, and the decryptor keeps the last two blocks until the end.
+    pub(crate) fn is_stealing(&self) -> bool
+    {
+        matches!(*self, OperationMode::CBC_CS1 | OperationMode::CBC_CS2 | OperationMode::CBC_CS3)
+    }
+
+    // Returns the start of the last two blocks, which may be partial, of the
+    // data of `length` bytes in the ciphertext stealing modes.
+    pub(crate) fn stealing_start(length: usize, block_size: usize) -> usize
+    {
+        if length <= block_size
+            { 0 }
+        else
+            { ((length - 1) / block_size - 1) * block_size }
+    }
+}
+
+
+// The chaining state of the modes of operation, which is carried across
+// the blocks and across the calls of `write()` and `read()` of the stream
+// adapters.
+#[derive(Debug, Clone)]
+pub(crate) struct Chain<C: BlockCipher>
+{
+    cipher: C,
+    mode: OperationMode,
+    register: Vec<u8>,
+}
+
+impl<C: BlockCipher> Chain<C>
+{
+    pub(crate) fn new(cipher: C, mode: OperationMode, iv: &[u8]) -> Self
+    {
+        let mut chain = Self { cipher, mode, register: vec![0_u8; C::BLOCK_SIZE] };
+        chain.set_register(iv);
+        chain
+    }
+
+    pub(crate) fn get_mode(&self) -> OperationMode
+    {
+        self.mode
+    }
+
+    // Sets the initialization vector. It is ignored in ECB mode.
+    pub(crate) fn set_register(&mut self, iv: &[u8])
+    {
+        if self.mode == OperationMode::ECB
+            { return; }
+        assert_eq!(iv.len(), C::BLOCK_SIZE, "The initialization vector should be as long as the block.");
+        self.register.copy_from_slice(iv);
+    }
+
+    pub(crate) fn encrypt_block(&mut self, block: &mut [u8])
+    {
+        match self.mode
+        {
+            OperationMode::ECB => { self.cipher.encrypt_one_block(block); },
+            OperationMode::CBC | OperationMode::CBC_CS1 | OperationMode::CBC_CS2 | OperationMode::CBC_CS3 => {
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                self.cipher.encrypt_one_block(block);
+                self.register.copy_from_slice(block);
+            },
+            OperationMode::PCBC => {
+                let message = block.to_vec();
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                self.cipher.encrypt_one_block(block);
+                for ((r, m), b) in self.register.iter_mut().zip(message.iter()).zip(block.iter())
+                    { *r = *m ^ *b; }
+            },
+            OperationMode::CFB => {
+                self.cipher.encrypt_one_block(&mut self.register);
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                self.register.copy_from_slice(block);
+            },
+            _ => { self.crypt_tail(block); },
+        }
+    }
+
+    pub(crate) fn decrypt_block(&mut self, block: &mut [u8])
+    {
+        match self.mode
+        {
+            OperationMode::ECB => { self.cipher.decrypt_one_block(block); },
+            OperationMode::CBC | OperationMode::CBC_CS1 | OperationMode::CBC_CS2 | OperationMode::CBC_CS3 => {
+                let chained = block.to_vec();
+                self.cipher.decrypt_one_block(block);
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                self.register.copy_from_slice(&chained);
+            },
+            OperationMode::PCBC => {
+                let chained = block.to_vec();
+                self.cipher.decrypt_one_block(block);
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                for ((r, c), b) in self.register.iter_mut().zip(chained.iter()).zip(block.iter())
+                    { *r = *c ^ *b; }
+            },
+            OperationMode::CFB => {
+                let chained = block.to_vec();
+                self.cipher.encrypt_one_block(&mut self.register);
+                for (b, r) in block.iter_mut().zip(self.register.iter())
+                    { *b ^= *r; }
+                self.register.copy_from_slice(&chained);
+            },
+            _ => { self.crypt_tail(block); },
+        }
+    }
+
+    // XORs the next keystream block to `data` which is a full block or the
+    // last partial block in CFB, OFB and CTR modes. It is the same for
+    // encryption and decryption.
+    pub(crate) fn crypt_tail(&mut self, data: &mut [u8])
+    {
+        let keystream = match self.mode
+        {
+            OperationMode::OFB => {
+                self.cipher.encrypt_one_block(&mut self.register);
+                self.register.clone()
+            },
+            OperationMode::CTR => {
+                for r in self.register.iter_mut()
+                {
+                    *r = r.wrapping_add(1);
+                    if *r != 0
+                        { break; }
+                }
+                let mut keystream = self.register.clone();
+                self.cipher.encrypt_one_block(&mut keystream);
+                keystream
+            },
+            _ => {
+                let mut keystream = self.register.clone();
+                self.cipher.encrypt_one_block(&mut keystream);
+                keystream
+            },
+        };
+        for (d, k) in data.iter_mut().zip(keystream.iter())
+            { *d ^= *k; }
+    }
+
+    // XORs the keystream to `blocks` which are full blocks in CTR mode. It
+    // makes the counter blocks in advance and encrypts them with
+    // `encrypt_blocks()` so that the block ciphers which encrypt many
+    // blocks in parallel can speed up. It is the same as `crypt_tail()`
+    // for each block.
+    fn crypt_counter(&mut self, blocks: &mut [u8])
+    {
+        const BATCH: usize = 64;
+        let size = C::BLOCK_SIZE;
+        let mut keystream = vec![0_u8; size * BATCH.min(blocks.len() / size)];
+        for chunk in blocks.chunks_mut(size * BATCH)
+        {
+            let keystream = &mut keystream[..chunk.len()];
+            for counter in keystream.chunks_exact_mut(size)
+            {
+                for r in self.register.iter_mut()
+                {
+                    *r = r.wrapping_add(1);
+                    if *r != 0
+                        { break; }
+                }
+                counter.copy_from_slice(&self.register);
+            }
+            self.cipher.encrypt_blocks(keystream);
+            for (d, k) in chunk.iter_mut().zip(keystream.iter())
+                { *d ^= *k; }
+        }
+    }
+
+    // Encrypts the whole blocks `blocks` in a row. ECB mode and CTR mode
+    // encrypt all of them at once by `encrypt_blocks()` of the block cipher
+    // and `crypt_counter()`, so that the block ciphers which encrypt many
+    // blocks in parallel can speed up. The other modes encrypt them one by
+    // one because of the chaining.
+    pub(crate) fn encrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        match self.mode
+        {
+            OperationMode::ECB => { self.cipher.encrypt_blocks(blocks); },
+            OperationMode::CTR => { self.crypt_counter(blocks); },
+            _ => {
+                for block in blocks.chunks_exact_mut(C::BLOCK_SIZE)
+                    { self.encrypt_block(block); }
+            },
+        }
+    }
+
+    // Decrypts the whole blocks `blocks` in a row in the same way as
+    // `encrypt_blocks()`.
+    pub(crate) fn decrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        match self.mode
+        {
+            OperationMode::ECB => { self.cipher.decrypt_blocks(blocks); },
+            OperationMode::CTR => { self.crypt_counter(blocks); },
+            _ => {
+                for block in blocks.chunks_exact_mut(C::BLOCK_SIZE)
+                    { self.decrypt_block(block); }
+            },
+        }
+    }
+
+    // Encrypts `data` block by block. The last partial block, which is
+    // allowed only in CFB, OFB and CTR modes, is encrypted by `crypt_tail()`.
+    // In the ciphertext stealing modes, the last two blocks are encrypted by
+    // `encrypt_stealing()`, and `data` should be at least one block long.
+    pub(crate) fn encrypt_all(&mut self, data: &mut [u8])
+    {
+        let size = C::BLOCK_SIZE;
+        let stealing = self.mode.is_stealing();
+        let whole = if stealing { OperationMode::stealing_start(data.len(), size) } else { data.len() - data.len() % size };
+        let (blocks, tail) = data.split_at_mut(whole);
+        self.encrypt_blocks(blocks);
+        if stealing
+            { self.encrypt_stealing(tail); }
+        else if !tail.is_empty()
+            { self.crypt_tail(tail); }
+    }
+
+    // Decrypts `data` block by block. The last partial block, which is
+    // allowed only in CFB, OFB and CTR modes, is decrypted by `crypt_tail()`.
+    // In the ciphertext stealing modes, the last two blocks are decrypted by
+    // `decrypt_stealing()`, and `data` should be at least one block long.
+    pub(crate) fn decrypt_all(&mut self, data: &mut [u8])
+    {
+        let size = C::BLOCK_SIZE;
+        let stealing = self.mode.is_stealing();
+        let whole = if stealing { OperationMode::stealing_start(data.len(), size) } else { data.len() - data.len() % size };
+        let (blocks, tail) = data.split_at_mut(whole);
+        self.decrypt_blocks(blocks);
+        if stealing
+            { self.decrypt_stealing(tail); }
+        else if !tail.is_empty()
+            { self.crypt_tail(tail); }
+    }
+
+    // Encrypts the last two blocks `tail` whose length is more than one
+    // block and at most two blocks, or which is exactly one block, with
+    // ciphertext stealing.
+    fn encrypt_stealing(&mut self, tail: &mut [u8])
+    {
+        let size = C::BLOCK_SIZE;
+        if tail.len() == size
+        {
+            self.encrypt_block(tail);
+            return;
+        }
+        let d = tail.len() - size;
+        self.encrypt_block(&mut tail[..size]);
+        let previous = tail[..size].to_vec();
+        let mut last = vec![0_u8; size];
+        last[..d].copy_from_slice(&tail[size..]);
+        self.encrypt_block(&mut last);
+        if self.mode == OperationMode::CBC_CS1 || (self.mode == OperationMode::CBC_CS2 && d == size)
+        {
+            tail[..d].copy_from_slice(&previous[..d]);
+            tail[d..].copy_from_slice(&last);
+        }
+        else
+        {
+            tail[..size].copy_from_slice(&last);
+            tail[size..].copy_from_slice(&previous[..d]);
+        }
+    }
+
+    // Decrypts the last two blocks `tail` which `encrypt_stealing()` made.
+    fn decrypt_stealing(&mut self, tail: &mut [u8])
+    {
+        let size = C::BLOCK_SIZE;
+        if tail.len() == size
+        {
+            self.decrypt_block(tail);
+            return;
+        }
+        let d = tail.len() - size;
+        let (mut previous, last) = if self.mode == OperationMode::CBC_CS1 || (self.mode == OperationMode::CBC_CS2 && d == size)
+            { (tail[..d].to_vec(), tail[d..].to_vec()) }
+        else
+            { (tail[size..].to_vec(), tail[..size].to_vec()) };
+
+        // The decrypted last block is the partial last block of message
+        // padded with zeros XORed with the full second last block of
+        // ciphertext, whose stolen part is recovered from it.
+        let mut z = last;
+        self.cipher.decrypt_one_block(&mut z);
+        for (zz, p) in z.iter_mut().zip(previous.iter())
+            { *zz ^= *p; }
+        previous.extend_from_slice(&z[d..]);
+        self.decrypt_block(&mut previous);
+        tail[..size].copy_from_slice(&previous);
+        tail[size..].copy_from_slice(&z[..d]);
+    }
+}
+
+
+/// The slice-based encryptor and decryptor with any block cipher that has
+/// the trait `BlockCipher` in the modes of operation
+///
+/// # Introduction
+/// The methods of `DES_Generic` such as `decrypt_with_padding_pkcs7_cbc()`
+/// take raw pointers and return a bare length, so that they cannot report
+/// the invalid padding and they cannot check the size of the output buffer.
+/// `ModeCipher` takes and gives slices instead, and returns
+/// `Result<usize, CipherError>`, so that you can tell a corrupted
+/// ciphertext from a real result.
+///
+/// Every method of encryption and decryption starts from the
+/// initialization vector given to `new()` or `set_iv()`, so that each
+/// call is independent of the others.
+///
+/// In the ciphertext stealing modes `CBC_CS1`, `CBC_CS2` and `CBC_CS3`,
+/// the ciphertext is exactly as long as the message without padding,
+/// which is good for fixed-size fields. The message should be at least
+/// one block long in these modes.
+///
+/// In ECB, CBC and PCBC modes, the message is padded by the padding scheme
+/// `P`. `new()` uses PKCS #7 padding, and `new_with_padding()` takes any
+/// padding scheme which has the trait `Padding`, such as
+/// `ANSI_X923_Padding` or `No_Padding`.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+/// - P: the padding scheme which has the trait `Padding`. It is
+///   `PKCS7_Padding` by default.
+///
+/// # Errors
+/// - `CipherError::BadLength`: the ciphertext of ECB, CBC or PCBC mode is
+///   not a multiple of the block size or too short to have the padding,
+///   the message cannot be padded by `P` such as the message of
+///   `No_Padding` which is not a multiple of the block size, or the
+///   message or the ciphertext of the ciphertext stealing modes is shorter
+///   than one block.
+/// - `CipherError::InvalidPadding`: the padding of the decrypted data is
+///   not correct.
+/// - `CipherError::BufferTooSmall`: the output buffer of `encrypt_into()`
+///   or `decrypt_into()` is too small.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, CipherError };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut des = ModeCipher::new(DES::new_with_key(key), OperationMode::CBC, &iv);
+/// let message = "In the beginning God created the heavens and the earth.";
+/// let cipher = des.encrypt(message.as_bytes());
+/// assert_eq!(cipher.len(), 56);
+///
+/// let mut recovered = [0_u8; 56];
+/// let size = des.decrypt_into(&cipher, &mut recovered).unwrap();
+/// assert_eq!(&recovered[..size], message.as_bytes());
+///
+/// let mut small = [0_u8; 32];
+/// assert_eq!(des.decrypt_into(&cipher, &mut small), Err(CipherError::BufferTooSmall));
+/// assert_eq!(des.decrypt(&cipher[..50]), Err(CipherError::BadLength));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModeCipher<C: BlockCipher, P: Padding = PKCS7_Padding>
+{
+    chain: Chain<C>,
+    iv: Vec<u8>,
+    padding: P,
+}
+
+impl<C: BlockCipher> ModeCipher<C>
+{
+    /// Constructs a new object ModeCipher with PKCS #7 padding.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`. It is ignored in ECB mode.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C` in the
+    /// modes other than ECB.
+    pub fn new(cipher: C, mode: OperationMode, iv: &[u8]) -> Self
+    {
+        Self::new_with_padding(cipher, mode, iv, PKCS7_Padding)
+    }
+}
+
+impl<C: BlockCipher, P: Padding> ModeCipher<C, P>
+{
+    /// Constructs a new object ModeCipher with the padding scheme
+    /// `padding`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`. It is ignored in ECB mode.
+    /// - `padding` is the padding scheme for ECB, CBC and PCBC modes. It is
+    ///   ignored in the other modes.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C` in the
+    /// modes other than ECB.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, No_Padding, CipherError };
+    ///
+    /// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    /// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+    /// let mut des = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::CBC, &iv, No_Padding);
+    /// let cipher = des.encrypt("Hello, World!!!!".as_bytes());
+    /// assert_eq!(cipher.len(), 16);
+    /// let mut small = [0_u8; 16];
+    /// assert_eq!(des.encrypt_into("Hello, World!".as_bytes(), &mut small), Err(CipherError::BadLength));
+    /// ```
+    pub fn new_with_padding(cipher: C, mode: OperationMode, iv: &[u8], padding: P) -> Self
+    {
+        let chain = Chain::new(cipher, mode, iv);
+        let iv = if mode == OperationMode::ECB { vec![0_u8; C::BLOCK_SIZE] } else { iv.to_vec() };
+        Self { chain, iv, padding }
+    }
+
+    /// Sets the initialization vector for the following encryption and
+    /// decryption.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C` in the
+    /// modes other than ECB.
+    pub fn set_iv(&mut self, iv: &[u8])
+    {
+        self.chain.set_register(iv);
+        if self.chain.get_mode() != OperationMode::ECB
+            { self.iv.copy_from_slice(iv); }
+    }
+
+    /// Returns the mode of operation.
+    pub fn get_mode(&self) -> OperationMode
+    {
+        self.chain.get_mode()
+    }
+
+    /// Returns the length of the ciphertext of the message of
+    /// `message_length` bytes. If the message cannot be padded by `P`, it
+    /// returns `message_length`, and encryption will fail.
+    pub fn encrypted_length(&self, message_length: usize) -> usize
+    {
+        if self.chain.get_mode().is_padded()
+            { message_length + self.padding.padding_length(message_length, C::BLOCK_SIZE).unwrap_or(0) }
+        else
+            { message_length }
+    }
+
+    /// Encrypts `message`.
+    ///
+    /// # Output
+    /// It returns the ciphertext, whose length is `encrypted_length()`.
+    ///
+    /// # Panics
+    /// It panics if `message` is shorter than one block in the ciphertext
+    /// stealing modes, or if `message` cannot be padded by `P`. Use
+    /// `encrypt_into()` if you want the error instead.
+    pub fn encrypt(&mut self, message: &[u8]) -> Vec<u8>
+    {
+        let mut cipher = vec![0_u8; self.encrypted_length(message.len())];
+        if let Err(e) = self.encrypt_into(message, &mut cipher)
+            { panic!("{}", e); }
+        cipher
+    }
+
+    /// Encrypts `message` into `cipher`.
+    ///
+    /// # Arguments
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the output buffer. It should be at least
+    ///   `encrypted_length(message.len())` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the ciphertext written in `cipher`, or
+    /// `CipherError::BufferTooSmall` without writing anything if `cipher`
+    /// is too small. It returns `CipherError::BadLength` if `message` is
+    /// shorter than one block in the ciphertext stealing modes, or if
+    /// `message` cannot be padded by `P` in ECB, CBC and PCBC modes.
+    pub fn encrypt_into(&mut self, message: &[u8], cipher: &mut [u8]) -> Result<usize, CipherError>
+    {
+        let mode = self.chain.get_mode();
+        if mode.is_stealing() && message.len() < C::BLOCK_SIZE
+            { return Err(CipherError::BadLength); }
+        if mode.is_padded()
+            { self.padding.padding_length(message.len(), C::BLOCK_SIZE)?; }
+        let length = self.encrypted_length(message.len());
+        if cipher.len() < length
+            { return Err(CipherError::BufferTooSmall); }
+        cipher[..message.len()].copy_from_slice(message);
+        if mode.is_padded()
+            { self.padding.fill(&mut cipher[message.len()..length]); }
+        self.chain.set_register(&self.iv);
+        self.chain.encrypt_all(&mut cipher[..length]);
+        Ok(length)
+    }
+
+    /// Decrypts `cipher`.
+    ///
+    /// # Output
+    /// It returns the decrypted message, or `CipherError::BadLength` or
+    /// `CipherError::InvalidPadding`.
+    pub fn decrypt(&mut self, cipher: &[u8]) -> Result<Vec<u8>, CipherError>
+    {
+        let mut message = cipher.to_vec();
+        let length = self.decrypt_in_place(&mut message)?;
+        message.truncate(length);
+        Ok(message)
+    }
+
+    /// Decrypts `cipher` into `message`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the data to be decrypted.
+    /// - `message` is the output buffer. It should be at least as long as
+    ///   the decrypted message.
+    ///
+    /// # Output
+    /// It returns the length of the message written in `message`, or
+    /// `CipherError::BadLength`, `CipherError::InvalidPadding` or
+    /// `CipherError::BufferTooSmall`. Nothing is written in `message` on
+    /// error.
+    pub fn decrypt_into(&mut self, cipher: &[u8], message: &mut [u8]) -> Result<usize, CipherError>
+    {
+        let mut plain = cipher.to_vec();
+        let result = self.decrypt_in_place(&mut plain);
+        if let Ok(length) = result
+        {
+            if message.len() < length
+            {
+                plain.fill(0);
+                return Err(CipherError::BufferTooSmall);
+            }
+            message[..length].copy_from_slice(&plain[..length]);
+        }
+        plain.fill(0);
+        result
+    }
+
+    fn decrypt_in_place(&mut self, data: &mut [u8]) -> Result<usize, CipherError>
+    {
+        let padded = self.chain.get_mode().is_padded();
+        if padded && (data.len() % C::BLOCK_SIZE != 0)
+            { return Err(CipherError::BadLength); }
+        if self.chain.get_mode().is_stealing() && data.len() < C::BLOCK_SIZE
+            { return Err(CipherError::BadLength); }
+        self.chain.set_register(&self.iv);
+        self.chain.decrypt_all(data);
+        if padded
+            { self.padding.unpad(data, C::BLOCK_SIZE) }
+        else
+            { Ok(data.len()) }
+    }
+}
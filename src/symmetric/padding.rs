@@ -0,0 +1,372 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the trait Padding and the padding schemes for
+//! the padded modes of operation
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::CipherError;
+use crate::random::Random;
+
+
+/// The trait for the padding schemes of ECB, CBC and PCBC modes of
+/// `ModeCipher`, `Encryptor` and `Decryptor`
+///
+/// # Introduction
+/// The padding scheme is given to `ModeCipher`, `Encryptor` and
+/// `Decryptor` as the type parameter `P`, so that one set of methods works
+/// with every padding scheme. The default padding scheme is
+/// `PKCS7_Padding`. The padding is not used in the other modes of
+/// operation.
+///
+/// This crate provides the following padding schemes.
+/// - `PKCS7_Padding`: PKCS #7 padding
+/// - `ISO7816_Padding`: ISO/IEC 7816-4 padding
+/// - `ANSI_X923_Padding`: ANSI X9.23 padding
+/// - `ISO10126_Padding`: ISO 10126 padding with random fill
+/// - `Zero_Padding`: zero padding
+/// - `No_Padding`: no padding at all
+///
+/// You can implement your own padding scheme with this trait.
+///
+/// # Vulnerability
+/// An attacker who can tell whether the padding of a tampered ciphertext
+/// is valid can decrypt the whole ciphertext by the padding oracle attack.
+/// The padding schemes of this crate check the padding in constant time,
+/// and report every invalid padding as the same error
+/// `CipherError::InvalidPadding`. However, the success and the failure of
+/// decryption still cannot be hidden. Authenticate the ciphertext with
+/// MAC such as `CMAC_Generic` before decryption, or use authenticated
+/// encryption, if the attacker can send you modified ciphertexts.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ DES, ModeCipher, OperationMode, ANSI_X923_Padding };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut des = ModeCipher::new_with_padding(DES::new_with_key(key), OperationMode::CBC, &iv, ANSI_X923_Padding);
+/// let cipher = des.encrypt("Hello, World!".as_bytes());
+/// assert_eq!(cipher.len(), 16);
+/// assert_eq!(des.decrypt(&cipher).unwrap(), "Hello, World!".as_bytes());
+/// ```
+pub trait Padding
+{
+    /// Returns the number of the padding bytes to be appended to the
+    /// message of `length` bytes.
+    ///
+    /// # Output
+    /// It returns `CipherError::BadLength` if the message of `length` bytes
+    /// cannot be padded by this padding scheme.
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>;
+
+    /// Fills `padding`, which is the whole padding area right after the
+    /// message, with the padding bytes. `padding` is as long as the result
+    /// of `padding_length()`.
+    fn fill(&self, padding: &mut [u8]);
+
+    /// Checks the padding of the decrypted data and returns the length of
+    /// the message without the padding. It should take the same time for
+    /// every invalid padding, so that it does not become a padding oracle.
+    ///
+    /// # Output
+    /// It returns `CipherError::BadLength` if `data` is too short to have
+    /// the padding, or `CipherError::InvalidPadding` if the padding is not
+    /// correct.
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>;
+}
+
+
+/// PKCS #7 padding, which appends `n` bytes of the value `n`
+///
+/// It always appends one to `BLOCK_SIZE` bytes. It is compatible with
+/// the methods of `DES_Generic` such as `encrypt_with_padding_pkcs7_cbc()`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PKCS7_Padding;
+
+impl Padding for PKCS7_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok(block_size - length % block_size)
+    }
+
+    fn fill(&self, padding: &mut [u8])
+    {
+        let length = padding.len() as u8;
+        padding.fill(length);
+    }
+
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>
+    {
+        if data.is_empty()
+            { return Err(CipherError::BadLength); }
+        let (padding, valid) = check_pkcs7_padding(last_block(data, block_size));
+        unpadded_length(data, padding, valid)
+    }
+}
+
+
+/// ISO/IEC 7816-4 padding, which appends one byte `0x80` and then as few
+/// zero bytes as possible
+///
+/// It always appends one to `BLOCK_SIZE` bytes. It is the same as the
+/// padding method 2 of ISO/IEC 9797-1, and is compatible with the methods
+/// of `DES_Generic` such as `encrypt_with_padding_iso_cbc()`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ISO7816_Padding;
+
+impl Padding for ISO7816_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok(block_size - length % block_size)
+    }
+
+    fn fill(&self, padding: &mut [u8])
+    {
+        padding.fill(0);
+        if let Some(first) = padding.first_mut()
+            { *first = 0x80; }
+    }
+
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>
+    {
+        if data.is_empty()
+            { return Err(CipherError::BadLength); }
+        let (padding, valid) = check_iso7816_padding(last_block(data, block_size));
+        unpadded_length(data, padding, valid)
+    }
+}
+
+
+/// ANSI X9.23 padding, which appends `n - 1` zero bytes and then one byte
+/// of the value `n`
+///
+/// It always appends one to `BLOCK_SIZE` bytes. It is widely used by
+/// mainframe systems.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ANSI_X923_Padding;
+
+impl Padding for ANSI_X923_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok(block_size - length % block_size)
+    }
+
+    fn fill(&self, padding: &mut [u8])
+    {
+        let length = padding.len();
+        padding.fill(0);
+        padding[length - 1] = length as u8;
+    }
+
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>
+    {
+        if data.is_empty()
+            { return Err(CipherError::BadLength); }
+        let (padding, valid) = check_ansi_x923_padding(last_block(data, block_size));
+        unpadded_length(data, padding, valid)
+    }
+}
+
+
+/// ISO 10126 padding, which appends `n - 1` random bytes and then one byte
+/// of the value `n`
+///
+/// It always appends one to `BLOCK_SIZE` bytes. The random bytes are not
+/// checked on decryption. ISO 10126 was withdrawn in 2007, so use it only
+/// for the compatibility with the existing systems.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ISO10126_Padding;
+
+impl Padding for ISO10126_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok(block_size - length % block_size)
+    }
+
+    fn fill(&self, padding: &mut [u8])
+    {
+        let length = padding.len();
+        let mut random = Random::new();
+        for p in padding[..length - 1].iter_mut()
+            { *p = random.random_u8(); }
+        padding[length - 1] = length as u8;
+    }
+
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>
+    {
+        if data.is_empty()
+            { return Err(CipherError::BadLength); }
+        let block = last_block(data, block_size);
+        let padding = block[block.len() - 1] as usize;
+        let valid = ct_less(0, padding) & !ct_less(block.len(), padding);
+        unpadded_length(data, padding, valid)
+    }
+}
+
+
+/// Zero padding, which appends as few zero bytes as possible (possibly
+/// none) to make the message a multiple of the block size
+///
+/// # Vulnerability
+/// It is ambiguous. The trailing zero bytes of the message are removed
+/// together with the padding on decryption. Use it only for the messages
+/// that never end with zero bytes, such as texts, or whose length is known.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Zero_Padding;
+
+impl Padding for Zero_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok((block_size - length % block_size) % block_size)
+    }
+
+    fn fill(&self, padding: &mut [u8])
+    {
+        padding.fill(0);
+    }
+
+    fn unpad(&self, data: &[u8], block_size: usize) -> Result<usize, CipherError>
+    {
+        let bottom = data.len().saturating_sub(block_size - 1);
+        let mut length = data.len();
+        while length > bottom && data[length - 1] == 0
+            { length -= 1; }
+        Ok(length)
+    }
+}
+
+
+/// No padding, which accepts only the messages whose length is a multiple
+/// of the block size
+///
+/// It appends nothing. The message whose length is not a multiple of the
+/// block size is rejected with `CipherError::BadLength`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct No_Padding;
+
+impl Padding for No_Padding
+{
+    fn padding_length(&self, length: usize, block_size: usize) -> Result<usize, CipherError>
+    {
+        if length % block_size == 0
+            { Ok(0) }
+        else
+            { Err(CipherError::BadLength) }
+    }
+
+    fn fill(&self, _padding: &mut [u8])
+    {
+    }
+
+    fn unpad(&self, data: &[u8], _block_size: usize) -> Result<usize, CipherError>
+    {
+        Ok(data.len())
+    }
+}
+
+
+// Returns the last block of `data`, which may be shorter than
+// `block_size` if `data` is shorter than one block.
+fn last_block(data: &[u8], block_size: usize) -> &[u8]
+{
+    &data[data.len().saturating_sub(block_size)..]
+}
+
+// The single error path of the padding schemes that check the padding.
+// Every invalid padding gives the same error.
+fn unpadded_length(data: &[u8], padding: usize, valid: u8) -> Result<usize, CipherError>
+{
+    if valid == 0
+        { Err(CipherError::InvalidPadding) }
+    else
+        { Ok(data.len() - padding) }
+}
+
+// Returns 0xFF if `a` is equal to `b`, and 0 otherwise, without branching.
+#[inline]
+fn ct_equal(a: usize, b: usize) -> u8
+{
+    let x = (a ^ b) as u64;
+    (((x | x.wrapping_neg()) >> 63) as u8 ^ 1).wrapping_neg()
+}
+
+// Returns 0xFF if `a` is less than `b`, and 0 otherwise, without
+// branching. Both `a` and `b` should be less than 2^63.
+#[inline]
+fn ct_less(a: usize, b: usize) -> u8
+{
+    (((a as u64).wrapping_sub(b as u64) >> 63) as u8).wrapping_neg()
+}
+
+// Checks the PKCS #7 padding at the end of `block` in constant time. It
+// returns the length of the padding and 0xFF if the padding is valid, or
+// any length and 0 otherwise. Every byte of `block` is examined in the
+// same way whatever the padding is.
+pub(crate) fn check_pkcs7_padding(block: &[u8]) -> (usize, u8)
+{
+    let size = block.len();
+    let padding = block[size - 1] as usize;
+    let mut valid = ct_less(0, padding) & !ct_less(size, padding);
+    for (i, b) in block.iter().enumerate()
+    {
+        let in_padding = ct_less(size - 1 - i, padding);
+        valid &= !in_padding | ct_equal(*b as usize, padding);
+    }
+    (padding, valid)
+}
+
+// Checks the ANSI X9.23 padding at the end of `block` in constant time in
+// the same way as `check_pkcs7_padding()`.
+pub(crate) fn check_ansi_x923_padding(block: &[u8]) -> (usize, u8)
+{
+    let size = block.len();
+    let padding = block[size - 1] as usize;
+    let mut valid = ct_less(0, padding) & !ct_less(size, padding);
+    for (i, b) in block[..size - 1].iter().enumerate()
+    {
+        let in_padding = ct_less(size - 1 - i, padding);
+        valid &= !in_padding | ct_equal(*b as usize, 0);
+    }
+    (padding, valid)
+}
+
+// Checks the ISO/IEC 7816-4 padding at the end of `block` in constant time
+// in the same way as `check_pkcs7_padding()`. The padding is the last byte
+// 0x80 and the zero bytes after it.
+pub(crate) fn check_iso7816_padding(block: &[u8]) -> (usize, u8)
+{
+    let size = block.len();
+    let mut found = 0_u8;
+    let mut valid = 0xFF_u8;
+    let mut position = 0_usize;
+    for (i, b) in block.iter().enumerate().rev()
+    {
+        let zero = ct_equal(*b as usize, 0);
+        let marker = ct_equal(*b as usize, 0x80);
+        valid &= found | zero | marker;
+        let first = (marker & !found) as usize & 1;
+        position |= i & first.wrapping_neg();
+        found |= marker;
+    }
+    (size - position, valid & found)
+}
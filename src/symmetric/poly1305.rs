@@ -0,0 +1,220 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Poly1305 one-time message authentication code
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+/// Poly1305 one-time message authenticator that produces a 128-bit tag
+/// from a 256-bit one-time key and a message of arbitrary length.
+///
+/// # Introduction
+/// Poly1305 was designed by Daniel J. Bernstein. It evaluates the message
+/// as a polynomial over the prime field modulo 2^130 - 5 at the secret point
+/// `r`, and then adds the secret value `s`. This implementation follows
+/// RFC 8439, and keeps the accumulator in three limbs of 44, 44 and 42 bits
+/// so that it runs in constant time.
+///
+/// # Vulnerability
+/// The key of Poly1305 is a **one-time** key. Authenticating two different
+/// messages with the same key allows forgery. Usually the key is generated
+/// for each message by a stream cipher such as ChaCha20 or XSalsa20.
+///
+/// # Reference
+/// Read [RFC 8439](https://datatracker.ietf.org/doc/html/rfc8439#section-2.5)
+/// about Poly1305 in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::Poly1305;
+///
+/// let key = [ 0x85_u8, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33,
+///             0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5, 0x06, 0xa8,
+///             0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd,
+///             0x4a, 0xbf, 0xf6, 0xaf, 0x41, 0x49, 0xf5, 0x1b ];
+/// let mut mac = Poly1305::new_with_key(key);
+/// mac.update("Cryptographic Forum Research Group".as_bytes());
+/// let tag = mac.finalize();
+/// let mut txt = String::new();
+/// for t in tag
+///     { write!(txt, "{:02X}", t); }
+/// assert_eq!(txt, "A8061DC1305136C6C22B8BAF0C0127A9");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Poly1305
+{
+    r: [u64; 3],
+    h: [u64; 3],
+    pad: [u64; 2],
+    buffer: [u8; 16],
+    buffered: usize,
+}
+
+impl Poly1305
+{
+    const MASK44: u64 = 0xfff_ffff_ffff;
+    const MASK42: u64 = 0x3ff_ffff_ffff;
+
+    /// Constructs a new object Poly1305.
+    ///
+    /// # Arguments
+    /// The argument `key` is the one-time key of 32 bytes. The first 16 bytes
+    /// are `r` which will be clamped, and the last 16 bytes are `s`.
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        let t0 = Self::read_u64(&key[0..8]);
+        let t1 = Self::read_u64(&key[8..16]);
+        let r = [   t0 & 0xffc_0fff_ffff,
+                    ((t0 >> 44) | (t1 << 20)) & 0xfff_ffc0_ffff,
+                    (t1 >> 24) & 0x00f_ffff_fc0f ];
+        let pad = [ Self::read_u64(&key[16..24]), Self::read_u64(&key[24..32]) ];
+        Self { r, h: [0_u64; 3], pad, buffer: [0_u8; 16], buffered: 0 }
+    }
+
+    /// Feeds `message` to the authenticator.
+    ///
+    /// # Arguments
+    /// The argument `message` is the slice of data to be authenticated.
+    /// You can call this method several times. The result is the same as
+    /// if the concatenation of all the pieces were fed at once.
+    pub fn update(&mut self, message: &[u8])
+    {
+        let mut message = message;
+        if self.buffered > 0
+        {
+            let want = 16 - self.buffered;
+            let take = if message.len() < want { message.len() } else { want };
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&message[..take]);
+            self.buffered += take;
+            message = &message[take..];
+            if self.buffered < 16
+                { return; }
+            let block = self.buffer;
+            self.process_block(&block, 1 << 40);
+            self.buffered = 0;
+        }
+        while message.len() >= 16
+        {
+            let mut block = [0_u8; 16];
+            block.copy_from_slice(&message[..16]);
+            self.process_block(&block, 1 << 40);
+            message = &message[16..];
+        }
+        self.buffer[..message.len()].copy_from_slice(message);
+        self.buffered = message.len();
+    }
+
+    /// Feeds `message` to the authenticator after padding it with zeros to
+    /// a multiple of 16 bytes, as the AEAD constructions of RFC 8439 do.
+    pub fn update_padded(&mut self, message: &[u8])
+    {
+        self.update(message);
+        let rest = message.len() % 16;
+        if rest != 0
+            { self.update(&[0_u8; 16][..16 - rest]); }
+    }
+
+    /// Returns the 128-bit tag of all the data fed so far.
+    pub fn finalize(mut self) -> [u8; 16]
+    {
+        if self.buffered > 0
+        {
+            let mut block = [0_u8; 16];
+            block[..self.buffered].copy_from_slice(&self.buffer[..self.buffered]);
+            block[self.buffered] = 1;
+            self.process_block(&block, 0);
+        }
+
+        let [mut h0, mut h1, mut h2] = self.h;
+        let mut c: u64;
+        c = h1 >> 44;   h1 &= Self::MASK44;
+        h2 += c;    c = h2 >> 42;   h2 &= Self::MASK42;
+        h0 += c * 5;    c = h0 >> 44;   h0 &= Self::MASK44;
+        h1 += c;    c = h1 >> 44;   h1 &= Self::MASK44;
+        h2 += c;    c = h2 >> 42;   h2 &= Self::MASK42;
+        h0 += c * 5;    c = h0 >> 44;   h0 &= Self::MASK44;
+        h1 += c;
+
+        // g = h + 5 - 2^130, and select g if it does not underflow.
+        let mut g0 = h0 + 5;    c = g0 >> 44;   g0 &= Self::MASK44;
+        let mut g1 = h1 + c;    c = g1 >> 44;   g1 &= Self::MASK44;
+        let g2 = (h2 + c).wrapping_sub(1 << 42);
+        let mask = (g2 >> 63).wrapping_sub(1);
+        h0 = (h0 & !mask) | (g0 & mask);
+        h1 = (h1 & !mask) | (g1 & mask);
+        h2 = (h2 & !mask) | (g2 & mask);
+
+        // h = h + s
+        let t0 = self.pad[0];
+        let t1 = self.pad[1];
+        h0 += t0 & Self::MASK44;    c = h0 >> 44;   h0 &= Self::MASK44;
+        h1 += (((t0 >> 44) | (t1 << 20)) & Self::MASK44) + c;   c = h1 >> 44;   h1 &= Self::MASK44;
+        h2 += ((t1 >> 24) & Self::MASK42) + c;  h2 &= Self::MASK42;
+
+        let low = h0 | (h1 << 44);
+        let high = (h1 >> 20) | (h2 << 24);
+        let mut tag = [0_u8; 16];
+        tag[..8].copy_from_slice(&low.to_le_bytes());
+        tag[8..].copy_from_slice(&high.to_le_bytes());
+        tag
+    }
+
+    /// Computes the tag of `message` with the one-time `key` at once.
+    #[inline]
+    pub fn mac(key: [u8; 32], message: &[u8]) -> [u8; 16]
+    {
+        let mut poly = Self::new_with_key(key);
+        poly.update(message);
+        poly.finalize()
+    }
+
+    fn process_block(&mut self, block: &[u8; 16], hibit: u64)
+    {
+        let [r0, r1, r2] = self.r;
+        let s1 = r1 * (5 << 2);
+        let s2 = r2 * (5 << 2);
+        let t0 = Self::read_u64(&block[0..8]);
+        let t1 = Self::read_u64(&block[8..16]);
+        let mut h0 = self.h[0] + (t0 & Self::MASK44);
+        let mut h1 = self.h[1] + (((t0 >> 44) | (t1 << 20)) & Self::MASK44);
+        let mut h2 = self.h[2] + (((t1 >> 24) & Self::MASK42) | hibit);
+
+        let d0 = (h0 as u128) * (r0 as u128) + (h1 as u128) * (s2 as u128) + (h2 as u128) * (s1 as u128);
+        let mut d1 = (h0 as u128) * (r1 as u128) + (h1 as u128) * (r0 as u128) + (h2 as u128) * (s2 as u128);
+        let mut d2 = (h0 as u128) * (r2 as u128) + (h1 as u128) * (r1 as u128) + (h2 as u128) * (r0 as u128);
+
+        let mut c = (d0 >> 44) as u64;  h0 = (d0 as u64) & Self::MASK44;
+        d1 += c as u128;    c = (d1 >> 44) as u64;  h1 = (d1 as u64) & Self::MASK44;
+        d2 += c as u128;    c = (d2 >> 42) as u64;  h2 = (d2 as u64) & Self::MASK42;
+        h0 += c * 5;    c = h0 >> 44;   h0 &= Self::MASK44;
+        h1 += c;
+        self.h = [h0, h1, h2];
+    }
+
+    #[inline]
+    fn read_u64(bytes: &[u8]) -> u64
+    {
+        let mut buf = [0_u8; 8];
+        buf.copy_from_slice(bytes);
+        u64::from_le_bytes(buf)
+    }
+}
+
+/// Compares two tags in constant time.
+pub(crate) fn tags_equal(a: &[u8], b: &[u8]) -> bool
+{
+    if a.len() != b.len()
+        { return false; }
+    let mut diff = 0_u8;
+    for i in 0..a.len()
+        { diff |= a[i] ^ b[i]; }
+    diff == 0
+}
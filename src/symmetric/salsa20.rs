@@ -0,0 +1,751 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Salsa20 and XSalsa20 stream cipher algorithms
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::ptr::copy_nonoverlapping;
+use std::vec::Vec;
+
+use crate::number::SmallUInt;
+
+
+macro_rules! quarter_round {
+    ($s:expr, $a:expr, $b:expr, $c:expr, $d:expr) => {
+        $s[$b] ^= $s[$a].wrapping_add($s[$d]).rotate_left(7);
+        $s[$c] ^= $s[$b].wrapping_add($s[$a]).rotate_left(9);
+        $s[$d] ^= $s[$c].wrapping_add($s[$b]).rotate_left(13);
+        $s[$a] ^= $s[$d].wrapping_add($s[$c]).rotate_left(18);
+    };
+}
+
+/// The official Salsa20/20 stream cipher algorithm of eSTREAM
+#[allow(non_camel_case_types)]
+pub type Salsa20 = Salsa_Generic;       // equivalent to `pub type Salsa20 = Salsa_Generic<20>;`
+
+/// The reduced-round Salsa20/12 stream cipher algorithm of eSTREAM
+#[allow(non_camel_case_types)]
+pub type Salsa20_12 = Salsa_Generic<12>;
+
+/// The reduced-round Salsa20/8 stream cipher algorithm, whose core is the
+/// mixing function of scrypt
+#[allow(non_camel_case_types)]
+pub type Salsa20_8 = Salsa_Generic<8>;
+
+/// The official XSalsa20 stream cipher algorithm with 192-bit nonce of NaCl
+#[allow(non_camel_case_types)]
+pub type XSalsa20 = XSalsa_Generic;     // equivalent to `pub type XSalsa20 = XSalsa_Generic<20>;`
+
+/// Salsa stream cipher algorithm that encrypts/decrypts data by XORing it
+/// with the keystream generated from a 256-bit key, a 64-bit nonce and a
+/// 64-bit block counter, and its reduced-round variants.
+///
+/// # Introduction
+/// Salsa20 was designed by Daniel J. Bernstein in 2005, and was selected for
+/// the final portfolio of eSTREAM in 2008. Salsa20/12 is the eSTREAM profile
+/// 1 cipher, and Salsa20/8 is used as the core of the key derivation
+/// function scrypt. It works with only additions, rotations and XORs of
+/// 32-bit words (so-called ARX design), so that it does not need any lookup
+/// table and runs in constant time. It is the ancestor of ChaCha, which
+/// this crate also has as `ChaCha_Generic`.
+///
+/// The block counter is 64 bits long, so the keystream of a pair of key and
+/// nonce is practically endless, and you can seek any position of it by
+/// `set_counter()`.
+///
+/// # Vulnerability
+/// There is no known practical attack against the full 20-round Salsa20.
+/// The best known attack breaks Salsa20/7 and is far from Salsa20/8.
+/// However, like all the stream ciphers, the same pair of key and nonce
+/// must never be used twice. The 64-bit nonce is too short to be chosen
+/// randomly for many messages. Use `XSalsa20` for random nonces.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds. A double round consists of one column round
+///   and one row round, so ROUND should be an even number. The default
+///   value is `20`. Salsa20/12 and Salsa20/8 use 12 and 8 respectively.
+///
+/// # Reference
+/// Read [the specification](https://cr.yp.to/snuffle/spec.pdf)
+/// and [more](https://en.wikipedia.org/wiki/Salsa20) about Salsa20 in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::Salsa20;
+///
+/// let key = [ 0x80_u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ];
+/// let nonce = [0_u8; 8];
+/// let a_salsa = Salsa20::new_with_key(key);
+/// let block = a_salsa.keystream_block(nonce, 0);
+/// let mut txt = String::new();
+/// for c in block[..16].iter()
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "E3BE8FDD8BECA2E3EA8EF9475B29A6E7");
+///
+/// let message = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+/// let mut a_salsa = Salsa20::new_with_key(key);
+/// let mut cipher = Vec::<u8>::new();
+/// a_salsa.encrypt_str_into_vec(nonce, message, &mut cipher);
+/// let mut recovered = Vec::<u8>::new();
+/// a_salsa.decrypt_into_vec(nonce, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct Salsa_Generic<const ROUND: usize = 20>
+{
+    key: [u32; 8],
+    counter: u64,
+}
+
+impl<const ROUND: usize> Salsa_Generic<ROUND>
+{
+    const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+    /// Constructs a new object Salsa_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros and the block counter to be
+    /// zero.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object Salsa_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    ///
+    /// # Features
+    /// This method sets the key to be the given argument `key` and the block
+    /// counter to be zero.
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        let mut salsa = Self { key: [0_u32; 8], counter: 0 };
+        salsa.set_key(key);
+        salsa
+    }
+
+    /// Sets the key.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        for i in 0..8
+            { self.key[i] = u32::from_le_bytes([key[i * 4], key[i * 4 + 1], key[i * 4 + 2], key[i * 4 + 3]]); }
+    }
+
+    /// Sets the initial block counter.
+    ///
+    /// # Arguments
+    /// The argument `counter` is the block counter for the first 64-byte
+    /// block of the keystream.
+    ///
+    /// # Features
+    /// Since each block is 64 bytes long, setting the counter to be `n`
+    /// means seeking the keystream to the byte position `64 * n`.
+    #[inline]
+    pub fn set_counter(&mut self, counter: u64)
+    {
+        self.counter = counter;
+    }
+
+    /// Returns the initial block counter.
+    #[inline]
+    pub fn get_counter(&self) -> u64
+    {
+        self.counter
+    }
+
+    /// Generates the 64-byte keystream block for the given `nonce` and
+    /// `counter`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 64-bit nonce.
+    /// - `counter` is the 64-bit block counter.
+    ///
+    /// # Output
+    /// It returns the 64-byte keystream block.
+    pub fn keystream_block(&self, nonce: [u8; 8], counter: u64) -> [u8; 64]
+    {
+        let state = self.make_state(&Self::nonce_to_words(nonce), counter);
+        let mut out = [0_u8; 64];
+        for i in 0..16
+            { out[i * 4..i * 4 + 4].copy_from_slice(&state[i].to_le_bytes()); }
+        out
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 64-bit nonce. It should be unique for the same key.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the pointer to the buffer that will have the encrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the encrypted data in bytes, which is the
+    /// same as `length_in_bytes`.
+    #[inline]
+    pub fn encrypt(&mut self, nonce: [u8; 8], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        self.crypt(nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 64-bit nonce that was used for encryption.
+    /// - `cipher` is the pointer to the data to be decrypted.
+    /// - `length_in_bytes` is the length of `cipher` in bytes.
+    /// - `message` is the pointer to the buffer that will have the decrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the decrypted data in bytes, which is the
+    /// same as `length_in_bytes`.
+    #[inline]
+    pub fn decrypt(&mut self, nonce: [u8; 8], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.crypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the data `message` into `cipher` of `Vec<u8>`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 64-bit nonce. It should be unique for the same key.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 8], message: *const u8, length_in_bytes: u64, cipher: &mut Vec<u8>)
+    {
+        cipher.clear();
+        cipher.resize(length_in_bytes as usize, 0);
+        self.crypt(nonce, message, length_in_bytes, cipher.as_mut_ptr());
+    }
+
+    /// Decrypts the data `cipher` into `message` of `Vec<u8>`.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 64-bit nonce that was used for encryption.
+    /// - `cipher` is the pointer to the data to be decrypted.
+    /// - `length_in_bytes` is the length of `cipher` in bytes.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    #[inline]
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 8], cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, cipher, length_in_bytes, message);
+    }
+
+    /// Encrypts the string `message`. You can also give `&String`.
+    #[inline]
+    pub fn encrypt_str(&mut self, nonce: [u8; 8], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_str_into_vec(&mut self, nonce: [u8; 8], message: &str, cipher: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr(), message.len() as u64, cipher);
+    }
+
+    /// Encrypts the array `message`.
+    #[inline]
+    pub fn encrypt_array<T, const M: usize>(&mut self, nonce: [u8; 8], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the array `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_array_into_vec<T, const M: usize>(&mut self, nonce: [u8; 8], message: &[T; M], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object.
+    #[inline]
+    pub fn encrypt_vec<T>(&mut self, nonce: [u8; 8], message: &[T], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object
+    /// into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_vec_into_vec<T>(&mut self, nonce: [u8; 8], message: &[T], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream in
+    /// counter mode. It is the same as `encrypt()` and named after the
+    /// CTR-mode methods of the block ciphers such as `DES_Generic`.
+    #[inline]
+    pub fn encrypt_ctr(&mut self, nonce: [u8; 8], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` in counter mode. It is the same as
+    /// `decrypt()`.
+    #[inline]
+    pub fn decrypt_ctr(&mut self, nonce: [u8; 8], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.decrypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the string `message` in counter mode.
+    #[inline]
+    pub fn encrypt_str_ctr(&mut self, nonce: [u8; 8], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt_ctr(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` of `String` in counter mode.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn encrypt_string_ctr(&mut self, nonce: [u8; 8], message: &String, cipher: *mut u8) -> u64
+    {
+        self.encrypt_ctr(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the array `message` in counter mode.
+    #[inline]
+    pub fn encrypt_array_ctr<T, const M: usize>(&mut self, nonce: [u8; 8], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_ctr(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the `Vec` object `message` in counter mode.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn encrypt_vec_ctr<T>(&mut self, nonce: [u8; 8], message: &Vec<T>, cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_ctr(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// The Salsa core function, which maps a 64-byte block to a 64-byte
+    /// block by `ROUND` rounds and the feedforward.
+    ///
+    /// # Arguments
+    /// `input` is the 64-byte block, which is read as sixteen 32-bit words
+    /// in little endianness.
+    ///
+    /// # Output
+    /// It returns the 64-byte block in little endianness.
+    ///
+    /// # Features
+    /// It is the keystream function without the constants, the key, the
+    /// nonce and the counter, so that other constructions can use it as a
+    /// mixing function. For example, `Salsa20_8::core()` is the function
+    /// `Salsa20/8 Core` that `BlockMix` of scrypt (RFC 7914) uses.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ Salsa20, Salsa20_8 };
+    ///
+    /// // The all-zero block is the fixed point of the Salsa core function.
+    /// assert_eq!(Salsa20::core(&[0_u8; 64]), [0_u8; 64]);
+    ///
+    /// // The test vector of Section 8 of RFC 7914
+    /// let input = [ 0x7e_u8, 0x87, 0x9a, 0x21, 0x4f, 0x3e, 0xc9, 0x86, 0x7c, 0xa9, 0x40, 0xe6, 0x41, 0x71, 0x8f, 0x26,
+    ///               0xba, 0xee, 0x55, 0x5b, 0x8c, 0x61, 0xc1, 0xb5, 0x0d, 0xf8, 0x46, 0x11, 0x6d, 0xcd, 0x3b, 0x1d,
+    ///               0xee, 0x24, 0xf3, 0x19, 0xdf, 0x9b, 0x3d, 0x85, 0x14, 0x12, 0x1e, 0x4b, 0x5a, 0xc5, 0xaa, 0x32,
+    ///               0x76, 0x02, 0x1d, 0x29, 0x09, 0xc7, 0x48, 0x29, 0xed, 0xeb, 0xc6, 0x8d, 0xb8, 0xb8, 0xc2, 0x5e ];
+    /// let output = Salsa20_8::core(&input);
+    /// assert_eq!(output[..8], [0xa4_u8, 0x1f, 0x85, 0x9c, 0x66, 0x08, 0xcc, 0x99]);
+    /// assert_eq!(output[56..], [0x24_u8, 0xad, 0x67, 0x3d, 0xc7, 0x61, 0x8f, 0x81]);
+    /// ```
+    pub fn core(input: &[u8; 64]) -> [u8; 64]
+    {
+        let mut state = [0_u32; 16];
+        for i in 0..16
+            { state[i] = u32::from_le_bytes([input[i * 4], input[i * 4 + 1], input[i * 4 + 2], input[i * 4 + 3]]); }
+        let original = state;
+        Self::permutate(&mut state);
+        let mut out = [0_u8; 64];
+        for i in 0..16
+            { out[i * 4..i * 4 + 4].copy_from_slice(&state[i].wrapping_add(original[i]).to_le_bytes()); }
+        out
+    }
+
+    /// HSalsa function which derives a 256-bit subkey from a 256-bit key
+    /// and a 128-bit nonce. XSalsa uses it.
+    pub(crate) fn hsalsa(&self, nonce: [u8; 16]) -> [u8; 32]
+    {
+        let mut state = [0_u32; 16];
+        self.put_key(&mut state);
+        for i in 0..4
+            { state[6 + i] = u32::from_le_bytes([nonce[i * 4], nonce[i * 4 + 1], nonce[i * 4 + 2], nonce[i * 4 + 3]]); }
+        Self::permutate(&mut state);
+        let mut subkey = [0_u8; 32];
+        for (i, &j) in [0, 5, 10, 15, 6, 7, 8, 9].iter().enumerate()
+            { subkey[i * 4..i * 4 + 4].copy_from_slice(&state[j].to_le_bytes()); }
+        subkey
+    }
+
+    fn crypt(&mut self, nonce: [u8; 8], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        let nonce = Self::nonce_to_words(nonce);
+        let mut counter = self.counter;
+        let mut progress = 0_usize;
+        let length = length_in_bytes as usize;
+        let mut keystream = [0_u8; 64];
+        while progress < length
+        {
+            let state = self.make_state(&nonce, counter);
+            for i in 0..16
+                { keystream[i * 4..i * 4 + 4].copy_from_slice(&state[i].to_le_bytes()); }
+            let size = if length - progress < 64 { length - progress } else { 64 };
+            let mut block = [0_u8; 64];
+            unsafe { copy_nonoverlapping(message.add(progress), block.as_mut_ptr(), size); }
+            for i in 0..size
+                { block[i] ^= keystream[i]; }
+            unsafe { copy_nonoverlapping(block.as_ptr(), cipher.add(progress), size); }
+            progress += size;
+            counter = counter.wrapping_add(1);
+        }
+        length_in_bytes
+    }
+
+    // Puts the constants and the key into their places of the state.
+    fn put_key(&self, state: &mut [u32; 16])
+    {
+        for i in 0..4
+        {
+            state[i * 5] = Self::SIGMA[i];
+            state[1 + i] = self.key[i];
+            state[11 + i] = self.key[4 + i];
+        }
+    }
+
+    fn make_state(&self, nonce: &[u32; 2], counter: u64) -> [u32; 16]
+    {
+        let mut state = [0_u32; 16];
+        self.put_key(&mut state);
+        state[6..8].copy_from_slice(nonce);
+        state[8] = counter as u32;
+        state[9] = (counter >> 32) as u32;
+        let original = state;
+        Self::permutate(&mut state);
+        for i in 0..16
+            { state[i] = state[i].wrapping_add(original[i]); }
+        state
+    }
+
+    fn permutate(state: &mut [u32; 16])
+    {
+        for _ in 0..ROUND / 2
+        {
+            quarter_round!(state,  0,  4,  8, 12);
+            quarter_round!(state,  5,  9, 13,  1);
+            quarter_round!(state, 10, 14,  2,  6);
+            quarter_round!(state, 15,  3,  7, 11);
+            quarter_round!(state,  0,  1,  2,  3);
+            quarter_round!(state,  5,  6,  7,  4);
+            quarter_round!(state, 10, 11,  8,  9);
+            quarter_round!(state, 15, 12, 13, 14);
+        }
+    }
+
+    fn nonce_to_words(nonce: [u8; 8]) -> [u32; 2]
+    {
+        [u32::from_le_bytes([nonce[0], nonce[1], nonce[2], nonce[3]]),
+         u32::from_le_bytes([nonce[4], nonce[5], nonce[6], nonce[7]])]
+    }
+}
+
+impl<const ROUND: usize> Default for Salsa_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+
+/// XSalsa stream cipher algorithm that extends the nonce of Salsa to
+/// 192 bits so that random nonces can be used safely.
+///
+/// # Introduction
+/// XSalsa20 was designed by Daniel J. Bernstein in 2008 for NaCl. It derives
+/// a subkey from the key and the first 128 bits of the 192-bit nonce by
+/// HSalsa, and then encrypts data with Salsa using the subkey and the
+/// remaining 64 bits of the nonce. Its 192-bit nonce is long enough to be
+/// chosen randomly without worrying about collision.
+///
+/// `crypto_secretbox` of NaCl and libsodium is XSalsa20 with `Poly1305`: the
+/// first 32 bytes of the keystream is the one-time key of `Poly1305`, the
+/// message is encrypted with the keystream from the 33rd byte, and the tag
+/// of `Poly1305` over the ciphertext is put in front of it.
+///
+/// # Generic Parameters
+/// - ROUND: the number of rounds. The default value is `20`.
+///
+/// # Reference
+/// Read [Extending the Salsa20 nonce](https://cr.yp.to/snuffle/xsalsa-20081128.pdf)
+/// about XSalsa20 in detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ XSalsa20, Poly1305 };
+///
+/// // crypto_secretbox of NaCl
+/// let key = [0x80_u8; 32];
+/// let nonce = [0x40_u8; 24];
+/// let message = "The XSalsa20 nonce is long enough to be random.";
+/// let mut a_xsalsa = XSalsa20::new_with_key(key);
+/// let mut padded = vec![0_u8; 32];
+/// padded.extend_from_slice(message.as_bytes());
+/// let mut stream = Vec::<u8>::new();
+/// a_xsalsa.encrypt_vec_into_vec(nonce, &padded, &mut stream);
+/// let mut poly_key = [0_u8; 32];
+/// poly_key.copy_from_slice(&stream[..32]);
+/// let tag = Poly1305::mac(poly_key, &stream[32..]);
+/// let mut boxed = tag.to_vec();
+/// boxed.extend_from_slice(&stream[32..]);
+/// assert_eq!(boxed.len(), message.len() + 16);
+///
+/// let mut recovered = Vec::<u8>::new();
+/// a_xsalsa.decrypt_into_vec(nonce, stream.as_ptr(), stream.len() as u64, &mut recovered);
+/// assert_eq!(&recovered[32..], message.as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct XSalsa_Generic<const ROUND: usize = 20>
+{
+    salsa: Salsa_Generic<ROUND>,
+}
+
+impl<const ROUND: usize> XSalsa_Generic<ROUND>
+{
+    /// Constructs a new object XSalsa_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros and the block counter to be
+    /// zero.
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self::new_with_key([0_u8; 32])
+    }
+
+    /// Constructs a new object XSalsa_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the array of u8 that has 32 elements.
+    #[inline]
+    pub fn new_with_key(key: [u8; 32]) -> Self
+    {
+        Self { salsa: Salsa_Generic::<ROUND>::new_with_key(key) }
+    }
+
+    /// Sets the key.
+    #[inline]
+    pub fn set_key(&mut self, key: [u8; 32])
+    {
+        self.salsa.set_key(key);
+    }
+
+    /// Sets the initial block counter.
+    #[inline]
+    pub fn set_counter(&mut self, counter: u64)
+    {
+        self.salsa.set_counter(counter);
+    }
+
+    /// Returns the initial block counter.
+    #[inline]
+    pub fn get_counter(&self) -> u64
+    {
+        self.salsa.get_counter()
+    }
+
+    /// Generates the 64-byte keystream block for the given `nonce` and
+    /// `counter`.
+    pub fn keystream_block(&self, nonce: [u8; 24], counter: u64) -> [u8; 64]
+    {
+        let (inner, inner_nonce) = self.derive(nonce);
+        inner.keystream_block(inner_nonce, counter)
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream.
+    ///
+    /// # Arguments
+    /// - `nonce` is the 192-bit nonce.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the length of `message` in bytes.
+    /// - `cipher` is the pointer to the buffer that will have the encrypted
+    ///   data. It should be at least `length_in_bytes` bytes long.
+    ///
+    /// # Output
+    /// It returns the length of the encrypted data in bytes.
+    pub fn encrypt(&mut self, nonce: [u8; 24], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        let (mut inner, inner_nonce) = self.derive(nonce);
+        inner.encrypt(inner_nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` by XORing it with the keystream.
+    #[inline]
+    pub fn decrypt(&mut self, nonce: [u8; 24], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.encrypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the data `message` into `cipher` of `Vec<u8>`.
+    pub fn encrypt_into_vec(&mut self, nonce: [u8; 24], message: *const u8, length_in_bytes: u64, cipher: &mut Vec<u8>)
+    {
+        let (mut inner, inner_nonce) = self.derive(nonce);
+        inner.encrypt_into_vec(inner_nonce, message, length_in_bytes, cipher);
+    }
+
+    /// Decrypts the data `cipher` into `message` of `Vec<u8>`.
+    #[inline]
+    pub fn decrypt_into_vec(&mut self, nonce: [u8; 24], cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, cipher, length_in_bytes, message);
+    }
+
+    /// Encrypts the string `message`. You can also give `&String`.
+    #[inline]
+    pub fn encrypt_str(&mut self, nonce: [u8; 24], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_str_into_vec(&mut self, nonce: [u8; 24], message: &str, cipher: &mut Vec<u8>)
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr(), message.len() as u64, cipher);
+    }
+
+    /// Encrypts the array `message`.
+    #[inline]
+    pub fn encrypt_array<T, const M: usize>(&mut self, nonce: [u8; 24], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the array `message` into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_array_into_vec<T, const M: usize>(&mut self, nonce: [u8; 24], message: &[T; M], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object.
+    #[inline]
+    pub fn encrypt_vec<T>(&mut self, nonce: [u8; 24], message: &[T], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the slice `message` such as the one of `Vec` object
+    /// into `cipher` of `Vec<u8>`.
+    #[inline]
+    pub fn encrypt_vec_into_vec<T>(&mut self, nonce: [u8; 24], message: &[T], cipher: &mut Vec<u8>)
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_into_vec(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher);
+    }
+
+    /// Encrypts the data `message` by XORing it with the keystream in
+    /// counter mode. It is the same as `encrypt()` and named after the
+    /// CTR-mode methods of the block ciphers such as `DES_Generic`.
+    #[inline]
+    pub fn encrypt_ctr(&mut self, nonce: [u8; 24], message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        self.encrypt(nonce, message, length_in_bytes, cipher)
+    }
+
+    /// Decrypts the data `cipher` in counter mode. It is the same as
+    /// `decrypt()`.
+    #[inline]
+    pub fn decrypt_ctr(&mut self, nonce: [u8; 24], cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        self.decrypt(nonce, cipher, length_in_bytes, message)
+    }
+
+    /// Encrypts the string `message` in counter mode.
+    #[inline]
+    pub fn encrypt_str_ctr(&mut self, nonce: [u8; 24], message: &str, cipher: *mut u8) -> u64
+    {
+        self.encrypt_ctr(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the string `message` of `String` in counter mode.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn encrypt_string_ctr(&mut self, nonce: [u8; 24], message: &String, cipher: *mut u8) -> u64
+    {
+        self.encrypt_ctr(nonce, message.as_ptr(), message.len() as u64, cipher)
+    }
+
+    /// Encrypts the array `message` in counter mode.
+    #[inline]
+    pub fn encrypt_array_ctr<T, const M: usize>(&mut self, nonce: [u8; 24], message: &[T; M], cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_ctr(nonce, message.as_ptr() as *const u8, (M * T::size_in_bytes()) as u64, cipher)
+    }
+
+    /// Encrypts the `Vec` object `message` in counter mode.
+    #[inline]
+    #[allow(clippy::ptr_arg)]
+    pub fn encrypt_vec_ctr<T>(&mut self, nonce: [u8; 24], message: &Vec<T>, cipher: *mut u8) -> u64
+    where T: SmallUInt + Copy + Clone
+    {
+        self.encrypt_ctr(nonce, message.as_ptr() as *const u8, (message.len() * T::size_in_bytes()) as u64, cipher)
+    }
+
+    pub(crate) fn derive(&self, nonce: [u8; 24]) -> (Salsa_Generic<ROUND>, [u8; 8])
+    {
+        let mut head = [0_u8; 16];
+        head.copy_from_slice(&nonce[..16]);
+        let mut inner = Salsa_Generic::<ROUND>::new_with_key(self.salsa.hsalsa(head));
+        inner.set_counter(self.salsa.get_counter());
+        let mut inner_nonce = [0_u8; 8];
+        inner_nonce.copy_from_slice(&nonce[16..]);
+        (inner, inner_nonce)
+    }
+}
+
+impl<const ROUND: usize> Default for XSalsa_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
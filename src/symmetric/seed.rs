@@ -0,0 +1,326 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains SEED block cipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::BlockCipher;
+
+
+/// The official SEED symmetric-key algorithm with 128-bit key
+#[allow(non_camel_case_types)]
+pub type SEED = SEED_Generic<16>;
+
+/// A SEED symmetric-key algorithm for the encryption of digital data
+///
+/// # Introduction
+/// SEED was developed by KISA (Korea Internet & Security Agency) in 1998,
+/// and is standardized in TTAS.KO-12.0004 and RFC 4269. It is a Feistel
+/// network of 128-bit block and 128-bit key. Its round function `F` is
+/// made of the function `G`, which combines two 8-bit S-boxes with the
+/// masks, and of the additions modulo 2^32.
+///
+/// # Vulnerability
+/// No practical attack against the full 16 rounds of SEED is known. This
+/// implementation looks up the S-boxes in memory, so it is not resistant
+/// to cache-timing attacks when an attacker shares the processor with you.
+///
+/// # Generic Parameters
+/// - ROUND: The number of rounds. It is `16` for SEED. You can change it
+///   to make your own expanded version. The round keys are made by the same
+///   key schedule, whose constants `KC` are rotated left by one bit every
+///   round.
+///
+/// # Byte order
+/// The block and the key are the sequences of 16 bytes as RFC 4269
+/// describes. The methods `encrypt_u128()` and `decrypt_u128()` read and
+/// write the block as `u128` in little endianness, just as `AES_Generic`
+/// does.
+///
+/// # Reference
+/// Read [RFC 4269](https://www.rfc-editor.org/rfc/rfc4269) about SEED in
+/// detail.
+///
+/// # Quick Start
+/// ```
+/// use cryptocol::symmetric::{ SEED, ModeCipher, OperationMode };
+///
+/// let key = [ 0x47_u8, 0x06, 0x48, 0x08, 0x51, 0xE6, 0x1B, 0xE8,
+///             0x5D, 0x74, 0xBF, 0xB3, 0xFD, 0x95, 0x61, 0x85 ];
+/// let mut a_seed = SEED::new_with_key(&key);
+/// let message = u128::from_le_bytes([ 0x83_u8, 0xA2, 0xF8, 0xA2, 0x88, 0x64, 0x1F, 0xB9,
+///                                     0xA4, 0xE9, 0xA5, 0xCC, 0x2F, 0x13, 0x1C, 0x7D ]);
+/// let cipher = a_seed.encrypt_u128(message);
+/// assert_eq!(cipher.to_le_bytes(), [ 0xEE_u8, 0x54, 0xD1, 0x3E, 0xBC, 0xAE, 0x70, 0x6D,
+///                                    0x22, 0x6B, 0xC3, 0x14, 0x2C, 0xD4, 0x0D, 0x4A ]);
+/// assert_eq!(a_seed.decrypt_u128(cipher), message);
+///
+/// // All the modes of operation and the padding schemes by ModeCipher
+/// let iv = [0x0F_u8; 16];
+/// let mut cbc = ModeCipher::new(SEED::new_with_key(&key), OperationMode::CBC, &iv);
+/// let cipher = cbc.encrypt("In the beginning God created the heavens and the earth.".as_bytes());
+/// assert_eq!(cbc.decrypt(&cipher).unwrap(), "In the beginning God created the heavens and the earth.".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct SEED_Generic<const ROUND: usize = 16>
+{
+    round_key: Vec<[u32; 2]>,
+}
+
+impl<const ROUND: usize> SEED_Generic<ROUND>
+{
+    const S1: [u8; 256] = [
+        0xA9, 0x85, 0xD6, 0xD3, 0x54, 0x1D, 0xAC, 0x25, 0x5D, 0x43, 0x18, 0x1E, 0x51, 0xFC, 0xCA, 0x63,
+        0x28, 0x44, 0x20, 0x9D, 0xE0, 0xE2, 0xC8, 0x17, 0xA5, 0x8F, 0x03, 0x7B, 0xBB, 0x13, 0xD2, 0xEE,
+        0x70, 0x8C, 0x3F, 0xA8, 0x32, 0xDD, 0xF6, 0x74, 0xEC, 0x95, 0x0B, 0x57, 0x5C, 0x5B, 0xBD, 0x01,
+        0x24, 0x1C, 0x73, 0x98, 0x10, 0xCC, 0xF2, 0xD9, 0x2C, 0xE7, 0x72, 0x83, 0x9B, 0xD1, 0x86, 0xC9,
+        0x60, 0x50, 0xA3, 0xEB, 0x0D, 0xB6, 0x9E, 0x4F, 0xB7, 0x5A, 0xC6, 0x78, 0xA6, 0x12, 0xAF, 0xD5,
+        0x61, 0xC3, 0xB4, 0x41, 0x52, 0x7D, 0x8D, 0x08, 0x1F, 0x99, 0x00, 0x19, 0x04, 0x53, 0xF7, 0xE1,
+        0xFD, 0x76, 0x2F, 0x27, 0xB0, 0x8B, 0x0E, 0xAB, 0xA2, 0x6E, 0x93, 0x4D, 0x69, 0x7C, 0x09, 0x0A,
+        0xBF, 0xEF, 0xF3, 0xC5, 0x87, 0x14, 0xFE, 0x64, 0xDE, 0x2E, 0x4B, 0x1A, 0x06, 0x21, 0x6B, 0x66,
+        0x02, 0xF5, 0x92, 0x8A, 0x0C, 0xB3, 0x7E, 0xD0, 0x7A, 0x47, 0x96, 0xE5, 0x26, 0x80, 0xAD, 0xDF,
+        0xA1, 0x30, 0x37, 0xAE, 0x36, 0x15, 0x22, 0x38, 0xF4, 0xA7, 0x45, 0x4C, 0x81, 0xE9, 0x84, 0x97,
+        0x35, 0xCB, 0xCE, 0x3C, 0x71, 0x11, 0xC7, 0x89, 0x75, 0xFB, 0xDA, 0xF8, 0x94, 0x59, 0x82, 0xC4,
+        0xFF, 0x49, 0x39, 0x67, 0xC0, 0xCF, 0xD7, 0xB8, 0x0F, 0x8E, 0x42, 0x23, 0x91, 0x6C, 0xDB, 0xA4,
+        0x34, 0xF1, 0x48, 0xC2, 0x6F, 0x3D, 0x2D, 0x40, 0xBE, 0x3E, 0xBC, 0xC1, 0xAA, 0xBA, 0x4E, 0x55,
+        0x3B, 0xDC, 0x68, 0x7F, 0x9C, 0xD8, 0x4A, 0x56, 0x77, 0xA0, 0xED, 0x46, 0xB5, 0x2B, 0x65, 0xFA,
+        0xE3, 0xB9, 0xB1, 0x9F, 0x5E, 0xF9, 0xE6, 0xB2, 0x31, 0xEA, 0x6D, 0x5F, 0xE4, 0xF0, 0xCD, 0x88,
+        0x16, 0x3A, 0x58, 0xD4, 0x62, 0x29, 0x07, 0x33, 0xE8, 0x1B, 0x05, 0x79, 0x90, 0x6A, 0x2A, 0x9A
+    ];
+
+    const S2: [u8; 256] = [
+        0x38, 0xE8, 0x2D, 0xA6, 0xCF, 0xDE, 0xB3, 0xB8, 0xAF, 0x60, 0x55, 0xC7, 0x44, 0x6F, 0x6B, 0x5B,
+        0xC3, 0x62, 0x33, 0xB5, 0x29, 0xA0, 0xE2, 0xA7, 0xD3, 0x91, 0x11, 0x06, 0x1C, 0xBC, 0x36, 0x4B,
+        0xEF, 0x88, 0x6C, 0xA8, 0x17, 0xC4, 0x16, 0xF4, 0xC2, 0x45, 0xE1, 0xD6, 0x3F, 0x3D, 0x8E, 0x98,
+        0x28, 0x4E, 0xF6, 0x3E, 0xA5, 0xF9, 0x0D, 0xDF, 0xD8, 0x2B, 0x66, 0x7A, 0x27, 0x2F, 0xF1, 0x72,
+        0x42, 0xD4, 0x41, 0xC0, 0x73, 0x67, 0xAC, 0x8B, 0xF7, 0xAD, 0x80, 0x1F, 0xCA, 0x2C, 0xAA, 0x34,
+        0xD2, 0x0B, 0xEE, 0xE9, 0x5D, 0x94, 0x18, 0xF8, 0x57, 0xAE, 0x08, 0xC5, 0x13, 0xCD, 0x86, 0xB9,
+        0xFF, 0x7D, 0xC1, 0x31, 0xF5, 0x8A, 0x6A, 0xB1, 0xD1, 0x20, 0xD7, 0x02, 0x22, 0x04, 0x68, 0x71,
+        0x07, 0xDB, 0x9D, 0x99, 0x61, 0xBE, 0xE6, 0x59, 0xDD, 0x51, 0x90, 0xDC, 0x9A, 0xA3, 0xAB, 0xD0,
+        0x81, 0x0F, 0x47, 0x1A, 0xE3, 0xEC, 0x8D, 0xBF, 0x96, 0x7B, 0x5C, 0xA2, 0xA1, 0x63, 0x23, 0x4D,
+        0xC8, 0x9E, 0x9C, 0x3A, 0x0C, 0x2E, 0xBA, 0x6E, 0x9F, 0x5A, 0xF2, 0x92, 0xF3, 0x49, 0x78, 0xCC,
+        0x15, 0xFB, 0x70, 0x75, 0x7F, 0x35, 0x10, 0x03, 0x64, 0x6D, 0xC6, 0x74, 0xD5, 0xB4, 0xEA, 0x09,
+        0x76, 0x19, 0xFE, 0x40, 0x12, 0xE0, 0xBD, 0x05, 0xFA, 0x01, 0xF0, 0x2A, 0x5E, 0xA9, 0x56, 0x43,
+        0x85, 0x14, 0x89, 0x9B, 0xB0, 0xE5, 0x48, 0x79, 0x97, 0xFC, 0x1E, 0x82, 0x21, 0x8C, 0x1B, 0x5F,
+        0x77, 0x54, 0xB2, 0x1D, 0x25, 0x4F, 0x00, 0x46, 0xED, 0x58, 0x52, 0xEB, 0x7E, 0xDA, 0xC9, 0xFD,
+        0x30, 0x95, 0x65, 0x3C, 0xB6, 0xE4, 0xBB, 0x7C, 0x0E, 0x50, 0x39, 0x26, 0x32, 0x84, 0x69, 0x93,
+        0x37, 0xE7, 0x24, 0xA4, 0xCB, 0x53, 0x0A, 0x87, 0xD9, 0x4C, 0x83, 0x8F, 0xCE, 0x3B, 0x4A, 0xB7
+    ];
+
+    // SS[t][x] is the output of the S-box of the t-th byte masked by the
+    // four masks, so that G(X) = SS[0][X0] ^ SS[1][X1] ^ SS[2][X2] ^ SS[3][X3].
+    const SS: [[u32; 256]; 4] = Self::make_ss();
+
+    const fn make_ss() -> [[u32; 256]; 4]
+    {
+        const MASK: [u32; 4] = [0xFC, 0xF3, 0xCF, 0x3F];
+        let mut ss = [[0_u32; 256]; 4];
+        let mut t = 0;
+        while t < 4
+        {
+            let mut x = 0;
+            while x < 256
+            {
+                let s = if t % 2 == 0 { Self::S1[x] } else { Self::S2[x] } as u32;
+                let mut i = 0;
+                while i < 4
+                {
+                    ss[t][x] |= (s & MASK[(i + t) % 4]) << (8 * i);
+                    i += 1;
+                }
+                x += 1;
+            }
+            t += 1;
+        }
+        ss
+    }
+
+    /// Constructs a new object SEED_Generic.
+    ///
+    /// # Features
+    /// This method sets the key to be all zeros.
+    pub fn new() -> Self
+    {
+        Self::new_with_key(&[0_u8; 16])
+    }
+
+    /// Constructs a new object SEED_Generic.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has 16 elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not 16.
+    pub fn new_with_key(key: &[u8]) -> Self
+    {
+        let mut seed = Self { round_key: Vec::new() };
+        seed.set_key(key);
+        seed
+    }
+
+    /// Sets the key and makes the round keys.
+    ///
+    /// # Arguments
+    /// The argument `key` is the slice of u8 that has 16 elements.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not 16.
+    pub fn set_key(&mut self, key: &[u8])
+    {
+        assert_eq!(key.len(), 16, "The key of SEED_Generic<{}> should be 16 bytes long.", ROUND);
+        let mut ab = u64::from_be_bytes([key[0], key[1], key[2], key[3], key[4], key[5], key[6], key[7]]);
+        let mut cd = u64::from_be_bytes([key[8], key[9], key[10], key[11], key[12], key[13], key[14], key[15]]);
+        let mut kc = 0x9E3779B9_u32;
+        self.round_key = Vec::with_capacity(ROUND);
+        for round in 0..ROUND
+        {
+            let (a, b) = ((ab >> 32) as u32, ab as u32);
+            let (c, d) = ((cd >> 32) as u32, cd as u32);
+            let k0 = Self::g(a.wrapping_add(c).wrapping_sub(kc));
+            let k1 = Self::g(b.wrapping_sub(d).wrapping_add(kc));
+            self.round_key.push([k0, k1]);
+            if round % 2 == 0
+                { ab = ab.rotate_right(8); }
+            else
+                { cd = cd.rotate_left(8); }
+            kc = kc.rotate_left(1);
+        }
+    }
+
+    /// Encrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `message` is the block to be encrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the encrypted block in little endianness.
+    pub fn encrypt_u128(&mut self, message: u128) -> u128
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Decrypts a 128-bit data block.
+    ///
+    /// # Arguments
+    /// `cipher` is the block to be decrypted in little endianness.
+    ///
+    /// # Output
+    /// It returns the decrypted block in little endianness.
+    pub fn decrypt_u128(&mut self, cipher: u128) -> u128
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_block(&mut block);
+        u128::from_le_bytes(block)
+    }
+
+    /// Encrypts the array of 128-bit data blocks independently of one another.
+    pub fn encrypt_array_u128<const N: usize>(&mut self, message: &[u128; N], cipher: &mut [u128; N])
+    {
+        for (c, m) in cipher.iter_mut().zip(message.iter())
+            { *c = self.encrypt_u128(*m); }
+    }
+
+    /// Decrypts the array of 128-bit data blocks independently of one another.
+    pub fn decrypt_array_u128<const N: usize>(&mut self, cipher: &[u128; N], message: &mut [u128; N])
+    {
+        for (m, c) in message.iter_mut().zip(cipher.iter())
+            { *m = self.decrypt_u128(*c); }
+    }
+
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16])
+    {
+        let (mut left, mut right) = Self::split(block);
+        for round_key in self.round_key.iter()
+        {
+            left ^= Self::f(right, round_key);
+            (left, right) = (right, left);
+        }
+        Self::combine(block, right, left);
+    }
+
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16])
+    {
+        let (mut left, mut right) = Self::split(block);
+        for round_key in self.round_key.iter().rev()
+        {
+            left ^= Self::f(right, round_key);
+            (left, right) = (right, left);
+        }
+        Self::combine(block, right, left);
+    }
+
+    #[inline]
+    fn split(block: &[u8; 16]) -> (u64, u64)
+    {
+        let left = u64::from_be_bytes([block[0], block[1], block[2], block[3], block[4], block[5], block[6], block[7]]);
+        let right = u64::from_be_bytes([block[8], block[9], block[10], block[11], block[12], block[13], block[14], block[15]]);
+        (left, right)
+    }
+
+    #[inline]
+    fn combine(block: &mut [u8; 16], left: u64, right: u64)
+    {
+        block[..8].copy_from_slice(&left.to_be_bytes());
+        block[8..].copy_from_slice(&right.to_be_bytes());
+    }
+
+    // The round function F of 64 bits
+    fn f(right: u64, round_key: &[u32; 2]) -> u64
+    {
+        let mut t0 = ((right >> 32) as u32) ^ round_key[0];
+        let mut t1 = (right as u32) ^ round_key[1];
+        t1 = Self::g(t1 ^ t0);
+        t0 = Self::g(t0.wrapping_add(t1));
+        t1 = Self::g(t1.wrapping_add(t0));
+        t0 = t0.wrapping_add(t1);
+        ((t0 as u64) << 32) | (t1 as u64)
+    }
+
+    #[inline]
+    fn g(x: u32) -> u32
+    {
+        Self::SS[0][(x & 0xFF) as usize] ^ Self::SS[1][((x >> 8) & 0xFF) as usize]
+            ^ Self::SS[2][((x >> 16) & 0xFF) as usize] ^ Self::SS[3][(x >> 24) as usize]
+    }
+}
+
+impl<const ROUND: usize> Default for SEED_Generic<ROUND>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const ROUND: usize> BlockCipher for SEED_Generic<ROUND>
+{
+    const BLOCK_SIZE: usize = 16;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.encrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.decrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+}
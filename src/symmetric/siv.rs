@@ -0,0 +1,251 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains SIV (Synthetic Initialization Vector)
+//! authenticated encryption with associated data (AEAD)
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256 };
+use crate::symmetric::cmac::{ CMAC_Generic, double };
+use crate::symmetric::poly1305::tags_equal;
+
+
+/// AES-SIV with two AES-128 keys, which is called AES-SIV-CMAC-256 in
+/// RFC 5297
+#[allow(non_camel_case_types)]
+pub type AES_128_SIV = SIV_Generic<AES_128>;
+
+/// AES-SIV with two AES-256 keys, which is called AES-SIV-CMAC-512 in
+/// RFC 5297
+#[allow(non_camel_case_types)]
+pub type AES_256_SIV = SIV_Generic<AES_256>;
+
+/// SIV misuse-resistant authenticated encryption with associated data on top
+/// of any 128-bit block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// SIV computes the synthetic initialization vector `V` from the
+/// associated data and the message by S2V which is based on CMAC, and then
+/// encrypts the message in counter mode whose initial counter is `V`.
+/// `V` also works as the authentication tag. Since the initial counter
+/// depends on the message itself, reusing a nonce reveals only whether the
+/// same message was encrypted with the same associated data, and nothing
+/// else.
+///
+/// SIV takes a vector of associated data. If you use a nonce, it should be
+/// the last element of the vector. Without a nonce, SIV is deterministic
+/// authenticated encryption, which is suitable for wrapping keys. The
+/// methods `wrap_key_into_vec()` and `unwrap_key_into_vec()` are for that
+/// usage.
+///
+/// # Vulnerability
+/// - Without a nonce, encrypting the same message with the same associated
+///   data always gives the same ciphertext.
+/// - The key of CMAC and the key of counter mode must be independent of
+///   each other.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+///
+/// # Reference
+/// Read [RFC 5297](https://datatracker.ietf.org/doc/html/rfc5297)
+/// about SIV in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, AES_128_SIV };
+///
+/// let mut key1 = [0_u8; 16];
+/// let mut key2 = [0_u8; 16];
+/// for i in 0..16
+/// {
+///     key1[i] = 0x7F - i as u8;
+///     key2[i] = 0x40 + i as u8;
+/// }
+/// let mut siv = AES_128_SIV::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+/// let nonce = "a nonce that might be repeated".as_bytes();
+/// let message = "The quick brown fox jumps over the lazy dog.";
+/// let mut cipher = Vec::<u8>::new();
+/// let v = siv.encrypt_into_vec(&["header".as_bytes(), nonce], message.as_bytes(), &mut cipher);
+///
+/// let mut recovered = Vec::<u8>::new();
+/// assert!(siv.decrypt_into_vec(&["header".as_bytes(), nonce], &cipher, v, &mut recovered));
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+///
+/// // The same nonce with a different message gives unrelated ciphertext.
+/// let mut cipher2 = Vec::<u8>::new();
+/// siv.encrypt_into_vec(&["header".as_bytes(), nonce], "The quick brown fox jumps over the lazy cat.".as_bytes(), &mut cipher2);
+/// assert_ne!(cipher[..16], cipher2[..16]);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct SIV_Generic<C: BlockCipher>
+{
+    mac: CMAC_Generic<C>,
+    ctr_cipher: C,
+}
+
+impl<C: BlockCipher> SIV_Generic<C>
+{
+    /// Constructs a new object SIV_Generic.
+    ///
+    /// # Arguments
+    /// - `mac_cipher` is the block cipher with the key for S2V, which is
+    ///   the first half of the key of RFC 5297.
+    /// - `ctr_cipher` is the block cipher with the key for counter mode,
+    ///   which is the second half of the key of RFC 5297.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16.
+    pub fn new(mac_cipher: C, ctr_cipher: C) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 16, "SIV_Generic supports only 128-bit block ciphers.");
+        Self { mac: CMAC_Generic::new(mac_cipher), ctr_cipher }
+    }
+
+    /// Encrypts `message` and authenticates it together with `aad`.
+    ///
+    /// # Arguments
+    /// - `aad` is the vector of associated data. When you use a nonce,
+    ///   put it at the end of `aad`. It can have at most 126 elements.
+    /// - `message` is the data to be encrypted.
+    /// - `cipher` is the `Vec<u8>` that will have the encrypted data.
+    ///
+    /// # Output
+    /// It returns the 16-byte synthetic initialization vector `V`, which is
+    /// also the authentication tag.
+    ///
+    /// # Panics
+    /// It panics if `aad` has more than 126 elements.
+    pub fn encrypt_into_vec(&mut self, aad: &[&[u8]], message: &[u8], cipher: &mut Vec<u8>) -> [u8; 16]
+    {
+        assert!(aad.len() <= 126, "SIV_Generic takes at most 126 associated data.");
+        let v = self.s2v(aad, message);
+        cipher.clear();
+        cipher.extend_from_slice(message);
+        self.crypt_ctr(&v, cipher);
+        v
+    }
+
+    /// Verifies `v` and decrypts `cipher` only if it is correct.
+    ///
+    /// # Arguments
+    /// - `aad` is the vector of associated data that was used for
+    ///   encryption.
+    /// - `cipher` is the data to be decrypted.
+    /// - `v` is the 16-byte synthetic initialization vector.
+    /// - `message` is the `Vec<u8>` that will have the decrypted data.
+    ///
+    /// # Output
+    /// It returns `true` if `v` is correct. Otherwise, it returns `false`
+    /// and `message` will be empty.
+    pub fn decrypt_into_vec(&mut self, aad: &[&[u8]], cipher: &[u8], v: [u8; 16], message: &mut Vec<u8>) -> bool
+    {
+        message.clear();
+        if aad.len() > 126
+            { return false; }
+        let mut plain = cipher.to_vec();
+        self.crypt_ctr(&v, &mut plain);
+        if !tags_equal(&self.s2v(aad, &plain), &v)
+            { return false; }
+        message.extend_from_slice(&plain);
+        true
+    }
+
+    /// Wraps `key` deterministically, as Section 1.3.1 of RFC 5297 describes.
+    ///
+    /// # Arguments
+    /// - `aad` is the vector of associated data such as the name or the
+    ///   usage of the key.
+    /// - `key` is the key material to be wrapped.
+    /// - `wrapped` is the `Vec<u8>` that will have `V` followed by the
+    ///   encrypted key, which is 16 bytes longer than `key`.
+    pub fn wrap_key_into_vec(&mut self, aad: &[&[u8]], key: &[u8], wrapped: &mut Vec<u8>)
+    {
+        let mut cipher = Vec::<u8>::new();
+        let v = self.encrypt_into_vec(aad, key, &mut cipher);
+        wrapped.clear();
+        wrapped.extend_from_slice(&v);
+        wrapped.extend_from_slice(&cipher);
+    }
+
+    /// Unwraps the key which `wrap_key_into_vec()` wrapped.
+    ///
+    /// # Output
+    /// It returns `true` if `wrapped` is authentic. Otherwise, it returns
+    /// `false` and `key` will be empty.
+    pub fn unwrap_key_into_vec(&mut self, aad: &[&[u8]], wrapped: &[u8], key: &mut Vec<u8>) -> bool
+    {
+        key.clear();
+        if wrapped.len() < 16
+            { return false; }
+        let mut v = [0_u8; 16];
+        v.copy_from_slice(&wrapped[..16]);
+        self.decrypt_into_vec(aad, &wrapped[16..], v, key)
+    }
+
+    fn s2v(&mut self, aad: &[&[u8]], message: &[u8]) -> [u8; 16]
+    {
+        let mut d = self.mac.mac(&[0_u8; 16]);
+        for a in aad
+        {
+            let m = self.mac.mac(a);
+            d = double(&d);
+            for (x, y) in d.iter_mut().zip(m.iter())
+                { *x ^= *y; }
+        }
+        let v = if message.len() >= 16
+        {
+            // T = message xorend D
+            let split = message.len() - 16;
+            let mut last = message[split..].to_vec();
+            for (x, y) in last.iter_mut().zip(d.iter())
+                { *x ^= *y; }
+            self.mac.update(&message[..split]);
+            self.mac.update(&last);
+            self.mac.finalize()
+        }
+        else
+        {
+            // T = dbl(D) xor pad(message)
+            let mut t = double(&d);
+            for (x, y) in t.iter_mut().zip(message.iter())
+                { *x ^= *y; }
+            t[message.len()] ^= 0x80;
+            self.mac.mac(&t)
+        };
+        let mut out = [0_u8; 16];
+        out.copy_from_slice(&v);
+        out
+    }
+
+    // Encrypts data in counter mode whose initial counter is V with the 31st
+    // and the 63rd bits from the right cleared.
+    fn crypt_ctr(&mut self, v: &[u8; 16], data: &mut [u8])
+    {
+        let mut q = *v;
+        q[8] &= 0x7F;
+        q[12] &= 0x7F;
+        let mut counter = u128::from_be_bytes(q);
+        for chunk in data.chunks_mut(16)
+        {
+            let mut keystream = counter.to_be_bytes();
+            self.ctr_cipher.encrypt_one_block(&mut keystream);
+            for (d, k) in chunk.iter_mut().zip(keystream.iter())
+                { *d ^= *k; }
+            counter = counter.wrapping_add(1);
+        }
+    }
+}
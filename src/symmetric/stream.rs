@@ -0,0 +1,415 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains the streaming encryptor and decryptor which
+//! implement `std::io::Write` and `std::io::Read`
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::vec::Vec;
+use std::io::{ self, Read, Write };
+
+use crate::symmetric::{ BlockCipher, CipherError, OperationMode, Padding, PKCS7_Padding };
+use crate::symmetric::operation_mode::Chain;
+
+
+/// The streaming encryptor that encrypts everything written to it and
+/// writes the ciphertext to the underlying writer
+///
+/// # Introduction
+/// The methods such as `DES_Generic::encrypt_with_padding_pkcs7_cbc()`
+/// need the whole message in one buffer. `Encryptor` implements the trait
+/// `std::io::Write` instead, so that you can encrypt a file of any size
+/// with constant memory, for example by `std::io::copy()`. It keeps the
+/// chaining state of the mode of operation across the calls of `write()`
+/// and buffers at most one block that is not complete yet, or at most
+/// two blocks in the ciphertext stealing modes.
+///
+/// The ciphertext that the underlying writer has not taken yet, because it
+/// failed or took only a part of it, is kept in `Encryptor` and sent first
+/// at the next call of `write()`, `flush()` or `finish()`. If it cannot be
+/// sent, `write()` returns the error without taking any of the new data,
+/// so that the same data can be written again without writing any
+/// ciphertext twice.
+///
+/// You must call `finish()` at the end. It pads the last block in ECB, CBC
+/// and PCBC modes, steals ciphertext for the last two blocks in the
+/// ciphertext stealing modes, or encrypts the last partial block in the
+/// other modes, and then returns the underlying writer. The data buffered
+/// in `Encryptor` is lost if it is dropped without `finish()`.
+///
+/// `new()` uses PKCS #7 padding, and `new_with_padding()` takes any
+/// padding scheme which has the trait `Padding`.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+/// - W: the underlying writer which has the trait `std::io::Write`.
+/// - P: the padding scheme which has the trait `Padding`. It is
+///   `PKCS7_Padding` by default.
+///
+/// # Quick Start
+/// ```
+/// use std::io::{ Read, Write };
+/// use cryptocol::symmetric::{ DES, Encryptor, Decryptor, OperationMode };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut encryptor = Encryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv, Vec::<u8>::new());
+/// encryptor.write_all("The quick brown fox ".as_bytes()).unwrap();
+/// encryptor.write_all("jumps over the lazy dog.".as_bytes()).unwrap();
+/// let cipher = encryptor.finish().unwrap();
+/// assert_eq!(cipher.len(), 48);
+///
+/// let mut decryptor = Decryptor::new(DES::new_with_key(key), OperationMode::CBC, &iv, cipher.as_slice());
+/// let mut recovered = String::new();
+/// decryptor.read_to_string(&mut recovered).unwrap();
+/// assert_eq!(recovered, "The quick brown fox jumps over the lazy dog.");
+/// ```
+#[derive(Debug)]
+pub struct Encryptor<C: BlockCipher, W: Write, P: Padding = PKCS7_Padding>
+{
+    chain: Chain<C>,
+    writer: W,
+    buffer: Vec<u8>,
+    output: Vec<u8>,
+    sent: usize,
+    padding: P,
+}
+
+impl<C: BlockCipher, W: Write> Encryptor<C, W>
+{
+    /// Constructs a new object Encryptor with PKCS #7 padding.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`.
+    /// - `writer` is the underlying writer to which the ciphertext is
+    ///   written.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C`.
+    pub fn new(cipher: C, mode: OperationMode, iv: &[u8], writer: W) -> Self
+    {
+        Self::new_with_padding(cipher, mode, iv, writer, PKCS7_Padding)
+    }
+}
+
+impl<C: BlockCipher, W: Write, P: Padding> Encryptor<C, W, P>
+{
+    /// Constructs a new object Encryptor with the padding scheme `padding`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`.
+    /// - `writer` is the underlying writer to which the ciphertext is
+    ///   written.
+    /// - `padding` is the padding scheme for ECB, CBC and PCBC modes. It is
+    ///   ignored in the other modes.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C`.
+    pub fn new_with_padding(cipher: C, mode: OperationMode, iv: &[u8], writer: W, padding: P) -> Self
+    {
+        Self { chain: Chain::new(cipher, mode, iv), writer, buffer: Vec::with_capacity(C::BLOCK_SIZE), output: Vec::new(), sent: 0, padding }
+    }
+
+    /// Finishes encryption and returns the underlying writer.
+    ///
+    /// # Output
+    /// It returns the underlying writer after writing the last block and
+    /// flushing it, or the error of the underlying writer. In the
+    /// ciphertext stealing modes, it returns the error of the kind
+    /// `std::io::ErrorKind::InvalidInput` whose inner error is
+    /// `CipherError::BadLength` if the whole message is shorter than one
+    /// block. In ECB, CBC and PCBC modes, it returns the same error if the
+    /// message cannot be padded by `P`.
+    ///
+    /// # Features
+    /// In ECB, CBC and PCBC modes, the last block is padded by `P`. With
+    /// PKCS #7 padding, one whole block of padding is written if the length
+    /// of the message is a multiple of the block size.
+    pub fn finish(mut self) -> io::Result<W>
+    {
+        self.send()?;
+        let mut last = std::mem::take(&mut self.buffer);
+        if self.chain.get_mode().is_stealing() && last.len() < C::BLOCK_SIZE
+            { return Err(io::Error::new(io::ErrorKind::InvalidInput, CipherError::BadLength)); }
+        if self.chain.get_mode().is_padded()
+        {
+            let length = last.len();
+            let padding = self.padding.padding_length(length, C::BLOCK_SIZE)
+                                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            last.resize(length + padding, 0);
+            self.padding.fill(&mut last[length..]);
+        }
+        self.chain.encrypt_all(&mut last);
+        self.writer.write_all(&last)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Returns the reference of the underlying writer.
+    pub fn get_ref(&self) -> &W
+    {
+        &self.writer
+    }
+
+    // Sends the ciphertext which the underlying writer has not taken yet.
+    fn send(&mut self) -> io::Result<()>
+    {
+        while self.sent < self.output.len()
+        {
+            match self.writer.write(&self.output[self.sent..])
+            {
+                Ok(0) => { return Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write the whole ciphertext")); },
+                Ok(written) => { self.sent += written; },
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => { return Err(e); },
+            }
+        }
+        self.output.clear();
+        self.sent = 0;
+        Ok(())
+    }
+}
+
+impl<C: BlockCipher, W: Write, P: Padding> Write for Encryptor<C, W, P>
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+    {
+        // The ciphertext of the previous calls goes first. If it cannot be
+        // sent, none of `buf` is taken.
+        self.send()?;
+        let size = C::BLOCK_SIZE;
+        self.buffer.extend_from_slice(buf);
+        let length = self.buffer.len();
+        // In the ciphertext stealing modes, the last two blocks are kept
+        // until `finish()` because the last block may be partial.
+        let whole = if self.chain.get_mode().is_stealing()
+            { length.saturating_sub(size + 1) / size * size }
+        else
+            { length - length % size };
+        self.chain.encrypt_blocks(&mut self.buffer[..whole]);
+        self.output.extend(self.buffer.drain(..whole));
+        // `buf` has been taken already, so that the error of sending is
+        // returned by the next call of `write()`, `flush()` or `finish()`.
+        let _ = self.send();
+        Ok(buf.len())
+    }
+
+    /// Sends the ciphertext which the underlying writer has not taken yet,
+    /// and flushes the underlying writer. The partial block which is not
+    /// complete yet, or the last two blocks in the ciphertext stealing
+    /// modes, stay in `Encryptor` until more data or `finish()`.
+    fn flush(&mut self) -> io::Result<()>
+    {
+        self.send()?;
+        self.writer.flush()
+    }
+}
+
+
+/// The streaming decryptor that reads the ciphertext from the underlying
+/// reader and gives the decrypted data
+///
+/// # Introduction
+/// `Decryptor` implements the trait `std::io::Read` so that you can
+/// decrypt a file of any size with constant memory. It reads the
+/// ciphertext from the underlying reader chunk by chunk and keeps the
+/// chaining state of the mode of operation across the calls of `read()`.
+/// In ECB, CBC and PCBC modes, it holds back the last block until the
+/// underlying reader reaches its end, and then removes the padding of the
+/// padding scheme `P`, which is PKCS #7 padding by default. In the
+/// ciphertext stealing modes, it holds back the last two blocks in the
+/// same way.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+/// - R: the underlying reader which has the trait `std::io::Read`.
+/// - P: the padding scheme which has the trait `Padding`. It is
+///   `PKCS7_Padding` by default.
+///
+/// # Errors
+/// In ECB, CBC and PCBC modes, `read()` returns the error of the kind
+/// `std::io::ErrorKind::InvalidData` if the ciphertext is not a multiple
+/// of the block size, is too short to have the padding, or its padding is
+/// not correct. The inner
+/// error is `CipherError::BadLength` or `CipherError::InvalidPadding`.
+/// In the ciphertext stealing modes, it returns the same error with
+/// `CipherError::BadLength` if the ciphertext is shorter than one block.
+/// Once `read()` fails in any of these ways, the data held back is wiped
+/// and every later call of `read()` returns the same error again.
+///
+/// # Quick Start
+/// Read [here](struct@Encryptor#quick-start).
+#[derive(Debug)]
+pub struct Decryptor<C: BlockCipher, R: Read, P: Padding = PKCS7_Padding>
+{
+    chain: Chain<C>,
+    reader: R,
+    input: Vec<u8>,
+    output: Vec<u8>,
+    position: usize,
+    eof: bool,
+    error: Option<CipherError>,
+    padding: P,
+}
+
+impl<C: BlockCipher, R: Read> Decryptor<C, R>
+{
+    /// Constructs a new object Decryptor with PKCS #7 padding.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`.
+    /// - `reader` is the underlying reader from which the ciphertext is
+    ///   read.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C`.
+    pub fn new(cipher: C, mode: OperationMode, iv: &[u8], reader: R) -> Self
+    {
+        Self::new_with_padding(cipher, mode, iv, reader, PKCS7_Padding)
+    }
+}
+
+impl<C: BlockCipher, R: Read, P: Padding> Decryptor<C, R, P>
+{
+    const CHUNK: usize = 4096;
+
+    /// Constructs a new object Decryptor with the padding scheme `padding`.
+    ///
+    /// # Arguments
+    /// - `cipher` is the underlying block cipher with the key.
+    /// - `mode` is the mode of operation.
+    /// - `iv` is the initialization vector, or the nonce in CTR mode,
+    ///   which is as long as the block of `C`.
+    /// - `reader` is the underlying reader from which the ciphertext is
+    ///   read.
+    /// - `padding` is the padding scheme for ECB, CBC and PCBC modes. It is
+    ///   ignored in the other modes.
+    ///
+    /// # Panics
+    /// It panics if the length of `iv` is not `BLOCK_SIZE` of `C`.
+    pub fn new_with_padding(cipher: C, mode: OperationMode, iv: &[u8], reader: R, padding: P) -> Self
+    {
+        Self { chain: Chain::new(cipher, mode, iv), reader, input: Vec::new(), output: Vec::new(), position: 0, eof: false, error: None, padding }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R
+    {
+        self.reader
+    }
+
+    // Reads the next chunk of the ciphertext and decrypts as much of it as
+    // possible into `output`.
+    fn fill_output(&mut self) -> io::Result<()>
+    {
+        let size = C::BLOCK_SIZE;
+        self.output.clear();
+        self.position = 0;
+        while self.output.is_empty() && !self.eof
+        {
+            let filled = self.input.len();
+            self.input.resize(filled + Self::CHUNK, 0);
+            let read = match self.reader.read(&mut self.input[filled..])
+            {
+                Ok(read) => read,
+                Err(e) => {
+                    self.input.truncate(filled);
+                    if e.kind() == io::ErrorKind::Interrupted
+                        { continue; }
+                    return Err(e);
+                },
+            };
+            self.input.truncate(filled + read);
+            self.eof = read == 0;
+
+            let length = self.input.len();
+            let mut whole = length - length % size;
+            // The last block of the padded modes is kept until the end
+            // because it has the padding, and the last two blocks of the
+            // ciphertext stealing modes are kept until the end because the
+            // last block may be partial.
+            if self.chain.get_mode().is_stealing()
+                { whole = if self.eof { 0 } else { length.saturating_sub(size + 1) / size * size }; }
+            else if self.chain.get_mode().is_padded() && !self.eof && whole == length
+                { whole = whole.saturating_sub(size); }
+            let rest = self.input.split_off(whole);
+            let mut decrypted = std::mem::replace(&mut self.input, rest);
+            self.chain.decrypt_blocks(&mut decrypted);
+            self.output = decrypted;
+
+            if self.eof
+                { self.finish_output()?; }
+        }
+        Ok(())
+    }
+
+    // Removes the padding in the padded modes, or decrypts the last two
+    // blocks in the ciphertext stealing modes, or decrypts the last partial
+    // block in the other modes.
+    fn finish_output(&mut self) -> io::Result<()>
+    {
+        let result = if self.chain.get_mode().is_padded()
+        {
+            if !self.input.is_empty()
+                { Err(CipherError::BadLength) }
+            else
+                { self.padding.unpad(&self.output, C::BLOCK_SIZE) }
+        }
+        else if self.chain.get_mode().is_stealing() && self.input.len() < C::BLOCK_SIZE
+            { Err(CipherError::BadLength) }
+        else
+        {
+            let mut last = std::mem::take(&mut self.input);
+            self.chain.decrypt_all(&mut last);
+            self.output.extend_from_slice(&last);
+            Ok(self.output.len())
+        };
+        match result
+        {
+            Ok(length) => { self.output.truncate(length); },
+            Err(e) => {
+                // Every failure wipes the data held back and is kept so that
+                // every later call of `read()` returns the same error.
+                self.output.fill(0);
+                self.output.clear();
+                self.input.fill(0);
+                self.input.clear();
+                self.error = Some(e);
+                return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<C: BlockCipher, R: Read, P: Padding> Read for Decryptor<C, R, P>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        if let Some(e) = self.error
+            { return Err(io::Error::new(io::ErrorKind::InvalidData, e)); }
+        if self.position == self.output.len()
+            { self.fill_output()?; }
+        let take = (self.output.len() - self.position).min(buf.len());
+        buf[..take].copy_from_slice(&self.output[self.position..self.position + take]);
+        self.position += take;
+        Ok(take)
+    }
+}
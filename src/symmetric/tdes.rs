@@ -0,0 +1,191 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains Triple DES (TDEA) in the EDE mode
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::{ BlockCipher, DES };
+
+
+/// The official three-key Triple DES (TDEA) of NIST SP 800-67
+#[allow(non_camel_case_types)]
+pub type TDES = TDES_Generic<DES>;
+
+/// Triple DES (TDEA), which encrypts each block by encryption with `K1`,
+/// decryption with `K2` and encryption with `K3` (EDE)
+///
+/// # Introduction
+/// Triple DES applies DES three times in order to make the key longer
+/// without designing a new block cipher.
+///
+/// `C = E_K3(D_K2(E_K1(M)))`
+///
+/// The key of three-key Triple DES is 24 bytes long: `K1`, `K2` and `K3`
+/// in this order. It is the same layout as NIST SP 800-67 and OpenSSL
+/// (`des-ede3`) use. If `K1`, `K2` and `K3` are all the same, it is the
+/// same as single DES.
+///
+/// `TDES_Generic` has the trait `BlockCipher`. So, you can use it with
+/// `ModeCipher`, `Encryptor` and `Decryptor` in all the modes of operation,
+/// and with the other generic constructions such as `CMAC_Generic` and
+/// `KeyWrap_Generic`.
+///
+/// # Vulnerability
+/// The effective key length of three-key Triple DES is about 112 bits
+/// because of the meet-in-the-middle attack. Its block is only 64 bits
+/// long, so NIST SP 800-67 limits the data encrypted with the same key to
+/// 2^20 blocks. `K1` and `K2`, and `K2` and `K3` should be different,
+/// otherwise it degenerates into single DES. You can check the keys with
+/// `DES::check_multiple_keys()`. Use it only for the compatibility with
+/// the existing systems.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`, and
+///   whose block is 8 bytes long. It is `DES` for the official Triple DES,
+///   but it can be any expanded version of `DES_Generic`.
+///
+/// # Reference
+/// Read [NIST SP 800-67](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-67r2.pdf)
+/// and [more](https://en.wikipedia.org/wiki/Triple_DES) about Triple DES
+/// in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ TDES, ModeCipher, OperationMode, No_Padding };
+///
+/// let key = [ 0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+///             0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01,
+///             0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23 ];
+/// let iv = [0x12_u8, 0x34, 0x56, 0x78, 0x90, 0xAB, 0xCD, 0xEF];
+/// let mut tdes = ModeCipher::new_with_padding(TDES::new_with_key(key), OperationMode::CBC, &iv, No_Padding);
+/// let cipher = tdes.encrypt("Now is the time for all ".as_bytes());
+/// let mut txt = String::new();
+/// for c in cipher.iter()
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "F3C0FF026C023089656FBB169DEF7EDB30BA36075D6F0176");
+/// assert_eq!(tdes.decrypt(&cipher).unwrap(), "Now is the time for all ".as_bytes());
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct TDES_Generic<C: BlockCipher = DES>
+{
+    first: C,
+    second: C,
+    third: C,
+}
+
+impl TDES_Generic<DES>
+{
+    /// Constructs a new object TDES with the key of 24 bytes.
+    ///
+    /// # Arguments
+    /// `key` is the keys `K1`, `K2` and `K3` of DES in this order, each of
+    /// which is 8 bytes long.
+    pub fn new_with_key(key: [u8; 24]) -> Self
+    {
+        let mut keys = [[0_u8; 8]; 3];
+        for (k, chunk) in keys.iter_mut().zip(key.chunks(8))
+            { k.copy_from_slice(chunk); }
+        Self::new(DES::new_with_key(keys[0]), DES::new_with_key(keys[1]), DES::new_with_key(keys[2]))
+    }
+}
+
+impl<C: BlockCipher> TDES_Generic<C>
+{
+    /// Constructs a new object TDES_Generic.
+    ///
+    /// # Arguments
+    /// - `first` is the underlying block cipher with the key `K1`.
+    /// - `second` is the underlying block cipher with the key `K2`.
+    /// - `third` is the underlying block cipher with the key `K3`.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 8.
+    ///
+    /// # Example
+    /// ```
+    /// use cryptocol::symmetric::{ DES, TDES_Generic };
+    ///
+    /// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+    /// let mut tdes = TDES_Generic::new(DES::new_with_key(key), DES::new_with_key(key), DES::new_with_key(key));
+    /// // Triple DES with three same keys is the same as DES.
+    /// assert_eq!(tdes.encrypt_u64(0x_EFCDAB89_67452301_u64), DES::new_with_key(key).encrypt_u64(0x_EFCDAB89_67452301_u64));
+    /// ```
+    pub fn new(first: C, second: C, third: C) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 8, "TDES_Generic supports only 64-bit block ciphers.");
+        Self { first, second, third }
+    }
+
+    /// Encrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `message` is the block of `u64` in the same byte order as
+    /// `DES_Generic::encrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the encrypted block of `u64`.
+    pub fn encrypt_u64(&mut self, message: u64) -> u64
+    {
+        let mut block = message.to_le_bytes();
+        self.encrypt_one_block(&mut block);
+        u64::from_le_bytes(block)
+    }
+
+    /// Decrypts one block of `u64`.
+    ///
+    /// # Arguments
+    /// `cipher` is the block of `u64` in the same byte order as
+    /// `DES_Generic::decrypt_u64()`.
+    ///
+    /// # Output
+    /// It returns the decrypted block of `u64`.
+    pub fn decrypt_u64(&mut self, cipher: u64) -> u64
+    {
+        let mut block = cipher.to_le_bytes();
+        self.decrypt_one_block(&mut block);
+        u64::from_le_bytes(block)
+    }
+}
+
+impl<C: BlockCipher> BlockCipher for TDES_Generic<C>
+{
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        self.first.encrypt_one_block(block);
+        self.second.decrypt_one_block(block);
+        self.third.encrypt_one_block(block);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        self.third.decrypt_one_block(block);
+        self.second.encrypt_one_block(block);
+        self.first.decrypt_one_block(block);
+    }
+
+    fn encrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        self.first.encrypt_blocks(blocks);
+        self.second.decrypt_blocks(blocks);
+        self.third.encrypt_blocks(blocks);
+    }
+
+    fn decrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        self.third.decrypt_blocks(blocks);
+        self.second.encrypt_blocks(blocks);
+        self.first.decrypt_blocks(blocks);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains trait BlockCipher and trait KeyedBlockCipher
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+/// The supporting trait for the generic modes of operation such as
+/// `XTS_Generic`.
+///
+/// The generic modes of operation use whatever block cipher that has this
+/// trait as their underlying block cipher. So, if you plug in any block
+/// cipher that has this trait, for example `DES_Generic` or `AES_Generic`,
+/// to a generic mode of operation, the mode of operation will encrypt and
+/// decrypt data with the block cipher.
+///
+/// You will hardly use the methods of this trait directly except the case
+/// that you implement your own block cipher or your own mode of operation.
+///
+/// # Example
+/// ```
+/// use cryptocol::symmetric::{ BlockCipher, DES };
+///
+/// let key = [0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1];
+/// let mut a_des = DES::new_with_key(key);
+/// let mut block = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+/// assert_eq!(<DES as BlockCipher>::BLOCK_SIZE, 8);
+/// a_des.encrypt_one_block(&mut block);
+/// assert_eq!(block, [0x85_u8, 0xE8, 0x13, 0x54, 0x0F, 0x0A, 0xB4, 0x05]);
+/// a_des.decrypt_one_block(&mut block);
+/// assert_eq!(block, [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]);
+/// ```
+pub trait BlockCipher
+{
+    /// The size of one block in bytes. For example, it is `8` for DES and
+    /// `16` for AES.
+    const BLOCK_SIZE: usize;
+
+    // fn encrypt_one_block(&mut self, block: &mut [u8]);
+    /// Encrypts one block in place.
+    ///
+    /// # Argument
+    /// `block` is the slice of `BLOCK_SIZE` bytes. It will be overwritten
+    /// by the encrypted block.
+    ///
+    /// # Panics
+    /// It panics if the length of `block` is not `BLOCK_SIZE`.
+    fn encrypt_one_block(&mut self, block: &mut [u8]);
+
+    // fn decrypt_one_block(&mut self, block: &mut [u8]);
+    /// Decrypts one block in place.
+    ///
+    /// # Argument
+    /// `block` is the slice of `BLOCK_SIZE` bytes. It will be overwritten
+    /// by the decrypted block.
+    ///
+    /// # Panics
+    /// It panics if the length of `block` is not `BLOCK_SIZE`.
+    fn decrypt_one_block(&mut self, block: &mut [u8]);
+
+    // fn encrypt_blocks(&mut self, blocks: &mut [u8]);
+    /// Encrypts several blocks in place independently of one another.
+    ///
+    /// # Argument
+    /// `blocks` is the slice whose length is a multiple of `BLOCK_SIZE`.
+    /// It will be overwritten by the encrypted blocks.
+    ///
+    /// # Features
+    /// By default, it encrypts the blocks one by one with
+    /// `encrypt_one_block()`. The block ciphers that can encrypt many
+    /// blocks in parallel such as `DES_Bitsliced` override it. ECB mode and
+    /// CTR mode of `ModeCipher`, `Encryptor` and `Decryptor` use it.
+    ///
+    /// # Panics
+    /// It panics if the length of `blocks` is not a multiple of
+    /// `BLOCK_SIZE`.
+    fn encrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % Self::BLOCK_SIZE, 0);
+        for block in blocks.chunks_exact_mut(Self::BLOCK_SIZE)
+            { self.encrypt_one_block(block); }
+    }
+
+    // fn decrypt_blocks(&mut self, blocks: &mut [u8]);
+    /// Decrypts several blocks in place independently of one another.
+    ///
+    /// # Argument
+    /// `blocks` is the slice whose length is a multiple of `BLOCK_SIZE`.
+    /// It will be overwritten by the decrypted blocks.
+    ///
+    /// # Features
+    /// By default, it decrypts the blocks one by one with
+    /// `decrypt_one_block()`. The block ciphers that can decrypt many
+    /// blocks in parallel such as `DES_Bitsliced` override it. ECB mode of
+    /// `ModeCipher` and `Decryptor` uses it.
+    ///
+    /// # Panics
+    /// It panics if the length of `blocks` is not a multiple of
+    /// `BLOCK_SIZE`.
+    fn decrypt_blocks(&mut self, blocks: &mut [u8])
+    {
+        assert_eq!(blocks.len() % Self::BLOCK_SIZE, 0);
+        for block in blocks.chunks_exact_mut(Self::BLOCK_SIZE)
+            { self.decrypt_one_block(block); }
+    }
+}
+
+
+/// The supporting trait for the constructions that change the key of the
+/// underlying block cipher very often, such as the hash functions based on
+/// block ciphers, for example `BlockCipherHash_Generic` and `MDC2_Generic`.
+///
+/// Those constructions use not only the data but also the previous hash
+/// value as the key of the block cipher. So, they need to set the key of
+/// whatever block cipher that has this trait from a slice of bytes.
+///
+/// # Example
+/// ```
+/// use cryptocol::symmetric::{ BlockCipher, KeyedBlockCipher, DES };
+///
+/// let mut a_des = DES::new();
+/// assert_eq!(<DES as KeyedBlockCipher>::KEY_SIZE, 8);
+/// a_des.set_key_slice(&[0x13_u8, 0x34, 0x57, 0x79, 0x9B, 0xBC, 0xDF, 0xF1]);
+/// let mut block = [0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
+/// a_des.encrypt_one_block(&mut block);
+/// assert_eq!(block, [0x85_u8, 0xE8, 0x13, 0x54, 0x0F, 0x0A, 0xB4, 0x05]);
+/// ```
+pub trait KeyedBlockCipher: BlockCipher
+{
+    /// The size of the key in bytes. For example, it is `8` for DES and
+    /// `16` for AES-128.
+    const KEY_SIZE: usize;
+
+    // fn set_key_slice(&mut self, key: &[u8]);
+    /// Sets the key and makes the round keys.
+    ///
+    /// # Argument
+    /// `key` is the slice of `KEY_SIZE` bytes.
+    ///
+    /// # Panics
+    /// It panics if the length of `key` is not `KEY_SIZE`.
+    fn set_key_slice(&mut self, key: &[u8]);
+}
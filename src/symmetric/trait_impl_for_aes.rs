@@ -0,0 +1,49 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains implementation of trait BlockCipher and trait
+//! KeyedBlockCipher for AES_Generic
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use crate::symmetric::{ BlockCipher, KeyedBlockCipher, AES_Generic };
+
+
+impl<const NK: usize, const ROUND: usize> BlockCipher for AES_Generic<NK, ROUND>
+{
+    const BLOCK_SIZE: usize = 16;
+
+    fn encrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.encrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+
+    fn decrypt_one_block(&mut self, block: &mut [u8])
+    {
+        let mut buf = [0_u8; 16];
+        buf.copy_from_slice(block);
+        self.decrypt_block(&mut buf);
+        block.copy_from_slice(&buf);
+    }
+}
+
+impl<const NK: usize, const ROUND: usize> KeyedBlockCipher for AES_Generic<NK, ROUND>
+{
+    const KEY_SIZE: usize = NK * 4;
+
+    #[inline]
+    fn set_key_slice(&mut self, key: &[u8])
+    {
+        self.set_key(key);
+    }
+}
@@ -0,0 +1,311 @@
+// Copyright 2024 PARK Youngho.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The module that contains XTS mode of operation for sector-based storage
+//! encryption
+
+// #![warn(missing_docs)]
+// #![warn(rustdoc::missing_doc_code_examples)]
+
+
+use std::ptr::copy;
+use std::slice::from_raw_parts_mut;
+use std::vec::Vec;
+
+use crate::symmetric::{ BlockCipher, AES_128, AES_256 };
+
+
+/// XTS-AES-128 defined in IEEE 1619 which uses two AES-128 keys
+#[allow(non_camel_case_types)]
+pub type XTS_AES_128 = XTS_Generic<AES_128>;
+
+/// XTS-AES-256 defined in IEEE 1619 which uses two AES-256 keys
+#[allow(non_camel_case_types)]
+pub type XTS_AES_256 = XTS_Generic<AES_256>;
+
+/// XTS (XEX-based tweaked-codebook mode with ciphertext stealing) mode of
+/// operation on top of any block cipher that has the trait `BlockCipher`
+///
+/// # Introduction
+/// XTS is the mode of operation for the encryption of data on storage
+/// devices such as disk sectors. The ciphertext is exactly as long as the
+/// plaintext, and each sector can be encrypted and decrypted independently
+/// of the other sectors. The sector number is encrypted with the tweak key
+/// to make the initial tweak, and the tweak is multiplied by the primitive
+/// element `α` in GF(2^128) for each block. When the length of the sector
+/// is not a multiple of the block size, the last partial block is handled
+/// by ciphertext stealing. So, the sector should be at least one block
+/// long. IEEE 1619 defines XTS only for 128-bit block ciphers, so that
+/// `XTS_Generic` does not accept 64-bit block ciphers such as DES.
+///
+/// # Vulnerability
+/// - XTS provides confidentiality but does not provide authenticity. An
+///   attacker who can modify the disk image can replace a block with an
+///   older version of the same block at the same position, or randomize a
+///   block.
+/// - The data key and the tweak key must be different from each other and
+///   independent of each other.
+///
+/// # Generic Parameters
+/// - C: the underlying block cipher which has the trait `BlockCipher`.
+///   Its block size should be 16 bytes.
+///
+/// # Reference
+/// Read [IEEE 1619](https://en.wikipedia.org/wiki/IEEE_P1619) and
+/// [NIST SP 800-38E](https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38e.pdf)
+/// about XTS in detail.
+///
+/// # Quick Start
+/// ```
+/// use std::fmt::Write;
+/// use cryptocol::symmetric::{ AES_128, XTS_AES_128 };
+///
+/// let mut key1 = [0_u8; 16];
+/// let mut key2 = [0_u8; 16];
+/// for i in 0..16
+/// {
+///     key1[i] = i as u8;
+///     key2[i] = 16 + i as u8;
+/// }
+/// let mut xts = XTS_AES_128::new(AES_128::new_with_key(&key1), AES_128::new_with_key(&key2));
+/// let message = "The quick brown fox jumps over the lazy dog.";
+/// let mut cipher = Vec::<u8>::new();
+/// xts.encrypt_sector_into_vec(5, message.as_ptr(), message.len() as u64, &mut cipher);
+/// let mut txt = String::new();
+/// for c in cipher.clone()
+///     { write!(txt, "{:02X}", c); }
+/// assert_eq!(txt, "586D0F12AFA755D597E8FF774597E33687A36B70F6E17F4B0A76AA13C7D7E292F9F40514B24DE3A1A812F0B1");
+///
+/// let mut recovered = Vec::<u8>::new();
+/// xts.decrypt_sector_into_vec(5, cipher.as_ptr(), cipher.len() as u64, &mut recovered);
+/// assert_eq!(String::from_utf8(recovered).unwrap(), message);
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct XTS_Generic<C: BlockCipher>
+{
+    data_cipher: C,
+    tweak_cipher: C,
+}
+
+impl<C: BlockCipher> XTS_Generic<C>
+{
+    /// Constructs a new object XTS_Generic.
+    ///
+    /// # Arguments
+    /// - `data_cipher` is the block cipher with the data key, which is
+    ///   `Key1` in IEEE 1619.
+    /// - `tweak_cipher` is the block cipher with the tweak key, which is
+    ///   `Key2` in IEEE 1619.
+    ///
+    /// # Panics
+    /// It panics if the block size of `C` is not 16.
+    pub fn new(data_cipher: C, tweak_cipher: C) -> Self
+    {
+        assert_eq!(C::BLOCK_SIZE, 16, "XTS_Generic supports only 128-bit block ciphers.");
+        Self { data_cipher, tweak_cipher }
+    }
+
+    /// Encrypts one sector.
+    ///
+    /// # Arguments
+    /// - `sector` is the sector number, that is, the data unit sequence
+    ///   number of IEEE 1619. It is encoded in little endianness to be the
+    ///   tweak.
+    /// - `message` is the pointer to the sector to be encrypted.
+    /// - `length_in_bytes` is the size of the sector in bytes. It should be
+    ///   at least the block size of `C`.
+    /// - `cipher` is the pointer to the buffer of at least `length_in_bytes`
+    ///   bytes. It may be the same as `message` for in-place encryption.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success, and returns `0` if
+    /// `length_in_bytes` is less than the block size.
+    pub fn encrypt_sector(&mut self, sector: u128, message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        if (length_in_bytes as usize) < C::BLOCK_SIZE
+            { return 0; }
+        let data = Self::prepare(message, length_in_bytes, cipher);
+        self.crypt_sector(sector, data, true);
+        length_in_bytes
+    }
+
+    /// Decrypts one sector.
+    ///
+    /// # Arguments
+    /// - `sector` is the sector number that was used for encryption.
+    /// - `cipher` is the pointer to the sector to be decrypted.
+    /// - `length_in_bytes` is the size of the sector in bytes. It should be
+    ///   at least the block size of `C`.
+    /// - `message` is the pointer to the buffer of at least
+    ///   `length_in_bytes` bytes. It may be the same as `cipher`.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success, and returns `0` if
+    /// `length_in_bytes` is less than the block size.
+    pub fn decrypt_sector(&mut self, sector: u128, cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        if (length_in_bytes as usize) < C::BLOCK_SIZE
+            { return 0; }
+        let data = Self::prepare(cipher, length_in_bytes, message);
+        self.crypt_sector(sector, data, false);
+        length_in_bytes
+    }
+
+    /// Encrypts one sector into `Vec<u8>`.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success. It returns `0` and leaves
+    /// `cipher` empty if `length_in_bytes` is less than the block size.
+    pub fn encrypt_sector_into_vec(&mut self, sector: u128, message: *const u8, length_in_bytes: u64, cipher: &mut Vec<u8>) -> u64
+    {
+        cipher.clear();
+        if (length_in_bytes as usize) < C::BLOCK_SIZE
+            { return 0; }
+        cipher.resize(length_in_bytes as usize, 0);
+        self.encrypt_sector(sector, message, length_in_bytes, cipher.as_mut_ptr())
+    }
+
+    /// Decrypts one sector into `Vec<u8>`.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success. It returns `0` and leaves
+    /// `message` empty if `length_in_bytes` is less than the block size.
+    pub fn decrypt_sector_into_vec(&mut self, sector: u128, cipher: *const u8, length_in_bytes: u64, message: &mut Vec<u8>) -> u64
+    {
+        message.clear();
+        if (length_in_bytes as usize) < C::BLOCK_SIZE
+            { return 0; }
+        message.resize(length_in_bytes as usize, 0);
+        self.decrypt_sector(sector, cipher, length_in_bytes, message.as_mut_ptr())
+    }
+
+    /// Encrypts consecutive sectors such as a whole disk image.
+    ///
+    /// # Arguments
+    /// - `first_sector` is the sector number of the first sector.
+    /// - `sector_size` is the size of each sector in bytes. The sectors are
+    ///   numbered `first_sector`, `first_sector + 1`, and so on.
+    /// - `message` is the pointer to the data to be encrypted.
+    /// - `length_in_bytes` is the size of the data in bytes. The last sector
+    ///   may be shorter than `sector_size`, but it should be at least the
+    ///   block size of `C`.
+    /// - `cipher` is the pointer to the buffer of at least `length_in_bytes`
+    ///   bytes. It may be the same as `message`.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success, and returns `0` without
+    /// touching `cipher` if `sector_size` or the last sector is shorter
+    /// than the block size.
+    pub fn encrypt_sectors(&mut self, first_sector: u128, sector_size: u64, message: *const u8, length_in_bytes: u64, cipher: *mut u8) -> u64
+    {
+        if !Self::is_valid_layout(sector_size, length_in_bytes)
+            { return 0; }
+        let data = Self::prepare(message, length_in_bytes, cipher);
+        let mut sector = first_sector;
+        for chunk in data.chunks_mut(sector_size as usize)
+        {
+            self.crypt_sector(sector, chunk, true);
+            sector = sector.wrapping_add(1);
+        }
+        length_in_bytes
+    }
+
+    /// Decrypts consecutive sectors such as a whole disk image.
+    ///
+    /// # Output
+    /// It returns `length_in_bytes` on success, and returns `0` without
+    /// touching `message` if `sector_size` or the last sector is shorter
+    /// than the block size.
+    pub fn decrypt_sectors(&mut self, first_sector: u128, sector_size: u64, cipher: *const u8, length_in_bytes: u64, message: *mut u8) -> u64
+    {
+        if !Self::is_valid_layout(sector_size, length_in_bytes)
+            { return 0; }
+        let data = Self::prepare(cipher, length_in_bytes, message);
+        let mut sector = first_sector;
+        for chunk in data.chunks_mut(sector_size as usize)
+        {
+            self.crypt_sector(sector, chunk, false);
+            sector = sector.wrapping_add(1);
+        }
+        length_in_bytes
+    }
+
+    fn is_valid_layout(sector_size: u64, length_in_bytes: u64) -> bool
+    {
+        let block_size = C::BLOCK_SIZE as u64;
+        if sector_size < block_size || length_in_bytes < block_size
+            { return false; }
+        let last = length_in_bytes % sector_size;
+        last == 0 || last >= block_size
+    }
+
+    // Copies the source into the destination, which may overlap with the
+    // source, so that the sector can be processed in place.
+    fn prepare<'a>(source: *const u8, length_in_bytes: u64, destination: *mut u8) -> &'a mut [u8]
+    {
+        unsafe {
+            copy(source, destination, length_in_bytes as usize);
+            from_raw_parts_mut(destination, length_in_bytes as usize)
+        }
+    }
+
+    fn crypt_sector(&mut self, sector: u128, data: &mut [u8], encrypting: bool)
+    {
+        let size = C::BLOCK_SIZE;
+        let mut tweak = sector.to_le_bytes().to_vec();
+        self.tweak_cipher.encrypt_one_block(&mut tweak);
+
+        let rest = data.len() % size;
+        let normal = if rest == 0 { data.len() / size } else { data.len() / size - 1 };
+        for block in data[..normal * size].chunks_exact_mut(size)
+        {
+            self.crypt_block(block, &tweak, encrypting);
+            Self::multiply_by_alpha(&mut tweak);
+        }
+        if rest == 0
+            { return; }
+
+        // Ciphertext stealing: the last full block is processed with the
+        // later tweak when decrypting, so the order of the tweaks swaps.
+        let mut next_tweak = tweak.clone();
+        Self::multiply_by_alpha(&mut next_tweak);
+        let (first, second) = if encrypting { (&tweak, &next_tweak) } else { (&next_tweak, &tweak) };
+        let (last, partial) = data[normal * size..].split_at_mut(size);
+        self.crypt_block(last, first, encrypting);
+        for i in 0..rest
+            { std::mem::swap(&mut last[i], &mut partial[i]); }
+        self.crypt_block(last, second, encrypting);
+    }
+
+    fn crypt_block(&mut self, block: &mut [u8], tweak: &[u8], encrypting: bool)
+    {
+        for (b, t) in block.iter_mut().zip(tweak.iter())
+            { *b ^= *t; }
+        if encrypting
+            { self.data_cipher.encrypt_one_block(block); }
+        else
+            { self.data_cipher.decrypt_one_block(block); }
+        for (b, t) in block.iter_mut().zip(tweak.iter())
+            { *b ^= *t; }
+    }
+
+    // Multiplies the tweak by α in GF(2^128) modulo x^128 + x^7 + x^2 + x + 1.
+    // The first byte is the least significant byte.
+    fn multiply_by_alpha(tweak: &mut [u8])
+    {
+        let mut carry = 0_u8;
+        for t in tweak.iter_mut()
+        {
+            let next_carry = *t >> 7;
+            *t = (*t << 1) | carry;
+            carry = next_carry;
+        }
+        tweak[0] ^= 0x87 & 0_u8.wrapping_sub(carry);
+    }
+}